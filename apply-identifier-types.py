@@ -0,0 +1,150 @@
+#!/usr/bin/env python3
+"""
+Post-processing script to replace plain String identifier fields in generated Rust code
+with the validated newtypes from `crate::identifiers`.
+
+The wire format is unchanged (the newtypes serialize as plain strings); the generated
+pattern checks are replaced with a call to the newtype's own `Validate` implementation,
+and the amount types get a check of their `@Ccy` attribute.
+"""
+
+import re
+import sys
+from pathlib import Path
+
+# (field name, XML element name, newtype)
+IDENTIFIER_FIELDS = [
+    ("bicfi", "BICFI", "Bic"),
+    ("any_bic", "AnyBIC", "Bic"),
+    ("iban", "IBAN", "Iban"),
+    ("ccy", "Ccy", "CurrencyCode"),
+    ("src_ccy", "SrcCcy", "CurrencyCode"),
+    ("trgt_ccy", "TrgtCcy", "CurrencyCode"),
+]
+
+
+def migrate_field(content: str, field: str, element: str, newtype: str) -> str:
+    # Field declarations
+    content = re.sub(
+        rf"pub {field}: Option<String>,", f"pub {field}: Option<{newtype}>,", content
+    )
+    content = re.sub(rf"pub {field}: String,", f"pub {field}: {newtype},", content)
+
+    # Optional field pattern check
+    content = re.sub(
+        rf"( *)if let Some\(ref val\) = self\.{field} \{{\n"
+        rf" *helpers::validate_pattern\(\n"
+        rf" *val,\n"
+        rf" *\"{element}\",\n"
+        rf" *\".*\",\n"
+        rf" *&helpers::child_path\(path, \"{element}\"\),\n"
+        rf" *config,\n"
+        rf" *collector,\n"
+        rf" *\);\n"
+        rf" *\}}\n",
+        lambda m: (
+            f"{m.group(1)}if let Some(ref val) = self.{field} {{\n"
+            f"{m.group(1)}    val.validate(&helpers::child_path(path, \"{element}\"), config, collector);\n"
+            f"{m.group(1)}}}\n"
+        ),
+        content,
+    )
+
+    # Mandatory field pattern check
+    content = re.sub(
+        rf"( *)helpers::validate_pattern\(\n"
+        rf" *&self\.{field},\n"
+        rf" *\"{element}\",\n"
+        rf" *\".*\",\n"
+        rf" *&helpers::child_path\(path, \"{element}\"\),\n"
+        rf" *config,\n"
+        rf" *collector,\n"
+        rf" *\);\n",
+        lambda m: (
+            f"{m.group(1)}self.{field}\n"
+            f"{m.group(1)}    .validate(&helpers::child_path(path, \"{element}\"), config, collector);\n"
+        ),
+        content,
+    )
+    return content
+
+
+def validate_amount_currency(content: str) -> str:
+    """Check the `@Ccy` attribute of the amount types, whose generated validate is empty.
+
+    `ActiveOrHistoric*` amounts accept historic ISO 4217 codes; every other amount
+    type (`ActiveCurrencyAndAmount`, the CBPR+ amounts) only accepts active ones.
+    """
+    amount_types = set(
+        re.findall(r"pub struct (\w+) \{\n *#\[serde\(rename = \"@Ccy\"\)\]", content)
+    )
+
+    def replace(m: re.Match) -> str:
+        name = m.group(1)
+        if name not in amount_types:
+            return m.group(0)
+        check = "validate" if name.startswith("ActiveOrHistoric") else "validate_active"
+        return (
+            f"impl Validate for {name} {{\n"
+            f"    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {{\n"
+            f"        self.ccy\n"
+            f"            .{check}(&helpers::child_path(path, \"Ccy\"), config, collector);\n"
+            f"    }}\n"
+            f"}}\n"
+        )
+
+    return re.sub(
+        r"impl Validate for (\w+) \{\n"
+        r" *fn validate\(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector\) \{\}\n"
+        r"\}\n",
+        replace,
+        content,
+    )
+
+
+def add_imports(content: str) -> str:
+    used = sorted({newtype for _, _, newtype in IDENTIFIER_FIELDS if re.search(rf"\b{newtype}\b", content)})
+    if not used:
+        return content
+    import_line = (
+        f"use crate::identifiers::{used[0]};\n"
+        if len(used) == 1
+        else f"use crate::identifiers::{{{', '.join(used)}}};\n"
+    )
+    if import_line in content:
+        return content
+    content = re.sub(r"use crate::identifiers::.*;\n", "", content)
+    return re.sub(r"(use crate::parse_result::)", import_line + r"\1", content, count=1)
+
+
+def process_file(path: Path) -> bool:
+    original = path.read_text()
+    content = original
+    for field, element, newtype in IDENTIFIER_FIELDS:
+        content = migrate_field(content, field, element, newtype)
+    content = validate_amount_currency(content)
+    content = add_imports(content)
+    if content != original:
+        path.write_text(content)
+        return True
+    return False
+
+
+def main():
+    if len(sys.argv) < 2:
+        print("Usage: apply-identifier-types.py <dir> [<dir> ...]")
+        sys.exit(1)
+
+    changed = 0
+    for directory in sys.argv[1:]:
+        for path in sorted(Path(directory).glob("*.rs")):
+            if path.name == "mod.rs":
+                continue
+            if process_file(path):
+                changed += 1
+                print(f"Updated identifier types in {path}")
+    print(f"Updated {changed} files")
+
+
+if __name__ == "__main__":
+    main()
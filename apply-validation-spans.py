@@ -0,0 +1,46 @@
+#!/usr/bin/env python3
+"""
+Post-processing script to open a trace span at the start of every generated
+`Validate::validate` implementation that walks nested elements.
+
+The span helper compiles to nothing unless the `trace-validation` feature is
+enabled. Implementations that ignore their path (leaf types) are left as is.
+Running the script again does not add a second span.
+"""
+
+import re
+import sys
+from pathlib import Path
+
+VALIDATE_FN = re.compile(
+    r"^( *)fn validate\(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector\) \{\n"
+    r"(?!\1    let _span = helpers::validation_span)",
+    re.MULTILINE,
+)
+
+
+def add_spans(content: str) -> str:
+    return VALIDATE_FN.sub(
+        lambda m: (
+            m.group(0)
+            + f"{m.group(1)}    let _span = helpers::validation_span::<Self>(path);\n"
+        ),
+        content,
+    )
+
+
+def main() -> None:
+    if len(sys.argv) < 2:
+        print(f"usage: {sys.argv[0]} <directory>...")
+        sys.exit(1)
+    for directory in sys.argv[1:]:
+        for path in sorted(Path(directory).glob("*.rs")):
+            content = path.read_text()
+            updated = add_spans(content)
+            if updated != content:
+                path.write_text(updated)
+                print(f"Added validation spans: {path}")
+
+
+if __name__ == "__main__":
+    main()
@@ -34,11 +34,10 @@ fn main() {
     println!("\n2. Testing fail_fast mode:");
 
     // Create a config with fail_fast enabled
-    let fail_fast_config = ParserConfig {
-        fail_fast: true,
-        validate_optional_fields: false,
-        collect_all_errors: false,
-    };
+    let fail_fast_config = ParserConfig::default()
+        .with_fail_fast(true)
+        .with_optional_field_validation(false)
+        .with_collect_all_errors(false);
 
     // Validate with fail_fast - should stop at first error
     let mut collector2 = ErrorCollector::new();
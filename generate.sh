@@ -7,5 +7,13 @@ sh generate-module.sh xsd/header/ src/header/
 echo "Applying Box wrapping to prevent stack overflow..."
 python3 apply-box-wrapping.py src/document/ --config box-config.json
 
+# Replace identifier strings with the validated newtypes
+echo "Applying identifier newtypes..."
+python3 apply-identifier-types.py src/document/ src/header/
+
+# Open a trace span in every generated validate() (feature "trace-validation")
+echo "Applying validation spans..."
+python3 apply-validation-spans.py src/document/ src/header/
+
 cargo clippy --fix --allow-dirty
 cargo fmt
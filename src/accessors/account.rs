@@ -0,0 +1,270 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Account identification accessors
+
+use crate::document::*;
+use crate::identifiers::CurrencyCode;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Scheme reported for accounts identified by IBAN
+pub const IBAN_SCHEME: &str = "IBAN";
+
+/// Number of trailing characters left readable by `masked_account_identifier`
+const VISIBLE_SUFFIX_LENGTH: usize = 4;
+
+/// IBAN country code and check digits
+const IBAN_PREFIX_LENGTH: usize = 4;
+
+const MASK_CHAR: char = '*';
+
+/// Account the settlement is booked on (`SttlmInf/SttlmAcct`)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SettlementAccount {
+    /// The IBAN, or the `Othr/Id` if no IBAN is given
+    pub identifier: String,
+    /// Currency of the account (`Ccy`), if given
+    pub currency: Option<CurrencyCode>,
+}
+
+/// Implement the identifier accessors on the `AccountIdentification4Choice` variants of each message module
+macro_rules! impl_account_identification {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// The IBAN, or the `Othr/Id` if no IBAN is given
+                /// An IBAN in paper format is returned without its spaces.
+                pub fn account_identifier(&self) -> Cow<'_, str> {
+                    match (&self.iban, &self.othr) {
+                        (Some(iban), _) => electronic_format(iban.as_str()),
+                        (None, Some(othr)) => Cow::Borrowed(othr.id.as_str()),
+                        (None, None) => Cow::Borrowed(""),
+                    }
+                }
+
+                /// Account identifier for display, with all but the last four characters masked
+                /// With `keep_iban_prefix`, an IBAN also keeps its country code and check digits.
+                /// Identifiers of four characters or less are masked entirely.
+                pub fn masked_account_identifier(&self, keep_iban_prefix: bool) -> String {
+                    let prefix = if keep_iban_prefix && self.iban.is_some() {
+                        IBAN_PREFIX_LENGTH
+                    } else {
+                        0
+                    };
+                    mask(&self.account_identifier(), prefix)
+                }
+
+                /// Identification scheme of the account
+                /// "IBAN" for IBAN accounts, otherwise `Othr/SchmeNm/Cd` falling back to `Othr/SchmeNm/Prtry`.
+                pub fn scheme(&self) -> Option<&str> {
+                    if self.iban.is_some() {
+                        return Some(IBAN_SCHEME);
+                    }
+                    let schme_nm = self.othr.as_ref()?.schme_nm.as_ref()?;
+                    schme_nm.cd.as_deref().or(schme_nm.prtry.as_deref())
+                }
+            }
+        )*
+    };
+}
+
+fn mask(identifier: &str, prefix: usize) -> String {
+    let len = identifier.chars().count();
+    let visible_tail = if len > VISIBLE_SUFFIX_LENGTH {
+        VISIBLE_SUFFIX_LENGTH
+    } else {
+        0
+    };
+    let prefix = prefix.min(len - visible_tail);
+    identifier
+        .chars()
+        .enumerate()
+        .map(|(idx, c)| {
+            if idx < prefix || idx >= len - visible_tail {
+                c
+            } else {
+                MASK_CHAR
+            }
+        })
+        .collect()
+}
+
+fn electronic_format(iban: &str) -> Cow<'_, str> {
+    if iban.contains(char::is_whitespace) {
+        Cow::Owned(iban.split_whitespace().collect())
+    } else {
+        Cow::Borrowed(iban)
+    }
+}
+
+impl_account_identification!(
+    camt_052_001_08::AccountIdentification4Choice1,
+    camt_053_001_08::AccountIdentification4Choice1,
+    camt_054_001_08::AccountIdentification4Choice1,
+    camt_057_001_06::AccountIdentification4Choice1,
+    camt_060_001_05::AccountIdentification4Choice1,
+    camt_105_001_02::AccountIdentification4Choice1,
+    camt_105_001_02_mc::AccountIdentification4Choice1,
+    camt_106_001_02::AccountIdentification4Choice1,
+    camt_106_001_02_mc::AccountIdentification4Choice1,
+    camt_107_001_01::AccountIdentification4Choice1,
+    camt_108_001_01::AccountIdentification4Choice1,
+    camt_109_001_01::AccountIdentification4Choice1,
+    pacs_003_001_08::AccountIdentification4Choice1,
+    pacs_003_001_08::AccountIdentification4Choice2,
+    pacs_004_001_09::AccountIdentification4Choice,
+    pacs_004_001_09::AccountIdentification4Choice1,
+    pacs_008_001_08::AccountIdentification4Choice1,
+    pacs_008_001_08_stp::AccountIdentification4Choice1,
+    pacs_009_001_08::AccountIdentification4Choice1,
+    pacs_009_001_08_adv::AccountIdentification4Choice1,
+    pacs_009_001_08_cov::AccountIdentification4Choice1,
+    pacs_010_001_03::AccountIdentification4Choice1,
+    pacs_010_001_03_mc::AccountIdentification4Choice1,
+    pain_001_001_09::AccountIdentification4Choice,
+    pain_008_001_08::AccountIdentification4Choice,
+);
+
+/// Implement `settlement_account()` on the settlement instructions carrying `SttlmAcct`
+macro_rules! impl_settlement_account {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Settlement account identifier and currency, used to match nostro/vostro bookings
+                pub fn settlement_account(&self) -> Option<SettlementAccount> {
+                    let acct = self.sttlm_acct.as_ref()?;
+                    Some(SettlementAccount {
+                        identifier: acct.id.account_identifier().into_owned(),
+                        currency: acct.ccy.clone(),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_settlement_account!(
+    pacs_008_001_08::SettlementInstruction71,
+    pacs_008_001_08_stp::SettlementInstruction71,
+    pacs_009_001_08::SettlementInstruction71,
+    pacs_009_001_08_cov::SettlementInstruction71,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pacs_008_001_08::{
+        AccountIdentification4Choice1, AccountSchemeName1Choice1, GenericAccountIdentification11,
+    };
+
+    #[test]
+    fn test_iban_account() {
+        let account = AccountIdentification4Choice1 {
+            iban: Some("DE89370400440532013000".into()),
+            othr: None,
+        };
+        assert_eq!(account.account_identifier(), "DE89370400440532013000");
+        assert!(matches!(account.account_identifier(), Cow::Borrowed(_)));
+        assert_eq!(account.scheme(), Some("IBAN"));
+
+        let paper = AccountIdentification4Choice1 {
+            iban: Some("DE89 3704 0044 0532 0130 00".into()),
+            othr: None,
+        };
+        assert_eq!(paper.account_identifier(), "DE89370400440532013000");
+    }
+
+    #[test]
+    fn test_other_account() {
+        let mut account = AccountIdentification4Choice1 {
+            iban: None,
+            othr: Some(GenericAccountIdentification11 {
+                id: "123456789".to_string(),
+                schme_nm: Some(AccountSchemeName1Choice1 {
+                    cd: Some("BBAN".to_string()),
+                    prtry: None,
+                }),
+                issr: None,
+            }),
+        };
+        assert_eq!(account.account_identifier(), "123456789");
+        assert_eq!(account.scheme(), Some("BBAN"));
+
+        account.othr.as_mut().unwrap().schme_nm = None;
+        assert_eq!(account.scheme(), None);
+        assert_eq!(
+            AccountIdentification4Choice1::default().account_identifier(),
+            ""
+        );
+    }
+
+    #[test]
+    fn test_masked_iban() {
+        let account = AccountIdentification4Choice1 {
+            iban: Some("DE89 3704 0044 0532 0130 00".into()),
+            othr: None,
+        };
+        assert_eq!(
+            account.masked_account_identifier(false),
+            "******************3000"
+        );
+        assert_eq!(
+            account.masked_account_identifier(true),
+            "DE89**************3000"
+        );
+    }
+
+    #[test]
+    fn test_masked_other_account() {
+        let mut account = AccountIdentification4Choice1 {
+            iban: None,
+            othr: Some(GenericAccountIdentification11 {
+                id: "12345555".to_string(),
+                ..Default::default()
+            }),
+        };
+        assert_eq!(account.masked_account_identifier(true), "****5555");
+
+        account.othr.as_mut().unwrap().id = "5555".to_string();
+        assert_eq!(account.masked_account_identifier(false), "****");
+    }
+
+    #[test]
+    fn test_settlement_account() {
+        use pacs_009_001_08::{
+            AccountIdentification4Choice1, CashAccount381, SettlementInstruction71,
+        };
+
+        let mut sttlm_inf = SettlementInstruction71::default();
+        assert_eq!(sttlm_inf.settlement_account(), None);
+
+        sttlm_inf.sttlm_acct = Some(CashAccount381 {
+            id: AccountIdentification4Choice1 {
+                iban: Some("GB29 NWBK 6016 1331 9268 19".into()),
+                othr: None,
+            },
+            ccy: Some("GBP".into()),
+            ..Default::default()
+        });
+        let account = sttlm_inf.settlement_account().unwrap();
+        assert_eq!(account.identifier, "GB29NWBK60161331926819");
+        assert_eq!(account.currency.as_deref(), Some("GBP"));
+    }
+}
@@ -0,0 +1,193 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Structured postal address accessors
+//!
+//! Screening and matching work on the structured address elements rather than
+//! on free-form address lines. `structured()` collects them into one typed
+//! `Address`, whatever variant of `PostalAddress24` the message uses. Address
+//! lines (`AdrLine`) are left out; they stay available on the source type.
+
+use crate::document::*;
+
+/// Structured elements of a postal address
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Address {
+    /// Street name (`StrtNm`)
+    pub street: Option<String>,
+    /// Building number (`BldgNb`), or the building name (`BldgNm`) when there is no number
+    pub building: Option<String>,
+    /// Post code (`PstCd`)
+    pub postcode: Option<String>,
+    /// Town name (`TwnNm`)
+    pub town: Option<String>,
+    /// Country subdivision, e.g. state or province (`CtrySubDvsn`)
+    pub country_subdivision: Option<String>,
+    /// ISO 3166 country code (`Ctry`)
+    pub country: Option<String>,
+}
+
+impl Address {
+    /// Whether none of the structured elements is present
+    pub fn is_empty(&self) -> bool {
+        *self == Address::default()
+    }
+}
+
+/// Address element that is optional in some variants and mandatory in others
+trait AddressElement {
+    fn text(&self) -> Option<String>;
+}
+
+impl AddressElement for String {
+    fn text(&self) -> Option<String> {
+        let trimmed = self.trim();
+        (!trimmed.is_empty()).then(|| trimmed.to_string())
+    }
+}
+
+impl AddressElement for Option<String> {
+    fn text(&self) -> Option<String> {
+        self.as_ref().and_then(AddressElement::text)
+    }
+}
+
+/// Implement `structured()` on the `PostalAddress24` variants
+macro_rules! impl_structured_address {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Structured address elements, without the address lines
+                pub fn structured(&self) -> Address {
+                    Address {
+                        street: self.strt_nm.text(),
+                        building: self.bldg_nb.text().or_else(|| self.bldg_nm.text()),
+                        postcode: self.pst_cd.text(),
+                        town: self.twn_nm.text(),
+                        country_subdivision: self.ctry_sub_dvsn.text(),
+                        country: self.ctry.text(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_structured_address!(
+    camt_029_001_09::PostalAddress241,
+    camt_052_001_08::PostalAddress241,
+    camt_052_001_08::PostalAddress242,
+    camt_053_001_08::PostalAddress241,
+    camt_053_001_08::PostalAddress242,
+    camt_054_001_08::PostalAddress241,
+    camt_054_001_08::PostalAddress242,
+    camt_054_001_08::PostalAddress243,
+    camt_054_001_08::PostalAddress244,
+    camt_055_001_08::PostalAddress241,
+    camt_055_001_08::PostalAddress242,
+    camt_056_001_08::PostalAddress241,
+    camt_057_001_06::PostalAddress241,
+    camt_058_001_08::PostalAddress241,
+    camt_060_001_05::PostalAddress241,
+    camt_060_001_05::PostalAddress242,
+    camt_107_001_01::PostalAddress241,
+    camt_107_001_01::PostalAddress242,
+    camt_108_001_01::PostalAddress241,
+    camt_109_001_01::PostalAddress241,
+    pacs_002_001_10::PostalAddress241,
+    pacs_003_001_08::PostalAddress241,
+    pacs_003_001_08::PostalAddress242,
+    pacs_003_001_08::PostalAddress243,
+    pacs_003_001_08::PostalAddress244,
+    pacs_003_001_08::PostalAddress245,
+    pacs_004_001_09::PostalAddress241,
+    pacs_004_001_09::PostalAddress242,
+    pacs_004_001_09::PostalAddress243,
+    pacs_008_001_08::PostalAddress241,
+    pacs_008_001_08::PostalAddress242,
+    pacs_008_001_08_stp::PostalAddress241,
+    pacs_008_001_08_stp::PostalAddress242,
+    pacs_009_001_08::PostalAddress241,
+    pacs_009_001_08_adv::PostalAddress241,
+    pacs_009_001_08_cov::PostalAddress241,
+    pacs_009_001_08_cov::PostalAddress242,
+    pacs_010_001_03::PostalAddress241,
+    pacs_010_001_03_mc::PostalAddress241,
+    pain_001_001_09::PostalAddress24,
+    pain_001_001_09::PostalAddress241,
+    pain_001_001_09::PostalAddress242,
+    pain_002_001_10::PostalAddress241,
+    pain_008_001_08::PostalAddress24,
+    pain_008_001_08::PostalAddress241,
+    pain_008_001_08::PostalAddress242,
+    pain_008_001_08::PostalAddress243,
+    pain_008_001_08::PostalAddress244,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_structured_address() {
+        let address = pacs_008_001_08::PostalAddress241 {
+            dept: Some("Payments".to_string()),
+            strt_nm: Some("Bahnhofstrasse".to_string()),
+            bldg_nb: Some("45".to_string()),
+            bldg_nm: Some("Paradeplatz Tower".to_string()),
+            pst_cd: Some("8001".to_string()),
+            twn_nm: Some("Zurich".to_string()),
+            ctry_sub_dvsn: Some("ZH".to_string()),
+            ctry: Some("CH".to_string()),
+            adr_line: Some(vec!["Bahnhofstrasse 45".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            address.structured(),
+            Address {
+                street: Some("Bahnhofstrasse".to_string()),
+                building: Some("45".to_string()),
+                postcode: Some("8001".to_string()),
+                town: Some("Zurich".to_string()),
+                country_subdivision: Some("ZH".to_string()),
+                country: Some("CH".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_structured_address_mandatory_elements() {
+        let address = pacs_008_001_08::PostalAddress242 {
+            bldg_nm: Some("Canary Wharf One".to_string()),
+            twn_nm: "London".to_string(),
+            ctry: "GB".to_string(),
+            ..Default::default()
+        };
+        let structured = address.structured();
+        assert_eq!(structured.building.as_deref(), Some("Canary Wharf One"));
+        assert_eq!(structured.town.as_deref(), Some("London"));
+        assert_eq!(structured.street, None);
+
+        let lines_only = pacs_008_001_08::PostalAddress241 {
+            adr_line: Some(vec!["1 Main Street".to_string()]),
+            ..Default::default()
+        };
+        assert!(lines_only.structured().is_empty());
+    }
+}
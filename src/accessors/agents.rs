@@ -0,0 +1,106 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Instructing and instructed agent accessors
+//!
+//! `InstgAgt` and `InstdAgt` name the two parties of the current interbank
+//! leg: the agent sending the message and the agent receiving it. They are
+//! the counterparties used for settlement reconciliation, distinct from the
+//! debtor and creditor agents that stay the same along the payment chain.
+//! The usage guidelines carry them on the transaction rather than the group
+//! header, so the accessors read them from there.
+
+use crate::document::*;
+
+/// Implement `instructing_agent()` / `instructed_agent()` on pacs message types
+macro_rules! impl_settlement_agents {
+    ($($module:ident :: $ty:ident => $agent:ident, $($tx:ident).+);* $(;)?) => {
+        $(
+            impl $module::$ty {
+                /// Agent instructing the next party in the chain (`InstgAgt`)
+                pub fn instructing_agent(&self) -> &$module::$agent {
+                    &self.$($tx).+.instg_agt
+                }
+
+                /// Agent instructed by the instructing agent (`InstdAgt`)
+                pub fn instructed_agent(&self) -> &$module::$agent {
+                    &self.$($tx).+.instd_agt
+                }
+            }
+        )*
+    };
+}
+
+impl_settlement_agents!(
+    pacs_002_001_10::FIToFIPaymentStatusReportV10
+        => BranchAndFinancialInstitutionIdentification61, tx_inf_and_sts;
+    pacs_003_001_08::FIToFICustomerDirectDebitV08
+        => BranchAndFinancialInstitutionIdentification62, drct_dbt_tx_inf;
+    pacs_004_001_09::PaymentReturnV09
+        => BranchAndFinancialInstitutionIdentification62, tx_inf;
+    pacs_008_001_08::FIToFICustomerCreditTransferV08
+        => BranchAndFinancialInstitutionIdentification62, cdt_trf_tx_inf;
+    pacs_008_001_08_stp::FIToFICustomerCreditTransferV08
+        => BranchAndFinancialInstitutionIdentification61, cdt_trf_tx_inf;
+    pacs_009_001_08::FinancialInstitutionCreditTransferV08
+        => BranchAndFinancialInstitutionIdentification62, cdt_trf_tx_inf;
+    pacs_009_001_08_adv::FinancialInstitutionCreditTransferV08
+        => BranchAndFinancialInstitutionIdentification62, cdt_trf_tx_inf;
+    pacs_009_001_08_cov::FinancialInstitutionCreditTransferV08
+        => BranchAndFinancialInstitutionIdentification62, cdt_trf_tx_inf;
+    pacs_010_001_03::FinancialInstitutionDirectDebitV03
+        => BranchAndFinancialInstitutionIdentification61, cdt_instr;
+    pacs_010_001_03_mc::FinancialInstitutionDirectDebitV03
+        => BranchAndFinancialInstitutionIdentification61, cdt_instr;
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pacs009_settlement_agents() {
+        let mut doc = pacs_009_001_08::FinancialInstitutionCreditTransferV08::default();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.instg_agt.fin_instn_id.bicfi = "DEUTDEFFXXX".into();
+        tx.instd_agt.fin_instn_id.bicfi = "CHASUS33XXX".into();
+        tx.dbtr.fin_instn_id.bicfi = Some("COBADEFFXXX".into());
+        tx.cdtr.fin_instn_id.bicfi = Some("BOFAUS3NXXX".into());
+
+        assert_eq!(
+            doc.instructing_agent().fin_instn_id.bicfi.as_str(),
+            "DEUTDEFFXXX"
+        );
+        assert_eq!(
+            doc.instructed_agent().fin_instn_id.bicfi.as_str(),
+            "CHASUS33XXX"
+        );
+    }
+
+    #[test]
+    fn test_pacs010_settlement_agents() {
+        let mut doc = pacs_010_001_03::FinancialInstitutionDirectDebitV03::default();
+        doc.cdt_instr.instg_agt.fin_instn_id.bicfi = "NWBKGB2LXXX".into();
+        assert_eq!(
+            doc.instructing_agent().fin_instn_id.bicfi.as_str(),
+            "NWBKGB2LXXX"
+        );
+        assert_eq!(doc.instructed_agent().fin_instn_id.bicfi.as_str(), "");
+    }
+}
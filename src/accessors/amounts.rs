@@ -0,0 +1,360 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Amount aggregation and charge deduction helpers
+
+use crate::document::*;
+use crate::identifiers::CurrencyCode;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Sum (currency, amount) pairs into a total per currency
+pub fn sum_by_currency<C, I>(amounts: I) -> HashMap<C, f64>
+where
+    C: Eq + Hash,
+    I: IntoIterator<Item = (C, f64)>,
+{
+    let mut totals = HashMap::new();
+    for (ccy, value) in amounts {
+        *totals.entry(ccy).or_insert(0.0) += value;
+    }
+    totals
+}
+
+/// Group (reference, amount, currency) triples by reference, keeping every amount
+fn group_by_reference<'a, I>(amounts: I) -> HashMap<String, Vec<(f64, CurrencyCode)>>
+where
+    I: IntoIterator<Item = (&'a String, f64, &'a CurrencyCode)>,
+{
+    let mut grouped: HashMap<String, Vec<(f64, CurrencyCode)>> = HashMap::new();
+    for (reference, value, ccy) in amounts {
+        grouped
+            .entry(reference.clone())
+            .or_default()
+            .push((value, ccy.clone()));
+    }
+    grouped
+}
+
+impl pacs_008_001_08::FIToFICustomerCreditTransferV08 {
+    /// Total interbank settlement amount of all transactions, per currency
+    pub fn total_settlement_amount(&self) -> HashMap<CurrencyCode, f64> {
+        let amt = &self.cdt_trf_tx_inf.intr_bk_sttlm_amt;
+        sum_by_currency([(amt.ccy.clone(), amt.value)])
+    }
+
+    /// Instructed amounts (`InstdAmt`) of the transactions, keyed by end-to-end id
+    /// A transaction without an instructed amount is not reported.
+    pub fn instructed_amounts(&self) -> HashMap<String, Vec<(f64, CurrencyCode)>> {
+        let tx = &self.cdt_trf_tx_inf;
+        group_by_reference(
+            tx.instd_amt
+                .as_ref()
+                .map(|amt| (&tx.pmt_id.end_to_end_id, amt.value, &amt.ccy)),
+        )
+    }
+}
+
+impl pacs_008_001_08::CreditTransferTransaction391 {
+    /// Amount the creditor is credited with once the charges are applied
+    ///
+    /// Starts from the instructed amount (`InstdAmt`) and deducts the charges
+    /// listed in `ChrgsInf` according to the charge bearer: none for DEBT,
+    /// all for CRED, and for SHAR all except those of the debtor agent, which
+    /// the debtor pays. Without an instructed amount in the settlement
+    /// currency, the interbank settlement amount is returned as is, since
+    /// earlier agents' deductions are already reflected in it. Returns `None`
+    /// when a charge to deduct is in another currency. Charges the creditor
+    /// agent takes on receipt are not in the message and not accounted for.
+    pub fn creditor_credited_amount(&self) -> Option<(f64, CurrencyCode)> {
+        use pacs_008_001_08::ChargeBearerType1Code1;
+
+        let settled = &self.intr_bk_sttlm_amt;
+        let Some(instructed) = self
+            .instd_amt
+            .as_ref()
+            .filter(|instd_amt| instd_amt.ccy == settled.ccy)
+        else {
+            return Some((settled.value, settled.ccy.clone()));
+        };
+        let mut credited = instructed.value;
+        for charge in self.chrgs_inf.iter().flatten() {
+            let deducted = match self.chrg_br {
+                ChargeBearerType1Code1::CodeDEBT => false,
+                ChargeBearerType1Code1::CodeCRED => true,
+                ChargeBearerType1Code1::CodeSHAR => charge.agt != self.dbtr_agt,
+            };
+            if !deducted {
+                continue;
+            }
+            if charge.amt.ccy != instructed.ccy {
+                return None;
+            }
+            credited -= charge.amt.value;
+        }
+        Some((credited, instructed.ccy.clone()))
+    }
+}
+
+impl pacs_009_001_08::FinancialInstitutionCreditTransferV08 {
+    /// Total interbank settlement amount of all transactions, per currency
+    pub fn total_settlement_amount(&self) -> HashMap<CurrencyCode, f64> {
+        let amt = &self.cdt_trf_tx_inf.intr_bk_sttlm_amt;
+        sum_by_currency([(amt.ccy.clone(), amt.value)])
+    }
+}
+
+impl pain_001_001_09::CustomerCreditTransferInitiationV09 {
+    /// Total instructed amount of all transactions, per currency
+    /// An initiation carries no settlement amount; an equivalent amount is
+    /// counted in the currency it is expressed in.
+    pub fn total_instructed_amount(&self) -> HashMap<CurrencyCode, f64> {
+        let amt = &self.pmt_inf.cdt_trf_tx_inf.amt;
+        let amount = amt
+            .instd_amt
+            .as_ref()
+            .or_else(|| amt.eqvt_amt.as_ref().map(|eqvt| &eqvt.amt));
+        sum_by_currency(amount.map(|a| (a.ccy.clone(), a.value)))
+    }
+
+    /// Instructed amounts of the transactions, keyed by end-to-end id
+    /// An equivalent amount is reported in the currency it is expressed in.
+    pub fn instructed_amounts(&self) -> HashMap<String, Vec<(f64, CurrencyCode)>> {
+        let tx = &self.pmt_inf.cdt_trf_tx_inf;
+        group_by_reference(
+            tx.amt
+                .instd_amt
+                .as_ref()
+                .or_else(|| tx.amt.eqvt_amt.as_ref().map(|eqvt| &eqvt.amt))
+                .map(|amt| (&tx.pmt_id.end_to_end_id, amt.value, &amt.ccy)),
+        )
+    }
+}
+
+impl pain_008_001_08::CustomerDirectDebitInitiationV08 {
+    /// Total instructed amount of all transactions, per currency
+    /// An initiation carries no settlement amount.
+    pub fn total_instructed_amount(&self) -> HashMap<CurrencyCode, f64> {
+        sum_by_currency(
+            self.pmt_inf
+                .drct_dbt_tx_inf
+                .iter()
+                .map(|tx| (tx.instd_amt.ccy.clone(), tx.instd_amt.value)),
+        )
+    }
+
+    /// Instructed amounts of the transactions, keyed by end-to-end id
+    /// End-to-end ids need not be unique (`NOTPROVIDED` is common), so every
+    /// transaction carrying an id is listed under it, in message order.
+    pub fn instructed_amounts(&self) -> HashMap<String, Vec<(f64, CurrencyCode)>> {
+        group_by_reference(self.pmt_inf.drct_dbt_tx_inf.iter().map(|tx| {
+            (
+                &tx.pmt_id.end_to_end_id,
+                tx.instd_amt.value,
+                &tx.instd_amt.ccy,
+            )
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn direct_debit(
+        ccy: &str,
+        value: f64,
+    ) -> pain_008_001_08::DirectDebitTransactionInformation231 {
+        pain_008_001_08::DirectDebitTransactionInformation231 {
+            instd_amt: pain_008_001_08::ActiveOrHistoricCurrencyAndAmount {
+                ccy: ccy.into(),
+                value,
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_total_instructed_amount_multi_currency_batch() {
+        let mut doc = pain_008_001_08::CustomerDirectDebitInitiationV08::default();
+        doc.pmt_inf.drct_dbt_tx_inf = vec![
+            direct_debit("EUR", 100.25),
+            direct_debit("USD", 50.0),
+            direct_debit("EUR", 200.50),
+        ];
+
+        let totals = doc.total_instructed_amount();
+        assert_eq!(totals.len(), 2);
+        assert!((totals[&CurrencyCode::from("EUR")] - 300.75).abs() < 1e-9);
+        assert!((totals[&CurrencyCode::from("USD")] - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_instructed_amounts_batch() {
+        let mut doc = pain_008_001_08::CustomerDirectDebitInitiationV08::default();
+        doc.pmt_inf.drct_dbt_tx_inf = [("E2E-1", "EUR", 100.25), ("E2E-2", "USD", 50.0)]
+            .into_iter()
+            .map(|(end_to_end_id, ccy, value)| {
+                let mut tx = direct_debit(ccy, value);
+                tx.pmt_id.end_to_end_id = end_to_end_id.to_string();
+                tx
+            })
+            .collect();
+
+        let amounts = doc.instructed_amounts();
+        assert_eq!(amounts.len(), 2);
+        assert_eq!(amounts["E2E-1"], [(100.25, CurrencyCode::from("EUR"))]);
+        assert_eq!(amounts["E2E-2"], [(50.0, CurrencyCode::from("USD"))]);
+    }
+
+    #[test]
+    fn test_instructed_amounts_duplicate_end_to_end_id() {
+        let mut doc = pain_008_001_08::CustomerDirectDebitInitiationV08::default();
+        doc.pmt_inf.drct_dbt_tx_inf = [("NOTPROVIDED", 100.25), ("NOTPROVIDED", 50.0)]
+            .into_iter()
+            .map(|(end_to_end_id, value)| {
+                let mut tx = direct_debit("EUR", value);
+                tx.pmt_id.end_to_end_id = end_to_end_id.to_string();
+                tx
+            })
+            .collect();
+
+        let amounts = doc.instructed_amounts();
+        assert_eq!(amounts.len(), 1);
+        assert_eq!(
+            amounts["NOTPROVIDED"],
+            [
+                (100.25, CurrencyCode::from("EUR")),
+                (50.0, CurrencyCode::from("EUR"))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_instructed_amounts_pacs008() {
+        let mut doc = pacs_008_001_08::FIToFICustomerCreditTransferV08::default();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.pmt_id.end_to_end_id = "E2E-1".to_string();
+        tx.intr_bk_sttlm_amt.ccy = "EUR".into();
+        tx.intr_bk_sttlm_amt.value = 985.0;
+        assert!(doc.instructed_amounts().is_empty());
+
+        doc.cdt_trf_tx_inf.instd_amt = Some(pacs_008_001_08::CBPRAmount1 {
+            ccy: "USD".into(),
+            value: 1100.0,
+        });
+        assert_eq!(
+            doc.instructed_amounts()["E2E-1"],
+            [(1100.0, CurrencyCode::from("USD"))]
+        );
+    }
+
+    #[test]
+    fn test_total_settlement_amount_pacs008() {
+        let mut doc = pacs_008_001_08::FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt.ccy = "GBP".into();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt.value = 1250.0;
+
+        let totals = doc.total_settlement_amount();
+        assert_eq!(totals.len(), 1);
+        assert_eq!(totals[&CurrencyCode::from("GBP")], 1250.0);
+    }
+
+    #[test]
+    fn test_total_instructed_amount_pain001_equivalent_amount() {
+        let mut doc = pain_001_001_09::CustomerCreditTransferInitiationV09::default();
+        doc.pmt_inf.cdt_trf_tx_inf.amt.eqvt_amt = Some(pain_001_001_09::EquivalentAmount2 {
+            amt: pain_001_001_09::ActiveOrHistoricCurrencyAndAmount {
+                ccy: "CHF".into(),
+                value: 75.0,
+            },
+            ccy_of_trf: "EUR".to_string(),
+        });
+
+        assert_eq!(
+            doc.total_instructed_amount()[&CurrencyCode::from("CHF")],
+            75.0
+        );
+    }
+
+    fn charged_transfer(
+        chrg_br: pacs_008_001_08::ChargeBearerType1Code1,
+    ) -> pacs_008_001_08::CreditTransferTransaction391 {
+        use pacs_008_001_08::*;
+
+        let amount = |value: f64| CBPRAmount1 {
+            ccy: "EUR".into(),
+            value,
+        };
+        let agent = |bic: &str| BranchAndFinancialInstitutionIdentification61 {
+            fin_instn_id: FinancialInstitutionIdentification181 {
+                bicfi: Some(bic.into()),
+                ..Default::default()
+            },
+        };
+        let mut tx = CreditTransferTransaction391 {
+            chrg_br,
+            instd_amt: Some(amount(1000.0)),
+            intr_bk_sttlm_amt: amount(985.0),
+            dbtr_agt: agent("DEUTDEFFXXX"),
+            ..Default::default()
+        };
+        tx.chrgs_inf = Some(vec![
+            Charges71 {
+                amt: amount(10.0),
+                agt: agent("DEUTDEFFXXX"),
+            },
+            Charges71 {
+                amt: amount(5.0),
+                agt: agent("CHASUS33XXX"),
+            },
+        ]);
+        tx
+    }
+
+    #[test]
+    fn test_creditor_credited_amount_by_charge_bearer() {
+        use pacs_008_001_08::ChargeBearerType1Code1::*;
+
+        let credited = |chrg_br| {
+            let (value, ccy) = charged_transfer(chrg_br)
+                .creditor_credited_amount()
+                .unwrap();
+            (value, ccy.as_str().to_string())
+        };
+        assert_eq!(credited(CodeDEBT), (1000.0, "EUR".to_string()));
+        assert_eq!(credited(CodeCRED), (985.0, "EUR".to_string()));
+        assert_eq!(credited(CodeSHAR), (995.0, "EUR".to_string()));
+    }
+
+    #[test]
+    fn test_creditor_credited_amount_without_instructed_amount() {
+        let mut tx = charged_transfer(pacs_008_001_08::ChargeBearerType1Code1::CodeCRED);
+        tx.instd_amt = None;
+        let (value, ccy) = tx.creditor_credited_amount().unwrap();
+        assert_eq!((value, ccy.as_str()), (985.0, "EUR"));
+    }
+
+    #[test]
+    fn test_creditor_credited_amount_foreign_currency_charge() {
+        let mut tx = charged_transfer(pacs_008_001_08::ChargeBearerType1Code1::CodeCRED);
+        tx.chrgs_inf.as_mut().unwrap()[1].amt.ccy = "USD".into();
+        assert_eq!(tx.creditor_credited_amount(), None);
+    }
+}
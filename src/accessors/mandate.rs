@@ -0,0 +1,105 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Direct debit mandate accessors
+//!
+//! Each pain.008 transaction refers to the mandate it is collected under in
+//! `DrctDbtTx/MndtRltdInf`: the mandate id, its signature date and, when the
+//! mandate changed since the last collection, the amendment details.
+
+use crate::document::pain_008_001_08::{
+    AmendmentInformationDetails131, CustomerDirectDebitInitiationV08,
+    DirectDebitTransactionInformation231, MandateRelatedInformation141,
+};
+
+impl DirectDebitTransactionInformation231 {
+    /// Mandate related information of the transaction (`DrctDbtTx/MndtRltdInf`)
+    pub fn mandate(&self) -> Option<&MandateRelatedInformation141> {
+        self.drct_dbt_tx.as_ref()?.mndt_rltd_inf.as_ref()
+    }
+
+    /// Unique mandate reference (`MndtId`)
+    pub fn mandate_id(&self) -> Option<&str> {
+        self.mandate()?.mndt_id.as_deref()
+    }
+
+    /// Date the debtor signed the mandate (`DtOfSgntr`, ISO date)
+    pub fn mandate_signature_date(&self) -> Option<&str> {
+        self.mandate()?.dt_of_sgntr.as_deref()
+    }
+
+    /// Whether the mandate was amended since the previous collection (`AmdmntInd`)
+    pub fn is_mandate_amended(&self) -> bool {
+        self.mandate()
+            .is_some_and(|mandate| mandate.amdmnt_ind == Some(true))
+    }
+
+    /// Original mandate data of an amended mandate (`AmdmntInfDtls`)
+    pub fn mandate_amendment(&self) -> Option<&AmendmentInformationDetails131> {
+        self.mandate()?.amdmnt_inf_dtls.as_ref()
+    }
+}
+
+impl CustomerDirectDebitInitiationV08 {
+    /// Mandate ids of all transactions, in transaction order
+    pub fn mandate_ids(&self) -> Vec<Option<&str>> {
+        self.pmt_inf
+            .drct_dbt_tx_inf
+            .iter()
+            .map(DirectDebitTransactionInformation231::mandate_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::pain_008_001_08::*;
+
+    #[test]
+    fn test_mandate_accessors() {
+        let tx = DirectDebitTransactionInformation231 {
+            drct_dbt_tx: Some(DirectDebitTransaction101 {
+                mndt_rltd_inf: Some(MandateRelatedInformation141 {
+                    mndt_id: Some("MNDT-2024-001".to_string()),
+                    dt_of_sgntr: Some("2024-03-01".to_string()),
+                    amdmnt_ind: Some(true),
+                    amdmnt_inf_dtls: Some(AmendmentInformationDetails131 {
+                        orgnl_mndt_id: Some("MNDT-2019-042".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(tx.mandate_id(), Some("MNDT-2024-001"));
+        assert_eq!(tx.mandate_signature_date(), Some("2024-03-01"));
+        assert!(tx.is_mandate_amended());
+        assert_eq!(
+            tx.mandate_amendment()
+                .and_then(|amdmnt| amdmnt.orgnl_mndt_id.as_deref()),
+            Some("MNDT-2019-042")
+        );
+
+        let mut doc = CustomerDirectDebitInitiationV08::default();
+        doc.pmt_inf.drct_dbt_tx_inf = vec![tx, DirectDebitTransactionInformation231::default()];
+        assert_eq!(doc.mandate_ids(), vec![Some("MNDT-2024-001"), None]);
+    }
+}
@@ -0,0 +1,36 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Accessors
+//!
+//! Convenience methods on the generated message types for reading commonly
+//! needed business data without walking the ISO 20022 tree by hand.
+
+pub mod account;
+pub mod address;
+pub mod agents;
+pub mod amounts;
+pub mod mandate;
+pub mod payment;
+pub mod priority;
+pub mod purpose;
+pub mod references;
+pub mod regulatory;
+pub mod remittance;
+pub mod transaction_code;
@@ -0,0 +1,221 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Owned payment summaries
+//!
+//! A small owned copy of the business data of a credit transfer, for handing a
+//! payment across threads or async tasks without cloning the full ISO tree.
+
+use crate::document::*;
+use serde::{Deserialize, Serialize};
+
+/// Amount, parties, agents, references and date of a single credit transfer
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaymentSummary {
+    /// Interbank settlement amount (pacs.008) or instructed amount (pain.001)
+    pub amount: f64,
+    /// Currency of `amount`
+    pub currency: String,
+    /// Debtor name (`Dbtr/Nm`)
+    pub debtor_name: Option<String>,
+    /// Debtor account IBAN or other identification (`DbtrAcct/Id`)
+    pub debtor_account: Option<String>,
+    /// Debtor agent BIC (`DbtrAgt/FinInstnId/BICFI`)
+    pub debtor_agent: Option<String>,
+    /// Creditor name (`Cdtr/Nm`)
+    pub creditor_name: Option<String>,
+    /// Creditor account IBAN or other identification (`CdtrAcct/Id`)
+    pub creditor_account: Option<String>,
+    /// Creditor agent BIC (`CdtrAgt/FinInstnId/BICFI`)
+    pub creditor_agent: Option<String>,
+    /// Instruction identification (`PmtId/InstrId`)
+    pub instruction_id: Option<String>,
+    /// End-to-end identification (`PmtId/EndToEndId`)
+    pub end_to_end_id: String,
+    /// Unique end-to-end transaction reference (`PmtId/UETR`)
+    pub uetr: String,
+    /// Interbank settlement date (pacs.008) or requested execution date (pain.001)
+    pub execution_date: Option<String>,
+}
+
+impl pacs_008_001_08::CreditTransferTransaction391 {
+    /// Owned summary of the transaction
+    pub fn to_payment_summary(&self) -> PaymentSummary {
+        PaymentSummary {
+            amount: self.intr_bk_sttlm_amt.value,
+            currency: self.intr_bk_sttlm_amt.ccy.as_str().to_string(),
+            debtor_name: self.dbtr.nm.clone(),
+            debtor_account: self
+                .dbtr_acct
+                .as_ref()
+                .map(|acct| acct.id.account_identifier().into_owned()),
+            debtor_agent: self
+                .dbtr_agt
+                .fin_instn_id
+                .bicfi
+                .as_ref()
+                .map(|bic| bic.as_str().to_string()),
+            creditor_name: self.cdtr.nm.clone(),
+            creditor_account: self
+                .cdtr_acct
+                .as_ref()
+                .map(|acct| acct.id.account_identifier().into_owned()),
+            creditor_agent: self
+                .cdtr_agt
+                .fin_instn_id
+                .bicfi
+                .as_ref()
+                .map(|bic| bic.as_str().to_string()),
+            instruction_id: Some(self.pmt_id.instr_id.clone()),
+            end_to_end_id: self.pmt_id.end_to_end_id.clone(),
+            uetr: self.pmt_id.uetr.clone(),
+            execution_date: Some(self.intr_bk_sttlm_dt.clone()),
+        }
+    }
+}
+
+impl pain_001_001_09::PaymentInstruction301 {
+    /// Owned summary of the payment instruction and its credit transfer
+    /// The debtor side and the execution date are carried on the payment
+    /// instruction rather than the transaction. An equivalent amount is
+    /// reported in the currency it is expressed in.
+    pub fn to_payment_summary(&self) -> PaymentSummary {
+        let tx = &self.cdt_trf_tx_inf;
+        let amount = tx
+            .amt
+            .instd_amt
+            .as_ref()
+            .or_else(|| tx.amt.eqvt_amt.as_ref().map(|eqvt| &eqvt.amt));
+        PaymentSummary {
+            amount: amount.map_or(0.0, |amt| amt.value),
+            currency: amount
+                .map(|amt| amt.ccy.as_str().to_string())
+                .unwrap_or_default(),
+            debtor_name: self.dbtr.nm.clone(),
+            debtor_account: Some(self.dbtr_acct.id.account_identifier().into_owned()),
+            debtor_agent: self
+                .dbtr_agt
+                .fin_instn_id
+                .bicfi
+                .as_ref()
+                .map(|bic| bic.as_str().to_string()),
+            creditor_name: tx.cdtr.nm.clone(),
+            creditor_account: tx
+                .cdtr_acct
+                .as_ref()
+                .map(|acct| acct.id.account_identifier().into_owned()),
+            creditor_agent: tx
+                .cdtr_agt
+                .as_ref()
+                .and_then(|agt| agt.fin_instn_id.bicfi.as_ref())
+                .map(|bic| bic.as_str().to_string()),
+            instruction_id: tx.pmt_id.instr_id.clone(),
+            end_to_end_id: tx.pmt_id.end_to_end_id.clone(),
+            uetr: tx.pmt_id.uetr.clone(),
+            execution_date: self
+                .reqd_exctn_dt
+                .dt
+                .clone()
+                .or_else(|| self.reqd_exctn_dt.dt_tm.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pacs008_payment_summary() {
+        use pacs_008_001_08::*;
+
+        let mut tx = CreditTransferTransaction391::default();
+        tx.pmt_id.instr_id = "INSTR-1".to_string();
+        tx.pmt_id.end_to_end_id = "E2E-1".to_string();
+        tx.pmt_id.uetr = "eb6305c9-1f7f-49de-aed0-16487c27b42d".to_string();
+        tx.intr_bk_sttlm_amt = CBPRAmount1 {
+            ccy: "EUR".into(),
+            value: 1500.25,
+        };
+        tx.intr_bk_sttlm_dt = "2024-01-16".to_string();
+        tx.dbtr.nm = Some("ACME CORP".to_string());
+        tx.dbtr_acct = Some(CashAccount381 {
+            id: AccountIdentification4Choice1 {
+                iban: Some("DE89370400440532013000".into()),
+                othr: None,
+            },
+            ..Default::default()
+        });
+        tx.dbtr_agt.fin_instn_id.bicfi = Some("DEUTDEFFXXX".into());
+        tx.cdtr.nm = Some("GLOBEX LTD".to_string());
+        tx.cdtr_agt.fin_instn_id.bicfi = Some("BARCGB22XXX".into());
+
+        assert_eq!(
+            tx.to_payment_summary(),
+            PaymentSummary {
+                amount: 1500.25,
+                currency: "EUR".to_string(),
+                debtor_name: Some("ACME CORP".to_string()),
+                debtor_account: Some("DE89370400440532013000".to_string()),
+                debtor_agent: Some("DEUTDEFFXXX".to_string()),
+                creditor_name: Some("GLOBEX LTD".to_string()),
+                creditor_account: None,
+                creditor_agent: Some("BARCGB22XXX".to_string()),
+                instruction_id: Some("INSTR-1".to_string()),
+                end_to_end_id: "E2E-1".to_string(),
+                uetr: "eb6305c9-1f7f-49de-aed0-16487c27b42d".to_string(),
+                execution_date: Some("2024-01-16".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_pain001_payment_summary() {
+        use pain_001_001_09::*;
+
+        let mut doc = CustomerCreditTransferInitiationV09::default();
+        let pmt_inf = &mut doc.pmt_inf;
+        pmt_inf.reqd_exctn_dt.dt = Some("2024-02-01".to_string());
+        pmt_inf.dbtr.nm = Some("ACME CORP".to_string());
+        pmt_inf.dbtr_acct.id.iban = Some("GB29NWBK60161331926819".into());
+        pmt_inf.dbtr_agt.fin_instn_id.bicfi = Some("NWBKGB2LXXX".into());
+        let tx = &mut pmt_inf.cdt_trf_tx_inf;
+        tx.pmt_id.end_to_end_id = "E2E-2".to_string();
+        tx.amt.instd_amt = Some(ActiveOrHistoricCurrencyAndAmount {
+            ccy: "GBP".into(),
+            value: 99.5,
+        });
+        tx.cdtr.nm = Some("GLOBEX LTD".to_string());
+
+        let summary = doc.pmt_inf.to_payment_summary();
+        assert_eq!(summary.amount, 99.5);
+        assert_eq!(summary.currency, "GBP");
+        assert_eq!(summary.debtor_name.as_deref(), Some("ACME CORP"));
+        assert_eq!(
+            summary.debtor_account.as_deref(),
+            Some("GB29NWBK60161331926819")
+        );
+        assert_eq!(summary.debtor_agent.as_deref(), Some("NWBKGB2LXXX"));
+        assert_eq!(summary.creditor_name.as_deref(), Some("GLOBEX LTD"));
+        assert_eq!(summary.creditor_agent, None);
+        assert_eq!(summary.instruction_id, None);
+        assert_eq!(summary.end_to_end_id, "E2E-2");
+        assert_eq!(summary.execution_date.as_deref(), Some("2024-02-01"));
+    }
+}
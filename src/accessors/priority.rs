@@ -0,0 +1,141 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Instruction priority accessors
+//!
+//! `InstrPrty` tells the instructed agent how urgently to process the
+//! instruction. Every message module generates its own `Priority2Code`, so
+//! [`InstructionPriority`] gives one type to route on. Only the exact codes
+//! `HIGH` and `NORM` are accepted: a lowercase or unknown priority fails to
+//! parse rather than being read as normal.
+
+use crate::document::*;
+use serde::{Deserialize, Serialize};
+
+/// Instruction priority (`InstrPrty`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InstructionPriority {
+    /// Priority level is high (`HIGH`)
+    #[serde(rename = "HIGH")]
+    High,
+    /// Priority level is normal (`NORM`)
+    #[serde(rename = "NORM")]
+    Normal,
+}
+
+impl InstructionPriority {
+    /// Priority for an ISO code; codes are case-sensitive
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "HIGH" => Some(InstructionPriority::High),
+            "NORM" => Some(InstructionPriority::Normal),
+            _ => None,
+        }
+    }
+
+    /// ISO code of the priority
+    pub fn code(&self) -> &'static str {
+        match self {
+            InstructionPriority::High => "HIGH",
+            InstructionPriority::Normal => "NORM",
+        }
+    }
+}
+
+/// Implement `instruction_priority()` on the payment type information of each message module
+macro_rules! impl_instruction_priority {
+    ($($module:ident::$ty:ident),* $(,)?) => {
+        $(
+            impl $module::$ty {
+                /// Instruction priority (`InstrPrty`), if given
+                pub fn instruction_priority(&self) -> Option<InstructionPriority> {
+                    self.instr_prty.as_ref().map(|prty| match prty {
+                        $module::Priority2Code::CodeHIGH => InstructionPriority::High,
+                        $module::Priority2Code::CodeNORM => InstructionPriority::Normal,
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_instruction_priority!(
+    pacs_003_001_08::PaymentTypeInformation271,
+    pacs_004_001_09::PaymentTypeInformation271,
+    pacs_008_001_08::PaymentTypeInformation281,
+    pacs_008_001_08_stp::PaymentTypeInformation281,
+    pacs_009_001_08::PaymentTypeInformation281,
+    pacs_009_001_08_adv::PaymentTypeInformation281,
+    pacs_009_001_08_cov::PaymentTypeInformation281,
+    pacs_010_001_03::PaymentTypeInformation281,
+    pacs_010_001_03_mc::PaymentTypeInformation281,
+    pacs_010_001_03_mc::PaymentTypeInformation282,
+    pain_001_001_09::PaymentTypeInformation261,
+    pain_008_001_08::PaymentTypeInformation291,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pacs_008_001_08::PaymentTypeInformation281;
+
+    fn parse(instr_prty: &str) -> Result<PaymentTypeInformation281, quick_xml::DeError> {
+        quick_xml::de::from_str(&format!(
+            "<PmtTpInf><InstrPrty>{instr_prty}</InstrPrty></PmtTpInf>"
+        ))
+    }
+
+    #[test]
+    fn test_high_priority() {
+        let pmt_tp_inf = parse("HIGH").unwrap();
+        assert_eq!(
+            pmt_tp_inf.instruction_priority(),
+            Some(InstructionPriority::High)
+        );
+        assert_eq!(InstructionPriority::High.code(), "HIGH");
+    }
+
+    #[test]
+    fn test_normal_priority() {
+        let pmt_tp_inf = parse("NORM").unwrap();
+        assert_eq!(
+            pmt_tp_inf.instruction_priority(),
+            Some(InstructionPriority::Normal)
+        );
+        assert_eq!(
+            InstructionPriority::from_code("NORM"),
+            Some(InstructionPriority::Normal)
+        );
+        assert_eq!(
+            PaymentTypeInformation281::default().instruction_priority(),
+            None
+        );
+        let mc = pacs_010_001_03_mc::PaymentTypeInformation282 {
+            instr_prty: Some(pacs_010_001_03_mc::Priority2Code::CodeNORM),
+            ..Default::default()
+        };
+        assert_eq!(mc.instruction_priority(), Some(InstructionPriority::Normal));
+    }
+
+    #[test]
+    fn test_invalid_priority() {
+        assert!(parse("high").is_err());
+        assert!(parse("URGT").is_err());
+        assert_eq!(InstructionPriority::from_code("norm"), None);
+    }
+}
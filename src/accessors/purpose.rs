@@ -0,0 +1,191 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Purpose and category purpose code accessors
+
+use crate::document::*;
+
+/// Implement `code()` on the `Purpose2Choice` variants of each message module
+macro_rules! impl_purpose_choice {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// The ExternalPurpose1Code, or the proprietary purpose if no code is given
+                pub fn code(&self) -> Option<&str> {
+                    self.cd.as_deref().or(self.prtry.as_deref())
+                }
+            }
+        )*
+    };
+}
+
+impl pacs_008_001_08_stp::Purpose2Choice1 {
+    /// The ExternalPurpose1Code (STP usage does not allow a proprietary purpose)
+    pub fn code(&self) -> Option<&str> {
+        self.cd.as_deref()
+    }
+}
+
+/// Implement `purpose_code()` on transaction types carrying a `Purp` element
+macro_rules! impl_purpose_code {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Purpose of the transaction (`Purp/Cd`, falling back to `Purp/Prtry`)
+                pub fn purpose_code(&self) -> Option<&str> {
+                    self.purp.as_ref().and_then(|purp| purp.code())
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `code()` on the `CategoryPurpose1Choice` variants of each message module
+macro_rules! impl_category_purpose_choice {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// The ExternalCategoryPurpose1Code, or the proprietary category if no code is given
+                pub fn code(&self) -> Option<&str> {
+                    self.cd.as_deref().or(self.prtry.as_deref())
+                }
+            }
+        )*
+    };
+}
+
+impl pacs_008_001_08_stp::CategoryPurpose1Choice1 {
+    /// The ExternalCategoryPurpose1Code (STP usage does not allow a proprietary category)
+    pub fn code(&self) -> Option<&str> {
+        self.cd.as_deref()
+    }
+}
+
+/// Implement `category_purpose_code()` on payment type types carrying a `CtgyPurp` element
+macro_rules! impl_category_purpose_code {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Category purpose of the payment (`CtgyPurp/Cd`, falling back to `CtgyPurp/Prtry`)
+                pub fn category_purpose_code(&self) -> Option<&str> {
+                    self.ctgy_purp.as_ref().and_then(|ctgy| ctgy.code())
+                }
+            }
+        )*
+    };
+}
+
+impl_purpose_choice!(
+    camt_052_001_08::Purpose2Choice1,
+    camt_053_001_08::Purpose2Choice1,
+    camt_054_001_08::Purpose2Choice1,
+    camt_057_001_06::Purpose2Choice1,
+    pacs_003_001_08::Purpose2Choice1,
+    pacs_004_001_09::Purpose2Choice1,
+    pacs_008_001_08::Purpose2Choice1,
+    pacs_009_001_08::Purpose2Choice1,
+    pacs_009_001_08_adv::Purpose2Choice1,
+    pacs_009_001_08_cov::Purpose2Choice1,
+    pacs_010_001_03::Purpose2Choice1,
+    pacs_010_001_03_mc::Purpose2Choice1,
+    pain_001_001_09::Purpose2Choice,
+    pain_008_001_08::Purpose2Choice,
+);
+
+impl_purpose_code!(
+    pacs_003_001_08::DirectDebitTransactionInformation241,
+    pacs_008_001_08::CreditTransferTransaction391,
+    pacs_008_001_08_stp::CreditTransferTransaction391,
+    pacs_009_001_08::CreditTransferTransaction361,
+    pacs_009_001_08_adv::CreditTransferTransaction361,
+    pacs_009_001_08_cov::CreditTransferTransaction361,
+    pacs_010_001_03::DirectDebitTransactionInformation251,
+    pacs_010_001_03_mc::DirectDebitTransactionInformation251,
+    pain_001_001_09::CreditTransferTransaction341,
+    pain_008_001_08::DirectDebitTransactionInformation231,
+);
+
+impl_category_purpose_choice!(
+    pacs_003_001_08::CategoryPurpose1Choice1,
+    pacs_004_001_09::CategoryPurpose1Choice1,
+    pacs_008_001_08::CategoryPurpose1Choice1,
+    pacs_009_001_08::CategoryPurpose1Choice1,
+    pacs_009_001_08_adv::CategoryPurpose1Choice1,
+    pacs_009_001_08_cov::CategoryPurpose1Choice1,
+    pacs_010_001_03::CategoryPurpose1Choice1,
+    pacs_010_001_03_mc::CategoryPurpose1Choice1,
+    pain_001_001_09::CategoryPurpose1Choice,
+    pain_008_001_08::CategoryPurpose1Choice,
+);
+
+impl_category_purpose_code!(
+    pacs_003_001_08::PaymentTypeInformation271,
+    pacs_004_001_09::PaymentTypeInformation271,
+    pacs_008_001_08::PaymentTypeInformation281,
+    pacs_008_001_08_stp::PaymentTypeInformation281,
+    pacs_009_001_08::PaymentTypeInformation281,
+    pacs_009_001_08_adv::PaymentTypeInformation281,
+    pacs_009_001_08_cov::PaymentTypeInformation281,
+    pacs_010_001_03::PaymentTypeInformation281,
+    pacs_010_001_03_mc::PaymentTypeInformation281,
+    pacs_010_001_03_mc::PaymentTypeInformation282,
+    pain_001_001_09::PaymentTypeInformation261,
+    pain_008_001_08::PaymentTypeInformation291,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_purpose_code() {
+        let mut tx = pacs_008_001_08::CreditTransferTransaction391::default();
+        assert_eq!(tx.purpose_code(), None);
+
+        tx.purp = Some(pacs_008_001_08::Purpose2Choice1 {
+            cd: Some("SALA".to_string()),
+            prtry: None,
+        });
+        assert_eq!(tx.purpose_code(), Some("SALA"));
+
+        tx.purp = Some(pacs_008_001_08::Purpose2Choice1 {
+            cd: None,
+            prtry: Some("PAYROLL".to_string()),
+        });
+        assert_eq!(tx.purpose_code(), Some("PAYROLL"));
+    }
+
+    #[test]
+    fn test_category_purpose_code() {
+        let mut tp = pain_001_001_09::PaymentTypeInformation261::default();
+        assert_eq!(tp.category_purpose_code(), None);
+
+        tp.ctgy_purp = Some(pain_001_001_09::CategoryPurpose1Choice {
+            cd: Some("SUPP".to_string()),
+            prtry: None,
+        });
+        assert_eq!(tp.category_purpose_code(), Some("SUPP"));
+
+        tp.ctgy_purp = Some(pain_001_001_09::CategoryPurpose1Choice {
+            cd: None,
+            prtry: Some("VENDOR".to_string()),
+        });
+        assert_eq!(tp.category_purpose_code(), Some("VENDOR"));
+    }
+}
@@ -0,0 +1,313 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Clearing system reference accessors
+//!
+//! The reference a clearing system (TARGET2, Fedwire, CHAPS, ...) assigns to a
+//! payment is carried in `PmtId/ClrSysRef` on pacs messages and in
+//! `Refs/ClrSysRef` on statement entries. It is the key used to reconcile a
+//! message with the RTGS.
+//!
+//! Returns, cancellations and status reports point back at the payment they
+//! concern through `Orgnl*` elements; [`Document::original_reference_chain`]
+//! collects them for investigations. `original_message_type()` resolves the
+//! `OrgnlMsgNmId` of an original group against the message registry.
+
+use crate::document::*;
+use crate::message_registry::{self, MessageTypeInfo};
+use crate::mx_envelope::Document;
+use crate::validation::helpers;
+use crate::visitor;
+use serde::{Deserialize, Serialize};
+
+/// Kind of an original reference, in lineage order from message to clearing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReferenceKind {
+    /// Original message identification (`OrgnlMsgId`)
+    MessageId,
+    /// Original payment information identification (`OrgnlPmtInfId`)
+    PaymentInformationId,
+    /// Original instruction identification (`OrgnlInstrId`)
+    InstructionId,
+    /// Original end-to-end identification (`OrgnlEndToEndId`)
+    EndToEndId,
+    /// Original transaction identification (`OrgnlTxId`)
+    TransactionId,
+    /// Original unique end-to-end transaction reference (`OrgnlUETR`)
+    Uetr,
+    /// Original clearing system reference (`OrgnlClrSysRef`)
+    ClearingSystemReference,
+}
+
+impl ReferenceKind {
+    /// Kind of the reference held by an element, by its XML name
+    pub fn from_element(name: &str) -> Option<Self> {
+        match name {
+            "OrgnlMsgId" => Some(ReferenceKind::MessageId),
+            "OrgnlPmtInfId" => Some(ReferenceKind::PaymentInformationId),
+            "OrgnlInstrId" => Some(ReferenceKind::InstructionId),
+            "OrgnlEndToEndId" => Some(ReferenceKind::EndToEndId),
+            "OrgnlTxId" => Some(ReferenceKind::TransactionId),
+            "OrgnlUETR" => Some(ReferenceKind::Uetr),
+            "OrgnlClrSysRef" => Some(ReferenceKind::ClearingSystemReference),
+            _ => None,
+        }
+    }
+}
+
+/// One original reference found in a document
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reference {
+    /// What the reference identifies
+    pub kind: ReferenceKind,
+    /// The reference value
+    pub value: String,
+    /// Message name of the original message (`OrgnlMsgNmId`), for message ids
+    pub message_name: Option<String>,
+    /// Path of the element, e.g. `TxInf.OrgnlGrpInf.OrgnlMsgId`
+    pub path: String,
+}
+
+/// Implement `clearing_system_reference()` on types holding `ClrSysRef` directly
+macro_rules! impl_clearing_system_reference {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Reference assigned by the clearing system (`ClrSysRef`)
+                pub fn clearing_system_reference(&self) -> Option<&str> {
+                    self.clr_sys_ref.as_deref()
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `clearing_system_reference()` on transaction types carrying a `PmtId` element
+macro_rules! impl_transaction_clearing_system_reference {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Reference assigned by the clearing system (`PmtId/ClrSysRef`)
+                pub fn clearing_system_reference(&self) -> Option<&str> {
+                    self.pmt_id.clearing_system_reference()
+                }
+            }
+        )*
+    };
+}
+
+impl_clearing_system_reference!(
+    camt_052_001_08::TransactionReferences61,
+    camt_053_001_08::TransactionReferences61,
+    camt_054_001_08::TransactionReferences61,
+    pacs_002_001_10::PaymentTransaction1101,
+    pacs_003_001_08::PaymentIdentification71,
+    pacs_004_001_09::PaymentTransaction1121,
+    pacs_008_001_08::PaymentIdentification71,
+    pacs_008_001_08_stp::PaymentIdentification71,
+    pacs_009_001_08::PaymentIdentification71,
+    pacs_009_001_08_adv::PaymentIdentification71,
+    pacs_009_001_08_cov::PaymentIdentification71,
+    pacs_010_001_03::PaymentIdentification71,
+    pacs_010_001_03_mc::PaymentIdentification71,
+);
+
+impl_transaction_clearing_system_reference!(
+    pacs_003_001_08::DirectDebitTransactionInformation241,
+    pacs_008_001_08::CreditTransferTransaction391,
+    pacs_008_001_08_stp::CreditTransferTransaction391,
+    pacs_009_001_08::CreditTransferTransaction361,
+    pacs_009_001_08_adv::CreditTransferTransaction361,
+    pacs_009_001_08_cov::CreditTransferTransaction361,
+    pacs_010_001_03::DirectDebitTransactionInformation251,
+    pacs_010_001_03_mc::DirectDebitTransactionInformation251,
+);
+
+impl Document {
+    /// Clearing system reference of the transaction in a pacs message
+    /// Returns `None` for other message families or when no reference is present.
+    pub fn clearing_system_reference(&self) -> Option<&str> {
+        match self {
+            Document::Pacs008(doc) => doc.cdt_trf_tx_inf.clearing_system_reference(),
+            Document::Pacs009(doc) => doc.cdt_trf_tx_inf.clearing_system_reference(),
+            Document::Pacs003(doc) => doc.drct_dbt_tx_inf.clearing_system_reference(),
+            Document::Pacs010(doc) => doc.cdt_instr.drct_dbt_tx_inf.clearing_system_reference(),
+            Document::Pacs002(doc) => doc.tx_inf_and_sts.clearing_system_reference(),
+            Document::Pacs004(doc) => doc.tx_inf.clearing_system_reference(),
+            _ => None,
+        }
+    }
+
+    /// Every original reference the document carries, ordered by kind then path
+    /// The same value of the same kind is reported once, at its first path.
+    pub fn original_reference_chain(&self) -> Vec<Reference> {
+        self.with_json(|value| {
+            let mut references = Vec::new();
+            visitor::visit(value, "", &mut |path, node| {
+                if let serde_json::Value::Object(map) = node {
+                    for (name, child) in map {
+                        let Some(kind) = ReferenceKind::from_element(name) else {
+                            continue;
+                        };
+                        let Some(reference) = child.as_str().filter(|v| !v.trim().is_empty())
+                        else {
+                            continue;
+                        };
+                        let message_name = match kind {
+                            ReferenceKind::MessageId => map
+                                .get("OrgnlMsgNmId")
+                                .and_then(|v| v.as_str())
+                                .map(str::to_string),
+                            _ => None,
+                        };
+                        references.push(Reference {
+                            kind,
+                            value: reference.to_string(),
+                            message_name,
+                            path: helpers::child_path(path, name),
+                        });
+                    }
+                }
+            });
+            references.sort_by(|a, b| (a.kind, &a.path).cmp(&(b.kind, &b.path)));
+            let mut seen = std::collections::HashSet::new();
+            references.retain(|r| seen.insert((r.kind, r.value.clone())));
+            references
+        })
+        .unwrap_or_default()
+    }
+}
+
+/// Implement `original_message_type()` on the original group types carrying `OrgnlMsgNmId`
+macro_rules! impl_original_message_type {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Registry entry of the original message type (`OrgnlMsgNmId`), if it is a
+                /// known message definition identifier such as "pacs.008.001.08"
+                pub fn original_message_type(&self) -> Option<&'static MessageTypeInfo> {
+                    message_registry::find_message_definition(&self.orgnl_msg_nm_id)
+                }
+            }
+        )*
+    };
+}
+
+impl_original_message_type!(
+    camt_029_001_09::OriginalGroupInformation291,
+    camt_055_001_08::OriginalGroupInformation29,
+    camt_056_001_08::OriginalGroupInformation291,
+    pacs_002_001_10::OriginalGroupInformation291,
+    pacs_004_001_09::OriginalGroupInformation291,
+    pain_002_001_10::OriginalGroupHeader171,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clearing_system_reference_present() {
+        let mut doc = pacs_008_001_08::FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.pmt_id.clr_sys_ref = Some("T2REF0001".to_string());
+        assert_eq!(
+            doc.cdt_trf_tx_inf.clearing_system_reference(),
+            Some("T2REF0001")
+        );
+        assert_eq!(
+            Document::Pacs008(Box::new(doc)).clearing_system_reference(),
+            Some("T2REF0001")
+        );
+
+        let mut status = pacs_002_001_10::FIToFIPaymentStatusReportV10::default();
+        status.tx_inf_and_sts.clr_sys_ref = Some("FEDREF0002".to_string());
+        assert_eq!(
+            Document::Pacs002(Box::new(status)).clearing_system_reference(),
+            Some("FEDREF0002")
+        );
+    }
+
+    #[test]
+    fn test_original_reference_chain() {
+        let mut doc = pacs_004_001_09::PaymentReturnV09::default();
+        let tx = &mut doc.tx_inf;
+        tx.orgnl_grp_inf = Some(pacs_004_001_09::OriginalGroupInformation291 {
+            orgnl_msg_id: "PAY-2024-0001".to_string(),
+            orgnl_msg_nm_id: "pacs.008.001.08".to_string(),
+            ..Default::default()
+        });
+        tx.orgnl_instr_id = Some("INSTR-1".to_string());
+        tx.orgnl_end_to_end_id = "E2E-1".to_string();
+        tx.orgnl_uetr = "8a562c67-ca16-48ba-b074-65581be6f001".to_string();
+        tx.orgnl_clr_sys_ref = Some("T2REF0001".to_string());
+
+        let chain = Document::Pacs004(Box::new(doc)).original_reference_chain();
+        let summary: Vec<_> = chain
+            .iter()
+            .map(|r| (r.kind, r.value.as_str(), r.message_name.as_deref()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (
+                    ReferenceKind::MessageId,
+                    "PAY-2024-0001",
+                    Some("pacs.008.001.08")
+                ),
+                (ReferenceKind::InstructionId, "INSTR-1", None),
+                (ReferenceKind::EndToEndId, "E2E-1", None),
+                (
+                    ReferenceKind::Uetr,
+                    "8a562c67-ca16-48ba-b074-65581be6f001",
+                    None
+                ),
+                (ReferenceKind::ClearingSystemReference, "T2REF0001", None),
+            ]
+        );
+        assert_eq!(chain[0].path, "TxInf.OrgnlGrpInf.OrgnlMsgId");
+    }
+
+    #[test]
+    fn test_clearing_system_reference_absent() {
+        let doc = pacs_009_001_08::FinancialInstitutionCreditTransferV08::default();
+        assert_eq!(doc.cdt_trf_tx_inf.clearing_system_reference(), None);
+        assert_eq!(
+            Document::Pacs009(Box::new(doc)).clearing_system_reference(),
+            None
+        );
+
+        let refs = camt_053_001_08::TransactionReferences61::default();
+        assert_eq!(refs.clearing_system_reference(), None);
+    }
+
+    #[test]
+    fn test_original_message_type() {
+        let mut grp = pacs_002_001_10::OriginalGroupInformation291 {
+            orgnl_msg_nm_id: "pacs.008.001.08".to_string(),
+            ..Default::default()
+        };
+        let info = grp.original_message_type().unwrap();
+        assert_eq!(info.short_form, "pacs.008");
+        assert_eq!(info.xml_element_name, "FIToFICstmrCdtTrf");
+
+        grp.orgnl_msg_nm_id = "pcas.008.001.08".to_string();
+        assert_eq!(grp.original_message_type(), None);
+    }
+}
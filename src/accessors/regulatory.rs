@@ -0,0 +1,145 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Regulatory reporting accessors
+//!
+//! `RgltryRptg` carries the information a payment must report to a
+//! regulator, typically for balance-of-payments statistics: the authority
+//! (`Authrty/Nm`, `Authrty/Ctry`) and one or more details, each with a
+//! reporting country (`Dtls/Ctry`), code (`Dtls/Cd`) and amount (`Dtls/Amt`).
+//! [`RegulatoryReportingEntry`] flattens each detail together with its
+//! authority into one value.
+
+use crate::document::*;
+use crate::identifiers::CurrencyCode;
+use serde::{Deserialize, Serialize};
+
+/// Flat view of one regulatory reporting detail
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegulatoryReportingEntry {
+    /// Name of the authority requiring the reporting (`Authrty/Nm`)
+    pub authority: Option<String>,
+    /// Country of the authority (`Authrty/Ctry`)
+    pub authority_country: Option<String>,
+    /// Country the detail is reported for (`Dtls/Ctry`)
+    pub country: Option<String>,
+    /// Reporting code (`Dtls/Cd`), e.g. a balance-of-payments transaction code
+    pub code: Option<String>,
+    /// Reported amount and its currency (`Dtls/Amt`)
+    pub amount: Option<(f64, CurrencyCode)>,
+}
+
+/// Implement `regulatory_reporting()` on transaction types carrying `RgltryRptg`
+macro_rules! impl_regulatory_reporting {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Every regulatory reporting detail of the transaction, in document order
+                /// A block without details yields one entry holding only its authority.
+                pub fn regulatory_reporting(&self) -> Vec<RegulatoryReportingEntry> {
+                    let mut entries = Vec::new();
+                    for rptg in self.rgltry_rptg.iter().flatten() {
+                        let authority = rptg.authrty.as_ref().and_then(|authrty| authrty.nm.clone());
+                        let authority_country =
+                            rptg.authrty.as_ref().and_then(|authrty| authrty.ctry.clone());
+                        let Some(dtls) = rptg.dtls.as_ref().filter(|dtls| !dtls.is_empty()) else {
+                            entries.push(RegulatoryReportingEntry {
+                                authority,
+                                authority_country,
+                                ..Default::default()
+                            });
+                            continue;
+                        };
+                        for dtl in dtls {
+                            entries.push(RegulatoryReportingEntry {
+                                authority: authority.clone(),
+                                authority_country: authority_country.clone(),
+                                country: dtl.ctry.clone(),
+                                code: dtl.cd.clone(),
+                                amount: dtl.amt.as_ref().map(|amt| (amt.value, amt.ccy.clone())),
+                            });
+                        }
+                    }
+                    entries
+                }
+            }
+        )*
+    };
+}
+
+impl_regulatory_reporting!(
+    pacs_003_001_08::DirectDebitTransactionInformation241,
+    pacs_008_001_08::CreditTransferTransaction391,
+    pacs_008_001_08_stp::CreditTransferTransaction391,
+    pain_001_001_09::CreditTransferTransaction341,
+    pain_008_001_08::DirectDebitTransactionInformation231,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pacs_008_001_08::*;
+
+    fn reporting(authority: &str, ctry: &str, cd: &str, value: f64) -> RegulatoryReporting31 {
+        RegulatoryReporting31 {
+            authrty: Some(RegulatoryAuthority21 {
+                nm: Some(authority.to_string()),
+                ctry: Some(ctry.to_string()),
+            }),
+            dtls: Some(vec![StructuredRegulatoryReporting31 {
+                ctry: Some(ctry.to_string()),
+                cd: Some(cd.to_string()),
+                amt: Some(CBPRAmount1 {
+                    ccy: "EUR".into(),
+                    value,
+                }),
+                ..Default::default()
+            }]),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_regulatory_reporting_entries() {
+        let tx = CreditTransferTransaction391 {
+            rgltry_rptg: Some(vec![
+                reporting("Deutsche Bundesbank", "DE", "150", 12500.0),
+                reporting("Banque de France", "FR", "AZ2", 800.0),
+            ]),
+            ..Default::default()
+        };
+        let entries = tx.regulatory_reporting();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].authority.as_deref(), Some("Deutsche Bundesbank"));
+        assert_eq!(entries[0].country.as_deref(), Some("DE"));
+        assert_eq!(entries[0].code.as_deref(), Some("150"));
+        assert_eq!(entries[0].amount, Some((12500.0, "EUR".into())));
+        assert_eq!(entries[1].authority_country.as_deref(), Some("FR"));
+        assert_eq!(entries[1].code.as_deref(), Some("AZ2"));
+        assert_eq!(entries[1].amount, Some((800.0, "EUR".into())));
+    }
+
+    #[test]
+    fn test_transaction_without_regulatory_reporting() {
+        assert!(
+            CreditTransferTransaction391::default()
+                .regulatory_reporting()
+                .is_empty()
+        );
+    }
+}
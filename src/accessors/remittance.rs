@@ -0,0 +1,440 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Structured remittance accessors
+//!
+//! Structured remittance (`RmtInf/Strd`) itemizes the documents a payment
+//! settles. Each referred document amount (`RfrdDocAmt`) gives the amount
+//! remitted for it, either directly as `RmtdAmt` or as the amount due less
+//! discounts and credit notes.
+//!
+//! Unstructured remittance (`RmtInf/Ustrd`) is free text. When a payment is
+//! forwarded to a receiver with less room for it (an MT field 70 takes 4
+//! lines of 35 characters), `fit_remittance` reflows the text to the
+//! receiver's capacity and reports what did not fit.
+
+use super::amounts::sum_by_currency;
+use crate::document::*;
+use std::collections::HashMap;
+
+/// Implement `document_amount()` on the `RemittanceAmount` types
+macro_rules! impl_remittance_amount {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Amount remitted for the document, with its currency
+                /// `RmtdAmt` if present, otherwise `DuePyblAmt` less `DscntApldAmt`
+                /// and `CdtNoteAmt` in the currency of the amount due.
+                pub fn document_amount(&self) -> Option<(&str, f64)> {
+                    if let Some(rmtd) = &self.rmtd_amt {
+                        return Some((rmtd.ccy.as_str(), rmtd.value));
+                    }
+                    let due = self.due_pybl_amt.as_ref()?;
+                    let discounts = self
+                        .dscnt_apld_amt
+                        .iter()
+                        .flatten()
+                        .map(|dscnt| &dscnt.amt)
+                        .chain(&self.cdt_note_amt)
+                        .filter(|amt| amt.ccy == due.ccy)
+                        .map(|amt| amt.value)
+                        .sum::<f64>();
+                    Some((due.ccy.as_str(), due.value - discounts))
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `structured_total()` on the `RemittanceInformation` types
+macro_rules! impl_remittance_information {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Sum of the referred document amounts of all structured entries, per currency
+                pub fn structured_total(&self) -> HashMap<String, f64> {
+                    sum_by_currency(
+                        self.strd
+                            .iter()
+                            .flatten()
+                            .filter_map(|strd| strd.rfrd_doc_amt.as_ref()?.document_amount())
+                            .map(|(ccy, value)| (ccy.to_string(), value)),
+                    )
+                }
+            }
+        )*
+    };
+}
+
+/// Implement `unstructured_text()` on the `RemittanceInformation` types
+macro_rules! impl_unstructured_remittance {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Unstructured remittance lines (`Ustrd`) joined by a space
+                /// `None` if the remittance carries no unstructured text.
+                pub fn unstructured_text(&self) -> Option<String> {
+                    let text = self
+                        .ustrd
+                        .as_lines()
+                        .iter()
+                        .map(|line| line.trim())
+                        .filter(|line| !line.is_empty())
+                        .collect::<Vec<_>>()
+                        .join(" ");
+                    (!text.is_empty()).then_some(text)
+                }
+            }
+        )*
+    };
+}
+
+/// Schema length of `Ustrd` (Max140Text), which holds the kept lines joined by a space
+const USTRD_MAX_LEN: usize = 140;
+
+/// Unstructured remittance reflowed to a receiver's capacity
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RemittanceFit {
+    /// Lines that fit, each at most the requested length
+    pub lines: Vec<String>,
+    /// Text that did not fit, `None` if everything was kept
+    pub dropped: Option<String>,
+}
+
+/// Implement `fit_remittance()` on the `RemittanceInformation` types
+macro_rules! impl_fit_remittance {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Reflow the unstructured remittance into at most `max_lines` lines of
+                /// `max_len` characters, keeping only the text that fits in `Ustrd`
+                /// Words are wrapped at whitespace, and a word longer than a line is
+                /// split. The kept lines are stored joined by a space, and are cut so
+                /// that the stored text stays within the 140 characters of `Ustrd`.
+                pub fn fit_remittance(&mut self, max_lines: usize, max_len: usize) -> RemittanceFit {
+                    let text = self.unstructured_text().unwrap_or_default();
+                    let fit = reflow(&text, max_lines, max_len, USTRD_MAX_LEN);
+                    self.ustrd.set_text(fit.lines.join(" "));
+                    fit
+                }
+            }
+        )*
+    };
+}
+
+/// Greedy word wrap of `text` into at most `max_lines` lines of `max_len` characters,
+/// the lines joined by a space taking at most `max_total` characters
+fn reflow(text: &str, max_lines: usize, max_len: usize, max_total: usize) -> RemittanceFit {
+    let mut lines: Vec<String> = Vec::new();
+    let mut words = text.split_whitespace();
+    let mut dropped = Vec::new();
+    // Characters of the lines joined by a space
+    let mut total = 0;
+    while let Some(word) = words.next() {
+        let mut rest = word;
+        while !rest.is_empty() {
+            let rest_len = rest.chars().count();
+            let current = lines.last_mut().filter(|line| {
+                let len = line.chars().count();
+                len > 0 && len + 1 + rest_len <= max_len && total + 1 + rest_len <= max_total
+            });
+            if let Some(line) = current {
+                line.push(' ');
+                line.push_str(rest);
+                total += 1 + rest_len;
+                rest = "";
+                continue;
+            }
+            let separator = usize::from(!lines.is_empty());
+            let room = max_len.min(max_total.saturating_sub(total + separator));
+            if lines.len() < max_lines && room > 0 {
+                let split = rest
+                    .char_indices()
+                    .nth(room)
+                    .map_or(rest.len(), |(idx, _)| idx);
+                lines.push(rest[..split].to_string());
+                total += separator + rest[..split].chars().count();
+                rest = &rest[split..];
+            } else {
+                dropped.push(rest.to_string());
+                dropped.extend(words.by_ref().map(str::to_string));
+                rest = "";
+            }
+        }
+    }
+    RemittanceFit {
+        lines,
+        dropped: (!dropped.is_empty()).then(|| dropped.join(" ")),
+    }
+}
+
+/// `Ustrd` is optional in most message types and mandatory in pacs.010
+trait AsLines {
+    fn as_lines(&self) -> &[String];
+
+    /// Replace the text, leaving an optional `Ustrd` absent when empty
+    fn set_text(&mut self, text: String);
+}
+
+impl AsLines for String {
+    fn as_lines(&self) -> &[String] {
+        std::slice::from_ref(self)
+    }
+
+    fn set_text(&mut self, text: String) {
+        *self = text;
+    }
+}
+
+impl AsLines for Option<String> {
+    fn as_lines(&self) -> &[String] {
+        self.as_slice()
+    }
+
+    fn set_text(&mut self, text: String) {
+        *self = (!text.is_empty()).then_some(text);
+    }
+}
+
+/// Implement `remittance_structured_total()` on transaction types carrying `RmtInf`
+macro_rules! impl_remittance_structured_total {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Sum of the structured remittance document amounts, per currency
+                /// Empty when the transaction has no structured remittance amounts.
+                pub fn remittance_structured_total(&self) -> HashMap<String, f64> {
+                    self.rmt_inf
+                        .as_ref()
+                        .map(|rmt_inf| rmt_inf.structured_total())
+                        .unwrap_or_default()
+                }
+            }
+        )*
+    };
+}
+
+impl_remittance_amount!(
+    pacs_003_001_08::RemittanceAmount2,
+    pacs_008_001_08::RemittanceAmount21,
+    pain_001_001_09::RemittanceAmount2,
+    pain_008_001_08::RemittanceAmount2,
+);
+
+impl_remittance_information!(
+    pacs_003_001_08::RemittanceInformation161,
+    pacs_008_001_08::RemittanceInformation161,
+    pain_001_001_09::RemittanceInformation161,
+    pain_008_001_08::RemittanceInformation161,
+);
+
+impl_unstructured_remittance!(
+    camt_052_001_08::RemittanceInformation161,
+    camt_053_001_08::RemittanceInformation161,
+    camt_054_001_08::RemittanceInformation161,
+    pacs_003_001_08::RemittanceInformation161,
+    pacs_004_001_09::RemittanceInformation161,
+    pacs_008_001_08::RemittanceInformation161,
+    pacs_008_001_08_stp::RemittanceInformation161,
+    pacs_009_001_08::RemittanceInformation21,
+    pacs_009_001_08_adv::RemittanceInformation21,
+    pacs_009_001_08_cov::RemittanceInformation161,
+    pacs_009_001_08_cov::RemittanceInformation21,
+    pacs_010_001_03::RemittanceInformation21,
+    pacs_010_001_03_mc::RemittanceInformation21,
+    pain_001_001_09::RemittanceInformation161,
+    pain_008_001_08::RemittanceInformation161,
+);
+
+impl_fit_remittance!(
+    camt_052_001_08::RemittanceInformation161,
+    camt_053_001_08::RemittanceInformation161,
+    camt_054_001_08::RemittanceInformation161,
+    pacs_003_001_08::RemittanceInformation161,
+    pacs_004_001_09::RemittanceInformation161,
+    pacs_008_001_08::RemittanceInformation161,
+    pacs_008_001_08_stp::RemittanceInformation161,
+    pacs_009_001_08::RemittanceInformation21,
+    pacs_009_001_08_adv::RemittanceInformation21,
+    pacs_009_001_08_cov::RemittanceInformation161,
+    pacs_009_001_08_cov::RemittanceInformation21,
+    pacs_010_001_03::RemittanceInformation21,
+    pacs_010_001_03_mc::RemittanceInformation21,
+    pain_001_001_09::RemittanceInformation161,
+    pain_008_001_08::RemittanceInformation161,
+);
+
+impl_remittance_structured_total!(
+    pacs_003_001_08::DirectDebitTransactionInformation241,
+    pacs_008_001_08::CreditTransferTransaction391,
+    pain_001_001_09::CreditTransferTransaction341,
+    pain_008_001_08::DirectDebitTransactionInformation231,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::RemittanceFit;
+    use crate::document::pain_001_001_09::*;
+
+    fn eur(value: f64) -> ActiveOrHistoricCurrencyAndAmount {
+        ActiveOrHistoricCurrencyAndAmount {
+            ccy: "EUR".into(),
+            value,
+        }
+    }
+
+    fn invoice(amount: RemittanceAmount2) -> StructuredRemittanceInformation161 {
+        StructuredRemittanceInformation161 {
+            rfrd_doc_amt: Some(amount),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_remittance_structured_total() {
+        let tx = CreditTransferTransaction341 {
+            rmt_inf: Some(RemittanceInformation161 {
+                ustrd: None,
+                strd: Some(vec![
+                    invoice(RemittanceAmount2 {
+                        rmtd_amt: Some(eur(400.0)),
+                        due_pybl_amt: Some(eur(420.0)),
+                        ..Default::default()
+                    }),
+                    invoice(RemittanceAmount2 {
+                        due_pybl_amt: Some(eur(250.0)),
+                        dscnt_apld_amt: Some(vec![DiscountAmountAndType1 {
+                            tp: None,
+                            amt: eur(5.0),
+                        }]),
+                        cdt_note_amt: Some(eur(20.0)),
+                        ..Default::default()
+                    }),
+                    StructuredRemittanceInformation161::default(),
+                ]),
+            }),
+            ..Default::default()
+        };
+
+        let total = tx.remittance_structured_total();
+        assert_eq!(total.len(), 1);
+        assert!((total["EUR"] - 625.0).abs() < 1e-9);
+        assert!(
+            CreditTransferTransaction341::default()
+                .remittance_structured_total()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_unstructured_text() {
+        let mut rmt_inf = RemittanceInformation161 {
+            ustrd: Some(" INV 2024-001 ".to_string()),
+            strd: None,
+        };
+        assert_eq!(rmt_inf.unstructured_text().as_deref(), Some("INV 2024-001"));
+        rmt_inf.ustrd = Some("  ".to_string());
+        assert_eq!(rmt_inf.unstructured_text(), None);
+        assert_eq!(
+            crate::document::pacs_010_001_03::RemittanceInformation21 {
+                ustrd: "COVER".to_string()
+            }
+            .unstructured_text()
+            .as_deref(),
+            Some("COVER")
+        );
+    }
+
+    #[test]
+    fn test_fit_remittance() {
+        let mut rmt_inf = RemittanceInformation161 {
+            ustrd: Some(
+                "Payment of invoices INV-2024-0001 INV-2024-0002 and INV-2024-0003 per contract 88"
+                    .to_string(),
+            ),
+            strd: None,
+        };
+        let fit = rmt_inf.fit_remittance(2, 35);
+        assert_eq!(
+            fit,
+            RemittanceFit {
+                lines: vec![
+                    "Payment of invoices INV-2024-0001".to_string(),
+                    "INV-2024-0002 and INV-2024-0003 per".to_string(),
+                ],
+                dropped: Some("contract 88".to_string()),
+            }
+        );
+        assert!(fit.lines.iter().all(|line| line.chars().count() <= 35));
+        assert_eq!(
+            rmt_inf.ustrd.as_deref(),
+            Some("Payment of invoices INV-2024-0001 INV-2024-0002 and INV-2024-0003 per")
+        );
+
+        // A word longer than a line is split; text that fits is kept whole
+        let mut rmt_inf = RemittanceInformation161 {
+            ustrd: Some("ABCDEFGHIJ KL".to_string()),
+            strd: None,
+        };
+        let fit = rmt_inf.fit_remittance(3, 4);
+        assert_eq!(fit.lines, vec!["ABCD", "EFGH", "IJ"]);
+        assert_eq!(fit.dropped.as_deref(), Some("KL"));
+        assert_eq!(rmt_inf.ustrd.as_deref(), Some("ABCD EFGH IJ"));
+        assert_eq!(
+            rmt_inf.fit_remittance(4, 35),
+            RemittanceFit {
+                lines: vec!["ABCD EFGH IJ".to_string()],
+                dropped: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fit_remittance_stays_valid() {
+        use crate::parse_result::{ErrorCollector, ParserConfig};
+        use crate::validation::Validate;
+
+        // 35-character words fill an MT field 70 line each, but 4 such lines and
+        // their separators would take 143 characters
+        let word = "A".repeat(35);
+        let mut rmt_inf = RemittanceInformation161 {
+            ustrd: Some(vec![word.as_str(); 5].join(" ")),
+            strd: None,
+        };
+        let fit = rmt_inf.fit_remittance(4, 35);
+        let stored = rmt_inf.ustrd.clone().unwrap();
+        assert_eq!(stored, fit.lines.join(" "));
+        assert_eq!(stored.chars().count(), 140);
+        assert_eq!(fit.lines.len(), 4);
+        assert_eq!(fit.lines[3].chars().count(), 32);
+        assert_eq!(fit.dropped, Some(format!("AAA {word}")));
+
+        let mut rmt_inf = RemittanceInformation161 {
+            ustrd: Some("ABCDEFGHIJ".repeat(20)),
+            strd: None,
+        };
+        let fit = rmt_inf.fit_remittance(4, 35);
+        assert_eq!(fit.lines.concat().chars().count(), 137);
+        assert_eq!(fit.dropped.map(|text| text.chars().count()), Some(63));
+
+        let mut collector = ErrorCollector::new();
+        rmt_inf.validate("RmtInf", &ParserConfig::default(), &mut collector);
+        assert!(!collector.has_errors(), "{:?}", collector.errors());
+    }
+}
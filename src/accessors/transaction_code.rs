@@ -0,0 +1,331 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Bank transaction code accessors
+//!
+//! `BkTxCd` identifies the kind of an entry either through the ISO
+//! domain/family/subfamily hierarchy (`Domn/Cd`, `Domn/Fmly/Cd`,
+//! `Domn/Fmly/SubFmlyCd`), through a bank proprietary code (`Prtry/Cd`), or
+//! both. [`TransactionCode`] flattens the two into one value for cash
+//! application and entry classification. Banks that keep their own codes
+//! alongside the ISO ones convert between the two forms with a
+//! [`TransactionCodeMapping`].
+
+use crate::document::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Flat view of a bank transaction code
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TransactionCode {
+    /// ISO domain code (`Domn/Cd`), e.g. "PMNT"
+    pub domain: Option<String>,
+    /// ISO family code (`Domn/Fmly/Cd`), e.g. "RCDT"
+    pub family: Option<String>,
+    /// ISO subfamily code (`Domn/Fmly/SubFmlyCd`), e.g. "ESCT"
+    pub subfamily: Option<String>,
+    /// Bank proprietary code (`Prtry/Cd`)
+    pub proprietary: Option<String>,
+}
+
+/// Caller-supplied table between ISO domain/family/subfamily codes and proprietary codes
+/// Each structured code maps to one proprietary code and back; adding an entry
+/// for a code already in the table replaces its earlier pairing on both sides.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionCodeMapping {
+    issuer: String,
+    to_proprietary: HashMap<(String, String, String), String>,
+    to_structured: HashMap<String, (String, String, String)>,
+}
+
+impl TransactionCodeMapping {
+    /// Empty table for the proprietary codes of the given issuer (`Prtry/Issr`)
+    pub fn new(issuer: impl Into<String>) -> Self {
+        TransactionCodeMapping {
+            issuer: issuer.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Pair a domain/family/subfamily code with a proprietary code
+    pub fn with_entry(
+        mut self,
+        (domain, family, subfamily): (&str, &str, &str),
+        proprietary: &str,
+    ) -> Self {
+        let structured = (
+            domain.to_string(),
+            family.to_string(),
+            subfamily.to_string(),
+        );
+        if let Some(old) = self.to_proprietary.remove(&structured) {
+            self.to_structured.remove(&old);
+        }
+        if let Some(old) = self.to_structured.remove(proprietary) {
+            self.to_proprietary.remove(&old);
+        }
+        self.to_proprietary
+            .insert(structured.clone(), proprietary.to_string());
+        self.to_structured
+            .insert(proprietary.to_string(), structured);
+        self
+    }
+
+    /// Issuer of the proprietary codes
+    pub fn issuer(&self) -> &str {
+        &self.issuer
+    }
+
+    /// Proprietary code for a domain/family/subfamily code
+    pub fn proprietary_for(&self, domain: &str, family: &str, subfamily: &str) -> Option<&str> {
+        self.to_proprietary
+            .get(&(
+                domain.to_string(),
+                family.to_string(),
+                subfamily.to_string(),
+            ))
+            .map(String::as_str)
+    }
+
+    /// Domain, family and subfamily code for a proprietary code
+    pub fn structured_for(&self, proprietary: &str) -> Option<(&str, &str, &str)> {
+        self.to_structured
+            .get(proprietary)
+            .map(|(domain, family, subfamily)| {
+                (domain.as_str(), family.as_str(), subfamily.as_str())
+            })
+    }
+}
+
+/// Implement the transaction code accessors on the entry types of each report message
+macro_rules! impl_transaction_code {
+    ($($module:ident),* $(,)?) => {
+        $(
+            impl $module::BankTransactionCodeStructure41 {
+                /// Domain, family, subfamily and proprietary codes as one flat value
+                pub fn transaction_code(&self) -> TransactionCode {
+                    TransactionCode {
+                        domain: self.domn.as_ref().map(|domn| domn.cd.clone()),
+                        family: self.domn.as_ref().map(|domn| domn.fmly.cd.clone()),
+                        subfamily: self
+                            .domn
+                            .as_ref()
+                            .map(|domn| domn.fmly.sub_fmly_cd.clone()),
+                        proprietary: self.prtry.as_ref().map(|prtry| prtry.cd.clone()),
+                    }
+                }
+
+                /// The code in proprietary form (`Prtry`), converted from `Domn` through the mapping
+                /// Returns `None` if the code has no `Domn` or the table has no entry for it.
+                pub fn to_proprietary(&self, mapping: &TransactionCodeMapping) -> Option<Self> {
+                    let domn = self.domn.as_ref()?;
+                    let cd = mapping.proprietary_for(&domn.cd, &domn.fmly.cd, &domn.fmly.sub_fmly_cd)?;
+                    Some($module::BankTransactionCodeStructure41 {
+                        domn: None,
+                        prtry: Some($module::ProprietaryBankTransactionCodeStructure11 {
+                            cd: cd.to_string(),
+                            issr: mapping.issuer().to_string(),
+                        }),
+                    })
+                }
+
+                /// The code in structured form (`Domn`), converted from `Prtry` through the mapping
+                /// Returns `None` if the code has no `Prtry`, was issued by another issuer,
+                /// or the table has no entry for it.
+                pub fn to_structured(&self, mapping: &TransactionCodeMapping) -> Option<Self> {
+                    let prtry = self.prtry.as_ref().filter(|prtry| prtry.issr == mapping.issuer())?;
+                    let (domain, family, subfamily) = mapping.structured_for(&prtry.cd)?;
+                    Some($module::BankTransactionCodeStructure41 {
+                        domn: Some($module::BankTransactionCodeStructure5 {
+                            cd: domain.to_string(),
+                            fmly: $module::BankTransactionCodeStructure6 {
+                                cd: family.to_string(),
+                                sub_fmly_cd: subfamily.to_string(),
+                            },
+                        }),
+                        prtry: None,
+                    })
+                }
+            }
+
+            impl $module::ReportEntry101 {
+                /// Bank transaction code of the entry (`BkTxCd`)
+                pub fn transaction_code(&self) -> TransactionCode {
+                    self.bk_tx_cd.transaction_code()
+                }
+            }
+
+            impl $module::EntryTransaction101 {
+                /// Bank transaction code of the transaction (`BkTxCd`), if given
+                pub fn transaction_code(&self) -> Option<TransactionCode> {
+                    self.bk_tx_cd.as_ref().map(|code| code.transaction_code())
+                }
+            }
+        )*
+    };
+}
+
+impl_transaction_code!(camt_052_001_08, camt_053_001_08, camt_054_001_08);
+
+impl camt_053_001_08::AccountStatement91 {
+    /// Entries of the statement grouped by bank transaction code domain (`Domn/Cd`)
+    /// Entries keep their statement order within a group; entries carrying only
+    /// a proprietary code have no domain and are left out.
+    pub fn entries_grouped_by_domain(
+        &self,
+    ) -> HashMap<String, Vec<&camt_053_001_08::ReportEntry101>> {
+        let mut groups: HashMap<String, Vec<_>> = HashMap::new();
+        for entry in self.ntry.iter().flatten() {
+            if let Some(domn) = &entry.bk_tx_cd.domn {
+                groups
+                    .entry(domn.cd.clone())
+                    .or_default()
+                    .push(entry.as_ref());
+            }
+        }
+        groups
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use camt_053_001_08::*;
+
+    fn entry(bk_tx_cd: BankTransactionCodeStructure41) -> ReportEntry101 {
+        ReportEntry101 {
+            bk_tx_cd,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_domain_transaction_code() {
+        let entry = entry(BankTransactionCodeStructure41 {
+            domn: Some(BankTransactionCodeStructure5 {
+                cd: "PMNT".to_string(),
+                fmly: BankTransactionCodeStructure6 {
+                    cd: "RCDT".to_string(),
+                    sub_fmly_cd: "ESCT".to_string(),
+                },
+            }),
+            prtry: None,
+        });
+        assert_eq!(
+            entry.transaction_code(),
+            TransactionCode {
+                domain: Some("PMNT".to_string()),
+                family: Some("RCDT".to_string()),
+                subfamily: Some("ESCT".to_string()),
+                proprietary: None,
+            }
+        );
+    }
+
+    fn domain_entry(domain: &str, family: &str, reference: &str) -> Box<ReportEntry101> {
+        Box::new(ReportEntry101 {
+            ntry_ref: Some(reference.to_string()),
+            ..entry(BankTransactionCodeStructure41 {
+                domn: Some(BankTransactionCodeStructure5 {
+                    cd: domain.to_string(),
+                    fmly: BankTransactionCodeStructure6 {
+                        cd: family.to_string(),
+                        sub_fmly_cd: "OTHR".to_string(),
+                    },
+                }),
+                prtry: None,
+            })
+        })
+    }
+
+    #[test]
+    fn test_entries_grouped_by_domain() {
+        let statement = AccountStatement91 {
+            ntry: Some(vec![
+                domain_entry("PMNT", "RCDT", "E1"),
+                domain_entry("SECU", "SETT", "E2"),
+                domain_entry("PMNT", "ICDT", "E3"),
+            ]),
+            ..Default::default()
+        };
+        let groups = statement.entries_grouped_by_domain();
+        assert_eq!(groups.len(), 2);
+        let refs = |domain: &str| -> Vec<&str> {
+            groups[domain]
+                .iter()
+                .filter_map(|entry| entry.ntry_ref.as_deref())
+                .collect()
+        };
+        assert_eq!(refs("PMNT"), ["E1", "E3"]);
+        assert_eq!(refs("SECU"), ["E2"]);
+    }
+
+    #[test]
+    fn test_proprietary_transaction_code() {
+        let entry = entry(BankTransactionCodeStructure41 {
+            domn: None,
+            prtry: Some(ProprietaryBankTransactionCodeStructure11 {
+                cd: "NTRF+166".to_string(),
+                issr: "SWIFT".to_string(),
+            }),
+        });
+        let code = entry.transaction_code();
+        assert_eq!(code.proprietary.as_deref(), Some("NTRF+166"));
+        assert_eq!(code.domain, None);
+        assert_eq!(code.subfamily, None);
+    }
+
+    #[test]
+    fn test_transaction_code_mapping_round_trip() {
+        let mapping = TransactionCodeMapping::new("BANKDEFF")
+            .with_entry(("PMNT", "RCDT", "ESCT"), "N166")
+            .with_entry(("PMNT", "IDDT", "ESDD"), "N105");
+        let structured = BankTransactionCodeStructure41 {
+            domn: Some(BankTransactionCodeStructure5 {
+                cd: "PMNT".to_string(),
+                fmly: BankTransactionCodeStructure6 {
+                    cd: "RCDT".to_string(),
+                    sub_fmly_cd: "ESCT".to_string(),
+                },
+            }),
+            prtry: None,
+        };
+
+        let proprietary = structured.to_proprietary(&mapping).unwrap();
+        assert_eq!(proprietary.domn, None);
+        assert_eq!(
+            proprietary.prtry,
+            Some(ProprietaryBankTransactionCodeStructure11 {
+                cd: "N166".to_string(),
+                issr: "BANKDEFF".to_string(),
+            })
+        );
+        assert_eq!(
+            proprietary.to_structured(&mapping),
+            Some(structured.clone())
+        );
+
+        // No entry in the table, or a code of another issuer
+        let unmapped = TransactionCodeMapping::new("BANKDEFF");
+        assert_eq!(structured.to_proprietary(&unmapped), None);
+        let other_issuer =
+            TransactionCodeMapping::new("OTHRGB22").with_entry(("PMNT", "RCDT", "ESCT"), "N166");
+        assert_eq!(proprietary.to_structured(&other_issuer), None);
+    }
+}
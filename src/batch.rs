@@ -0,0 +1,306 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Partial batch parsing
+//!
+//! A malformed transaction makes the whole message fail to deserialize. For
+//! messages carrying a repeating transaction block (pain.008 `DrctDbtTxInf`,
+//! camt.052/053/054 `Ntry`) each occurrence can instead be checked on its own:
+//! the malformed ones are reported with their index and error and removed, and
+//! the rest of the message is parsed as usual.
+//!
+//! Files delivered over FileAct or similar channels may also carry several
+//! `AppHdr`/`Document` pairs in one container; [`parse_batch`] splits them and
+//! parses each message on its own.
+
+use crate::document::{camt_052_001_08, camt_053_001_08, camt_054_001_08, pain_008_001_08};
+use crate::error::MxError;
+use crate::message_registry;
+use crate::mx_envelope::MxMessage;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+use serde::de::DeserializeOwned;
+use std::ops::Range;
+
+/// A transaction that could not be parsed
+#[derive(Debug)]
+pub struct TransactionFailure {
+    /// Position of the transaction among its siblings, starting at 0
+    pub index: usize,
+    pub error: MxError,
+}
+
+/// Result of a partial parse: the message without its malformed transactions
+#[derive(Debug)]
+pub struct PartialParse {
+    pub message: MxMessage,
+    pub failures: Vec<TransactionFailure>,
+}
+
+impl PartialParse {
+    /// Whether every transaction parsed
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+}
+
+type TransactionParser = fn(&str) -> Result<(), MxError>;
+
+/// Repeating transaction element of a message type and the parser for one occurrence
+fn transaction_element(message_type: &str) -> Option<(&'static str, TransactionParser)> {
+    match message_type {
+        "pain.008" => Some((
+            "DrctDbtTxInf",
+            parse_as::<pain_008_001_08::DirectDebitTransactionInformation231>,
+        )),
+        "camt.052" => Some(("Ntry", parse_as::<camt_052_001_08::ReportEntry101>)),
+        "camt.053" => Some(("Ntry", parse_as::<camt_053_001_08::ReportEntry101>)),
+        "camt.054" => Some(("Ntry", parse_as::<camt_054_001_08::ReportEntry101>)),
+        _ => None,
+    }
+}
+
+fn parse_as<T: DeserializeOwned>(xml: &str) -> Result<(), MxError> {
+    quick_xml::de::from_str::<T>(xml)
+        .map(|_| ())
+        .map_err(|e| MxError::XmlDeserialization(e.to_string()))
+}
+
+impl MxMessage {
+    /// Parse XML, isolating malformed transactions instead of failing the message
+    /// Messages without a repeating transaction block parse as with `from_xml`.
+    /// Fails if the message is still invalid once the malformed transactions
+    /// are removed, e.g. because none of them parsed.
+    pub fn from_xml_partial(xml: &str) -> Result<PartialParse, MxError> {
+        let message_type = document_message_type(xml)?;
+        let Some((element, parse)) = transaction_element(message_type) else {
+            return Ok(PartialParse {
+                message: Self::from_xml(xml)?,
+                failures: Vec::new(),
+            });
+        };
+
+        let mut failures = Vec::new();
+        let mut kept = String::with_capacity(xml.len());
+        let mut last = 0;
+        for (index, span) in element_spans(xml, element)?.into_iter().enumerate() {
+            if let Err(error) = parse(&xml[span.clone()]) {
+                kept.push_str(&xml[last..span.start]);
+                last = span.end;
+                failures.push(TransactionFailure { index, error });
+            }
+        }
+        kept.push_str(&xml[last..]);
+
+        Ok(PartialParse {
+            message: Self::from_xml(&kept)?,
+            failures,
+        })
+    }
+}
+
+/// Split a container of several messages and parse each one independently
+/// Every `Document` is paired with the `AppHdr` preceding it, whatever the
+/// wrapper elements around them (`Envelope`, a batch root, or none). The
+/// container itself must be well-formed XML; otherwise the only result is the
+/// XML error.
+pub fn parse_batch(xml: &str) -> Vec<Result<MxMessage, MxError>> {
+    let (headers, documents) = match (element_spans(xml, "AppHdr"), element_spans(xml, "Document"))
+    {
+        (Ok(headers), Ok(documents)) => (headers, documents),
+        (Err(e), _) | (_, Err(e)) => return vec![Err(e)],
+    };
+
+    let mut previous_end = 0;
+    documents
+        .into_iter()
+        .map(|document| {
+            let header = headers
+                .iter()
+                .rev()
+                .find(|header| header.start >= previous_end && header.end <= document.start);
+            previous_end = document.end;
+            match header {
+                Some(header) => MxMessage::from_xml(&xml[header.start..document.end]),
+                None => Err(MxError::XmlDeserialization(
+                    "AppHdr not found for Document".to_string(),
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Message type of the first element inside `Document`
+fn document_message_type(xml: &str) -> Result<&'static str, MxError> {
+    let mut reader = Reader::from_str(xml);
+    let mut in_document = false;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(e)) | Ok(Event::Empty(e)) => {
+                let name = e.local_name();
+                let name = String::from_utf8_lossy(name.as_ref());
+                if in_document {
+                    return message_registry::element_to_message_type(&name)
+                        .ok_or_else(|| MxError::UnknownMessageType(name.to_string()));
+                }
+                in_document = name == "Document";
+            }
+            Ok(Event::Eof) => {
+                return Err(MxError::XmlDeserialization(
+                    "Document not found in XML".to_string(),
+                ));
+            }
+            Err(e) => return Err(MxError::XmlDeserialization(e.to_string())),
+            Ok(_) => {}
+        }
+    }
+}
+
+/// Byte ranges of the outermost occurrences of an element
+fn element_spans(xml: &str, element: &str) -> Result<Vec<Range<usize>>, MxError> {
+    let mut reader = Reader::from_str(xml);
+    let mut spans = Vec::new();
+    let mut open: Option<(usize, usize)> = None;
+    let mut depth = 0usize;
+    loop {
+        let start = reader.buffer_position() as usize;
+        match reader.read_event() {
+            Ok(Event::Start(e)) => {
+                depth += 1;
+                if open.is_none() && e.local_name().as_ref() == element.as_bytes() {
+                    open = Some((start, depth));
+                }
+            }
+            Ok(Event::Empty(e)) => {
+                if open.is_none() && e.local_name().as_ref() == element.as_bytes() {
+                    spans.push(start..reader.buffer_position() as usize);
+                }
+            }
+            Ok(Event::End(_)) => {
+                if let Some((span_start, span_depth)) = open
+                    && span_depth == depth
+                {
+                    spans.push(span_start..reader.buffer_position() as usize);
+                    open = None;
+                }
+                depth = depth.saturating_sub(1);
+            }
+            Ok(Event::Eof) => return Ok(spans),
+            Err(e) => return Err(MxError::XmlDeserialization(e.to_string())),
+            Ok(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mx_envelope::{AppHdr, Document};
+    use crate::xml::XmlConfig;
+    use pain_008_001_08::*;
+
+    fn direct_debit(end_to_end_id: &str, value: f64) -> DirectDebitTransactionInformation231 {
+        DirectDebitTransactionInformation231 {
+            pmt_id: PaymentIdentification6 {
+                end_to_end_id: end_to_end_id.to_string(),
+                ..Default::default()
+            },
+            instd_amt: ActiveOrHistoricCurrencyAndAmount {
+                ccy: "EUR".into(),
+                value,
+            },
+            ..Default::default()
+        }
+    }
+
+    fn batch_xml() -> String {
+        let mut doc = CustomerDirectDebitInitiationV08::default();
+        doc.pmt_inf.drct_dbt_tx_inf = vec![
+            direct_debit("E2E-1", 100.0),
+            direct_debit("E2E-2", 200.0),
+            direct_debit("E2E-3", 300.0),
+        ];
+        let app_hdr = AppHdr {
+            msg_def_idr: "pain.008.001.08".to_string(),
+            ..Default::default()
+        };
+        MxMessage::new(app_hdr, Document::Pain008(Box::new(doc)))
+            .to_xml()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_partial_parse_isolates_malformed_transaction() {
+        let xml = batch_xml().replacen(">200</InstdAmt>", ">two hundred</InstdAmt>", 1);
+        assert!(MxMessage::from_xml(&xml).is_err());
+
+        let partial = MxMessage::from_xml_partial(&xml).unwrap();
+        assert!(!partial.is_complete());
+        assert_eq!(partial.failures.len(), 1);
+        assert_eq!(partial.failures[0].index, 1);
+
+        let Document::Pain008(doc) = &partial.message.document else {
+            panic!("expected pain.008");
+        };
+        let ids: Vec<&str> = doc
+            .pmt_inf
+            .drct_dbt_tx_inf
+            .iter()
+            .map(|tx| tx.pmt_id.end_to_end_id.as_str())
+            .collect();
+        assert_eq!(ids, ["E2E-1", "E2E-3"]);
+    }
+
+    #[test]
+    fn test_parse_batch_reports_each_message() {
+        let config = XmlConfig {
+            include_xml_declaration: false,
+            ..Default::default()
+        };
+        let message = |msg_id: &str| {
+            let mut doc = CustomerDirectDebitInitiationV08::default();
+            doc.grp_hdr.msg_id = msg_id.to_string();
+            doc.pmt_inf.drct_dbt_tx_inf = vec![direct_debit("E2E-1", 100.0)];
+            let app_hdr = AppHdr {
+                msg_def_idr: "pain.008.001.08".to_string(),
+                ..Default::default()
+            };
+            MxMessage::new(app_hdr, Document::Pain008(Box::new(doc)))
+                .to_xml_with_config(&config)
+                .unwrap()
+        };
+        let invalid = message("MSG-2").replace(">100</InstdAmt>", ">one hundred</InstdAmt>");
+        let file = format!("<Batch>{}{}</Batch>", message("MSG-1"), invalid);
+
+        let results = parse_batch(&file);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].as_ref().unwrap().document.message_id(), "MSG-1");
+        assert!(results[1].is_err());
+    }
+
+    #[test]
+    fn test_partial_parse_complete_batch() {
+        let partial = MxMessage::from_xml_partial(&batch_xml()).unwrap();
+        assert!(partial.is_complete());
+        let Document::Pain008(doc) = &partial.message.document else {
+            panic!("expected pain.008");
+        };
+        assert_eq!(doc.pmt_inf.drct_dbt_tx_inf.len(), 3);
+    }
+}
@@ -0,0 +1,223 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Account currency rules (camt.052/053 Acct.Ccy, CardTx.PrePdAcct.Ccy)
+//!
+//! A report or statement is kept in the currency of its account, so every
+//! balance and every entry amount must be in `Acct.Ccy`. Only the entry's own
+//! `Amt` is checked: the amount details of the underlying transactions
+//! (`NtryDtls/TxDtls/AmtDtls`) may legitimately carry the instructed or
+//! counter-value amounts in another currency.
+//!
+//! The prepaid account of a card entry or transaction leaves `Ccy` optional,
+//! but when the entry or transaction carries amounts the currency is needed
+//! to tell which of them were loaded onto the card.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, index_path, report_error};
+use crate::visitor;
+use serde::Serialize;
+
+/// Validate that balances and entries are in the account currency
+/// `path` is the path of the report or statement. The check is skipped when
+/// the account currency is empty, which the schema rules already report.
+pub fn validate_account_currency<'a>(
+    account_ccy: &str,
+    balances: impl IntoIterator<Item = &'a str>,
+    entries: impl IntoIterator<Item = &'a str>,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if account_ccy.is_empty() {
+        return;
+    }
+    for (element, currencies) in [
+        ("Bal", balances.into_iter().collect::<Vec<_>>()),
+        ("Ntry", entries.into_iter().collect()),
+    ] {
+        for (idx, ccy) in currencies.into_iter().enumerate() {
+            if ccy == account_ccy {
+                continue;
+            }
+            let element_path = index_path(&child_path(path, element), idx);
+            report_error(
+                ValidationError::new(
+                    2015,
+                    format!("{element} amount is in {ccy}, account currency is {account_ccy}"),
+                )
+                .with_field("Amt".to_string())
+                .with_path(child_path(&element_path, "Amt")),
+                config,
+                collector,
+            );
+        }
+    }
+}
+
+/// Validate that every prepaid account used next to amounts states its currency
+/// Applies to `CardTx/PrePdAcct` on entries and transaction details that carry
+/// `Amt` or `AmtDtls`.
+pub fn validate_prepaid_account_currency<T: Serialize>(
+    document: &T,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let Ok(value) = serde_json::to_value(document) else {
+        return;
+    };
+    visitor::visit(&value, path, &mut |node_path, node| {
+        let Some(account) = node.get("CardTx").and_then(|card| card.get("PrePdAcct")) else {
+            return;
+        };
+        let has_amounts = node.get("Amt").is_some() || node.get("AmtDtls").is_some();
+        if !has_amounts || account.get("Ccy").is_some() {
+            return;
+        }
+        let account_path = child_path(&child_path(node_path, "CardTx"), "PrePdAcct");
+        report_error(
+            ValidationError::new(
+                2022,
+                "PrePdAcct Ccy is required when the prepaid account is used with amounts"
+                    .to_string(),
+            )
+            .with_field("Ccy".to_string())
+            .with_path(child_path(&account_path, "Ccy")),
+            config,
+            collector,
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::camt_053_001_08::*;
+    use crate::error::ValidationError;
+    use crate::parse_result::{ErrorCollector, ParserConfig};
+    use crate::validation::BusinessRules;
+
+    fn amount(ccy: &str, value: f64) -> ActiveOrHistoricCurrencyAndAmount {
+        ActiveOrHistoricCurrencyAndAmount {
+            ccy: ccy.into(),
+            value,
+        }
+    }
+
+    fn balance(code: &str, ccy: &str, value: f64) -> CashBalance81 {
+        CashBalance81 {
+            tp: BalanceType131 {
+                cd_or_prtry: BalanceType10Choice1 {
+                    cd: Some(code.to_string()),
+                    prtry: None,
+                },
+                sub_tp: None,
+            },
+            amt: amount(ccy, value),
+            cdt_dbt_ind: CreditDebitCode::CodeCRDT,
+            ..Default::default()
+        }
+    }
+
+    fn check(closing_ccy: &str) -> Vec<ValidationError> {
+        let doc = BankToCustomerStatementV08 {
+            stmt: AccountStatement91 {
+                acct: CashAccount391 {
+                    ccy: "EUR".into(),
+                    ..Default::default()
+                },
+                bal: vec![
+                    balance("OPBD", "EUR", 100.0),
+                    balance("CLBD", closing_ccy, 150.0),
+                ],
+                ntry: Some(vec![Box::new(ReportEntry101 {
+                    amt: amount("EUR", 50.0),
+                    cdt_dbt_ind: CreditDebitCode::CodeCRDT,
+                    sts: EntryStatus1Choice1 {
+                        cd: Some(ExternalEntryStatus1Codefixed::CodeBOOK),
+                    },
+                    ..Default::default()
+                })]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_balances_in_account_currency() {
+        assert!(check("EUR").is_empty());
+    }
+
+    #[test]
+    fn test_balance_in_other_currency() {
+        let errors = check("USD");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2015);
+        assert_eq!(errors[0].path.as_deref(), Some("Stmt.Bal[1].Amt"));
+    }
+
+    fn check_prepaid_account(ccy: Option<&str>) -> Vec<ValidationError> {
+        let doc = BankToCustomerStatementV08 {
+            stmt: AccountStatement91 {
+                ntry: Some(vec![Box::new(ReportEntry101 {
+                    amt: amount("EUR", 50.0),
+                    card_tx: Some(CardEntry41 {
+                        pre_pd_acct: Some(CashAccount382 {
+                            ccy: ccy.map(Into::into),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                })]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut collector = ErrorCollector::new();
+        super::validate_prepaid_account_currency(
+            &doc,
+            "",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    #[test]
+    fn test_prepaid_account_with_currency() {
+        assert!(check_prepaid_account(Some("EUR")).is_empty());
+    }
+
+    #[test]
+    fn test_prepaid_account_without_currency() {
+        let errors = check_prepaid_account(None);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2022);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("Stmt.Ntry[0].CardTx.PrePdAcct.Ccy")
+        );
+    }
+}
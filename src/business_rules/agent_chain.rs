@@ -0,0 +1,153 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Agent chain rules (DbtrAgt, IntrmyAgt1-3, CdtrAgt)
+//!
+//! The payment travels from the debtor agent through up to three
+//! intermediary agents to the creditor agent. An intermediary with the same
+//! BIC as the agent before or after it routes the payment to the agent that
+//! already holds it, which points at a misconfigured routing table. The debtor
+//! and creditor agent may be the same bank (an on-us payment), so only pairs
+//! involving an intermediary are checked. A BIC8 and its primary-office BIC11
+//! (`XXX` branch) count as the same agent.
+
+use crate::error::ValidationError;
+use crate::identifiers::Bic;
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+use crate::visitor;
+use serde_json::Value;
+
+/// Agents of the routing chain, in payment order
+pub const AGENT_CHAIN: [&str; 5] = [
+    "DbtrAgt",
+    "IntrmyAgt1",
+    "IntrmyAgt2",
+    "IntrmyAgt3",
+    "CdtrAgt",
+];
+
+/// Validate that no intermediary agent repeats the agent next to it in the chain
+pub fn validate_agent_chain(
+    document: &Document,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    // The document serializes wrapped in its root element
+    let Ok(Value::Object(root)) = serde_json::to_value(document) else {
+        return;
+    };
+    let Some(content) = root.values().next() else {
+        return;
+    };
+    visitor::visit(content, path, &mut |node_path, node| {
+        let Value::Object(map) = node else {
+            return;
+        };
+        if !map.contains_key("IntrmyAgt1") {
+            return;
+        }
+        let chain: Vec<(&str, Bic)> = AGENT_CHAIN
+            .iter()
+            .filter_map(|name| Some((*name, agent_bic(map.get(*name)?)?)))
+            .collect();
+        for pair in chain.windows(2) {
+            let [(previous, previous_bic), (agent, bic)] = pair else {
+                continue;
+            };
+            if previous_bic != bic || !(is_intermediary(previous) || is_intermediary(agent)) {
+                continue;
+            }
+            report_error(
+                ValidationError::new(
+                    2034,
+                    format!("{agent} {bic} repeats {previous} in the agent chain"),
+                )
+                .with_field(agent.to_string())
+                .with_path(child_path(node_path, agent)),
+                config,
+                collector,
+            );
+        }
+    });
+}
+
+fn is_intermediary(name: &str) -> bool {
+    name.starts_with("IntrmyAgt")
+}
+
+/// BIC of a serialized agent in its primary-office form
+fn agent_bic(agent: &Value) -> Option<Bic> {
+    let bic = agent.pointer("/FinInstnId/BICFI")?.as_str()?.trim();
+    if bic.is_empty() {
+        return None;
+    }
+    Some(Bic::from(bic).primary_office())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::*;
+
+    fn agent(bic: &str) -> BranchAndFinancialInstitutionIdentification61 {
+        BranchAndFinancialInstitutionIdentification61 {
+            fin_instn_id: FinancialInstitutionIdentification181 {
+                bicfi: Some(bic.into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn check(intermediaries: &[&str]) -> Vec<ValidationError> {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.dbtr_agt = agent("DEUTDEFFXXX");
+        tx.cdtr_agt.fin_instn_id.bicfi = Some("BNPAFRPPXXX".into());
+        let mut intermediaries = intermediaries.iter().map(|bic| agent(bic));
+        tx.intrmy_agt1 = intermediaries.next();
+        tx.intrmy_agt2 = intermediaries.next();
+        let mut collector = ErrorCollector::new();
+        validate_agent_chain(
+            &Document::Pacs008(Box::new(doc)),
+            "",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    #[test]
+    fn test_clean_agent_chain() {
+        assert!(check(&["CITIUS33XXX", "CHASUS33XXX"]).is_empty());
+    }
+
+    #[test]
+    fn test_duplicated_adjacent_agent() {
+        let errors = check(&["CITIUS33XXX", "CITIUS33"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2034);
+        assert_eq!(errors[0].path.as_deref(), Some("CdtTrfTxInf.IntrmyAgt2"));
+
+        let errors = check(&["DEUTDEFFXXX"]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field.as_deref(), Some("IntrmyAgt1"));
+    }
+}
@@ -0,0 +1,150 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! AnyBIC rules (OrgId/AnyBIC)
+//!
+//! `AnyBIC` accepts any BIC, including BEIs of non-financial institutions
+//! that are not connected to the SWIFT network. That is fine for identifying
+//! a debtor or creditor, but the parties of an investigation case
+//! (`Assgnr`, `Assgne`, `Cretr`) are the addressees of the case messages and
+//! must be reachable. Under the profiles that route case management over
+//! SWIFT, every `AnyBIC` must be 8 or 11 characters long (the schema pattern
+//! also matches a BIC embedded in a longer value), and an `AnyBIC` in those
+//! elements must be a connected BIC.
+
+use crate::error::ValidationError;
+use crate::identifiers::Bic;
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{leaf_name, report_error};
+use crate::visitor;
+use serde_json::Value;
+
+/// Elements whose `AnyBIC` must be a connected BIC rather than a BEI
+pub const CONNECTED_BIC_CONTEXTS: &[&str] = &["Assgnr", "Assgne", "Cretr"];
+
+/// Validate every `AnyBIC` of a document against the context it appears in
+pub fn validate_any_bic(
+    document: &Document,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.profile.requires_connected_bic() {
+        return;
+    }
+    // The document serializes wrapped in its root element
+    let Ok(Value::Object(root)) = serde_json::to_value(document) else {
+        return;
+    };
+    let Some(content) = root.values().next() else {
+        return;
+    };
+    visitor::visit(content, path, &mut |node_path, node| {
+        if leaf_name(node_path) != "AnyBIC" {
+            return;
+        }
+        let Some(bic) = node.as_str().map(Bic::from) else {
+            return;
+        };
+        let message = if !matches!(bic.as_str().len(), 8 | 11) {
+            format!("AnyBIC '{bic}' must be an 8 or 11 character BIC")
+        } else if bic.is_valid() && !bic.is_connected() && requires_connected_bic(node_path) {
+            format!(
+                "AnyBIC '{bic}' is not a connected BIC; a BEI cannot identify a case party under the {:?} profile",
+                config.profile
+            )
+        } else {
+            return;
+        };
+        report_error(
+            ValidationError::new(2019, message)
+                .with_field("AnyBIC".to_string())
+                .with_path(node_path.to_string()),
+            config,
+            collector,
+        );
+    });
+}
+
+fn requires_connected_bic(path: &str) -> bool {
+    path.split('.')
+        .any(|segment| CONNECTED_BIC_CONTEXTS.contains(&segment))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::camt_056_001_08::*;
+    use crate::error::ValidationError;
+    use crate::mx_envelope::Document;
+    use crate::parse_result::{ErrorCollector, ParserConfig, ValidationProfile};
+    use crate::validation::Validate;
+
+    fn check(creator_bic: &str, profile: ValidationProfile) -> Vec<ValidationError> {
+        let mut doc = FIToFIPaymentCancellationRequestV08::default();
+        doc.undrlyg.tx_inf.case.cretr.pty = Some(PartyIdentification1351 {
+            nm: Some("ACME Corp".to_string()),
+            id: Some(Party38Choice1 {
+                org_id: Some(OrganisationIdentification291 {
+                    any_bic: Some(creator_bic.into()),
+                    ..Default::default()
+                }),
+                prvt_id: None,
+            }),
+            ..Default::default()
+        });
+        let config = ParserConfig::default().with_profile(profile);
+        let mut collector = ErrorCollector::new();
+        Document::Camt056(Box::new(doc)).validate("", &config, &mut collector);
+        collector
+            .errors()
+            .into_iter()
+            .filter(|err| {
+                err.path.as_deref() == Some("Undrlyg.TxInf.Case.Cretr.Pty.Id.OrgId.AnyBIC")
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_eight_character_bic() {
+        assert!(check("DEUTDEFF", ValidationProfile::CbprPlus).is_empty());
+    }
+
+    #[test]
+    fn test_eleven_character_bic() {
+        assert!(check("DEUTDEFF500", ValidationProfile::CbprPlus).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        let errors = check("DEUTDEFF5", ValidationProfile::CbprPlus);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2019);
+        assert!(errors[0].message.contains("8 or 11"));
+    }
+
+    #[test]
+    fn test_bei_as_case_party() {
+        let errors = check("ACMEGB21", ValidationProfile::CbprPlus);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2019);
+
+        assert!(check("ACMEGB21", ValidationProfile::Base).is_empty());
+    }
+}
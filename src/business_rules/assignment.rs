@@ -0,0 +1,146 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Case assignment rules (Assgnmt)
+//!
+//! Investigation and cancellation messages (camt.029, camt.055, camt.056)
+//! are routed on their assignment: the assigner (`Assgnr`) passes the case
+//! to the assignee (`Assgne`). Both are choices between an agent and a
+//! party, and the schema accepts an empty choice, which leaves the case with
+//! nowhere to go. Each must be populated: an agent with a valid BIC
+//! (`Agt/FinInstnId/BICFI`) or, where the BIC is optional, another
+//! institution identification, or a party with a name or an identification.
+
+use crate::error::ValidationError;
+use crate::identifiers::Bic;
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, leaf_name, report_error};
+use crate::visitor;
+use serde_json::Value;
+
+/// Assignment parties that must be populated
+pub const ASSIGNMENT_PARTIES: [&str; 2] = ["Assgnr", "Assgne"];
+
+/// Validate that the assigner and assignee of every case assignment are populated
+pub fn validate_assignment_parties(
+    document: &Document,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    // The document serializes wrapped in its root element
+    let Ok(Value::Object(root)) = serde_json::to_value(document) else {
+        return;
+    };
+    let Some(content) = root.values().next() else {
+        return;
+    };
+    visitor::visit(content, path, &mut |node_path, node| {
+        if leaf_name(node_path) != "Assgnmt" {
+            return;
+        }
+        for element in ASSIGNMENT_PARTIES {
+            let Some(problem) = assignment_party_problem(node.get(element)) else {
+                continue;
+            };
+            report_error(
+                ValidationError::new(2042, format!("Assignment {element} {problem}"))
+                    .with_field(element.to_string())
+                    .with_path(child_path(node_path, element)),
+                config,
+                collector,
+            );
+        }
+    });
+}
+
+/// Why a serialized assignment party cannot route the case, if it cannot
+fn assignment_party_problem(party: Option<&Value>) -> Option<String> {
+    if let Some(agent) = party.and_then(|party| party.get("Agt")) {
+        let fin_instn_id = agent.get("FinInstnId");
+        return match fin_instn_id.and_then(|id| id.get("BICFI")) {
+            Some(bicfi) => {
+                let bic = bicfi.as_str().unwrap_or_default();
+                (!Bic::from(bic).is_valid())
+                    .then(|| format!("agent BICFI '{bic}' is not a valid BIC"))
+            }
+            None => fin_instn_id
+                .and_then(Value::as_object)
+                .is_none_or(|id| id.is_empty())
+                .then(|| "agent has no financial institution identification".to_string()),
+        };
+    }
+    if let Some(pty) = party.and_then(|party| party.get("Pty")) {
+        let identified = ["Nm", "Id"].iter().any(|field| pty.get(field).is_some());
+        return (!identified).then(|| "party has neither a name nor an identification".to_string());
+    }
+    Some("is not populated with an agent or a party".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_056_001_08::*;
+
+    fn agent(bic: &str) -> Party40Choice1 {
+        Party40Choice1 {
+            agt: Some(BranchAndFinancialInstitutionIdentification61 {
+                fin_instn_id: FinancialInstitutionIdentification181 {
+                    bicfi: bic.into(),
+                    ..Default::default()
+                },
+            }),
+        }
+    }
+
+    fn check(assgnr: Party40Choice1, assgne: Party40Choice1) -> Vec<ValidationError> {
+        let mut doc = FIToFIPaymentCancellationRequestV08::default();
+        doc.assgnmt.assgnr = assgnr;
+        doc.assgnmt.assgne = assgne;
+        let mut collector = ErrorCollector::new();
+        validate_assignment_parties(
+            &Document::Camt056(Box::new(doc)),
+            "",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    #[test]
+    fn test_complete_assignment() {
+        assert!(check(agent("DEUTDEFFXXX"), agent("BNPAFRPP")).is_empty());
+    }
+
+    #[test]
+    fn test_missing_assignee() {
+        let errors = check(agent("DEUTDEFFXXX"), Party40Choice1::default());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2042);
+        assert!(errors[0].message.contains("not populated"));
+        assert_eq!(errors[0].path.as_deref(), Some("Assgnmt.Assgne"));
+    }
+
+    #[test]
+    fn test_invalid_assigner_bic() {
+        let errors = check(agent("DEUT"), agent("BNPAFRPP"));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path.as_deref(), Some("Assgnmt.Assgnr"));
+    }
+}
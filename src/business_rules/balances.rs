@@ -0,0 +1,415 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Statement balance rules (camt.053)
+//!
+//! A statement must report where the account started and ended: an opening
+//! booked balance (OPBD, or PRCD for the previous day's closing) and a closing
+//! booked balance (CLBD). The check is enabled with
+//! `ParserConfig::require_statement_balances`, since interim statements of
+//! some banks leave them out.
+//!
+//! Within one statement, the opening booked balance (OPBD or PRCD) plus the
+//! net of the booked entries (status BOOK) must give the closing booked
+//! balance (CLBD). Statements carrying no entries, such as summary-only
+//! statements, are not checked.
+//! Across statements, consecutive statements for an account form a chain: the
+//! opening booked balance of a statement must equal the closing booked balance
+//! (CLBD) of the one before it. A break in the chain usually means a statement was
+//! lost in transit, so the check compares two statements rather than looking
+//! at a single message.
+
+use crate::document::camt_053_001_08::{
+    AccountStatement91, CashBalance81, CreditDebitCode, ExternalEntryStatus1Codefixed,
+};
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, index_path, report_error};
+
+/// Tolerance when comparing balance amounts, which are carried as f64
+const BALANCE_TOLERANCE: f64 = 1e-6;
+
+/// Opening balance types that satisfy the opening balance requirement
+const OPENING_BALANCE_CODES: &[&str] = &["OPBD", "PRCD"];
+
+/// Validate that the statement carries an opening and a closing booked balance
+/// Each missing balance is reported separately.
+pub fn validate_required_balances(
+    statement: &AccountStatement91,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.require_statement_balances {
+        return;
+    }
+    let has_opening = find_opening_balance(statement).is_some();
+    let has_closing = find_balance(statement, "CLBD").is_some();
+    for (missing, description) in [
+        (!has_opening, "an opening booked balance (OPBD or PRCD)"),
+        (!has_closing, "a closing booked balance (CLBD)"),
+    ] {
+        if !missing {
+            continue;
+        }
+        report_error(
+            ValidationError::new(
+                2023,
+                format!("Statement {} has no {description}", statement.id),
+            )
+            .with_field("Bal".to_string())
+            .with_path(child_path(path, "Bal")),
+            config,
+            collector,
+        );
+    }
+}
+
+/// Validate that opening balance plus booked entries equals the closing balance
+///
+/// Only entries with status BOOK are added. The check is skipped when the statement
+/// carries no entries, lacks an opening or CLBD balance, or when the balances or
+/// any booked entry are in different currencies.
+pub fn validate_statement_balances(
+    statement: &AccountStatement91,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let (Some(opening), Some((closing_idx, closing))) = (
+        find_opening_balance(statement),
+        statement
+            .bal
+            .iter()
+            .enumerate()
+            .find(|(_, bal)| bal.tp.cd_or_prtry.cd.as_deref() == Some("CLBD")),
+    ) else {
+        return;
+    };
+    let ccy = &closing.amt.ccy;
+    if opening.amt.ccy != *ccy || statement.ntry.as_ref().is_none_or(Vec::is_empty) {
+        return;
+    }
+
+    let mut expected = signed_amount(opening);
+    let booked = statement
+        .ntry
+        .iter()
+        .flatten()
+        .filter(|entry| entry.sts.cd == Some(ExternalEntryStatus1Codefixed::CodeBOOK));
+    for entry in booked {
+        if entry.amt.ccy != *ccy {
+            return;
+        }
+        expected += match entry.cdt_dbt_ind {
+            CreditDebitCode::CodeCRDT => entry.amt.value,
+            CreditDebitCode::CodeDBIT => -entry.amt.value,
+        };
+    }
+
+    let declared = signed_amount(closing);
+    if (expected - declared).abs() > BALANCE_TOLERANCE {
+        report_error(
+            ValidationError::new(
+                2012,
+                format!(
+                    "Closing balance {declared} {ccy} differs from opening balance plus entries {expected}"
+                ),
+            )
+            .with_field("Bal".to_string())
+            .with_path(index_path(&child_path(path, "Bal"), closing_idx)),
+            config,
+            collector,
+        );
+    }
+}
+
+/// Check that `next` continues the balance chain of `prev`
+///
+/// Both statements must report on the same account in the same currency,
+/// `prev` must carry a CLBD balance and `next` an OPBD or PRCD balance, and the two
+/// must agree in currency and signed amount.
+pub fn check_balance_continuity(
+    prev: &AccountStatement91,
+    next: &AccountStatement91,
+) -> Result<(), ValidationError> {
+    if prev.acct.id != next.acct.id || prev.acct.ccy != next.acct.ccy {
+        return Err(continuity_error(
+            "Acct",
+            format!(
+                "Statement {} is not for the same account and currency as statement {}",
+                next.id, prev.id
+            ),
+        ));
+    }
+
+    let closing = find_balance(prev, "CLBD").ok_or_else(|| {
+        continuity_error(
+            "Bal",
+            format!("Statement {} has no closing booked balance (CLBD)", prev.id),
+        )
+    })?;
+    let opening = find_opening_balance(next).ok_or_else(|| {
+        continuity_error(
+            "Bal",
+            format!(
+                "Statement {} has no opening booked balance (OPBD or PRCD)",
+                next.id
+            ),
+        )
+    })?;
+
+    if closing.amt.ccy != opening.amt.ccy
+        || (signed_amount(closing) - signed_amount(opening)).abs() > BALANCE_TOLERANCE
+    {
+        return Err(continuity_error(
+            "Bal",
+            format!(
+                "Opening balance {} {} of statement {} does not match closing balance {} {} of statement {}",
+                signed_amount(opening),
+                opening.amt.ccy,
+                next.id,
+                signed_amount(closing),
+                closing.amt.ccy,
+                prev.id
+            ),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Opening booked balance, OPBD preferred over PRCD
+fn find_opening_balance(statement: &AccountStatement91) -> Option<&CashBalance81> {
+    OPENING_BALANCE_CODES
+        .iter()
+        .find_map(|code| find_balance(statement, code))
+}
+
+fn find_balance<'a>(statement: &'a AccountStatement91, code: &str) -> Option<&'a CashBalance81> {
+    statement
+        .bal
+        .iter()
+        .find(|bal| bal.tp.cd_or_prtry.cd.as_deref() == Some(code))
+}
+
+fn signed_amount(balance: &CashBalance81) -> f64 {
+    match balance.cdt_dbt_ind {
+        CreditDebitCode::CodeCRDT => balance.amt.value,
+        CreditDebitCode::CodeDBIT => -balance.amt.value,
+    }
+}
+
+fn continuity_error(field: &str, message: String) -> ValidationError {
+    ValidationError::new(2011, message)
+        .with_field(field.to_string())
+        .with_path(format!("Stmt.{field}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_053_001_08::*;
+    use crate::validation::BusinessRules;
+
+    fn balance(code: &str, value: f64, cdt_dbt_ind: CreditDebitCode) -> CashBalance81 {
+        CashBalance81 {
+            tp: BalanceType131 {
+                cd_or_prtry: BalanceType10Choice1 {
+                    cd: Some(code.to_string()),
+                    prtry: None,
+                },
+                sub_tp: None,
+            },
+            amt: ActiveOrHistoricCurrencyAndAmount {
+                ccy: "EUR".into(),
+                value,
+            },
+            cdt_dbt_ind,
+            ..Default::default()
+        }
+    }
+
+    fn statement(id: &str, opening: f64, closing: f64) -> AccountStatement91 {
+        AccountStatement91 {
+            id: id.to_string(),
+            acct: CashAccount391 {
+                id: AccountIdentification4Choice1 {
+                    iban: Some("DE89370400440532013000".into()),
+                    othr: None,
+                },
+                ccy: "EUR".into(),
+                ..Default::default()
+            },
+            bal: vec![
+                balance("OPBD", opening, CreditDebitCode::CodeCRDT),
+                balance("CLBD", closing, CreditDebitCode::CodeCRDT),
+            ],
+            ..Default::default()
+        }
+    }
+
+    fn entry(value: f64, cdt_dbt_ind: CreditDebitCode) -> Box<ReportEntry101> {
+        Box::new(ReportEntry101 {
+            amt: ActiveOrHistoricCurrencyAndAmount {
+                ccy: "EUR".into(),
+                value,
+            },
+            cdt_dbt_ind,
+            sts: EntryStatus1Choice1 {
+                cd: Some(ExternalEntryStatus1Codefixed::CodeBOOK),
+            },
+            ..Default::default()
+        })
+    }
+
+    /// Entry that is not booked; the guideline status code only names BOOK, so a
+    /// pending (PDNG) or information (INFO) entry arrives without it
+    fn pending_entry(value: f64, cdt_dbt_ind: CreditDebitCode) -> Box<ReportEntry101> {
+        let mut entry = entry(value, cdt_dbt_ind);
+        entry.sts.cd = None;
+        entry
+    }
+
+    fn check_arithmetic(statement: AccountStatement91) -> Vec<ValidationError> {
+        let doc = BankToCustomerStatementV08 {
+            stmt: statement,
+            ..Default::default()
+        };
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_statement_balances() {
+        let mut stmt = statement("STMT-1", 100.0, 250.5);
+        stmt.ntry = Some(vec![
+            entry(200.0, CreditDebitCode::CodeCRDT),
+            entry(49.5, CreditDebitCode::CodeDBIT),
+        ]);
+        assert!(check_arithmetic(stmt).is_empty());
+    }
+
+    #[test]
+    fn test_statement_balances_ignore_pending_entries() {
+        let mut stmt = statement("STMT-1", 100.0, 250.5);
+        stmt.ntry = Some(vec![
+            entry(200.0, CreditDebitCode::CodeCRDT),
+            pending_entry(1000.0, CreditDebitCode::CodeCRDT),
+            entry(49.5, CreditDebitCode::CodeDBIT),
+        ]);
+        assert!(check_arithmetic(stmt).is_empty());
+    }
+
+    #[test]
+    fn test_statement_balances_without_entries() {
+        // A summary-only statement has nothing to add up
+        assert!(check_arithmetic(statement("STMT-1", 100.0, 250.5)).is_empty());
+    }
+
+    #[test]
+    fn test_statement_balances_off_by_entry() {
+        let mut stmt = statement("STMT-1", 100.0, 250.5);
+        stmt.ntry = Some(vec![entry(200.0, CreditDebitCode::CodeCRDT)]);
+        let errors = check_arithmetic(stmt);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2012);
+        assert_eq!(errors[0].path.as_deref(), Some("Stmt.Bal[1]"));
+    }
+
+    fn check_required(statement: AccountStatement91) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_required_balances(
+            &statement,
+            "Stmt",
+            &ParserConfig::default().with_required_statement_balances(true),
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    #[test]
+    fn test_required_balances_present() {
+        assert!(check_required(statement("STMT-1", 100.0, 250.5)).is_empty());
+
+        let mut stmt = statement("STMT-1", 100.0, 250.5);
+        stmt.bal[0].tp.cd_or_prtry.cd = Some("PRCD".to_string());
+        assert!(check_required(stmt).is_empty());
+    }
+
+    #[test]
+    fn test_required_closing_balance_missing() {
+        let mut stmt = statement("STMT-1", 100.0, 250.5);
+        stmt.bal.truncate(1);
+        let errors = check_required(stmt.clone());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2023);
+        assert!(errors[0].message.contains("CLBD"));
+        assert_eq!(errors[0].path.as_deref(), Some("Stmt.Bal"));
+
+        // Off unless requested
+        let mut collector = ErrorCollector::new();
+        validate_required_balances(&stmt, "Stmt", &ParserConfig::default(), &mut collector);
+        assert!(!collector.has_errors());
+    }
+
+    #[test]
+    fn test_previous_closing_as_opening_balance() {
+        let mut stmt = statement("STMT-1", 100.0, 250.5);
+        stmt.bal[0].tp.cd_or_prtry.cd = Some("PRCD".to_string());
+        stmt.ntry = Some(vec![entry(200.0, CreditDebitCode::CodeCRDT)]);
+        assert_eq!(check_arithmetic(stmt.clone())[0].code, 2012);
+
+        let prev = statement("STMT-0", 0.0, 90.0);
+        assert_eq!(
+            check_balance_continuity(&prev, &stmt).unwrap_err().code,
+            2011
+        );
+    }
+
+    #[test]
+    fn test_continuous_statements() {
+        let prev = statement("STMT-1", 100.0, 250.5);
+        let next = statement("STMT-2", 250.5, 80.0);
+        assert!(check_balance_continuity(&prev, &next).is_ok());
+    }
+
+    #[test]
+    fn test_broken_balance_chain() {
+        let prev = statement("STMT-1", 100.0, 250.5);
+        let next = statement("STMT-3", 80.0, 40.0);
+        let err = check_balance_continuity(&prev, &next).unwrap_err();
+        assert_eq!(err.code, 2011);
+        assert_eq!(err.path.as_deref(), Some("Stmt.Bal"));
+
+        let mut debit = statement("STMT-2", 250.5, 10.0);
+        debit.bal[0].cdt_dbt_ind = CreditDebitCode::CodeDBIT;
+        assert!(check_balance_continuity(&prev, &debit).is_err());
+    }
+
+    #[test]
+    fn test_different_account() {
+        let prev = statement("STMT-1", 100.0, 250.5);
+        let mut next = statement("STMT-2", 250.5, 80.0);
+        next.acct.ccy = "USD".into();
+        let err = check_balance_continuity(&prev, &next).unwrap_err();
+        assert_eq!(err.path.as_deref(), Some("Stmt.Acct"));
+    }
+}
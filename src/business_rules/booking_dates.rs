@@ -0,0 +1,207 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Booking date rules (camt.053)
+//!
+//! A booked entry (`Sts/Cd` BOOK) has already been posted to the account, so
+//! its booking date cannot lie after the statement was created. Such an
+//! entry is usually a producer stamping the expected booking date of a
+//! pending entry while reporting it as booked. Entries with another status
+//! are exempt. Dates are compared as calendar dates in their own offsets.
+//!
+//! When the statement declares its period (`FrToDt`), every entry must be
+//! dated within it, by its booking date or else its value date. An entry
+//! outside the period belongs to another statement.
+
+use crate::dates::{ParsedDate, entry_date};
+use crate::document::camt_053_001_08::{BankToCustomerStatementV08, ExternalEntryStatus1Codefixed};
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, index_path, report_error};
+
+/// Validate that no booked entry has a booking date after the statement creation date
+/// The statement `CreDtTm` is used when present, the group header `CreDtTm`
+/// otherwise. Dates that are not ISO dates are left to schema validation.
+pub fn validate_booking_dates(
+    doc: &BankToCustomerStatementV08,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let stmt = &doc.stmt;
+    let created_at = stmt.cre_dt_tm.as_deref().unwrap_or(&doc.grp_hdr.cre_dt_tm);
+    let Some(created) = ParsedDate::parse(created_at).map(|parsed| parsed.date()) else {
+        return;
+    };
+    let entries_path = child_path(&child_path(path, "Stmt"), "Ntry");
+    for (idx, entry) in stmt.ntry.iter().flatten().enumerate() {
+        if entry.sts.cd != Some(ExternalEntryStatus1Codefixed::CodeBOOK) {
+            continue;
+        }
+        let Some(booked) = entry
+            .bookg_dt
+            .as_ref()
+            .and_then(|bookg_dt| bookg_dt.dt.as_deref().or(bookg_dt.dt_tm.as_deref()))
+            .and_then(ParsedDate::parse)
+            .map(|parsed| parsed.date())
+        else {
+            continue;
+        };
+        if booked > created {
+            report_error(
+                ValidationError::new(
+                    2026,
+                    format!(
+                        "Booked entry has booking date {booked}, after statement creation date {created}"
+                    ),
+                )
+                .with_field("BookgDt".to_string())
+                .with_path(child_path(&index_path(&entries_path, idx), "BookgDt")),
+                config,
+                collector,
+            );
+        }
+    }
+}
+
+/// Validate that every entry is dated within the declared statement period (`FrToDt`)
+/// Periods whose bounds are not ISO date times are left to schema validation.
+pub fn validate_entry_period(
+    doc: &BankToCustomerStatementV08,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let stmt = &doc.stmt;
+    let Some(fr_to_dt) = stmt.fr_to_dt.as_ref() else {
+        return;
+    };
+    let (Some(from), Some(to)) = (
+        ParsedDate::parse(&fr_to_dt.fr_dt_tm).map(|parsed| parsed.date()),
+        ParsedDate::parse(&fr_to_dt.to_dt_tm).map(|parsed| parsed.date()),
+    ) else {
+        return;
+    };
+    let entries_path = child_path(&child_path(path, "Stmt"), "Ntry");
+    for (idx, entry) in stmt.ntry.iter().flatten().enumerate() {
+        let Some(date) = entry_date(entry) else {
+            continue;
+        };
+        if date < from || date > to {
+            report_error(
+                ValidationError::new(
+                    2038,
+                    format!("Entry dated {date} lies outside the statement period {from} to {to}"),
+                )
+                .with_field("Ntry".to_string())
+                .with_path(index_path(&entries_path, idx)),
+                config,
+                collector,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_053_001_08::{
+        DateAndDateTime2Choice1, DateTimePeriod11, EntryStatus1Choice1, ReportEntry101,
+    };
+
+    fn statement(
+        sts: Option<ExternalEntryStatus1Codefixed>,
+        bookg_dt: &str,
+    ) -> BankToCustomerStatementV08 {
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.grp_hdr.cre_dt_tm = "2024-03-05T18:00:00+01:00".to_string();
+        doc.stmt.ntry = Some(vec![Box::new(ReportEntry101 {
+            sts: EntryStatus1Choice1 { cd: sts },
+            bookg_dt: Some(DateAndDateTime2Choice1 {
+                dt: Some(bookg_dt.to_string()),
+                dt_tm: None,
+            }),
+            ..Default::default()
+        })]);
+        doc
+    }
+
+    fn check(doc: &BankToCustomerStatementV08) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_booking_dates(doc, "", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_booked_entry_on_or_before_creation() {
+        let booked = Some(ExternalEntryStatus1Codefixed::CodeBOOK);
+        assert!(check(&statement(booked.clone(), "2024-03-05")).is_empty());
+        assert!(check(&statement(booked, "2024-03-04")).is_empty());
+    }
+
+    #[test]
+    fn test_future_dated_booked_entry() {
+        let errors = check(&statement(
+            Some(ExternalEntryStatus1Codefixed::CodeBOOK),
+            "2024-03-06",
+        ));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2026);
+        assert_eq!(errors[0].path.as_deref(), Some("Stmt.Ntry[0].BookgDt"));
+    }
+
+    #[test]
+    fn test_statement_creation_date_takes_precedence() {
+        let mut doc = statement(Some(ExternalEntryStatus1Codefixed::CodeBOOK), "2024-03-06");
+        doc.stmt.cre_dt_tm = Some("2024-03-06T08:00:00+01:00".to_string());
+        assert!(check(&doc).is_empty());
+    }
+
+    #[test]
+    fn test_entry_without_booked_status_is_exempt() {
+        assert!(check(&statement(None, "2024-03-20")).is_empty());
+    }
+
+    fn check_period(doc: &mut BankToCustomerStatementV08) -> Vec<ValidationError> {
+        doc.stmt.fr_to_dt = Some(DateTimePeriod11 {
+            fr_dt_tm: "2024-03-01T00:00:00+01:00".to_string(),
+            to_dt_tm: "2024-03-05T23:59:59+01:00".to_string(),
+        });
+        let mut collector = ErrorCollector::new();
+        validate_entry_period(doc, "", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_entries_within_period() {
+        let booked = Some(ExternalEntryStatus1Codefixed::CodeBOOK);
+        assert!(check_period(&mut statement(booked.clone(), "2024-03-01")).is_empty());
+        assert!(check_period(&mut statement(booked, "2024-03-05")).is_empty());
+    }
+
+    #[test]
+    fn test_entry_outside_period() {
+        let mut doc = statement(Some(ExternalEntryStatus1Codefixed::CodeBOOK), "2024-02-29");
+        let errors = check_period(&mut doc);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2038);
+        assert!(errors[0].message.contains("2024-02-29"));
+        assert_eq!(errors[0].path.as_deref(), Some("Stmt.Ntry[0]"));
+    }
+}
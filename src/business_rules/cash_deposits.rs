@@ -0,0 +1,166 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Cash deposit limit rules (CshDpst)
+//!
+//! A cash deposit on a reported transaction breaks the deposited cash down
+//! by note denomination (`NoteDnmtn`) and number of notes (`NbOfNotes`).
+//! Physical cash above a reporting threshold is subject to AML cash
+//! reporting, so under scheme profiles a deposit whose total exceeds the
+//! limit for its currency in `ParserConfig::cash_deposit_limits` raises a
+//! warning; the message itself stays valid. Deposits in currencies without a
+//! limit, or whose number of notes is not an integer, are not checked.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::leaf_name;
+use crate::visitor;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Warn about every cash deposit of a document above the limit for its currency
+pub fn validate_cash_deposits<T: Serialize>(
+    document: &T,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.profile.checks_cash_deposit_limit() {
+        return;
+    }
+    let Ok(value) = serde_json::to_value(document) else {
+        return;
+    };
+    visitor::visit(&value, path, &mut |node_path, node| {
+        if leaf_name(node_path) != "CshDpst" {
+            return;
+        }
+        let Some((ccy, total)) = deposit_total(node) else {
+            return;
+        };
+        let Some(&limit) = config.cash_deposit_limits.get(ccy) else {
+            return;
+        };
+        if total > limit {
+            collector.add_warning(
+                ValidationError::new(
+                    2030,
+                    format!(
+                        "Cash deposit of {total} {ccy} exceeds the cash deposit limit {limit} {ccy}"
+                    ),
+                )
+                .with_field("CshDpst".to_string())
+                .with_path(node_path.to_string()),
+            );
+        }
+    });
+}
+
+/// Currency and total (NbOfNotes times NoteDnmtn) of a serialized cash deposit
+fn deposit_total(deposit: &Value) -> Option<(&str, f64)> {
+    let denomination = deposit.get("NoteDnmtn")?;
+    let notes: u64 = deposit.get("NbOfNotes")?.as_str()?.parse().ok()?;
+    let ccy = denomination.get("@Ccy")?.as_str()?;
+    let value = denomination.get("$value")?.as_f64()?;
+    Some((ccy, notes as f64 * value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_053_001_08::*;
+    use crate::parse_result::ValidationProfile;
+
+    fn statement(nb_of_notes: &str, denomination: f64) -> BankToCustomerStatementV08 {
+        statement_in("EUR", nb_of_notes, denomination)
+    }
+
+    fn statement_in(ccy: &str, nb_of_notes: &str, denomination: f64) -> BankToCustomerStatementV08 {
+        let amount = |value| ActiveCurrencyAndAmount {
+            ccy: ccy.into(),
+            value,
+        };
+        let deposit = CashDeposit1 {
+            note_dnmtn: amount(denomination),
+            nb_of_notes: nb_of_notes.to_string(),
+            amt: amount(denomination * nb_of_notes.parse::<f64>().unwrap_or_default()),
+        };
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.stmt.ntry = Some(vec![Box::new(ReportEntry101 {
+            ntry_dtls: Some(vec![Box::new(EntryDetails91 {
+                tx_dtls: Box::new(EntryTransaction101 {
+                    csh_dpst: Some(vec![deposit]),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })]),
+            ..Default::default()
+        })]);
+        doc
+    }
+
+    fn check(doc: &BankToCustomerStatementV08, config: &ParserConfig) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_cash_deposits(doc, "", config, &mut collector);
+        assert!(!collector.has_errors());
+        collector.warnings().to_vec()
+    }
+
+    fn cbpr() -> ParserConfig {
+        ParserConfig::default().with_profile(ValidationProfile::CbprPlus)
+    }
+
+    #[test]
+    fn test_deposit_within_limit() {
+        assert!(check(&statement("200", 50.0), &cbpr()).is_empty());
+    }
+
+    #[test]
+    fn test_deposit_over_limit() {
+        let errors = check(&statement("250", 50.0), &cbpr());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2030);
+        assert!(errors[0].message.contains("12500 EUR"));
+        assert!(errors[0].message.contains("10000 EUR"));
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("Stmt.Ntry[0].NtryDtls[0].TxDtls.CshDpst[0]")
+        );
+    }
+
+    #[test]
+    fn test_configured_limit() {
+        let config = cbpr().with_cash_deposit_limit("EUR", 5_000.0);
+        assert_eq!(check(&statement("200", 50.0), &config).len(), 1);
+    }
+
+    #[test]
+    fn test_currency_without_limit() {
+        assert!(check(&statement_in("JPY", "250", 10_000.0), &cbpr()).is_empty());
+        let config = cbpr().with_cash_deposit_limit("JPY", 1_000_000.0);
+        assert_eq!(
+            check(&statement_in("JPY", "250", 10_000.0), &config).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_base_profile_is_exempt() {
+        assert!(check(&statement("250", 50.0), &ParserConfig::default()).is_empty());
+    }
+}
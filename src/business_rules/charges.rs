@@ -0,0 +1,171 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Charges total rules (Chrgs)
+//!
+//! A charges block on a statement entry or transaction declares the total of
+//! all charges and taxes (`TtlChrgsAndTaxAmt`) next to the individual charge
+//! records (`Rcrd`). The total must equal the sum of the records' charge and
+//! tax amounts, debits counting positive and credits negative. Records flagged
+//! as not included (`ChrgInclInd` false) are pre-advised and left out. Blocks
+//! whose records are in a different currency than the total are not checked.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, leaf_name, report_error};
+use crate::visitor;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Largest difference between the declared total and the recomputed sum
+/// treated as rounding noise
+const TOTAL_TOLERANCE: f64 = 1e-6;
+
+/// Validate every charges block of a document against its records
+pub fn validate_charges_totals<T: Serialize>(
+    document: &T,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let Ok(value) = serde_json::to_value(document) else {
+        return;
+    };
+    visitor::visit(&value, path, &mut |node_path, node| {
+        if leaf_name(node_path) != "Chrgs" {
+            return;
+        }
+        let (Some(total), Some(Value::Array(records))) =
+            (node.get("TtlChrgsAndTaxAmt"), node.get("Rcrd"))
+        else {
+            return;
+        };
+        let Some((ccy, declared)) = amount(total) else {
+            return;
+        };
+        let Some(sum) = records_sum(records, ccy) else {
+            return;
+        };
+        let sum = sum.abs();
+        if (sum - declared).abs() > TOTAL_TOLERANCE {
+            report_error(
+                ValidationError::new(
+                    2010,
+                    format!(
+                        "TtlChrgsAndTaxAmt {declared} {ccy} differs from the records' sum {sum}"
+                    ),
+                )
+                .with_field("TtlChrgsAndTaxAmt".to_string())
+                .with_path(child_path(node_path, "TtlChrgsAndTaxAmt")),
+                config,
+                collector,
+            );
+        }
+    });
+}
+
+/// Signed sum of the included records, or `None` if any is in another currency
+fn records_sum(records: &[Value], ccy: &str) -> Option<f64> {
+    let mut sum = 0.0;
+    for record in records {
+        if record.get("ChrgInclInd") == Some(&Value::Bool(false)) {
+            continue;
+        }
+        let sign = match record.get("CdtDbtInd").and_then(Value::as_str) {
+            Some("CRDT") => -1.0,
+            _ => 1.0,
+        };
+        let amounts = [
+            record.get("Amt"),
+            record.get("Tax").and_then(|tax| tax.get("Amt")),
+        ];
+        for (record_ccy, value) in amounts.into_iter().flatten().filter_map(amount) {
+            if record_ccy != ccy {
+                return None;
+            }
+            sum += sign * value;
+        }
+    }
+    Some(sum)
+}
+
+/// Currency and value of a serialized currency-and-amount element
+fn amount(value: &Value) -> Option<(&str, f64)> {
+    Some((value.get("@Ccy")?.as_str()?, value.get("$value")?.as_f64()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_053_001_08::*;
+    use crate::validation::BusinessRules;
+
+    fn eur(value: f64) -> ActiveOrHistoricCurrencyAndAmount {
+        ActiveOrHistoricCurrencyAndAmount {
+            ccy: "EUR".into(),
+            value,
+        }
+    }
+
+    fn record(value: f64, tax: Option<f64>) -> ChargesRecord31 {
+        ChargesRecord31 {
+            amt: eur(value),
+            cdt_dbt_ind: Some(CreditDebitCode::CodeDBIT),
+            tax: tax.map(|tax| TaxCharges21 {
+                amt: Some(eur(tax)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn check(total: f64, records: Vec<ChargesRecord31>) -> Vec<ValidationError> {
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.stmt.ntry = Some(vec![Box::new(ReportEntry101 {
+            chrgs: Some(Charges61 {
+                ttl_chrgs_and_tax_amt: Some(eur(total)),
+                rcrd: Some(records),
+            }),
+            ..Default::default()
+        })]);
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_charges_total_matches_records() {
+        assert!(check(12.3, vec![record(10.0, Some(1.9)), record(0.4, None)]).is_empty());
+
+        let mut pre_advised = record(5.0, None);
+        pre_advised.chrg_incl_ind = Some(false);
+        assert!(check(10.0, vec![record(10.0, None), pre_advised]).is_empty());
+    }
+
+    #[test]
+    fn test_charges_total_mismatch() {
+        let errors = check(15.0, vec![record(10.0, Some(1.9))]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2010);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("Stmt.Ntry[0].Chrgs.TtlChrgsAndTaxAmt")
+        );
+    }
+}
@@ -0,0 +1,258 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Contact detail rules (CtctDtls)
+//!
+//! Contact details can carry phone, mobile and fax numbers and an email
+//! address for notification channels. The schema pattern for phone numbers
+//! (`\+[0-9]{1,3}-[0-9()+\-]{1,30}`) still admits numbers that cannot be
+//! dialled, so when `ParserConfig::validate_contact_details` is set every
+//! number must be in E.164 form with the separator after the country code
+//! (`+49-3012345678`), and every email address must have a local part and a
+//! dotted domain. `normalize()` on the contact types rewrites numbers into
+//! that form.
+
+use crate::document::*;
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, leaf_name, report_error};
+use crate::visitor;
+use serde::Serialize;
+
+/// Phone number elements of a contact
+const PHONE_FIELDS: [&str; 3] = ["PhneNb", "MobNb", "FaxNb"];
+
+/// Largest number of digits in an E.164 number, country code included
+const E164_MAX_DIGITS: usize = 15;
+
+/// Validate the phone numbers and email address of every contact in a document
+pub fn validate_contact_details<T: Serialize>(
+    document: &T,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.validate_contact_details {
+        return;
+    }
+    let Ok(value) = serde_json::to_value(document) else {
+        return;
+    };
+    visitor::visit(&value, path, &mut |node_path, node| {
+        if leaf_name(node_path) != "CtctDtls" {
+            return;
+        }
+        for field in PHONE_FIELDS {
+            let Some(number) = node.get(field).and_then(|v| v.as_str()) else {
+                continue;
+            };
+            if !is_valid_e164_phone(number) {
+                report_error(
+                    ValidationError::new(
+                        2035,
+                        format!("{field} '{number}' is not an E.164 phone number"),
+                    )
+                    .with_field(field.to_string())
+                    .with_path(child_path(node_path, field)),
+                    config,
+                    collector,
+                );
+            }
+        }
+        if let Some(email) = node.get("EmailAdr").and_then(|v| v.as_str())
+            && !is_valid_email(email)
+        {
+            report_error(
+                ValidationError::new(2036, format!("EmailAdr '{email}' is not an email address"))
+                    .with_field("EmailAdr".to_string())
+                    .with_path(child_path(node_path, "EmailAdr")),
+                config,
+                collector,
+            );
+        }
+    });
+}
+
+/// Whether the value is an E.164 number in `+CC-NUMBER` form
+pub fn is_valid_e164_phone(number: &str) -> bool {
+    let Some(digits) = number.strip_prefix('+') else {
+        return false;
+    };
+    let Some((country_code, subscriber)) = digits.split_once('-') else {
+        return false;
+    };
+    let total = country_code.len() + subscriber.len();
+    (1..=3).contains(&country_code.len())
+        && !subscriber.is_empty()
+        && (2..=E164_MAX_DIGITS).contains(&total)
+        && !digits.starts_with('0')
+        && country_code
+            .chars()
+            .chain(subscriber.chars())
+            .all(|c| c.is_ascii_digit())
+}
+
+/// Whether the value has the structure `local@domain.tld`
+pub fn is_valid_email(email: &str) -> bool {
+    let Some((local, domain)) = email.split_once('@') else {
+        return false;
+    };
+    let valid_label = |label: &str| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+    !local.is_empty()
+        && !local.chars().any(|c| c.is_whitespace() || c == '@')
+        && domain.contains('.')
+        && domain.split('.').all(valid_label)
+}
+
+/// Canonical `+CC-NUMBER` form of a phone number, or None if it is not E.164
+///
+/// Spaces, dots, dashes, parentheses and a `(0)` trunk prefix are dropped and
+/// a leading `00` is read as `+`. The first separator after the country code
+/// marks where it ends; a number without one gives None, as the country code
+/// cannot be told apart from the subscriber number.
+pub fn normalize_phone_number(number: &str) -> Option<String> {
+    let number = number.trim().replace("(0)", "");
+    let digits = match number.strip_prefix("00") {
+        Some(rest) => rest,
+        None => number.strip_prefix('+')?,
+    };
+    let is_separator = |c: char| matches!(c, ' ' | '-' | '.' | '(' | ')');
+    let keep_digits = |part: &str| {
+        part.chars()
+            .filter(|c| !is_separator(*c))
+            .collect::<String>()
+    };
+    let end = digits.find(is_separator).filter(|&end| end > 0)?;
+    let normalized = format!("+{}-{}", &digits[..end], keep_digits(&digits[end..]));
+    is_valid_e164_phone(&normalized).then_some(normalized)
+}
+
+/// Implement `normalize()` on the contact types that carry phone numbers
+macro_rules! impl_contact_normalize {
+    ($($module:ident::$ty:ident),* $(,)?) => {
+        $(
+            impl $module::$ty {
+                /// Rewrite phone, mobile and fax numbers into `+CC-NUMBER` form and trim
+                /// the email address; numbers that cannot be normalized are left unchanged
+                pub fn normalize(&mut self) {
+                    for number in [&mut self.phne_nb, &mut self.mob_nb, &mut self.fax_nb]
+                        .into_iter()
+                        .flatten()
+                    {
+                        if let Some(normalized) = normalize_phone_number(number) {
+                            *number = normalized;
+                        }
+                    }
+                    if let Some(email) = self.email_adr.as_mut() {
+                        *email = email.trim().to_string();
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_contact_normalize!(pain_001_001_09::Contact4, pain_008_001_08::Contact4);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pain_001_001_09::{Contact4, PartyIdentification1351};
+
+    fn debtor(phne_nb: &str, email_adr: &str) -> PartyIdentification1351 {
+        PartyIdentification1351 {
+            ctct_dtls: Some(Contact4 {
+                phne_nb: Some(phne_nb.to_string()),
+                email_adr: Some(email_adr.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn check(party: &PartyIdentification1351, config: &ParserConfig) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_contact_details(party, "Dbtr", config, &mut collector);
+        collector.errors()
+    }
+
+    fn enabled() -> ParserConfig {
+        ParserConfig::default().with_contact_validation(true)
+    }
+
+    #[test]
+    fn test_valid_contact() {
+        let party = debtor("+49-3012345678", "payments@example.com");
+        assert!(check(&party, &enabled()).is_empty());
+        assert!(is_valid_e164_phone("+1-4155552671"));
+    }
+
+    #[test]
+    fn test_invalid_phone() {
+        let errors = check(&debtor("030 1234 5678", "payments@example.com"), &enabled());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2035);
+        assert_eq!(errors[0].path.as_deref(), Some("Dbtr.CtctDtls.PhneNb"));
+        assert!(!is_valid_e164_phone("+49-30123456789012"));
+        assert!(!is_valid_e164_phone("+0-301234"));
+        assert!(!is_valid_e164_phone("+14155552671"));
+        assert!(!is_valid_e164_phone("+1234-567890"));
+    }
+
+    #[test]
+    fn test_invalid_email() {
+        let errors = check(&debtor("+49-3012345678", "payments@example"), &enabled());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2036);
+        assert_eq!(errors[0].path.as_deref(), Some("Dbtr.CtctDtls.EmailAdr"));
+        assert!(!is_valid_email("pay ments@example.com"));
+        assert!(!is_valid_email("@example.com"));
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        let party = debtor("030 1234 5678", "not an email");
+        assert!(check(&party, &ParserConfig::default()).is_empty());
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut contact = Contact4 {
+            phne_nb: Some("+49 (0)30 1234-5678".to_string()),
+            mob_nb: Some("0044 7911.123456".to_string()),
+            fax_nb: Some("call reception".to_string()),
+            email_adr: Some(" payments@example.com ".to_string()),
+            ..Default::default()
+        };
+        contact.normalize();
+        assert_eq!(contact.phne_nb.as_deref(), Some("+49-3012345678"));
+        assert_eq!(contact.mob_nb.as_deref(), Some("+44-7911123456"));
+        assert_eq!(contact.fax_nb.as_deref(), Some("call reception"));
+        assert_eq!(contact.email_adr.as_deref(), Some("payments@example.com"));
+        assert_eq!(
+            normalize_phone_number("+1 415 555 2671").as_deref(),
+            Some("+1-4155552671")
+        );
+        assert_eq!(normalize_phone_number("+14155552671"), None);
+    }
+}
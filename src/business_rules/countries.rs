@@ -0,0 +1,116 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Country code rules (Ctry, CtryOfRes, CtryOfBirth)
+//!
+//! Sanctions geo-screening works on every country a message mentions:
+//! postal addresses, residence and birth of parties, regulatory reporting.
+//! The schema only requires two uppercase letters, so a code that is not an
+//! ISO 3166-1 alpha-2 country slips past it and past the screening lists.
+//! [`Document::all_countries`] enumerates the same elements.
+
+use crate::error::ValidationError;
+use crate::identifiers::is_country_code;
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{leaf_name, report_error};
+use crate::visitor;
+use serde_json::Value;
+
+/// Elements holding an ISO 3166 country code
+pub const COUNTRY_ELEMENTS: [&str; 3] = ["Ctry", "CtryOfRes", "CtryOfBirth"];
+
+/// Whether the element name holds a country code
+pub fn is_country_element(name: &str) -> bool {
+    COUNTRY_ELEMENTS.contains(&name)
+}
+
+/// Validate that every country code of the document is an ISO 3166 country
+pub fn validate_country_codes(
+    document: &Document,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    // The document serializes wrapped in its root element
+    let Ok(Value::Object(root)) = serde_json::to_value(document) else {
+        return;
+    };
+    let Some(content) = root.values().next() else {
+        return;
+    };
+    visitor::visit(content, path, &mut |node_path, node| {
+        let element = leaf_name(node_path);
+        if !is_country_element(element) {
+            return;
+        }
+        let Some(code) = node.as_str() else {
+            return;
+        };
+        if !is_country_code(code) {
+            report_error(
+                ValidationError::new(
+                    2040,
+                    format!("{element} '{code}' is not an ISO 3166 country code"),
+                )
+                .with_field(element.to_string())
+                .with_path(node_path.to_string()),
+                config,
+                collector,
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::*;
+
+    fn check(dbtr_ctry: &str) -> Vec<ValidationError> {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.dbtr.pstl_adr = Some(PostalAddress241 {
+            ctry: Some(dbtr_ctry.to_string()),
+            ..Default::default()
+        });
+        let mut collector = ErrorCollector::new();
+        validate_country_codes(
+            &Document::Pacs008(Box::new(doc)),
+            "",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    #[test]
+    fn test_known_country() {
+        assert!(check("DE").is_empty());
+    }
+
+    #[test]
+    fn test_unknown_country() {
+        let errors = check("XX");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2040);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("CdtTrfTxInf.Dbtr.PstlAdr.Ctry")
+        );
+    }
+}
@@ -0,0 +1,105 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Header and document creation time consistency
+//!
+//! The AppHdr `CreDt` and the document `CreDtTm` are both stamped when the
+//! message is built, so they should agree to within a few minutes. A larger
+//! gap usually means the header was reused or built for another document.
+//! The gap is reported as a warning; the allowed tolerance comes from
+//! `ParserConfig::creation_time_tolerance_secs`.
+
+use chrono::{DateTime, FixedOffset};
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+
+/// Warn when the header creation time is further from the document's than the tolerance
+/// A header CreDt that is not a valid ISO date time is left to the header's own validation.
+pub fn validate_creation_time_consistency(
+    header_cre_dt: &str,
+    document_cre_dt_tm: DateTime<FixedOffset>,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let Ok(header_cre_dt_parsed) = DateTime::parse_from_rfc3339(header_cre_dt) else {
+        return;
+    };
+    let gap = (header_cre_dt_parsed - document_cre_dt_tm)
+        .num_seconds()
+        .unsigned_abs();
+    if gap > config.creation_time_tolerance_secs {
+        collector.add_warning(
+            ValidationError::new(
+                2021,
+                format!(
+                    "AppHdr CreDt {header_cre_dt} is {gap}s away from document CreDtTm {}, more than the allowed {}s",
+                    document_cre_dt_tm.to_rfc3339(),
+                    config.creation_time_tolerance_secs
+                ),
+            )
+            .with_field("CreDt".to_string())
+            .with_path("AppHdr.CreDt".to_string()),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(header: &str, document: &str, config: &ParserConfig) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_creation_time_consistency(
+            header,
+            DateTime::parse_from_rfc3339(document).unwrap(),
+            config,
+            &mut collector,
+        );
+        assert!(!collector.has_errors());
+        collector.warnings().to_vec()
+    }
+
+    #[test]
+    fn test_aligned_creation_times() {
+        let config = ParserConfig::default();
+        assert!(check("2024-01-15T09:00:00Z", "2024-01-15T09:00:00Z", &config).is_empty());
+        // Same instant in different offsets, and a gap inside the tolerance
+        assert!(check("2024-01-15T10:00:00+01:00", "2024-01-15T09:00:00Z", &config).is_empty());
+        assert!(check("2024-01-15T09:04:00Z", "2024-01-15T09:00:00Z", &config).is_empty());
+    }
+
+    #[test]
+    fn test_creation_time_discrepancy() {
+        let warnings = check(
+            "2024-01-15T09:00:00Z",
+            "2024-01-12T17:30:00Z",
+            &ParserConfig::default(),
+        );
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, 2021);
+        assert_eq!(warnings[0].path.as_deref(), Some("AppHdr.CreDt"));
+
+        let config = ParserConfig::default().with_creation_time_tolerance(60);
+        assert_eq!(
+            check("2024-01-15T09:04:00Z", "2024-01-15T09:00:00Z", &config).len(),
+            1
+        );
+    }
+}
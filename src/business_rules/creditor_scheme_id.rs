@@ -0,0 +1,206 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! SEPA creditor identifier rules (CdtrSchmeId)
+//!
+//! A SEPA direct debit identifies the creditor by its creditor identifier in
+//! `CdtrSchmeId/Id/PrvtId/Othr/Id`. The identifier is built from the ISO
+//! country code, two check digits, a three-character creditor business code
+//! and the national identifier, e.g. `DE98ZZZ09999999999`. The check digits
+//! are ISO 7064 mod-97 over the national identifier followed by the country
+//! code; the business code is left out so a creditor can change it freely.
+//! That `CdtrSchmeId` is present at all is a SEPA scheme requirement, see
+//! [`super::scheme_requirements`].
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, index_path, leaf_name, report_error};
+use crate::visitor;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Validate every creditor scheme identification of a direct debit document
+pub fn validate_creditor_scheme_ids<T: Serialize>(
+    document: &T,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.profile.requires_sepa_creditor_identifier() {
+        return;
+    }
+    let Ok(value) = serde_json::to_value(document) else {
+        return;
+    };
+    visitor::visit(&value, path, &mut |node_path, node| {
+        if leaf_name(node_path) != "CdtrSchmeId" {
+            return;
+        }
+        let othr_path = child_path(&child_path(&child_path(node_path, "Id"), "PrvtId"), "Othr");
+        let identifiers = node
+            .pointer("/Id/PrvtId/Othr")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        if identifiers.is_empty() {
+            report_error(
+                ValidationError::new(
+                    2033,
+                    "CdtrSchmeId must carry the SEPA creditor identifier in Id/PrvtId/Othr/Id"
+                        .to_string(),
+                )
+                .with_field("CdtrSchmeId".to_string())
+                .with_path(node_path.to_string()),
+                config,
+                collector,
+            );
+            return;
+        }
+        for (idx, othr) in identifiers.iter().enumerate() {
+            let Some(id) = othr.get("Id").and_then(Value::as_str) else {
+                continue;
+            };
+            if !is_valid_sepa_creditor_id(id) {
+                report_error(
+                    ValidationError::new(
+                        2033,
+                        format!("'{id}' is not a valid SEPA creditor identifier"),
+                    )
+                    .with_field("Id".to_string())
+                    .with_path(child_path(&index_path(&othr_path, idx), "Id")),
+                    config,
+                    collector,
+                );
+            }
+        }
+    });
+}
+
+/// Whether the value is a SEPA creditor identifier with valid check digits
+pub fn is_valid_sepa_creditor_id(id: &str) -> bool {
+    let bytes = id.as_bytes();
+    let well_formed = (8..=35).contains(&bytes.len())
+        && bytes[0..2].iter().all(u8::is_ascii_uppercase)
+        && bytes[2..4].iter().all(u8::is_ascii_digit)
+        && bytes[4..].iter().all(u8::is_ascii_alphanumeric);
+    if !well_formed {
+        return false;
+    }
+    // National identifier, then country code and check digits; business code skipped
+    let checked = id[7..].chars().chain(id[0..4].chars());
+    let mut remainder = 0u32;
+    for c in checked {
+        let Some(digit) = c.to_digit(36) else {
+            return false;
+        };
+        remainder = if digit < 10 {
+            (remainder * 10 + digit) % 97
+        } else {
+            (remainder * 100 + digit) % 97
+        };
+    }
+    remainder == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pain_008_001_08::*;
+    use crate::parse_result::ValidationProfile;
+
+    fn direct_debit(creditor_id: &str) -> CustomerDirectDebitInitiationV08 {
+        let mut doc = CustomerDirectDebitInitiationV08::default();
+        doc.pmt_inf.drct_dbt_tx_inf = vec![DirectDebitTransactionInformation231 {
+            drct_dbt_tx: Some(DirectDebitTransaction101 {
+                cdtr_schme_id: Some(PartyIdentification1353 {
+                    id: Some(Party38Choice {
+                        org_id: None,
+                        prvt_id: Some(PersonIdentification13 {
+                            othr: Some(vec![GenericPersonIdentification1 {
+                                id: creditor_id.to_string(),
+                                schme_nm: Some(PersonIdentificationSchemeName1Choice {
+                                    prtry: Some("SEPA".to_string()),
+                                    ..Default::default()
+                                }),
+                                issr: None,
+                            }]),
+                            ..Default::default()
+                        }),
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        doc
+    }
+
+    fn check(doc: &CustomerDirectDebitInitiationV08) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        let config = ParserConfig::default().with_profile(ValidationProfile::Sepa);
+        validate_creditor_scheme_ids(doc, "", &config, &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_valid_sepa_creditor_id() {
+        assert!(is_valid_sepa_creditor_id("DE98ZZZ09999999999"));
+        assert!(is_valid_sepa_creditor_id("DE98ABC09999999999"));
+        assert!(check(&direct_debit("DE98ZZZ09999999999")).is_empty());
+    }
+
+    #[test]
+    fn test_malformed_sepa_creditor_id() {
+        assert!(!is_valid_sepa_creditor_id("DE99ZZZ09999999999"));
+        assert!(!is_valid_sepa_creditor_id("DE98ZZZ"));
+        assert!(!is_valid_sepa_creditor_id("de98ZZZ09999999999"));
+
+        let errors = check(&direct_debit("DE99ZZZ09999999999"));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2033);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("PmtInf.DrctDbtTxInf[0].DrctDbtTx.CdtrSchmeId.Id.PrvtId.Othr[0].Id")
+        );
+    }
+
+    #[test]
+    fn test_creditor_scheme_id_without_identifier() {
+        let mut doc = direct_debit("DE98ZZZ09999999999");
+        doc.pmt_inf.drct_dbt_tx_inf[0]
+            .drct_dbt_tx
+            .as_mut()
+            .unwrap()
+            .cdtr_schme_id
+            .as_mut()
+            .unwrap()
+            .id = None;
+        let errors = check(&doc);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field.as_deref(), Some("CdtrSchmeId"));
+    }
+
+    #[test]
+    fn test_other_profiles_are_exempt() {
+        let mut collector = ErrorCollector::new();
+        let doc = direct_debit("DE99ZZZ09999999999");
+        validate_creditor_scheme_ids(&doc, "", &ParserConfig::default(), &mut collector);
+        assert!(!collector.has_errors());
+    }
+}
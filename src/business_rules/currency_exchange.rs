@@ -0,0 +1,214 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Currency exchange rules (AmtDtls/*/CcyXchg)
+//!
+//! A currency exchange record states the source (`SrcCcy`) and target
+//! (`TrgtCcy`) currencies, the rate (`XchgRate`) and the currency the rate is
+//! quoted per unit of (`UnitCcy`): with 1 GBP = 1.17 EUR the unit currency is
+//! GBP. The unit currency must therefore be the source or the target
+//! currency. When the amount details carry an amount in both currencies
+//! (typically the instructed and the counter-value amount), converting the
+//! source amount at the rate in the quoted direction must give the target
+//! amount.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, leaf_name, report_error};
+use crate::visitor;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Amount details carrying a currency and an optional exchange record
+const AMOUNT_DETAILS: [&str; 4] = ["InstdAmt", "TxAmt", "CntrValAmt", "AnncdPstngAmt"];
+
+/// Largest relative difference between the converted and the stated amount
+/// treated as rounding of the rate or the amounts
+const CONVERSION_TOLERANCE: f64 = 1e-3;
+
+/// Validate every currency exchange record of a document against its amounts
+pub fn validate_currency_exchanges<T: Serialize>(
+    document: &T,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let Ok(value) = serde_json::to_value(document) else {
+        return;
+    };
+    visitor::visit(&value, path, &mut |node_path, node| {
+        if leaf_name(node_path) != "AmtDtls" {
+            return;
+        }
+        let amounts: Vec<(&str, &str, f64)> = AMOUNT_DETAILS
+            .iter()
+            .filter_map(|name| {
+                let amt = node.get(name)?.get("Amt")?;
+                Some((
+                    *name,
+                    amt.get("@Ccy")?.as_str()?,
+                    amt.get("$value")?.as_f64()?,
+                ))
+            })
+            .collect();
+        for name in AMOUNT_DETAILS {
+            let Some(exchange) = node.get(name).and_then(|details| details.get("CcyXchg")) else {
+                continue;
+            };
+            let exchange_path = child_path(&child_path(node_path, name), "CcyXchg");
+            check_exchange(exchange, &amounts, &exchange_path, config, collector);
+        }
+    });
+}
+
+/// Check one exchange record's unit currency and conversion direction
+fn check_exchange(
+    exchange: &Value,
+    amounts: &[(&str, &str, f64)],
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let (Some(src), Some(rate)) = (
+        exchange.get("SrcCcy").and_then(Value::as_str),
+        exchange.get("XchgRate").and_then(Value::as_f64),
+    ) else {
+        return;
+    };
+    let trgt = exchange.get("TrgtCcy").and_then(Value::as_str);
+    let unit = exchange.get("UnitCcy").and_then(Value::as_str);
+    if let Some(unit) = unit
+        && unit != src
+        && Some(unit) != trgt
+    {
+        let expected = match trgt {
+            Some(trgt) => format!("SrcCcy {src} or TrgtCcy {trgt}"),
+            None => format!("SrcCcy {src}"),
+        };
+        report_error(
+            ValidationError::new(2031, format!("UnitCcy {unit} must be {expected}"))
+                .with_field("UnitCcy".to_string())
+                .with_path(child_path(path, "UnitCcy")),
+            config,
+            collector,
+        );
+        return;
+    }
+    let Some(trgt) = trgt.filter(|trgt| *trgt != src) else {
+        return;
+    };
+    if rate <= 0.0 {
+        return;
+    }
+    let find = |ccy: &str| amounts.iter().find(|(_, amount_ccy, _)| *amount_ccy == ccy);
+    let (Some(&(src_name, _, src_amount)), Some(&(trgt_name, _, trgt_amount))) =
+        (find(src), find(trgt))
+    else {
+        return;
+    };
+    let converted = if unit == Some(trgt) {
+        src_amount / rate
+    } else {
+        src_amount * rate
+    };
+    if (converted - trgt_amount).abs() > trgt_amount.abs() * CONVERSION_TOLERANCE {
+        report_error(
+            ValidationError::new(
+                2032,
+                format!(
+                    "{src_name} {src_amount} {src} at XchgRate {rate} gives {converted:.2} {trgt}, \
+                     not the {trgt_name} {trgt_amount} {trgt}"
+                ),
+            )
+            .with_field("XchgRate".to_string())
+            .with_path(child_path(path, "XchgRate")),
+            config,
+            collector,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_053_001_08::*;
+
+    fn details(
+        ccy: &str,
+        value: f64,
+        ccy_xchg: Option<CurrencyExchange51>,
+    ) -> AmountAndCurrencyExchangeDetails31 {
+        AmountAndCurrencyExchangeDetails31 {
+            amt: ActiveOrHistoricCurrencyAndAmount {
+                ccy: ccy.into(),
+                value,
+            },
+            ccy_xchg,
+        }
+    }
+
+    fn statement(unit_ccy: &str, xchg_rate: f64) -> BankToCustomerStatementV08 {
+        let exchange = CurrencyExchange51 {
+            src_ccy: "GBP".into(),
+            trgt_ccy: Some("EUR".into()),
+            unit_ccy: Some(unit_ccy.to_string()),
+            xchg_rate,
+            ..Default::default()
+        };
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.stmt.ntry = Some(vec![Box::new(ReportEntry101 {
+            amt_dtls: Some(AmountAndCurrencyExchange31 {
+                instd_amt: Some(details("GBP", 1000.0, Some(exchange))),
+                cntr_val_amt: Some(details("EUR", 1170.0, None)),
+                ..Default::default()
+            }),
+            ..Default::default()
+        })]);
+        doc
+    }
+
+    fn check(doc: &BankToCustomerStatementV08) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_currency_exchanges(doc, "", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_coherent_exchange() {
+        assert!(check(&statement("GBP", 1.17)).is_empty());
+        assert!(check(&statement("EUR", 0.854701)).is_empty());
+    }
+
+    #[test]
+    fn test_rate_in_wrong_direction() {
+        let errors = check(&statement("EUR", 1.17));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2032);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("Stmt.Ntry[0].AmtDtls.InstdAmt.CcyXchg.XchgRate")
+        );
+    }
+
+    #[test]
+    fn test_unit_currency_outside_pair() {
+        let errors = check(&statement("USD", 1.17));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2031);
+    }
+}
@@ -0,0 +1,122 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Requested execution date rules (pain.001 ReqdExctnDt)
+//!
+//! A payment initiation asks the debtor agent to execute the payment on
+//! `ReqdExctnDt`, given either as a date (`Dt`) or a date time (`DtTm`). A
+//! requested execution before the message was even created cannot be met and
+//! usually comes from a stale template or a swapped day and month. A date is
+//! compared with the calendar date of the creation time, so execution on the
+//! day of creation is accepted; a date time is compared exactly.
+
+use crate::dates::ParsedDate;
+use crate::document::pain_001_001_09::DateAndDateTime2Choice;
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+
+/// Validate that the requested execution date is not before the creation date time
+/// Values that are not ISO dates are left to schema validation.
+pub fn validate_requested_execution_date(
+    cre_dt_tm: &str,
+    reqd_exctn_dt: &DateAndDateTime2Choice,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.profile.checks_requested_execution_date() {
+        return;
+    }
+    let (field, requested) = match (&reqd_exctn_dt.dt, &reqd_exctn_dt.dt_tm) {
+        (Some(dt), _) => ("Dt", dt),
+        (None, Some(dt_tm)) => ("DtTm", dt_tm),
+        (None, None) => return,
+    };
+    let (Some(created), Some(execution)) =
+        (ParsedDate::parse(cre_dt_tm), ParsedDate::parse(requested))
+    else {
+        return;
+    };
+    if created.duration_until(&execution) < chrono::Duration::zero() {
+        report_error(
+            ValidationError::new(
+                2041,
+                format!(
+                    "Requested execution date {requested} is before creation date time {cre_dt_tm}"
+                ),
+            )
+            .with_field("ReqdExctnDt".to_string())
+            .with_path(child_path(&child_path(path, "ReqdExctnDt"), field)),
+            config,
+            collector,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_result::ValidationProfile;
+
+    const CREATED: &str = "2024-03-05T10:00:00+01:00";
+
+    fn check(dt: Option<&str>, dt_tm: Option<&str>, config: &ParserConfig) -> Vec<ValidationError> {
+        let reqd_exctn_dt = DateAndDateTime2Choice {
+            dt: dt.map(str::to_string),
+            dt_tm: dt_tm.map(str::to_string),
+        };
+        let mut collector = ErrorCollector::new();
+        validate_requested_execution_date(
+            CREATED,
+            &reqd_exctn_dt,
+            "PmtInf",
+            config,
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    fn sepa() -> ParserConfig {
+        ParserConfig::default().with_profile(ValidationProfile::Sepa)
+    }
+
+    #[test]
+    fn test_future_execution_date() {
+        assert!(check(Some("2024-03-06"), None, &sepa()).is_empty());
+        assert!(check(Some("2024-03-05"), None, &sepa()).is_empty());
+        assert!(check(None, Some("2024-03-05T09:30:00Z"), &sepa()).is_empty());
+    }
+
+    #[test]
+    fn test_past_execution_date() {
+        let errors = check(Some("2024-03-04"), None, &sepa());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2041);
+        assert_eq!(errors[0].path.as_deref(), Some("PmtInf.ReqdExctnDt.Dt"));
+
+        let errors = check(None, Some("2024-03-05T09:30:00+01:00"), &sepa());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path.as_deref(), Some("PmtInf.ReqdExctnDt.DtTm"));
+    }
+
+    #[test]
+    fn test_base_profile_is_exempt() {
+        assert!(check(Some("2024-03-04"), None, &ParserConfig::default()).is_empty());
+    }
+}
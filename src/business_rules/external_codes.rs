@@ -0,0 +1,211 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! External code set rules
+//!
+//! Coded elements (`Cd`) must come from the ISO 20022 external code lists.
+//! Proprietary alternatives (`Prtry`) are bilaterally agreed and not checked.
+//! The bundled lists are a snapshot (see
+//! [`EXTERNAL_CODE_SETS_RELEASE`](crate::external_codes::EXTERNAL_CODE_SETS_RELEASE)),
+//! so the Base profile only warns about an unknown code; scheme profiles reject it.
+
+use crate::error::ValidationError;
+use crate::external_codes::{is_external_category_purpose_code, is_external_purpose_code};
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+
+/// Validate `Purp/Cd` against the ExternalPurpose1Code list
+pub fn validate_purpose_code(
+    code: Option<&str>,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if let Some(code) = code
+        && !is_external_purpose_code(code)
+    {
+        report_unknown_code(
+            ValidationError::new(
+                2003,
+                format!("Purpose code '{code}' is not an ExternalPurpose1Code"),
+            )
+            .with_field("Cd".to_string())
+            .with_path(child_path(path, "Cd")),
+            config,
+            collector,
+        );
+    }
+}
+
+/// Validate `CtgyPurp/Cd` against the ExternalCategoryPurpose1Code list
+pub fn validate_category_purpose_code(
+    code: Option<&str>,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if let Some(code) = code
+        && !is_external_category_purpose_code(code)
+    {
+        report_unknown_code(
+            ValidationError::new(
+                2028,
+                format!("Category purpose code '{code}' is not an ExternalCategoryPurpose1Code"),
+            )
+            .with_field("Cd".to_string())
+            .with_path(child_path(path, "Cd")),
+            config,
+            collector,
+        );
+    }
+}
+
+/// Report a code missing from the bundled lists: a warning under Base, an error otherwise
+fn report_unknown_code(
+    error: ValidationError,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if config.profile.rejects_unknown_external_codes() {
+        report_error(error, config, collector);
+    } else {
+        collector.add_warning(error);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::{
+        CategoryPurpose1Choice1, FIToFICustomerCreditTransferV08, PaymentTypeInformation281,
+        Purpose2Choice1,
+    };
+    use crate::parse_result::ValidationProfile;
+    use crate::validation::BusinessRules;
+
+    fn check(purp: Purpose2Choice1) -> Vec<ValidationError> {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.purp = Some(purp);
+        let config = ParserConfig::default().with_profile(ValidationProfile::CbprPlus);
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &config, &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_valid_purpose_code() {
+        let errors = check(Purpose2Choice1 {
+            cd: Some("SALA".to_string()),
+            prtry: None,
+        });
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_purpose_code() {
+        let errors = check(Purpose2Choice1 {
+            cd: Some("XXXX".to_string()),
+            prtry: None,
+        });
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2003);
+        assert_eq!(errors[0].path.as_deref(), Some("CdtTrfTxInf.Purp.Cd"));
+    }
+
+    #[test]
+    fn test_unknown_purpose_code_warns_under_base() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.purp = Some(Purpose2Choice1 {
+            cd: Some("XXXX".to_string()),
+            prtry: None,
+        });
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &ParserConfig::default(), &mut collector);
+        assert_eq!(collector.warnings().len(), 1);
+        assert_eq!(collector.warnings()[0].code, 2003);
+        assert!(collector.errors().is_empty());
+    }
+
+    #[test]
+    fn test_proprietary_purpose_is_lenient() {
+        let errors = check(Purpose2Choice1 {
+            cd: None,
+            prtry: Some("ANYTHING".to_string()),
+        });
+        assert!(errors.is_empty());
+    }
+
+    fn check_category(ctgy_purp: CategoryPurpose1Choice1) -> Vec<ValidationError> {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.pmt_tp_inf = Some(PaymentTypeInformation281 {
+            ctgy_purp: Some(ctgy_purp),
+            ..Default::default()
+        });
+        let config = ParserConfig::default().with_profile(ValidationProfile::CbprPlus);
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &config, &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_valid_category_purpose_code() {
+        let errors = check_category(CategoryPurpose1Choice1 {
+            cd: Some("SUPP".to_string()),
+            prtry: None,
+        });
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_category_purpose_code() {
+        let errors = check_category(CategoryPurpose1Choice1 {
+            cd: Some("XXXX".to_string()),
+            prtry: None,
+        });
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2028);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("CdtTrfTxInf.PmtTpInf.CtgyPurp.Cd")
+        );
+
+        let errors = check_category(CategoryPurpose1Choice1 {
+            cd: None,
+            prtry: Some("PAYROLL".to_string()),
+        });
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_unknown_category_purpose_code_warns_under_base() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.pmt_tp_inf = Some(PaymentTypeInformation281 {
+            ctgy_purp: Some(CategoryPurpose1Choice1 {
+                cd: Some("XXXX".to_string()),
+                prtry: None,
+            }),
+            ..Default::default()
+        });
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &ParserConfig::default(), &mut collector);
+        assert_eq!(collector.warnings().len(), 1);
+        assert_eq!(collector.warnings()[0].code, 2028);
+        assert!(collector.errors().is_empty());
+    }
+}
@@ -0,0 +1,105 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Forwarding agent rules (pain.001 GrpHdr/FwdgAgt)
+//!
+//! pain.001 has no `FrstAgt`; the agent that first receives the initiation
+//! is the forwarding agent (`GrpHdr/FwdgAgt`), or the debtor agent
+//! (`PmtInf/DbtrAgt`) when none is given. Under the SEPA and instant
+//! profiles the customer instructs its own bank directly, so a forwarding
+//! agent must be the debtor agent itself; a different BIC signals an
+//! initiation built for another channel. A BIC8 and its primary-office BIC11
+//! (`XXX` branch) count as the same agent, and a debtor agent identified
+//! without a BIC is not compared.
+
+use crate::document::pain_001_001_09::CustomerCreditTransferInitiationV09;
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+
+/// Validate that the forwarding agent of a pain.001 is its debtor agent
+pub fn validate_forwarding_agent(
+    doc: &CustomerCreditTransferInitiationV09,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.profile.requires_forwarding_agent_as_debtor_agent() {
+        return;
+    }
+    let Some(fwdg_agt) = &doc.grp_hdr.fwdg_agt else {
+        return;
+    };
+    let Some(dbtr_agt) = &doc.pmt_inf.dbtr_agt.fin_instn_id.bicfi else {
+        return;
+    };
+    let forwarding = fwdg_agt.fin_instn_id.bicfi.primary_office();
+    let debtor = dbtr_agt.primary_office();
+    if forwarding != debtor {
+        report_error(
+            ValidationError::new(
+                2043,
+                format!(
+                    "Forwarding agent {forwarding} is not the debtor agent {debtor} under the {:?} profile",
+                    config.profile
+                ),
+            )
+            .with_field("FwdgAgt".to_string())
+            .with_path(child_path(&child_path(path, "GrpHdr"), "FwdgAgt")),
+            config,
+            collector,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pain_001_001_09::BranchAndFinancialInstitutionIdentification61;
+    use crate::parse_result::ValidationProfile;
+
+    fn check(fwdg_agt: &str, dbtr_agt: &str, profile: ValidationProfile) -> Vec<ValidationError> {
+        let mut doc = CustomerCreditTransferInitiationV09::default();
+        let mut agent = BranchAndFinancialInstitutionIdentification61::default();
+        agent.fin_instn_id.bicfi = fwdg_agt.into();
+        doc.grp_hdr.fwdg_agt = Some(agent);
+        doc.pmt_inf.dbtr_agt.fin_instn_id.bicfi = Some(dbtr_agt.into());
+        let config = ParserConfig::default().with_profile(profile);
+        let mut collector = ErrorCollector::new();
+        validate_forwarding_agent(&doc, "", &config, &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_consistent_forwarding_agent() {
+        assert!(check("DEUTDEFFXXX", "DEUTDEFF", ValidationProfile::Sepa).is_empty());
+    }
+
+    #[test]
+    fn test_inconsistent_forwarding_agent() {
+        let errors = check("COBADEFFXXX", "DEUTDEFFXXX", ValidationProfile::Sepa);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2043);
+        assert_eq!(errors[0].path.as_deref(), Some("GrpHdr.FwdgAgt"));
+    }
+
+    #[test]
+    fn test_base_profile_is_exempt() {
+        assert!(check("COBADEFFXXX", "DEUTDEFFXXX", ValidationProfile::Base).is_empty());
+    }
+}
@@ -0,0 +1,144 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Instruction for creditor agent rules (InstrForCdtrAgt)
+//!
+//! A transaction may carry several coded instructions for the creditor agent.
+//! Each code may appear only once, and some codes ask for incompatible
+//! handling of the funds (e.g. pay by cheque vs. hold cash for the creditor).
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, index_path, report_error};
+use serde::Serialize;
+
+/// Pairs of InstrForCdtrAgt codes that must not be combined in one transaction
+pub const CONFLICTING_CREDITOR_AGENT_INSTRUCTIONS: &[(&str, &str)] =
+    &[("CHQB", "HOLD"), ("PHOB", "TELB")];
+
+/// Validate the `InstrForCdtrAgt/Cd` codes of a transaction
+///
+/// Reports a repeated code, and a code that contradicts an earlier one.
+pub fn validate_creditor_agent_instructions<'a, T>(
+    codes: impl IntoIterator<Item = Option<&'a T>>,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) where
+    T: Serialize + 'a,
+{
+    let instr_path = child_path(path, "InstrForCdtrAgt");
+    let mut seen: Vec<String> = Vec::new();
+    for (idx, code) in codes.into_iter().enumerate() {
+        let Some(code) = code.and_then(code_str) else {
+            continue;
+        };
+        let code_path = child_path(&index_path(&instr_path, idx), "Cd");
+        let error = if seen.contains(&code) {
+            Some(ValidationError::new(
+                2004,
+                format!("InstrForCdtrAgt code {code} is repeated"),
+            ))
+        } else {
+            seen.iter()
+                .find(|other| conflicts(other, &code))
+                .map(|other| {
+                    ValidationError::new(
+                        2005,
+                        format!("InstrForCdtrAgt code {code} contradicts code {other}"),
+                    )
+                })
+        };
+        if let Some(error) = error {
+            report_error(
+                error.with_field("Cd".to_string()).with_path(code_path),
+                config,
+                collector,
+            );
+        }
+        seen.push(code);
+    }
+}
+
+/// Check whether two instruction codes are mutually exclusive
+pub fn conflicts(a: &str, b: &str) -> bool {
+    CONFLICTING_CREDITOR_AGENT_INSTRUCTIONS
+        .iter()
+        .any(|&(x, y)| (a == x && b == y) || (a == y && b == x))
+}
+
+/// The XML code of a generated instruction code enum
+fn code_str<T: Serialize>(code: &T) -> Option<String> {
+    serde_json::to_value(code)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::{
+        FIToFICustomerCreditTransferV08, Instruction3Code, InstructionForCreditorAgent11,
+    };
+    use crate::validation::BusinessRules;
+
+    fn check(codes: &[Instruction3Code]) -> Vec<ValidationError> {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.instr_for_cdtr_agt = Some(
+            codes
+                .iter()
+                .map(|cd| InstructionForCreditorAgent11 {
+                    cd: Some(cd.clone()),
+                    instr_inf: None,
+                })
+                .collect(),
+        );
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_single_instruction() {
+        assert!(check(&[Instruction3Code::CodeHOLD]).is_empty());
+        assert!(check(&[Instruction3Code::CodeHOLD, Instruction3Code::CodePHOB]).is_empty());
+    }
+
+    #[test]
+    fn test_contradictory_instructions() {
+        let errors = check(&[Instruction3Code::CodePHOB, Instruction3Code::CodeTELB]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2005);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("CdtTrfTxInf.InstrForCdtrAgt[1].Cd")
+        );
+
+        let errors = check(&[Instruction3Code::CodeHOLD, Instruction3Code::CodeCHQB]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2005);
+    }
+
+    #[test]
+    fn test_duplicate_instructions() {
+        let errors = check(&[Instruction3Code::CodeHOLD, Instruction3Code::CodeHOLD]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2004);
+    }
+}
@@ -0,0 +1,168 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Local instrument rules (PmtTpInf/LclInstrm)
+//!
+//! The schema only limits `LclInstrm/Cd` by length, but a scheme accepts a
+//! fixed set of local instruments per message type, e.g. SEPA credit
+//! transfers only know `INST` and SEPA direct debits `CORE` and `B2B`. Codes
+//! are checked against the table of the configured profile; profiles without
+//! an entry for the message type accept any code, and proprietary local
+//! instruments (`Prtry`) are not checked.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig, ValidationProfile};
+use crate::validation::helpers::{child_path, report_error};
+
+/// Local instrument codes a scheme accepts for a message type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemeLocalInstruments {
+    /// Profile the codes apply to
+    pub profile: ValidationProfile,
+    /// Message type the codes apply to (e.g. "pacs.008")
+    pub message_type: &'static str,
+    /// Accepted `LclInstrm/Cd` values
+    pub codes: &'static [&'static str],
+}
+
+const fn local_instruments(
+    profile: ValidationProfile,
+    message_type: &'static str,
+    codes: &'static [&'static str],
+) -> SchemeLocalInstruments {
+    SchemeLocalInstruments {
+        profile,
+        message_type,
+        codes,
+    }
+}
+
+/// Built-in local instrument codes of the supported scheme profiles
+pub const SCHEME_LOCAL_INSTRUMENTS: &[SchemeLocalInstruments] = &[
+    local_instruments(ValidationProfile::Sepa, "pacs.008", &["INST"]),
+    local_instruments(ValidationProfile::Sepa, "pain.001", &["INST"]),
+    local_instruments(ValidationProfile::Sepa, "pacs.003", &["CORE", "B2B"]),
+    local_instruments(ValidationProfile::Sepa, "pain.008", &["CORE", "B2B"]),
+];
+
+/// Local instrument codes accepted under a profile, if the profile restricts them
+pub fn allowed_local_instruments(
+    profile: ValidationProfile,
+    message_type: &str,
+) -> Option<&'static [&'static str]> {
+    SCHEME_LOCAL_INSTRUMENTS
+        .iter()
+        .find(|entry| {
+            entry.profile == profile.usage_guideline() && entry.message_type == message_type
+        })
+        .map(|entry| entry.codes)
+}
+
+/// Validate `LclInstrm/Cd` against the configured profile
+/// `path` is the path of the enclosing `PmtTpInf`.
+pub fn validate_local_instrument(
+    message_type: &str,
+    code: Option<&str>,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let Some(code) = code else {
+        return;
+    };
+    let Some(allowed) = allowed_local_instruments(config.profile, message_type) else {
+        return;
+    };
+    if allowed.contains(&code) {
+        return;
+    }
+    report_error(
+        ValidationError::new(
+            2018,
+            format!(
+                "Local instrument '{code}' is not accepted under the {:?} profile for {message_type}",
+                config.profile
+            ),
+        )
+        .with_field("Cd".to_string())
+        .with_path(child_path(&child_path(path, "LclInstrm"), "Cd")),
+        config,
+        collector,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::pacs_003_001_08::{
+        FIToFICustomerDirectDebitV08, LocalInstrument2Choice1, PaymentTypeInformation271,
+    };
+    use crate::error::ValidationError;
+    use crate::parse_result::{ErrorCollector, ParserConfig, ValidationProfile};
+    use crate::validation::BusinessRules;
+
+    fn check(
+        lcl_instrm: LocalInstrument2Choice1,
+        profile: ValidationProfile,
+    ) -> Vec<ValidationError> {
+        let mut doc = FIToFICustomerDirectDebitV08::default();
+        doc.drct_dbt_tx_inf.pmt_id.end_to_end_id = "E2E-001".to_string();
+        doc.drct_dbt_tx_inf.pmt_tp_inf = Some(PaymentTypeInformation271 {
+            lcl_instrm: Some(lcl_instrm),
+            ..Default::default()
+        });
+        let config = ParserConfig::default().with_profile(profile);
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &config, &mut collector);
+        collector
+            .errors()
+            .into_iter()
+            .filter(|err| err.code == 2018)
+            .collect()
+    }
+
+    fn code(cd: &str) -> LocalInstrument2Choice1 {
+        LocalInstrument2Choice1 {
+            cd: Some(cd.to_string()),
+            prtry: None,
+        }
+    }
+
+    #[test]
+    fn test_valid_sepa_local_instrument() {
+        assert!(check(code("CORE"), ValidationProfile::Sepa).is_empty());
+        assert!(check(code("B2B"), ValidationProfile::Sepa).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_sepa_local_instrument() {
+        let errors = check(code("INST"), ValidationProfile::Sepa);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("DrctDbtTxInf.PmtTpInf.LclInstrm.Cd")
+        );
+
+        assert!(check(code("INST"), ValidationProfile::Base).is_empty());
+        let proprietary = LocalInstrument2Choice1 {
+            cd: None,
+            prtry: Some("ANYTHING".to_string()),
+        };
+        assert!(check(proprietary, ValidationProfile::Sepa).is_empty());
+    }
+}
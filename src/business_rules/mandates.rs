@@ -0,0 +1,129 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Direct debit mandate amendment rules (MndtRltdInf)
+//!
+//! A collection under an amended mandate sets `AmdmntInd` and must say what
+//! changed in `AmdmntInfDtls`, so the debtor agent can match it to the mandate
+//! it holds. The details must carry at least one original element: the
+//! mandate id, creditor scheme id, debtor account or debtor agent.
+
+use crate::document::pain_008_001_08::MandateRelatedInformation141;
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+
+/// Validate that an amended mandate carries the original mandate data
+pub fn validate_mandate_amendment(
+    mandate: &MandateRelatedInformation141,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if mandate.amdmnt_ind != Some(true) {
+        return;
+    }
+
+    let details_path = child_path(path, "AmdmntInfDtls");
+    let Some(details) = &mandate.amdmnt_inf_dtls else {
+        report_error(
+            ValidationError::new(
+                2013,
+                "AmdmntInfDtls is required when AmdmntInd is true".to_string(),
+            )
+            .with_field("AmdmntInfDtls".to_string())
+            .with_path(details_path),
+            config,
+            collector,
+        );
+        return;
+    };
+
+    let has_original = details.orgnl_mndt_id.is_some()
+        || details.orgnl_cdtr_schme_id.is_some()
+        || details.orgnl_dbtr_acct.is_some()
+        || details.orgnl_dbtr_agt.is_some();
+    if !has_original {
+        report_error(
+            ValidationError::new(
+                2013,
+                "AmdmntInfDtls must carry OrgnlMndtId, OrgnlCdtrSchmeId, OrgnlDbtrAcct or OrgnlDbtrAgt"
+                    .to_string(),
+            )
+            .with_field("AmdmntInfDtls".to_string())
+            .with_path(details_path),
+            config,
+            collector,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pain_008_001_08::*;
+    use crate::validation::BusinessRules;
+
+    fn check(mandate: MandateRelatedInformation141) -> Vec<ValidationError> {
+        let mut doc = CustomerDirectDebitInitiationV08::default();
+        doc.pmt_inf.drct_dbt_tx_inf = vec![DirectDebitTransactionInformation231 {
+            drct_dbt_tx: Some(DirectDebitTransaction101 {
+                mndt_rltd_inf: Some(mandate),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }];
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_new_mandate() {
+        let errors = check(MandateRelatedInformation141 {
+            mndt_id: Some("MNDT-2024-001".to_string()),
+            dt_of_sgntr: Some("2024-03-01".to_string()),
+            amdmnt_ind: Some(false),
+            ..Default::default()
+        });
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_amended_mandate_without_original_data() {
+        let errors = check(MandateRelatedInformation141 {
+            mndt_id: Some("MNDT-2024-001".to_string()),
+            amdmnt_ind: Some(true),
+            amdmnt_inf_dtls: Some(AmendmentInformationDetails131::default()),
+            ..Default::default()
+        });
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2013);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("PmtInf.DrctDbtTxInf[0].DrctDbtTx.MndtRltdInf.AmdmntInfDtls")
+        );
+
+        let errors = check(MandateRelatedInformation141 {
+            amdmnt_ind: Some(true),
+            ..Default::default()
+        });
+        assert_eq!(errors[0].code, 2013);
+    }
+}
@@ -0,0 +1,610 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Business Rules
+//!
+//! Cross-field and scheme-specific checks that the XSD-derived `Validate`
+//! implementations cannot express. Each submodule holds one family of rules;
+//! the `BusinessRules` implementations below wire them into the message types.
+
+pub mod account_currency;
+pub mod agent_chain;
+pub mod any_bic;
+pub mod assignment;
+pub mod balances;
+pub mod booking_dates;
+pub mod cash_deposits;
+pub mod charges;
+pub mod contacts;
+pub mod countries;
+pub mod creation_time;
+pub mod creditor_scheme_id;
+pub mod currency_exchange;
+pub mod execution_date;
+pub mod external_codes;
+pub mod forwarding_agent;
+pub mod instructions;
+pub mod local_instrument;
+pub mod mandates;
+pub mod original_message;
+pub mod parties;
+pub mod payment_identification;
+pub mod rates;
+pub mod remittance;
+pub mod scheme_requirements;
+pub mod sequence;
+pub mod serialization;
+pub mod settlement_total;
+pub mod transaction_summary;
+
+use crate::document::*;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::BusinessRules;
+use crate::validation::helpers::{child_path, index_path};
+
+impl BusinessRules for pacs_008_001_08::FIToFICustomerCreditTransferV08 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        let tx_path = child_path(path, "CdtTrfTxInf");
+        payment_identification::validate_end_to_end_id(
+            &self.cdt_trf_tx_inf.pmt_id.end_to_end_id,
+            &child_path(&tx_path, "PmtId"),
+            config,
+            collector,
+        );
+        payment_identification::validate_instant_identifier(
+            &self.grp_hdr.msg_id,
+            "MsgId",
+            &child_path(path, "GrpHdr"),
+            config,
+            collector,
+        );
+        payment_identification::validate_instant_identifier(
+            &self.cdt_trf_tx_inf.pmt_id.end_to_end_id,
+            "EndToEndId",
+            &child_path(&tx_path, "PmtId"),
+            config,
+            collector,
+        );
+        external_codes::validate_purpose_code(
+            self.cdt_trf_tx_inf
+                .purp
+                .as_ref()
+                .and_then(|purp| purp.cd.as_deref()),
+            &child_path(&tx_path, "Purp"),
+            config,
+            collector,
+        );
+        instructions::validate_creditor_agent_instructions(
+            self.cdt_trf_tx_inf
+                .instr_for_cdtr_agt
+                .iter()
+                .flatten()
+                .map(|instr| instr.cd.as_ref()),
+            &tx_path,
+            config,
+            collector,
+        );
+        parties::validate_ultimate_party(
+            &self.cdt_trf_tx_inf.dbtr,
+            self.cdt_trf_tx_inf.ultmt_dbtr.as_ref(),
+            ("Dbtr", "UltmtDbtr"),
+            &tx_path,
+            config,
+            collector,
+        );
+        parties::validate_ultimate_party(
+            &self.cdt_trf_tx_inf.cdtr,
+            self.cdt_trf_tx_inf.ultmt_cdtr.as_ref(),
+            ("Cdtr", "UltmtCdtr"),
+            &tx_path,
+            config,
+            collector,
+        );
+        let tx = &self.cdt_trf_tx_inf;
+        let amount = tx.instd_amt.as_ref().unwrap_or(&tx.intr_bk_sttlm_amt);
+        remittance::validate_remittance_total(
+            &tx.remittance_structured_total(),
+            Some((amount.ccy.as_str(), amount.value)),
+            &tx_path,
+            config,
+            collector,
+        );
+        local_instrument::validate_local_instrument(
+            "pacs.008",
+            self.cdt_trf_tx_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.lcl_instrm.as_ref())
+                .and_then(|lcl| lcl.cd.as_deref()),
+            &child_path(&tx_path, "PmtTpInf"),
+            config,
+            collector,
+        );
+        external_codes::validate_category_purpose_code(
+            self.cdt_trf_tx_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.ctgy_purp.as_ref())
+                .and_then(|ctgy| ctgy.cd.as_deref()),
+            &child_path(&child_path(&tx_path, "PmtTpInf"), "CtgyPurp"),
+            config,
+            collector,
+        );
+        rates::validate_rates(self, path, config, collector);
+    }
+}
+
+impl BusinessRules for pacs_009_001_08::FinancialInstitutionCreditTransferV08 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        let tx_path = child_path(path, "CdtTrfTxInf");
+        payment_identification::validate_end_to_end_id(
+            &self.cdt_trf_tx_inf.pmt_id.end_to_end_id,
+            &child_path(&tx_path, "PmtId"),
+            config,
+            collector,
+        );
+        external_codes::validate_purpose_code(
+            self.cdt_trf_tx_inf
+                .purp
+                .as_ref()
+                .and_then(|purp| purp.cd.as_deref()),
+            &child_path(&tx_path, "Purp"),
+            config,
+            collector,
+        );
+        external_codes::validate_category_purpose_code(
+            self.cdt_trf_tx_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.ctgy_purp.as_ref())
+                .and_then(|ctgy| ctgy.cd.as_deref()),
+            &child_path(&child_path(&tx_path, "PmtTpInf"), "CtgyPurp"),
+            config,
+            collector,
+        );
+        instructions::validate_creditor_agent_instructions(
+            self.cdt_trf_tx_inf
+                .instr_for_cdtr_agt
+                .iter()
+                .flatten()
+                .map(|instr| instr.cd.as_ref()),
+            &tx_path,
+            config,
+            collector,
+        );
+        rates::validate_rates(self, path, config, collector);
+    }
+}
+
+impl BusinessRules for pacs_003_001_08::FIToFICustomerDirectDebitV08 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        let tx_path = child_path(path, "DrctDbtTxInf");
+        payment_identification::validate_end_to_end_id(
+            &self.drct_dbt_tx_inf.pmt_id.end_to_end_id,
+            &child_path(&tx_path, "PmtId"),
+            config,
+            collector,
+        );
+        external_codes::validate_purpose_code(
+            self.drct_dbt_tx_inf
+                .purp
+                .as_ref()
+                .and_then(|purp| purp.cd.as_deref()),
+            &child_path(&tx_path, "Purp"),
+            config,
+            collector,
+        );
+        parties::validate_ultimate_party(
+            &self.drct_dbt_tx_inf.dbtr,
+            self.drct_dbt_tx_inf.ultmt_dbtr.as_ref(),
+            ("Dbtr", "UltmtDbtr"),
+            &tx_path,
+            config,
+            collector,
+        );
+        parties::validate_ultimate_party(
+            &self.drct_dbt_tx_inf.cdtr,
+            self.drct_dbt_tx_inf.ultmt_cdtr.as_ref(),
+            ("Cdtr", "UltmtCdtr"),
+            &tx_path,
+            config,
+            collector,
+        );
+        let tx = &self.drct_dbt_tx_inf;
+        let amount = tx.instd_amt.as_ref().unwrap_or(&tx.intr_bk_sttlm_amt);
+        remittance::validate_remittance_total(
+            &tx.remittance_structured_total(),
+            Some((amount.ccy.as_str(), amount.value)),
+            &tx_path,
+            config,
+            collector,
+        );
+        local_instrument::validate_local_instrument(
+            "pacs.003",
+            self.drct_dbt_tx_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.lcl_instrm.as_ref())
+                .and_then(|lcl| lcl.cd.as_deref()),
+            &child_path(&tx_path, "PmtTpInf"),
+            config,
+            collector,
+        );
+        external_codes::validate_category_purpose_code(
+            self.drct_dbt_tx_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.ctgy_purp.as_ref())
+                .and_then(|ctgy| ctgy.cd.as_deref()),
+            &child_path(&child_path(&tx_path, "PmtTpInf"), "CtgyPurp"),
+            config,
+            collector,
+        );
+        rates::validate_rates(self, path, config, collector);
+        creditor_scheme_id::validate_creditor_scheme_ids(self, path, config, collector);
+    }
+}
+
+impl BusinessRules for pacs_004_001_09::PaymentReturnV09 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        rates::validate_rates(self, path, config, collector);
+    }
+}
+
+impl BusinessRules for pain_001_001_09::CustomerCreditTransferInitiationV09 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        let tx_path = child_path(&child_path(path, "PmtInf"), "CdtTrfTxInf");
+        payment_identification::validate_end_to_end_id(
+            &self.pmt_inf.cdt_trf_tx_inf.pmt_id.end_to_end_id,
+            &child_path(&tx_path, "PmtId"),
+            config,
+            collector,
+        );
+        payment_identification::validate_instant_identifier(
+            &self.grp_hdr.msg_id,
+            "MsgId",
+            &child_path(path, "GrpHdr"),
+            config,
+            collector,
+        );
+        payment_identification::validate_instant_identifier(
+            &self.pmt_inf.cdt_trf_tx_inf.pmt_id.end_to_end_id,
+            "EndToEndId",
+            &child_path(&tx_path, "PmtId"),
+            config,
+            collector,
+        );
+        external_codes::validate_purpose_code(
+            self.pmt_inf
+                .cdt_trf_tx_inf
+                .purp
+                .as_ref()
+                .and_then(|purp| purp.cd.as_deref()),
+            &child_path(&tx_path, "Purp"),
+            config,
+            collector,
+        );
+        instructions::validate_creditor_agent_instructions(
+            self.pmt_inf
+                .cdt_trf_tx_inf
+                .instr_for_cdtr_agt
+                .iter()
+                .flatten()
+                .map(|instr| instr.cd.as_ref()),
+            &tx_path,
+            config,
+            collector,
+        );
+        parties::validate_ultimate_party(
+            &self.pmt_inf.dbtr,
+            self.pmt_inf.ultmt_dbtr.as_ref(),
+            ("Dbtr", "UltmtDbtr"),
+            &child_path(path, "PmtInf"),
+            config,
+            collector,
+        );
+        parties::validate_ultimate_party(
+            &self.pmt_inf.dbtr,
+            self.pmt_inf.cdt_trf_tx_inf.ultmt_dbtr.as_ref(),
+            ("Dbtr", "UltmtDbtr"),
+            &tx_path,
+            config,
+            collector,
+        );
+        parties::validate_ultimate_party(
+            &self.pmt_inf.cdt_trf_tx_inf.cdtr,
+            self.pmt_inf.cdt_trf_tx_inf.ultmt_cdtr.as_ref(),
+            ("Cdtr", "UltmtCdtr"),
+            &tx_path,
+            config,
+            collector,
+        );
+        let tx = &self.pmt_inf.cdt_trf_tx_inf;
+        let amount = tx
+            .amt
+            .instd_amt
+            .as_ref()
+            .or_else(|| tx.amt.eqvt_amt.as_ref().map(|eqvt| &eqvt.amt));
+        remittance::validate_remittance_total(
+            &tx.remittance_structured_total(),
+            amount.map(|amt| (amt.ccy.as_str(), amt.value)),
+            &tx_path,
+            config,
+            collector,
+        );
+        local_instrument::validate_local_instrument(
+            "pain.001",
+            self.pmt_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.lcl_instrm.as_ref())
+                .and_then(|lcl| lcl.cd.as_deref()),
+            &child_path(&child_path(path, "PmtInf"), "PmtTpInf"),
+            config,
+            collector,
+        );
+        external_codes::validate_category_purpose_code(
+            self.pmt_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.ctgy_purp.as_ref())
+                .and_then(|ctgy| ctgy.cd.as_deref()),
+            &child_path(
+                &child_path(&child_path(path, "PmtInf"), "PmtTpInf"),
+                "CtgyPurp",
+            ),
+            config,
+            collector,
+        );
+        local_instrument::validate_local_instrument(
+            "pain.001",
+            self.pmt_inf
+                .cdt_trf_tx_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.lcl_instrm.as_ref())
+                .and_then(|lcl| lcl.cd.as_deref()),
+            &child_path(&tx_path, "PmtTpInf"),
+            config,
+            collector,
+        );
+        external_codes::validate_category_purpose_code(
+            self.pmt_inf
+                .cdt_trf_tx_inf
+                .pmt_tp_inf
+                .as_ref()
+                .and_then(|tp| tp.ctgy_purp.as_ref())
+                .and_then(|ctgy| ctgy.cd.as_deref()),
+            &child_path(&child_path(&tx_path, "PmtTpInf"), "CtgyPurp"),
+            config,
+            collector,
+        );
+        execution_date::validate_requested_execution_date(
+            &self.grp_hdr.cre_dt_tm,
+            &self.pmt_inf.reqd_exctn_dt,
+            &child_path(path, "PmtInf"),
+            config,
+            collector,
+        );
+        rates::validate_rates(self, path, config, collector);
+        contacts::validate_contact_details(self, path, config, collector);
+        forwarding_agent::validate_forwarding_agent(self, path, config, collector);
+    }
+}
+
+impl BusinessRules for pain_008_001_08::CustomerDirectDebitInitiationV08 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        let pmt_inf_path = child_path(path, "PmtInf");
+        for (idx, tx) in self.pmt_inf.drct_dbt_tx_inf.iter().enumerate() {
+            let tx_path = index_path(&child_path(&pmt_inf_path, "DrctDbtTxInf"), idx);
+            payment_identification::validate_end_to_end_id(
+                &tx.pmt_id.end_to_end_id,
+                &child_path(&tx_path, "PmtId"),
+                config,
+                collector,
+            );
+            external_codes::validate_purpose_code(
+                tx.purp.as_ref().and_then(|purp| purp.cd.as_deref()),
+                &child_path(&tx_path, "Purp"),
+                config,
+                collector,
+            );
+            parties::validate_ultimate_party(
+                &tx.dbtr,
+                tx.ultmt_dbtr.as_ref(),
+                ("Dbtr", "UltmtDbtr"),
+                &tx_path,
+                config,
+                collector,
+            );
+            parties::validate_ultimate_party(
+                &self.pmt_inf.cdtr,
+                tx.ultmt_cdtr.as_ref(),
+                ("Cdtr", "UltmtCdtr"),
+                &tx_path,
+                config,
+                collector,
+            );
+            remittance::validate_remittance_total(
+                &tx.remittance_structured_total(),
+                Some((tx.instd_amt.ccy.as_str(), tx.instd_amt.value)),
+                &tx_path,
+                config,
+                collector,
+            );
+            local_instrument::validate_local_instrument(
+                "pain.008",
+                tx.pmt_tp_inf
+                    .as_ref()
+                    .and_then(|tp| tp.lcl_instrm.as_ref())
+                    .and_then(|lcl| lcl.cd.as_deref()),
+                &child_path(&tx_path, "PmtTpInf"),
+                config,
+                collector,
+            );
+            external_codes::validate_category_purpose_code(
+                tx.pmt_tp_inf
+                    .as_ref()
+                    .and_then(|tp| tp.ctgy_purp.as_ref())
+                    .and_then(|ctgy| ctgy.cd.as_deref()),
+                &child_path(&child_path(&tx_path, "PmtTpInf"), "CtgyPurp"),
+                config,
+                collector,
+            );
+            if let Some(mandate) = tx.mandate() {
+                mandates::validate_mandate_amendment(
+                    mandate,
+                    &child_path(&child_path(&tx_path, "DrctDbtTx"), "MndtRltdInf"),
+                    config,
+                    collector,
+                );
+            }
+        }
+        payment_identification::validate_unique_payment_ids(
+            self.pmt_inf.drct_dbt_tx_inf.iter().map(|tx| {
+                (
+                    tx.pmt_id.instr_id.as_deref(),
+                    tx.pmt_id.end_to_end_id.as_str(),
+                )
+            }),
+            &child_path(&pmt_inf_path, "DrctDbtTxInf"),
+            config,
+            collector,
+        );
+        rates::validate_rates(self, path, config, collector);
+        creditor_scheme_id::validate_creditor_scheme_ids(self, path, config, collector);
+        contacts::validate_contact_details(self, path, config, collector);
+    }
+}
+
+impl BusinessRules for camt_052_001_08::BankToCustomerAccountReportV08 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        charges::validate_charges_totals(self, path, config, collector);
+        rates::validate_rates(self, path, config, collector);
+        cash_deposits::validate_cash_deposits(self, path, config, collector);
+        currency_exchange::validate_currency_exchanges(self, path, config, collector);
+        transaction_summary::validate_net_entries(self, path, config, collector);
+        account_currency::validate_prepaid_account_currency(self, path, config, collector);
+        let rpt = &self.rpt;
+        account_currency::validate_account_currency(
+            rpt.acct.ccy.as_str(),
+            rpt.bal.iter().flatten().map(|bal| bal.amt.ccy.as_str()),
+            rpt.ntry
+                .iter()
+                .flatten()
+                .map(|entry| entry.amt.ccy.as_str()),
+            &child_path(path, "Rpt"),
+            config,
+            collector,
+        );
+    }
+}
+
+impl BusinessRules for camt_053_001_08::BankToCustomerStatementV08 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        charges::validate_charges_totals(self, path, config, collector);
+        rates::validate_rates(self, path, config, collector);
+        cash_deposits::validate_cash_deposits(self, path, config, collector);
+        currency_exchange::validate_currency_exchanges(self, path, config, collector);
+        transaction_summary::validate_net_entries(self, path, config, collector);
+        account_currency::validate_prepaid_account_currency(self, path, config, collector);
+        let stmt = &self.stmt;
+        account_currency::validate_account_currency(
+            stmt.acct.ccy.as_str(),
+            stmt.bal.iter().map(|bal| bal.amt.ccy.as_str()),
+            stmt.ntry
+                .iter()
+                .flatten()
+                .map(|entry| entry.amt.ccy.as_str()),
+            &child_path(path, "Stmt"),
+            config,
+            collector,
+        );
+        balances::validate_required_balances(
+            &self.stmt,
+            &child_path(path, "Stmt"),
+            config,
+            collector,
+        );
+        balances::validate_statement_balances(
+            &self.stmt,
+            &child_path(path, "Stmt"),
+            config,
+            collector,
+        );
+        booking_dates::validate_booking_dates(self, path, config, collector);
+        booking_dates::validate_entry_period(self, path, config, collector);
+    }
+}
+
+impl BusinessRules for camt_054_001_08::BankToCustomerDebitCreditNotificationV08 {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        charges::validate_charges_totals(self, path, config, collector);
+        rates::validate_rates(self, path, config, collector);
+        cash_deposits::validate_cash_deposits(self, path, config, collector);
+        currency_exchange::validate_currency_exchanges(self, path, config, collector);
+        transaction_summary::validate_net_entries(self, path, config, collector);
+        account_currency::validate_prepaid_account_currency(self, path, config, collector);
+    }
+}
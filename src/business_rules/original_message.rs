@@ -0,0 +1,119 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Original message name rules (OrgnlMsgNmId)
+//!
+//! Status reports, returns, cancellations and resolutions name the message
+//! they concern in `OrgnlMsgNmId`, e.g. `pacs.008.001.08`. The receiver uses
+//! it together with `OrgnlMsgId` to find the original, so a misspelt
+//! identifier breaks the correlation. The schema allows free text, and CBPR+
+//! coexistence traffic names MT originals (`MT103`), so only a value in the
+//! `area.nnn.nnn.nn` form of a message definition identifier is checked: if
+//! its message type is not in the registry a warning is raised. Other
+//! versions than the supported one are accepted.
+
+use crate::error::ValidationError;
+use crate::message_registry::{find_message_definition, is_message_definition_identifier};
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::leaf_name;
+use crate::visitor;
+use serde_json::Value;
+
+/// Warn about every `OrgnlMsgNmId` of the document that is a message definition
+/// identifier of an unknown message type
+pub fn validate_original_message_name_ids(
+    document: &Document,
+    path: &str,
+    _config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    // The document serializes wrapped in its root element
+    let Ok(Value::Object(root)) = serde_json::to_value(document) else {
+        return;
+    };
+    let Some(content) = root.values().next() else {
+        return;
+    };
+    visitor::visit(content, path, &mut |node_path, node| {
+        if leaf_name(node_path) != "OrgnlMsgNmId" {
+            return;
+        }
+        let Some(identifier) = node.as_str() else {
+            return;
+        };
+        if is_message_definition_identifier(identifier)
+            && find_message_definition(identifier).is_none()
+        {
+            collector.add_warning(
+                ValidationError::new(
+                    2039,
+                    format!(
+                        "OrgnlMsgNmId '{identifier}' is not a known message definition identifier"
+                    ),
+                )
+                .with_field("OrgnlMsgNmId".to_string())
+                .with_path(node_path.to_string()),
+            );
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_002_001_10::FIToFIPaymentStatusReportV10;
+
+    fn check(orgnl_msg_nm_id: &str) -> ErrorCollector {
+        let mut doc = FIToFIPaymentStatusReportV10::default();
+        doc.tx_inf_and_sts.orgnl_grp_inf.orgnl_msg_nm_id = orgnl_msg_nm_id.to_string();
+        let mut collector = ErrorCollector::new();
+        validate_original_message_name_ids(
+            &Document::Pacs002(Box::new(doc)),
+            "",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        collector
+    }
+
+    #[test]
+    fn test_known_original_message() {
+        for identifier in [
+            "pacs.008.001.08",
+            "pain.001.001.08",
+            "MT103",
+            "pacs.008.01.08",
+        ] {
+            assert!(check(identifier).warnings().is_empty(), "{identifier}");
+        }
+    }
+
+    #[test]
+    fn test_unknown_original_message() {
+        let collector = check("pacs.999.001.01");
+        let warnings = collector.warnings();
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, 2039);
+        assert!(!collector.has_errors());
+        assert_eq!(
+            warnings[0].path.as_deref(),
+            Some("TxInfAndSts.OrgnlGrpInf.OrgnlMsgNmId")
+        );
+    }
+}
@@ -0,0 +1,177 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Ultimate party rules (UltmtDbtr, UltmtCdtr)
+//!
+//! An ultimate party is only meaningful when it differs from the party it
+//! stands behind. When present it must identify someone, by name or by an
+//! identification, and a postal address is only usable together with a name.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+use serde::Serialize;
+use serde_json::Value;
+
+/// Validate an ultimate party against the party it stands behind
+///
+/// `party` and `ultimate` are the element names, e.g. "Dbtr" and "UltmtDbtr".
+/// A redundant copy of the party is a warning for the profiles that flag it.
+pub fn validate_ultimate_party<P, U>(
+    party: &P,
+    ultimate: Option<&U>,
+    names: (&str, &str),
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) where
+    P: Serialize,
+    U: Serialize,
+{
+    let Some(ultimate) = ultimate.and_then(|u| serde_json::to_value(u).ok()) else {
+        return;
+    };
+    let (party_name, ultimate_name) = names;
+    let ultimate_path = child_path(path, ultimate_name);
+
+    let has = |field: &str| ultimate.get(field).is_some_and(|v| !is_empty(v));
+    if !has("Nm") && !has("Id") {
+        report_error(
+            ValidationError::new(
+                2007,
+                format!("{ultimate_name} must contain a name (Nm) or an identification (Id)"),
+            )
+            .with_field(ultimate_name.to_string())
+            .with_path(ultimate_path),
+            config,
+            collector,
+        );
+        return;
+    }
+    if has("PstlAdr") && !has("Nm") {
+        report_error(
+            ValidationError::new(
+                2007,
+                format!("{ultimate_name} has a postal address (PstlAdr) but no name (Nm)"),
+            )
+            .with_field("Nm".to_string())
+            .with_path(child_path(&ultimate_path, "Nm")),
+            config,
+            collector,
+        );
+    }
+
+    if config.profile.flags_redundant_ultimate_parties()
+        && serde_json::to_value(party).ok() == Some(ultimate)
+    {
+        collector.add_warning(
+            ValidationError::new(
+                2006,
+                format!("{ultimate_name} is identical to {party_name} and should be omitted"),
+            )
+            .with_field(ultimate_name.to_string())
+            .with_path(ultimate_path),
+        );
+    }
+}
+
+/// Whether a serialized element carries no content
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::String(s) => s.trim().is_empty(),
+        Value::Object(map) => map.values().all(is_empty),
+        Value::Array(items) => items.iter().all(is_empty),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::{
+        FIToFICustomerCreditTransferV08, PartyIdentification1351, PostalAddress242,
+    };
+    use crate::parse_result::ValidationProfile;
+    use crate::validation::BusinessRules;
+
+    fn transfer() -> FIToFICustomerCreditTransferV08 {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.dbtr.nm = Some("ACME Corp".to_string());
+        doc.cdt_trf_tx_inf.cdtr.nm = Some("Widget Ltd".to_string());
+        doc
+    }
+
+    fn check(
+        doc: &FIToFICustomerCreditTransferV08,
+        profile: ValidationProfile,
+    ) -> (Vec<ValidationError>, Vec<ValidationError>) {
+        let config = ParserConfig::default().with_profile(profile);
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &config, &mut collector);
+        let warnings = collector.warnings().to_vec();
+        (collector.errors(), warnings)
+    }
+
+    #[test]
+    fn test_redundant_ultimate_debtor() {
+        let mut doc = transfer();
+        doc.cdt_trf_tx_inf.ultmt_dbtr = Some(PartyIdentification1351 {
+            nm: Some("ACME Corp".to_string()),
+            ..Default::default()
+        });
+
+        let (errors, warnings) = check(&doc, ValidationProfile::CbprPlus);
+        assert!(errors.is_empty());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, 2006);
+        assert_eq!(warnings[0].path.as_deref(), Some("CdtTrfTxInf.UltmtDbtr"));
+
+        let (errors, warnings) = check(&doc, ValidationProfile::Base);
+        assert!(errors.is_empty());
+        assert!(warnings.is_empty());
+
+        doc.cdt_trf_tx_inf.ultmt_dbtr.as_mut().unwrap().nm = Some("ACME Holdings".to_string());
+        let (_, warnings) = check(&doc, ValidationProfile::CbprPlus);
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_partial_ultimate_creditor() {
+        let mut doc = transfer();
+        doc.cdt_trf_tx_inf.ultmt_cdtr = Some(PartyIdentification1351 {
+            pstl_adr: Some(PostalAddress242 {
+                twn_nm: "London".to_string(),
+                ctry: "GB".to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+
+        let (errors, _) = check(&doc, ValidationProfile::Base);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2007);
+        assert_eq!(errors[0].path.as_deref(), Some("CdtTrfTxInf.UltmtCdtr"));
+
+        doc.cdt_trf_tx_inf.ultmt_cdtr = Some(PartyIdentification1351::default());
+        let (errors, _) = check(&doc, ValidationProfile::Base);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2007);
+    }
+}
@@ -0,0 +1,310 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Payment identification rules (InstrId, EndToEndId, UETR)
+//!
+//! Instant payment schemes restrict `MsgId` and `EndToEndId` to the EPC
+//! identifier set: letters, digits, space and `/ - ? : ( ) . , ' +`, with no
+//! leading, trailing or double `/`. Their receivers also deduplicate on these
+//! ids, so values that look like placeholders are warned about.
+//!
+//! Within a batch, every transaction must carry its own `InstrId` and
+//! `EndToEndId`: receivers match returns, investigations and statements on
+//! them. Only pain.008 carries more than one transaction per message in this
+//! crate; the pain.001 and pacs.008 guidelines allow a single one.
+
+use crate::core_validation::is_swift_x_character;
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, index_path, report_error};
+use std::collections::HashMap;
+
+/// Placeholder allowed by the schema when the originator supplied no end-to-end reference
+pub const NOT_PROVIDED: &str = "NOTPROVIDED";
+
+/// Values that originators commonly send instead of a unique reference
+const PLACEHOLDER_IDS: &[&str] = &["NONREF", "NONE", "N/A", "NA", "TEST", "UNKNOWN"];
+
+/// Check whether a character belongs to the instant-scheme identifier set
+/// The SWIFT x character set without its line breaks.
+fn is_identifier_character(c: char) -> bool {
+    is_swift_x_character(c) && !matches!(c, '\r' | '\n')
+}
+
+/// Validate an identifier (MsgId, EndToEndId) against the instant-scheme rules
+///
+/// Characters outside the identifier set and misplaced slashes are errors; a
+/// placeholder-like value (a known placeholder, or one repeated character) is
+/// a warning. Only applies under profiles that restrict identifiers.
+pub fn validate_instant_identifier(
+    value: &str,
+    field: &str,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.profile.restricts_identifiers() || value.is_empty() {
+        return;
+    }
+    let path = child_path(path, field);
+    let problem = if let Some(c) = value.chars().find(|&c| !is_identifier_character(c)) {
+        Some(format!(
+            "contains '{c}', which is outside the identifier set"
+        ))
+    } else if value.starts_with('/') || value.ends_with('/') || value.contains("//") {
+        Some("must not start or end with '/' or contain '//'".to_string())
+    } else {
+        None
+    };
+    if let Some(problem) = problem {
+        report_error(
+            ValidationError::new(2024, format!("{field} '{value}' {problem}"))
+                .with_field(field.to_string())
+                .with_path(path),
+            config,
+            collector,
+        );
+        return;
+    }
+
+    let upper = value.trim().to_ascii_uppercase();
+    let repeated = upper
+        .chars()
+        .next()
+        .is_some_and(|first| upper.chars().all(|c| c == first));
+    if repeated || PLACEHOLDER_IDS.contains(&upper.as_str()) {
+        collector.add_warning(
+            ValidationError::new(
+                2025,
+                format!("{field} '{value}' looks like a placeholder and is unlikely to be unique"),
+            )
+            .with_field(field.to_string())
+            .with_path(path),
+        );
+    }
+}
+
+/// Validate that EndToEndId carries a real reference when the profile requires one
+///
+/// Profiles that accept the `NOTPROVIDED` placeholder (Base, CBPR+) skip this rule.
+pub fn validate_end_to_end_id(
+    end_to_end_id: &str,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.profile.requires_end_to_end_id() {
+        return;
+    }
+
+    let path = child_path(path, "EndToEndId");
+    let value = end_to_end_id.trim();
+    if value.is_empty() {
+        report_error(
+            ValidationError::new(
+                2001,
+                "EndToEndId is required by the scheme profile".to_string(),
+            )
+            .with_field("EndToEndId".to_string())
+            .with_path(path),
+            config,
+            collector,
+        );
+    } else if value == NOT_PROVIDED {
+        report_error(
+            ValidationError::new(
+                2002,
+                format!("EndToEndId must not be the {NOT_PROVIDED} placeholder"),
+            )
+            .with_field("EndToEndId".to_string())
+            .with_path(path),
+            config,
+            collector,
+        );
+    }
+}
+
+/// Validate that no two transactions of a batch share an InstrId or EndToEndId
+///
+/// `ids` yields the `(InstrId, EndToEndId)` of each transaction in order and
+/// `path` is the path of the repeating transaction element, e.g.
+/// `PmtInf.DrctDbtTxInf`. Each repeat is reported at the later transaction
+/// with the index of the first; empty ids and the `NOTPROVIDED` placeholder
+/// are not compared.
+pub fn validate_unique_payment_ids<'a>(
+    ids: impl IntoIterator<Item = (Option<&'a str>, &'a str)>,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let mut seen: [HashMap<&str, usize>; 2] = Default::default();
+    for (idx, (instr_id, end_to_end_id)) in ids.into_iter().enumerate() {
+        let fields = [("InstrId", instr_id), ("EndToEndId", Some(end_to_end_id))];
+        for ((field, value), seen) in fields.into_iter().zip(seen.iter_mut()) {
+            let Some(value) = value.map(str::trim) else {
+                continue;
+            };
+            if value.is_empty() || value == NOT_PROVIDED {
+                continue;
+            }
+            let Some(first) = seen.get(value).copied() else {
+                seen.insert(value, idx);
+                continue;
+            };
+            report_error(
+                ValidationError::new(
+                    2027,
+                    format!("{field} {value} is also used by transaction {first}"),
+                )
+                .with_field(field.to_string())
+                .with_path(child_path(
+                    &child_path(&index_path(path, idx), "PmtId"),
+                    field,
+                )),
+                config,
+                collector,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_result::ValidationProfile;
+
+    fn check(value: &str, profile: ValidationProfile) -> Vec<ValidationError> {
+        let config = ParserConfig::default().with_profile(profile);
+        let mut collector = ErrorCollector::new();
+        validate_end_to_end_id(value, "CdtTrfTxInf.PmtId", &config, &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_real_end_to_end_id() {
+        assert!(check("E2E-2024-0001", ValidationProfile::Target2).is_empty());
+    }
+
+    #[test]
+    fn test_placeholder_end_to_end_id() {
+        let errors = check("NOTPROVIDED", ValidationProfile::Target2);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2002);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("CdtTrfTxInf.PmtId.EndToEndId")
+        );
+
+        // Placeholder is permitted outside the gated profiles
+        assert!(check("NOTPROVIDED", ValidationProfile::Base).is_empty());
+        assert!(check("NOTPROVIDED", ValidationProfile::CbprPlus).is_empty());
+    }
+
+    fn check_instant(value: &str) -> (Vec<ValidationError>, Vec<ValidationError>) {
+        let config = ParserConfig::default().with_profile(ValidationProfile::Instant);
+        let mut collector = ErrorCollector::new();
+        validate_instant_identifier(value, "MsgId", "GrpHdr", &config, &mut collector);
+        let warnings = collector.warnings().to_vec();
+        (collector.errors(), warnings)
+    }
+
+    #[test]
+    fn test_compliant_instant_identifier() {
+        let (errors, warnings) = check_instant("MSG-2024/0001 (A)");
+        assert!(errors.is_empty());
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn test_non_compliant_instant_identifier() {
+        let (errors, _) = check_instant("MSG_2024#1");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2024);
+        assert_eq!(errors[0].path.as_deref(), Some("GrpHdr.MsgId"));
+
+        assert_eq!(check_instant("/MSG1").0.len(), 1);
+        assert_eq!(check_instant("MSG//1").0.len(), 1);
+        // Line breaks are in the x character set but not in identifiers
+        assert_eq!(check_instant("MSG\n1").0.len(), 1);
+
+        let (errors, warnings) = check_instant("00000000");
+        assert!(errors.is_empty());
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].code, 2025);
+        assert_eq!(check_instant("nonref").1.len(), 1);
+
+        // Other profiles leave the identifier alone
+        let mut collector = ErrorCollector::new();
+        validate_instant_identifier(
+            "MSG_2024#1",
+            "MsgId",
+            "GrpHdr",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        assert!(!collector.has_errors());
+    }
+
+    #[test]
+    fn test_empty_end_to_end_id() {
+        let errors = check("", ValidationProfile::Sepa);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2001);
+    }
+
+    fn check_unique(ids: &[(Option<&str>, &str)]) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_unique_payment_ids(
+            ids.iter().copied(),
+            "PmtInf.DrctDbtTxInf",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    #[test]
+    fn test_unique_payment_ids() {
+        assert!(
+            check_unique(&[
+                (Some("INSTR-1"), "E2E-1"),
+                (Some("INSTR-2"), "E2E-2"),
+                (None, "NOTPROVIDED"),
+                (None, "NOTPROVIDED"),
+            ])
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_duplicate_end_to_end_id() {
+        let errors = check_unique(&[
+            (Some("INSTR-1"), "E2E-1"),
+            (Some("INSTR-2"), "E2E-2"),
+            (Some("INSTR-3"), "E2E-1"),
+        ]);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2027);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("PmtInf.DrctDbtTxInf[2].PmtId.EndToEndId")
+        );
+        assert!(errors[0].message.contains("transaction 0"));
+    }
+}
@@ -0,0 +1,159 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Rate plausibility rules (Rate, Pctg, XchgRate)
+//!
+//! Percentage rates (charge and tax record `Rate`, interest `Rate/Tp/Pctg`)
+//! are bare decimals in the schema, so a rate of 10000% passes the schema
+//! rules. Under scheme profiles they must lie within
+//! `ParserConfig::rate_range`, and exchange rates (`XchgRate`) must be
+//! positive. Price rates of securities (`Pric/Val/Rate`) are not percentages
+//! of an amount and are left out.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{leaf_name, report_error};
+use crate::visitor;
+use serde::Serialize;
+
+/// Validate every rate and exchange rate of a document
+pub fn validate_rates<T: Serialize>(
+    document: &T,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.profile.checks_rate_plausibility() {
+        return;
+    }
+    let Ok(value) = serde_json::to_value(document) else {
+        return;
+    };
+    visitor::visit(&value, path, &mut |node_path, node| {
+        let Some(rate) = node.as_f64() else {
+            return;
+        };
+        let field = leaf_name(node_path);
+        let error = match field {
+            "XchgRate" if rate <= 0.0 => {
+                ValidationError::new(2017, format!("XchgRate {rate} must be greater than zero"))
+            }
+            "Rate" | "Pctg"
+                if !node_path.ends_with(".Val.Rate") && !config.rate_range.contains(rate) =>
+            {
+                let range = config.rate_range;
+                ValidationError::new(
+                    2016,
+                    format!(
+                        "{field} {rate} is outside the plausible range {} to {}",
+                        range.min, range.max
+                    ),
+                )
+            }
+            _ => return,
+        };
+        report_error(
+            error
+                .with_field(field.to_string())
+                .with_path(node_path.to_string()),
+            config,
+            collector,
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::document::camt_053_001_08::*;
+    use crate::document::pacs_004_001_09::PaymentReturnV09;
+    use crate::document::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+    use crate::error::ValidationError;
+    use crate::parse_result::{ErrorCollector, ParserConfig, ValidationProfile};
+    use crate::validation::BusinessRules;
+
+    fn check<T: BusinessRules>(doc: &T, config: ParserConfig) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", &config, &mut collector);
+        collector.errors()
+    }
+
+    fn statement(rate: f64) -> BankToCustomerStatementV08 {
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.stmt.ntry = Some(vec![Box::new(ReportEntry101 {
+            chrgs: Some(Charges61 {
+                ttl_chrgs_and_tax_amt: None,
+                rcrd: Some(vec![ChargesRecord31 {
+                    rate: Some(rate),
+                    ..Default::default()
+                }]),
+            }),
+            ..Default::default()
+        })]);
+        doc
+    }
+
+    fn cbpr() -> ParserConfig {
+        ParserConfig::default().with_profile(ValidationProfile::CbprPlus)
+    }
+
+    #[test]
+    fn test_plausible_rate() {
+        assert!(check(&statement(2.5), cbpr()).is_empty());
+        assert!(check(&statement(-0.5), cbpr()).is_empty());
+    }
+
+    #[test]
+    fn test_implausible_rate() {
+        let errors = check(&statement(10000.0), cbpr());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2016);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("Stmt.Ntry[0].Chrgs.Rcrd[0].Rate")
+        );
+
+        assert!(check(&statement(10000.0), ParserConfig::default()).is_empty());
+        assert!(check(&statement(10000.0), cbpr().with_rate_range(0.0, 20000.0)).is_empty());
+    }
+
+    #[test]
+    fn test_zero_exchange_rate() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.xchg_rate = Some(0.0);
+        let errors: Vec<_> = check(&doc, cbpr())
+            .into_iter()
+            .filter(|err| err.code == 2017)
+            .collect();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path.as_deref(), Some("CdtTrfTxInf.XchgRate"));
+
+        doc.cdt_trf_tx_inf.xchg_rate = Some(1.0842);
+        assert!(check(&doc, cbpr()).iter().all(|err| err.code != 2017));
+    }
+
+    #[test]
+    fn test_return_exchange_rate() {
+        let mut doc = PaymentReturnV09::default();
+        doc.tx_inf.xchg_rate = Some(-1.0842);
+        let errors = check(&doc, cbpr());
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2017);
+        assert_eq!(errors[0].path.as_deref(), Some("TxInf.XchgRate"));
+    }
+}
@@ -0,0 +1,231 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Structured remittance reconciliation (RmtInf/Strd)
+//!
+//! When a payment settles itemized documents, the remitted document amounts
+//! should add up to the amount of the transaction; a difference usually means
+//! an invoice was left out or paid twice. The check is opt-in through
+//! `ParserConfig::reconcile_remittance`, since partial payments and unlisted
+//! charges legitimately break it. Remittances listing amounts in a currency
+//! other than the transaction's are not checked.
+//!
+//! Unstructured remittance (`RmtInf/Ustrd`) repeats in the base schema, but
+//! the usage guidelines the message types are generated from allow a single
+//! line per remittance, and the generated types hold it as one optional
+//! string. The cap is therefore 1 for every message type; larger per-scheme
+//! caps would need `Ustrd` modelled as a repeating element first.
+//! `check_unstructured_lines` enforces the cap on the raw XML before
+//! deserialization, so parsing a message with an extra line still fails, but
+//! the error carries code 2020 and the path of the first line over the cap.
+
+use crate::error::ValidationError;
+use crate::message_registry;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, index_path, report_error};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use std::collections::HashMap;
+
+/// Largest difference between the document total and the transaction amount
+/// treated as rounding noise
+const RECONCILIATION_TOLERANCE: f64 = 1e-6;
+
+/// Validate that the structured remittance total matches the transaction amount
+/// `path` is the path of the transaction; the error is reported on its `RmtInf`.
+pub fn validate_remittance_total(
+    total: &HashMap<String, f64>,
+    amount: Option<(&str, f64)>,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    if !config.reconcile_remittance || total.len() != 1 {
+        return;
+    }
+    let Some((ccy, value)) = amount else {
+        return;
+    };
+    let Some(&documents) = total.get(ccy) else {
+        return;
+    };
+    if (documents - value).abs() > RECONCILIATION_TOLERANCE {
+        report_error(
+            ValidationError::new(
+                2014,
+                format!(
+                    "Structured remittance totals {documents} {ccy}, transaction amount is {value}"
+                ),
+            )
+            .with_field("RmtInf".to_string())
+            .with_path(child_path(path, "RmtInf")),
+            config,
+            collector,
+        );
+    }
+}
+
+/// Maximum number of `Ustrd` lines per `RmtInf`
+/// One line for every message type, the most the generated types can hold.
+pub const MAX_UNSTRUCTURED_LINES: usize = 1;
+
+/// Check that no `RmtInf` of an MX XML message has more than [`MAX_UNSTRUCTURED_LINES`] `Ustrd` lines
+/// The error path starts below the document root element and points at the
+/// first line over the cap. Malformed XML is left for the parser to report.
+pub fn check_unstructured_lines(xml: &str) -> Result<(), ValidationError> {
+    let max = MAX_UNSTRUCTURED_LINES;
+    let mut reader = Reader::from_str(xml);
+    let mut in_document = false;
+    // Open elements below the document root, with the Ustrd lines seen in each
+    let mut open: Vec<(String, usize)> = Vec::new();
+    loop {
+        let (element, is_empty) = match reader.read_event() {
+            Ok(Event::Start(element)) => (element, false),
+            Ok(Event::Empty(element)) => (element, true),
+            Ok(Event::End(_)) => {
+                open.pop();
+                continue;
+            }
+            Ok(Event::Eof) | Err(_) => return Ok(()),
+            Ok(_) => continue,
+        };
+        let name = local_name(&element);
+        if !in_document {
+            // Elements above the document root (Envelope, AppHdr, Document)
+            in_document = !is_empty && message_registry::element_to_message_type(&name).is_some();
+            continue;
+        }
+        if name == "Ustrd"
+            && let Some((parent, lines)) = open.last_mut()
+            && parent == "RmtInf"
+        {
+            *lines += 1;
+            if *lines > max {
+                let path = open
+                    .iter()
+                    .map(|(name, _)| name.as_str())
+                    .fold(String::new(), |path, name| child_path(&path, name));
+                let path = index_path(&child_path(&path, "Ustrd"), max);
+                return Err(ValidationError::new(
+                    2020,
+                    format!(
+                        "RmtInf carries more than {max} unstructured remittance line(s) (Ustrd)"
+                    ),
+                )
+                .with_field("Ustrd".to_string())
+                .with_path(path));
+            }
+        }
+        if !is_empty {
+            open.push((name, 0));
+        }
+    }
+}
+
+fn local_name(element: &BytesStart) -> String {
+    String::from_utf8_lossy(element.local_name().as_ref()).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::*;
+    use crate::error::MxError;
+    use crate::mx_envelope::MxMessage;
+    use crate::validation::BusinessRules;
+
+    fn invoice(value: f64) -> StructuredRemittanceInformation161 {
+        StructuredRemittanceInformation161 {
+            rfrd_doc_amt: Some(RemittanceAmount21 {
+                rmtd_amt: Some(ActiveOrHistoricCurrencyAndAmount {
+                    ccy: "EUR".into(),
+                    value,
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    fn check(settled: f64, invoices: &[f64], config: &ParserConfig) -> Vec<ValidationError> {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt = CBPRAmount1 {
+            ccy: "EUR".into(),
+            value: settled,
+        };
+        doc.cdt_trf_tx_inf.rmt_inf = Some(RemittanceInformation161 {
+            ustrd: None,
+            strd: Some(invoices.iter().copied().map(invoice).collect()),
+        });
+        let mut collector = ErrorCollector::new();
+        doc.validate_business_rules("", config, &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_remittance_reconciles() {
+        let config = ParserConfig::default().with_remittance_reconciliation(true);
+        assert!(check(1500.0, &[1000.0, 499.99, 0.01], &config).is_empty());
+    }
+
+    #[test]
+    fn test_remittance_does_not_reconcile() {
+        let config = ParserConfig::default().with_remittance_reconciliation(true);
+        let errors = check(1500.0, &[1000.0, 400.0], &config);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2014);
+        assert_eq!(errors[0].path.as_deref(), Some("CdtTrfTxInf.RmtInf"));
+
+        assert!(check(1500.0, &[1000.0, 400.0], &ParserConfig::default()).is_empty());
+    }
+
+    fn transfer_xml(lines: &[&str]) -> String {
+        let ustrd: String = lines
+            .iter()
+            .map(|line| format!("<Ustrd>{line}</Ustrd>"))
+            .collect();
+        format!(
+            "<Document xmlns=\"urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08\"><FIToFICstmrCdtTrf>\
+             <GrpHdr><MsgId>MSG-001</MsgId></GrpHdr>\
+             <CdtTrfTxInf><RmtInf>{ustrd}</RmtInf></CdtTrfTxInf>\
+             </FIToFICstmrCdtTrf></Document>"
+        )
+    }
+
+    #[test]
+    fn test_unstructured_lines_absent_and_at_cap() {
+        // Below the cap of one line means no Ustrd at all
+        assert!(check_unstructured_lines(&transfer_xml(&[])).is_ok());
+        assert!(check_unstructured_lines(&transfer_xml(&["INV 2024-001"])).is_ok());
+    }
+
+    #[test]
+    fn test_unstructured_lines_above_cap() {
+        let error =
+            check_unstructured_lines(&transfer_xml(&["INV 2024-001", "INV 2024-002"])).unwrap_err();
+        assert_eq!(error.code, 2020);
+        assert_eq!(error.path.as_deref(), Some("CdtTrfTxInf.RmtInf.Ustrd[1]"));
+
+        let xml = transfer_xml(&["INV 2024-001", "INV 2024-002"]);
+        assert!(matches!(
+            MxMessage::from_xml(&xml),
+            Err(MxError::Validation { code: 2020, .. })
+        ));
+    }
+}
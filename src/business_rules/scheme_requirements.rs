@@ -0,0 +1,241 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Scheme-mandatory fields
+//!
+//! Usage guidelines make some elements mandatory that the base schema leaves
+//! optional, e.g. the creditor agent of a cross-border CBPR+ initiation or the
+//! accounts of a SEPA credit transfer. Requirements are declared per profile
+//! and message type as element paths, and checked against the serialized
+//! document so the same table drives every message type.
+
+use crate::error::ValidationError;
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig, ValidationProfile};
+use crate::validation::helpers::{child_path, index_path, leaf_name, report_error};
+use serde_json::Value;
+
+/// An element that is optional in the base schema but mandatory under a scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemeRequirement {
+    /// Profile that makes the element mandatory
+    pub profile: ValidationProfile,
+    /// Message type the requirement applies to (e.g. "pacs.008")
+    pub message_type: &'static str,
+    /// Dotted element path below the document root
+    /// Repeating elements along the path are checked per occurrence.
+    pub path: &'static str,
+}
+
+const fn requirement(
+    profile: ValidationProfile,
+    message_type: &'static str,
+    path: &'static str,
+) -> SchemeRequirement {
+    SchemeRequirement {
+        profile,
+        message_type,
+        path,
+    }
+}
+
+/// Built-in conditionally mandatory elements of the supported scheme profiles
+pub const SCHEME_REQUIREMENTS: &[SchemeRequirement] = &[
+    requirement(
+        ValidationProfile::CbprPlus,
+        "pain.001",
+        "PmtInf.CdtTrfTxInf.CdtrAgt",
+    ),
+    requirement(ValidationProfile::Sepa, "pacs.008", "CdtTrfTxInf.DbtrAcct"),
+    requirement(ValidationProfile::Sepa, "pacs.008", "CdtTrfTxInf.CdtrAcct"),
+    requirement(
+        ValidationProfile::Sepa,
+        "pain.001",
+        "PmtInf.CdtTrfTxInf.CdtrAcct",
+    ),
+    requirement(ValidationProfile::Sepa, "pacs.003", "DrctDbtTxInf.CdtrAcct"),
+    requirement(
+        ValidationProfile::Sepa,
+        "pacs.003",
+        "DrctDbtTxInf.DrctDbtTx.CdtrSchmeId",
+    ),
+    requirement(
+        ValidationProfile::Sepa,
+        "pain.008",
+        "PmtInf.DrctDbtTxInf.DrctDbtTx.CdtrSchmeId",
+    ),
+];
+
+/// Validate the built-in scheme-mandatory elements for the configured profile
+pub fn validate_required_for_scheme(
+    document: &Document,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    validate_required_fields(SCHEME_REQUIREMENTS, document, path, config, collector);
+}
+
+/// Validate a custom table of scheme-mandatory elements for the configured profile
+pub fn validate_required_fields(
+    requirements: &[SchemeRequirement],
+    document: &Document,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let message_type = document.message_type();
+    let applicable: Vec<_> = requirements
+        .iter()
+        .filter(|r| r.profile == config.profile.usage_guideline() && r.message_type == message_type)
+        .collect();
+    if applicable.is_empty() {
+        return;
+    }
+    // The document serializes wrapped in its root element
+    let Ok(Value::Object(root)) = serde_json::to_value(document) else {
+        return;
+    };
+    let Some(content) = root.values().next() else {
+        return;
+    };
+
+    for requirement in applicable {
+        let segments: Vec<&str> = requirement.path.split('.').collect();
+        let mut missing = Vec::new();
+        find_missing(content, &segments, path.to_string(), &mut missing);
+        for missing_path in missing {
+            let field = leaf_name(&missing_path).to_string();
+            report_error(
+                ValidationError::new(
+                    2008,
+                    format!(
+                        "{field} is required under the {:?} profile for {message_type}",
+                        config.profile
+                    ),
+                )
+                .with_field(field)
+                .with_path(missing_path),
+                config,
+                collector,
+            );
+        }
+    }
+}
+
+/// Collect the paths at which the remaining segments cannot be resolved
+/// When an intermediate element is absent the full required path is reported.
+fn find_missing(value: &Value, segments: &[&str], path: String, missing: &mut Vec<String>) {
+    let Some((first, rest)) = segments.split_first() else {
+        return;
+    };
+    match value {
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                find_missing(item, segments, index_path(&path, i), missing);
+            }
+        }
+        Value::Object(map) => {
+            let child = child_path(&path, first);
+            match map.get(*first) {
+                Some(next) if !next.is_null() => find_missing(next, rest, child, missing),
+                _ => missing.push(
+                    rest.iter()
+                        .fold(child, |acc, segment| child_path(&acc, segment)),
+                ),
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::{CashAccount381, FIToFICustomerCreditTransferV08};
+    use crate::document::pain_008_001_08::{
+        CustomerDirectDebitInitiationV08, DirectDebitTransaction101,
+        DirectDebitTransactionInformation231, PartyIdentification1353,
+    };
+
+    fn check(document: &Document, profile: ValidationProfile) -> Vec<ValidationError> {
+        let config = ParserConfig::default().with_profile(profile);
+        let mut collector = ErrorCollector::new();
+        validate_required_for_scheme(document, "", &config, &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_scheme_requires_optional_fields() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.dbtr_acct = Some(CashAccount381::default());
+        let document = Document::Pacs008(Box::new(doc));
+
+        assert!(check(&document, ValidationProfile::Base).is_empty());
+        assert!(check(&document, ValidationProfile::CbprPlus).is_empty());
+
+        let errors = check(&document, ValidationProfile::Sepa);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2008);
+        assert_eq!(errors[0].field.as_deref(), Some("CdtrAcct"));
+        assert_eq!(errors[0].path.as_deref(), Some("CdtTrfTxInf.CdtrAcct"));
+    }
+
+    #[test]
+    fn test_scheme_requirement_per_occurrence() {
+        let with_scheme_id = DirectDebitTransactionInformation231 {
+            drct_dbt_tx: Some(DirectDebitTransaction101 {
+                cdtr_schme_id: Some(PartyIdentification1353::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let mut doc = CustomerDirectDebitInitiationV08::default();
+        doc.pmt_inf.drct_dbt_tx_inf = vec![
+            with_scheme_id,
+            DirectDebitTransactionInformation231::default(),
+        ];
+        let document = Document::Pain008(Box::new(doc));
+
+        let paths: Vec<_> = check(&document, ValidationProfile::Sepa)
+            .into_iter()
+            .filter_map(|e| e.path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec!["PmtInf.DrctDbtTxInf[1].DrctDbtTx.CdtrSchmeId".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_custom_requirements() {
+        let requirements = [requirement(
+            ValidationProfile::Target2,
+            "pacs.008",
+            "CdtTrfTxInf.Purp",
+        )];
+        let document = Document::Pacs008(Box::default());
+        let config = ParserConfig::default().with_profile(ValidationProfile::Target2);
+        let mut collector = ErrorCollector::new();
+        validate_required_fields(&requirements, &document, "", &config, &mut collector);
+        let errors = collector.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].field.as_deref(), Some("Purp"));
+    }
+}
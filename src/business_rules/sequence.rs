@@ -0,0 +1,203 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Direct debit sequence rules (SeqTp)
+//!
+//! Collections under a recurrent mandate run FRST, then RCUR any number of
+//! times, and end with FNAL; a one-off mandate is used by a single OOFF
+//! collection. Once a mandate has seen FNAL or OOFF it cannot be collected
+//! again. A re-presentment (RPRE) re-sends an earlier collection and may follow
+//! any of them. Whether a sequence type is plausible depends on the previous
+//! collection under the same mandate, so the check only runs when the caller
+//! supplies that prior state.
+
+use crate::document::{pacs_003_001_08, pacs_004_001_09, pain_008_001_08};
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+
+/// Direct debit sequence type, independent of the message the code appears in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SequenceType {
+    /// First collection of a series (FRST)
+    First,
+    /// Recurring collection (RCUR)
+    Recurring,
+    /// Final collection of a series (FNAL)
+    Final,
+    /// One-off collection (OOFF)
+    OneOff,
+    /// Re-presentment of a reversed or returned collection (RPRE)
+    Represented,
+}
+
+impl SequenceType {
+    /// ISO 20022 code of the sequence type
+    pub fn code(&self) -> &'static str {
+        match self {
+            SequenceType::First => "FRST",
+            SequenceType::Recurring => "RCUR",
+            SequenceType::Final => "FNAL",
+            SequenceType::OneOff => "OOFF",
+            SequenceType::Represented => "RPRE",
+        }
+    }
+}
+
+macro_rules! impl_from_sequence_type_code {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl From<&$ty> for SequenceType {
+                fn from(code: &$ty) -> Self {
+                    use $ty as Code;
+                    match code {
+                        Code::CodeFRST => SequenceType::First,
+                        Code::CodeRCUR => SequenceType::Recurring,
+                        Code::CodeFNAL => SequenceType::Final,
+                        Code::CodeOOFF => SequenceType::OneOff,
+                        Code::CodeRPRE => SequenceType::Represented,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_sequence_type_code!(
+    pacs_003_001_08::SequenceType3Code,
+    pacs_004_001_09::SequenceType3Code,
+    pain_008_001_08::SequenceType3Code,
+);
+
+/// Whether `next` may follow `prev` under the same mandate
+pub fn valid_next_sequence(prev: SequenceType, next: SequenceType) -> bool {
+    use SequenceType::*;
+    matches!(
+        (prev, next),
+        (_, Represented) | (First | Recurring | Represented, Recurring | Final)
+    )
+}
+
+/// Validate `SeqTp` against the sequence type of the previous collection
+pub fn validate_sequence_transition(
+    prev: SequenceType,
+    next: Option<SequenceType>,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let Some(next) = next else {
+        return;
+    };
+    if !valid_next_sequence(prev, next) {
+        report_error(
+            ValidationError::new(
+                2009,
+                format!(
+                    "Sequence type {} cannot follow {} under the same mandate",
+                    next.code(),
+                    prev.code()
+                ),
+            )
+            .with_field("SeqTp".to_string())
+            .with_path(child_path(path, "SeqTp")),
+            config,
+            collector,
+        );
+    }
+}
+
+impl pacs_003_001_08::FIToFICustomerDirectDebitV08 {
+    /// Check the collection's `SeqTp` against the previous collection under its mandate
+    pub fn validate_sequence_after(
+        &self,
+        prev: SequenceType,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        let tx = &self.drct_dbt_tx_inf;
+        validate_sequence_transition(
+            prev,
+            tx.pmt_tp_inf
+                .as_ref()
+                .and_then(|info| info.seq_tp.as_ref())
+                .map(SequenceType::from),
+            &child_path(&child_path(path, "DrctDbtTxInf"), "PmtTpInf"),
+            config,
+            collector,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use SequenceType::*;
+
+    const ALL: [SequenceType; 5] = [First, Recurring, Final, OneOff, Represented];
+
+    #[test]
+    fn test_transition_matrix() {
+        let allowed = [
+            (First, Recurring),
+            (First, Final),
+            (Recurring, Recurring),
+            (Recurring, Final),
+            (Represented, Recurring),
+            (Represented, Final),
+        ];
+        for prev in ALL {
+            for next in ALL {
+                let expected = next == Represented || allowed.contains(&(prev, next));
+                assert_eq!(
+                    valid_next_sequence(prev, next),
+                    expected,
+                    "{} -> {}",
+                    prev.code(),
+                    next.code()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_sequence_after() {
+        let mut doc = pacs_003_001_08::FIToFICustomerDirectDebitV08::default();
+        doc.drct_dbt_tx_inf.pmt_tp_inf = Some(pacs_003_001_08::PaymentTypeInformation271 {
+            seq_tp: Some(pacs_003_001_08::SequenceType3Code::CodeFNAL),
+            ..Default::default()
+        });
+        let config = ParserConfig::default();
+
+        let mut collector = ErrorCollector::new();
+        doc.validate_sequence_after(Recurring, "", &config, &mut collector);
+        assert!(!collector.has_errors());
+
+        let mut collector = ErrorCollector::new();
+        doc.validate_sequence_after(Final, "", &config, &mut collector);
+        let errors = collector.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2009);
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("DrctDbtTxInf.PmtTpInf.SeqTp")
+        );
+    }
+}
@@ -0,0 +1,103 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Serialization checks (finite amounts and rates)
+//!
+//! A decimal element can only be written in plain notation if the number is
+//! finite: `NaN` and infinities have no ISO 20022 form, and JSON has no
+//! literal for them at all. serde_json turns such a float into `null` when
+//! building a value, and since every optional element is skipped when absent,
+//! a `null` in the document's JSON form always marks one of them.
+
+use crate::error::ValidationError;
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{leaf_name, report_error};
+use crate::visitor;
+use serde_json::Value;
+
+/// Validate that every decimal of the document can be written as a plain number
+pub fn validate_finite_numbers(
+    document: &Document,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    // The document serializes wrapped in its root element
+    let Ok(Value::Object(root)) = serde_json::to_value(document) else {
+        return;
+    };
+    let Some(content) = root.values().next() else {
+        return;
+    };
+    visitor::visit(content, path, &mut |node_path, node| {
+        if !node.is_null() {
+            return;
+        }
+        // Amounts hold their number in the element's text content
+        let element_path = node_path.strip_suffix(".$value").unwrap_or(node_path);
+        let element = leaf_name(element_path);
+        report_error(
+            ValidationError::new(
+                2044,
+                format!("{element} is not a finite number and cannot be serialized"),
+            )
+            .with_field(element.to_string())
+            .with_path(element_path.to_string()),
+            config,
+            collector,
+        );
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::*;
+
+    fn check(amount: f64) -> Vec<ValidationError> {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt.value = amount;
+        let mut collector = ErrorCollector::new();
+        validate_finite_numbers(
+            &Document::Pacs008(Box::new(doc)),
+            "",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    #[test]
+    fn test_finite_amount() {
+        assert!(check(1_000_000.25).is_empty());
+    }
+
+    #[test]
+    fn test_non_finite_amount() {
+        for amount in [f64::NAN, f64::INFINITY] {
+            let errors = check(amount);
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].code, 2044);
+            assert_eq!(
+                errors[0].path.as_deref(),
+                Some("CdtTrfTxInf.IntrBkSttlmAmt")
+            );
+        }
+    }
+}
@@ -0,0 +1,247 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Group header settlement total (pacs.008, pacs.009)
+//!
+//! A group header may declare the total interbank settlement amount of the
+//! message (`GrpHdr/TtlIntrBkSttlmAmt`), which must equal the sum of the
+//! transactions' `IntrBkSttlmAmt` in the same currency. The usage guidelines
+//! the message types are generated from carry one transaction and drop the
+//! total, so the check runs on the raw XML as received: receivers of messages
+//! built against the base schema verify the declared total before settling.
+//! `MxMessage::from_xml_validated` runs it with the other business rules; it
+//! can also be called on its own as a pre-check of raw XML.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+use quick_xml::Reader;
+use quick_xml::events::{BytesStart, Event};
+use std::collections::HashMap;
+
+/// Document root elements of the message types carrying `CdtTrfTxInf`
+/// transactions under a group header total: pacs.008 and pacs.009
+const CREDIT_TRANSFER_ROOTS: &[&str] = &["FIToFICstmrCdtTrf", "FICdtTrf"];
+
+/// Largest difference between the declared total and the recomputed sum
+/// treated as rounding noise
+const TOTAL_TOLERANCE: f64 = 1e-6;
+
+/// Validate the declared `TtlIntrBkSttlmAmt` against the transactions of a message
+///
+/// Transactions in another currency than the total make the total
+/// inconsistent and are reported as a mismatch too, and so is an amount that
+/// is not a decimal number, as the sum cannot be recomputed. Nothing is
+/// checked for other message types than pacs.008 and pacs.009, nor when the
+/// group header declares no total. Malformed XML is left for the parser to
+/// report. The path of the error starts below the document root.
+pub fn validate_settlement_total(xml: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+    let Some(scan) = scan_amounts(xml) else {
+        return;
+    };
+    let declares_total = scan.declared.is_some()
+        || scan
+            .unparsable
+            .iter()
+            .any(|(element, _)| element == "TtlIntrBkSttlmAmt");
+    if !declares_total {
+        return;
+    }
+    let message = if let Some((element, text)) = scan.unparsable.first() {
+        format!("TtlIntrBkSttlmAmt cannot be checked: {element} '{text}' is not a decimal amount")
+    } else if let Some((ccy, total)) = &scan.declared {
+        let sum = scan.computed.get(ccy).copied().unwrap_or_default();
+        let mut other: Vec<_> = scan
+            .computed
+            .keys()
+            .filter(|key| *key != ccy)
+            .cloned()
+            .collect();
+        other.sort();
+        if !other.is_empty() {
+            format!(
+                "TtlIntrBkSttlmAmt is in {ccy} but transactions also settle in {}",
+                other.join(", ")
+            )
+        } else if (sum - total).abs() > TOTAL_TOLERANCE {
+            format!("TtlIntrBkSttlmAmt {total} {ccy} differs from the transactions' sum {sum}")
+        } else {
+            return;
+        }
+    } else {
+        return;
+    };
+    report_error(
+        ValidationError::new(2029, message)
+            .with_field("TtlIntrBkSttlmAmt".to_string())
+            .with_path(child_path("GrpHdr", "TtlIntrBkSttlmAmt")),
+        config,
+        collector,
+    );
+}
+
+/// Amounts found on a scan of the raw XML
+#[derive(Default)]
+struct AmountScan {
+    /// Currency and value of the declared total
+    declared: Option<(String, f64)>,
+    /// Sum of the transaction amounts per currency
+    computed: HashMap<String, f64>,
+    /// Element name and text of every amount that is not a decimal number
+    unparsable: Vec<(String, String)>,
+}
+
+/// Declared total and per-currency sum of the transaction amounts, or `None`
+/// when the message is not a credit transfer
+fn scan_amounts(xml: &str) -> Option<AmountScan> {
+    let mut reader = Reader::from_str(xml);
+    let mut scan = AmountScan::default();
+    let mut open: Vec<String> = Vec::new();
+    loop {
+        let element = match reader.read_event().ok()? {
+            Event::Start(element) => element,
+            Event::End(_) => {
+                open.pop();
+                continue;
+            }
+            Event::Eof => return Some(scan),
+            _ => continue,
+        };
+        let name = local_name(&element);
+        let parent = open.last().map(String::as_str);
+        if parent == Some("Document") && !CREDIT_TRANSFER_ROOTS.contains(&name.as_str()) {
+            return None;
+        }
+        let target = match (parent, name.as_str()) {
+            (Some("GrpHdr"), "TtlIntrBkSttlmAmt") => Some(true),
+            (Some("CdtTrfTxInf"), "IntrBkSttlmAmt") => Some(false),
+            _ => None,
+        };
+        let Some(is_total) = target else {
+            open.push(name);
+            continue;
+        };
+        let ccy = currency(&element).unwrap_or_default();
+        let text = reader.read_text(element.name()).ok()?;
+        let Ok(value) = text.trim().parse::<f64>() else {
+            scan.unparsable.push((name, text.trim().to_string()));
+            continue;
+        };
+        if is_total {
+            scan.declared = Some((ccy, value));
+        } else {
+            *scan.computed.entry(ccy).or_insert(0.0) += value;
+        }
+    }
+}
+
+fn currency(element: &BytesStart) -> Option<String> {
+    element
+        .try_get_attribute("Ccy")
+        .ok()
+        .flatten()
+        .map(|attr| String::from_utf8_lossy(&attr.value).into_owned())
+}
+
+fn local_name(element: &BytesStart) -> String {
+    String::from_utf8_lossy(element.local_name().as_ref()).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transfer_xml(total: &str, amounts: &[(&str, &str)]) -> String {
+        let transactions: String = amounts
+            .iter()
+            .map(|(ccy, value)| {
+                format!(
+                    r#"<CdtTrfTxInf><IntrBkSttlmAmt Ccy="{ccy}">{value}</IntrBkSttlmAmt></CdtTrfTxInf>"#
+                )
+            })
+            .collect();
+        format!(
+            "<Document><FICdtTrf><GrpHdr><MsgId>MSG-1</MsgId><NbOfTxs>{}</NbOfTxs>{total}</GrpHdr>{transactions}</FICdtTrf></Document>",
+            amounts.len()
+        )
+    }
+
+    fn check(xml: &str) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_settlement_total(xml, &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_matching_settlement_total() {
+        let xml = transfer_xml(
+            r#"<TtlIntrBkSttlmAmt Ccy="EUR">1500.75</TtlIntrBkSttlmAmt>"#,
+            &[("EUR", "1000.50"), ("EUR", "500.25")],
+        );
+        assert!(check(&xml).is_empty());
+
+        // No declared total, nothing to compare
+        assert!(check(&transfer_xml("", &[("EUR", "1.00")])).is_empty());
+    }
+
+    #[test]
+    fn test_mismatched_settlement_total() {
+        let xml = transfer_xml(
+            r#"<TtlIntrBkSttlmAmt Ccy="EUR">1600.00</TtlIntrBkSttlmAmt>"#,
+            &[("EUR", "1000.50"), ("EUR", "500.25")],
+        );
+        let errors = check(&xml);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2029);
+        assert_eq!(errors[0].path.as_deref(), Some("GrpHdr.TtlIntrBkSttlmAmt"));
+
+        let xml = transfer_xml(
+            r#"<TtlIntrBkSttlmAmt Ccy="EUR">1000.50</TtlIntrBkSttlmAmt>"#,
+            &[("EUR", "1000.50"), ("USD", "500.25")],
+        );
+        let errors = check(&xml);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].message.contains("USD"));
+    }
+
+    #[test]
+    fn test_other_message_types_skipped() {
+        // pacs.003 declares the total over DrctDbtTxInf transactions
+        let xml = r#"<Document><FIToFICstmrDrctDbt><GrpHdr><MsgId>MSG-1</MsgId><TtlIntrBkSttlmAmt Ccy="EUR">100.00</TtlIntrBkSttlmAmt></GrpHdr><DrctDbtTxInf><IntrBkSttlmAmt Ccy="EUR">100.00</IntrBkSttlmAmt></DrctDbtTxInf></FIToFICstmrDrctDbt></Document>"#;
+        assert!(check(xml).is_empty());
+    }
+
+    #[test]
+    fn test_unparsable_amount() {
+        let xml = transfer_xml(
+            r#"<TtlIntrBkSttlmAmt Ccy="EUR">1500.75</TtlIntrBkSttlmAmt>"#,
+            &[("EUR", "1000.50"), ("EUR", "500,25")],
+        );
+        let errors = check(&xml);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2029);
+        assert!(errors[0].message.contains("'500,25'"));
+
+        let xml = transfer_xml(
+            r#"<TtlIntrBkSttlmAmt Ccy="EUR">n/a</TtlIntrBkSttlmAmt>"#,
+            &[("EUR", "1000.50")],
+        );
+        assert_eq!(check(&xml).len(), 1);
+    }
+}
@@ -0,0 +1,172 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+//! Transaction summary rules (TxsSummry)
+//!
+//! A statement, report or notification may summarise its entries in
+//! `TxsSummry/TtlNtries`, whose `TtlNetNtry` gives the net of all entries as
+//! an amount and a direction (`CRDT` when credits exceed debits). The net is
+//! recomputed from the `Ntry` elements as credits minus debits, and both the
+//! amount and the direction must agree. A zero net matches either direction.
+//! The check is skipped when the account carries no entries or the entries
+//! are in different currencies.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::{child_path, report_error};
+use crate::visitor;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Tolerance when comparing net amounts, which are carried as f64
+const NET_TOLERANCE: f64 = 1e-6;
+
+/// Validate the declared total net entry of every account against its entries
+pub fn validate_net_entries<T: Serialize>(
+    document: &T,
+    path: &str,
+    config: &ParserConfig,
+    collector: &mut ErrorCollector,
+) {
+    let Ok(value) = serde_json::to_value(document) else {
+        return;
+    };
+    visitor::visit(&value, path, &mut |node_path, node| {
+        let Some(net) = node.pointer("/TxsSummry/TtlNtries/TtlNetNtry") else {
+            return;
+        };
+        let (Some(declared), Some(direction)) = (
+            net.get("Amt").and_then(Value::as_f64),
+            net.get("CdtDbtInd").and_then(Value::as_str),
+        ) else {
+            return;
+        };
+        let Some((ccy, computed)) = net_of_entries(node) else {
+            return;
+        };
+        let computed_direction = if computed < 0.0 { "DBIT" } else { "CRDT" };
+        let amount_matches = (computed.abs() - declared).abs() <= NET_TOLERANCE;
+        let direction_matches = computed.abs() <= NET_TOLERANCE || direction == computed_direction;
+        if amount_matches && direction_matches {
+            return;
+        }
+        let net_path = child_path(
+            &child_path(&child_path(node_path, "TxsSummry"), "TtlNtries"),
+            "TtlNetNtry",
+        );
+        report_error(
+            ValidationError::new(
+                2037,
+                format!(
+                    "TtlNetNtry {declared} {direction} does not match the net of the entries {} {ccy} {computed_direction}",
+                    computed.abs()
+                ),
+            )
+            .with_field("TtlNetNtry".to_string())
+            .with_path(net_path),
+            config,
+            collector,
+        );
+    });
+}
+
+/// Currency and signed net (credits minus debits) of the serialized entries of an account
+fn net_of_entries(account: &Value) -> Option<(&str, f64)> {
+    let entries = account.get("Ntry")?.as_array().filter(|e| !e.is_empty())?;
+    let mut ccy = None;
+    let mut net = 0.0;
+    for entry in entries {
+        let amt = entry.get("Amt")?;
+        let entry_ccy = amt.get("@Ccy")?.as_str()?;
+        if *ccy.get_or_insert(entry_ccy) != entry_ccy {
+            return None;
+        }
+        let value = amt.get("$value")?.as_f64()?;
+        net += match entry.get("CdtDbtInd")?.as_str()? {
+            "DBIT" => -value,
+            _ => value,
+        };
+    }
+    Some((ccy?, net))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_053_001_08::*;
+
+    fn entry(value: f64, cdt_dbt_ind: CreditDebitCode) -> Box<ReportEntry101> {
+        Box::new(ReportEntry101 {
+            amt: ActiveOrHistoricCurrencyAndAmount {
+                ccy: "EUR".into(),
+                value,
+            },
+            cdt_dbt_ind,
+            ..Default::default()
+        })
+    }
+
+    fn statement(net: f64, direction: CreditDebitCode) -> BankToCustomerStatementV08 {
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.stmt.ntry = Some(vec![
+            entry(200.0, CreditDebitCode::CodeCRDT),
+            entry(49.5, CreditDebitCode::CodeDBIT),
+        ]);
+        doc.stmt.txs_summry = Some(TotalTransactions61 {
+            ttl_ntries: Some(NumberAndSumOfTransactions4 {
+                ttl_net_ntry: Some(AmountAndDirection35 {
+                    amt: net,
+                    cdt_dbt_ind: direction,
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        doc
+    }
+
+    fn check(doc: &BankToCustomerStatementV08) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        validate_net_entries(doc, "", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_matching_net_entry() {
+        assert!(check(&statement(150.5, CreditDebitCode::CodeCRDT)).is_empty());
+    }
+
+    #[test]
+    fn test_net_entry_amount_mismatch() {
+        let errors = check(&statement(200.0, CreditDebitCode::CodeCRDT));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2037);
+        assert!(errors[0].message.contains("150.5 EUR CRDT"));
+        assert_eq!(
+            errors[0].path.as_deref(),
+            Some("Stmt.TxsSummry.TtlNtries.TtlNetNtry")
+        );
+    }
+
+    #[test]
+    fn test_net_entry_direction_mismatch() {
+        let errors = check(&statement(150.5, CreditDebitCode::CodeDBIT));
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 2037);
+    }
+}
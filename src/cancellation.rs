@@ -0,0 +1,212 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Payment Cancellation
+//!
+//! Builds the camt.056 cancellation request that recalls a payment we sent.
+//! The underlying transaction is referenced by the original group header and
+//! payment identification, and the request travels along the original
+//! instructing agent (assigner, case creator) to the original instructed
+//! agent (assignee).
+
+use crate::document::camt_056_001_08::{
+    BranchAndFinancialInstitutionIdentification61, BranchAndFinancialInstitutionIdentification62,
+    CBPRAmount, CBPRCancellationReasonCode, CancellationReason33Choice1, Case51, CaseAssignment51,
+    ClearingSystemIdentification2Choice1, ClearingSystemMemberIdentification21,
+    FIToFIPaymentCancellationRequestV08, FinancialInstitutionIdentification181,
+    FinancialInstitutionIdentification182, OriginalGroupInformation291, Party40Choice1,
+    Party40Choice2, PaymentCancellationReason51, PaymentTransaction1061, UnderlyingTransaction231,
+};
+use crate::document::pacs_008_001_08::{self, FIToFICustomerCreditTransferV08};
+use crate::ids::generate_msg_id;
+use chrono::{SecondsFormat, Utc};
+
+/// Message definition identifier of the original message (OrgnlMsgNmId)
+const PACS_008_MSG_NM_ID: &str = "pacs.008.001.08";
+
+/// Prefix of the generated assignment identifier
+const ASSIGNMENT_ID_PREFIX: &str = "CXL";
+
+/// Maximum length of the case identifier (Case/Id is Max16Text in CBPR+)
+const CASE_ID_LENGTH: usize = 16;
+
+/// Build a camt.056 cancellation request for a pacs.008 we sent
+/// The case identifier is the tail of the generated assignment identifier
+/// (its timestamp and sequence number), and the creation date time is the
+/// current UTC time.
+pub fn camt056_from_pacs008(
+    original: &FIToFICustomerCreditTransferV08,
+    reason: CBPRCancellationReasonCode,
+) -> FIToFIPaymentCancellationRequestV08 {
+    let grp_hdr = &original.grp_hdr;
+    let tx = &original.cdt_trf_tx_inf;
+    let id = generate_msg_id(ASSIGNMENT_ID_PREFIX);
+    let case_id = id[id.len().saturating_sub(CASE_ID_LENGTH)..].to_string();
+
+    FIToFIPaymentCancellationRequestV08 {
+        assgnmt: CaseAssignment51 {
+            id,
+            assgnr: assignment_party(&tx.instg_agt),
+            assgne: assignment_party(&tx.instd_agt),
+            cre_dt_tm: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, false),
+        },
+        undrlyg: UnderlyingTransaction231 {
+            tx_inf: PaymentTransaction1061 {
+                cxl_id: None,
+                case: Case51 {
+                    id: case_id,
+                    cretr: Party40Choice2 {
+                        pty: None,
+                        agt: Some(case_creator(&tx.instg_agt)),
+                    },
+                },
+                orgnl_grp_inf: OriginalGroupInformation291 {
+                    orgnl_msg_id: grp_hdr.msg_id.clone(),
+                    orgnl_msg_nm_id: PACS_008_MSG_NM_ID.to_string(),
+                    orgnl_cre_dt_tm: Some(grp_hdr.cre_dt_tm.clone()),
+                },
+                orgnl_instr_id: Some(tx.pmt_id.instr_id.clone()),
+                orgnl_end_to_end_id: tx.pmt_id.end_to_end_id.clone(),
+                orgnl_tx_id: tx.pmt_id.tx_id.clone(),
+                orgnl_uetr: tx.pmt_id.uetr.clone(),
+                orgnl_clr_sys_ref: tx.pmt_id.clr_sys_ref.clone(),
+                orgnl_intr_bk_sttlm_amt: CBPRAmount {
+                    ccy: tx.intr_bk_sttlm_amt.ccy.clone(),
+                    value: tx.intr_bk_sttlm_amt.value,
+                },
+                orgnl_intr_bk_sttlm_dt: tx.intr_bk_sttlm_dt.clone(),
+                cxl_rsn_inf: PaymentCancellationReason51 {
+                    orgtr: None,
+                    rsn: CancellationReason33Choice1 { cd: Some(reason) },
+                    addtl_inf: None,
+                },
+            },
+        },
+    }
+}
+
+fn assignment_party(
+    agent: &pacs_008_001_08::BranchAndFinancialInstitutionIdentification62,
+) -> Party40Choice1 {
+    let fin_instn_id = &agent.fin_instn_id;
+    Party40Choice1 {
+        agt: Some(BranchAndFinancialInstitutionIdentification61 {
+            fin_instn_id: FinancialInstitutionIdentification181 {
+                bicfi: fin_instn_id.bicfi.clone(),
+                clr_sys_mmb_id: fin_instn_id.clr_sys_mmb_id.as_ref().map(clearing_member),
+                lei: fin_instn_id.lei.clone(),
+            },
+        }),
+    }
+}
+
+fn case_creator(
+    agent: &pacs_008_001_08::BranchAndFinancialInstitutionIdentification62,
+) -> BranchAndFinancialInstitutionIdentification62 {
+    let fin_instn_id = &agent.fin_instn_id;
+    BranchAndFinancialInstitutionIdentification62 {
+        fin_instn_id: FinancialInstitutionIdentification182 {
+            bicfi: Some(fin_instn_id.bicfi.clone()),
+            clr_sys_mmb_id: fin_instn_id.clr_sys_mmb_id.as_ref().map(clearing_member),
+            lei: fin_instn_id.lei.clone(),
+            nm: None,
+            pstl_adr: None,
+        },
+    }
+}
+
+fn clearing_member(
+    member: &pacs_008_001_08::ClearingSystemMemberIdentification21,
+) -> ClearingSystemMemberIdentification21 {
+    ClearingSystemMemberIdentification21 {
+        clr_sys_id: ClearingSystemIdentification2Choice1 {
+            cd: member.clr_sys_id.cd.clone(),
+        },
+        mmb_id: member.mmb_id.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_result::{ErrorCollector, ParserConfig};
+    use crate::validation::Validate;
+
+    fn original() -> FIToFICustomerCreditTransferV08 {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = "MSG-20240115-001".to_string();
+        doc.grp_hdr.cre_dt_tm = "2024-01-15T10:30:00+00:00".to_string();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.pmt_id.instr_id = "INSTR-001".to_string();
+        tx.pmt_id.end_to_end_id = "E2E-001".to_string();
+        tx.pmt_id.tx_id = Some("TX-001".to_string());
+        tx.pmt_id.uetr = "8a562c67-ca16-48ba-b074-65581be6f011".to_string();
+        tx.intr_bk_sttlm_amt.ccy = "EUR".into();
+        tx.intr_bk_sttlm_amt.value = 1500.25;
+        tx.intr_bk_sttlm_dt = "2024-01-15".to_string();
+        tx.instg_agt.fin_instn_id.bicfi = "DEUTDEFFXXX".into();
+        tx.instd_agt.fin_instn_id.bicfi = "BNPAFRPPXXX".into();
+        doc
+    }
+
+    #[test]
+    fn test_original_references_carry_through() {
+        let request = camt056_from_pacs008(&original(), CBPRCancellationReasonCode::CodeDUPL);
+        let tx_inf = &request.undrlyg.tx_inf;
+
+        assert_eq!(tx_inf.orgnl_grp_inf.orgnl_msg_id, "MSG-20240115-001");
+        assert_eq!(tx_inf.orgnl_grp_inf.orgnl_msg_nm_id, "pacs.008.001.08");
+        assert_eq!(tx_inf.orgnl_instr_id.as_deref(), Some("INSTR-001"));
+        assert_eq!(tx_inf.orgnl_end_to_end_id, "E2E-001");
+        assert_eq!(tx_inf.orgnl_tx_id.as_deref(), Some("TX-001"));
+        assert_eq!(tx_inf.orgnl_uetr, "8a562c67-ca16-48ba-b074-65581be6f011");
+        assert_eq!(tx_inf.orgnl_intr_bk_sttlm_amt.ccy.as_str(), "EUR");
+        assert_eq!(tx_inf.orgnl_intr_bk_sttlm_amt.value, 1500.25);
+        assert_eq!(tx_inf.orgnl_intr_bk_sttlm_dt, "2024-01-15");
+        assert_eq!(
+            tx_inf.cxl_rsn_inf.rsn.cd,
+            Some(CBPRCancellationReasonCode::CodeDUPL)
+        );
+
+        let agent_bic = |party: &Party40Choice1| {
+            party
+                .agt
+                .as_ref()
+                .map(|agt| agt.fin_instn_id.bicfi.as_str().to_string())
+        };
+        assert_eq!(
+            agent_bic(&request.assgnmt.assgnr).as_deref(),
+            Some("DEUTDEFFXXX")
+        );
+        assert_eq!(
+            agent_bic(&request.assgnmt.assgne).as_deref(),
+            Some("BNPAFRPPXXX")
+        );
+        assert!(request.assgnmt.id.ends_with(&tx_inf.case.id));
+    }
+
+    #[test]
+    fn test_cancellation_request_validates() {
+        let request = camt056_from_pacs008(&original(), CBPRCancellationReasonCode::CodeFRAD);
+        let mut collector = ErrorCollector::new();
+        request.validate("", &ParserConfig::default(), &mut collector);
+        let errors = collector.errors();
+        assert!(errors.is_empty(), "{errors:?}");
+    }
+}
@@ -0,0 +1,112 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Content Hash
+//!
+//! SHA-256 fingerprint of a document for idempotency keys and duplicate
+//! suppression. The hash is taken over the canonical JSON form of the
+//! document (object keys sorted, no whitespace) with the volatile message
+//! identification removed: the message id and creation date time of the
+//! group header, message header or case assignment. A resend of the same
+//! business content under a new MsgId therefore keeps its hash, while any
+//! change to the payment content (amounts, parties, EndToEndId, UETR) gives
+//! a new one.
+
+use crate::mx_envelope::{Document, MxMessage};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+/// Element paths below the document root that change on every send
+pub const VOLATILE_FIELDS: &[&str] = &[
+    "GrpHdr.MsgId",
+    "GrpHdr.CreDtTm",
+    "MsgHdr.MsgId",
+    "MsgHdr.CreDtTm",
+    "Assgnmt.Id",
+    "Assgnmt.CreDtTm",
+];
+
+impl Document {
+    /// SHA-256 of the document content, excluding volatile ids and timestamps
+    pub fn content_hash(&self) -> [u8; 32] {
+        let mut value = serde_json::to_value(self).unwrap_or(Value::Null);
+        if let Some(content) = value
+            .as_object_mut()
+            .and_then(|root| root.values_mut().next())
+        {
+            for path in VOLATILE_FIELDS {
+                remove_path(content, path);
+            }
+        }
+        value.sort_all_objects();
+        Sha256::digest(value.to_string().as_bytes()).into()
+    }
+}
+
+impl MxMessage {
+    /// SHA-256 of the document content, excluding volatile ids and timestamps
+    /// The AppHdr only describes the transport of the document and is left out.
+    pub fn content_hash(&self) -> [u8; 32] {
+        self.document.content_hash()
+    }
+}
+
+fn remove_path(value: &mut Value, path: &str) {
+    let mut segments: Vec<&str> = path.split('.').collect();
+    let Some(leaf) = segments.pop() else {
+        return;
+    };
+    if let Some(map) = segments
+        .into_iter()
+        .try_fold(value, |node, key| node.get_mut(key))
+        .and_then(Value::as_object_mut)
+    {
+        map.remove(leaf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+
+    fn transfer(msg_id: &str, cre_dt_tm: &str, amount: f64) -> Document {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = msg_id.to_string();
+        doc.grp_hdr.cre_dt_tm = cre_dt_tm.to_string();
+        doc.cdt_trf_tx_inf.pmt_id.end_to_end_id = "E2E-001".to_string();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt.ccy = "EUR".into();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt.value = amount;
+        Document::Pacs008(Box::new(doc))
+    }
+
+    #[test]
+    fn test_volatile_fields_do_not_change_hash() {
+        let original = transfer("MSG-001", "2024-01-15T10:30:00+00:00", 100.0);
+        let resent = transfer("MSG-002", "2024-01-15T11:45:00+00:00", 100.0);
+        assert_eq!(original.content_hash(), resent.content_hash());
+    }
+
+    #[test]
+    fn test_content_change_changes_hash() {
+        let original = transfer("MSG-001", "2024-01-15T10:30:00+00:00", 100.0);
+        let amended = transfer("MSG-001", "2024-01-15T10:30:00+00:00", 100.01);
+        assert_ne!(original.content_hash(), amended.content_hash());
+    }
+}
@@ -0,0 +1,138 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Core Validation
+//!
+//! The text checks behind the generated validators: length facets, control
+//! characters and pattern facets. They need only `alloc`, so this module builds
+//! without the `std` feature for embedded or WASM gateways. Pattern facets use
+//! `regex` when `std` is enabled and the built-in [`crate::pattern`] engine
+//! without it; the `no_std` feature only marks the slimmed build, so it never
+//! changes the engine of a build that has `std`.
+
+use crate::pattern::PatternError;
+
+/// Length facet a value falls outside of
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LengthViolation {
+    /// Shorter than the given minimum length
+    TooShort(usize),
+    /// Longer than the given maximum length
+    TooLong(usize),
+}
+
+/// Check a value against the min/max length facets, counted in characters
+pub fn check_length(
+    value: &str,
+    min: Option<usize>,
+    max: Option<usize>,
+) -> Option<LengthViolation> {
+    let len = value.chars().count();
+    match (min, max) {
+        (Some(min), _) if len < min => Some(LengthViolation::TooShort(min)),
+        (_, Some(max)) if len > max => Some(LengthViolation::TooLong(max)),
+        _ => None,
+    }
+}
+
+/// Check whether a character is a C0 control character other than the
+/// XML whitespace characters (tab, line feed and carriage return)
+pub fn is_disallowed_control(c: char) -> bool {
+    c < '\u{20}' && !matches!(c, '\t' | '\n' | '\r')
+}
+
+/// First disallowed control character in a value, if any
+pub fn find_disallowed_control(value: &str) -> Option<char> {
+    value.chars().find(|&c| is_disallowed_control(c))
+}
+
+/// Check whether a character belongs to the SWIFT x character set:
+/// letters, digits, space, `/ - ? : ( ) . , ' +` and line breaks
+pub fn is_swift_x_character(c: char) -> bool {
+    c.is_ascii_alphanumeric()
+        || matches!(
+            c,
+            '/' | '-' | '?' | ':' | '(' | ')' | '.' | ',' | '\'' | '+' | ' ' | '\r' | '\n'
+        )
+}
+
+/// First character outside the SWIFT x character set, if any
+pub fn find_non_swift_x_character(value: &str) -> Option<char> {
+    value.chars().find(|&c| !is_swift_x_character(c))
+}
+
+/// Check a value against a pattern facet
+/// Surrounding whitespace is ignored, as it is for the generated validators.
+pub fn matches_pattern(value: &str, pattern: &str) -> Result<bool, PatternError> {
+    #[cfg(feature = "std")]
+    {
+        use alloc::string::ToString;
+
+        let regex = regex::Regex::new(pattern).map_err(|e| PatternError {
+            position: 0,
+            message: e.to_string(),
+        })?;
+        Ok(regex.is_match(value.trim()))
+    }
+    #[cfg(not(feature = "std"))]
+    {
+        Ok(crate::pattern::Pattern::new(pattern)?.is_match(value.trim()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_length() {
+        assert_eq!(
+            check_length("", Some(1), Some(35)),
+            Some(LengthViolation::TooShort(1))
+        );
+        assert_eq!(check_length("ÄÖÜ", Some(1), Some(3)), None);
+        assert_eq!(
+            check_length("ABCD", None, Some(3)),
+            Some(LengthViolation::TooLong(3))
+        );
+    }
+
+    #[test]
+    fn test_find_disallowed_control() {
+        assert_eq!(find_disallowed_control("line\r\n\tbreak"), None);
+        assert_eq!(find_disallowed_control("bell\u{7}"), Some('\u{7}'));
+    }
+
+    #[test]
+    fn test_find_non_swift_x_character() {
+        assert_eq!(
+            find_non_swift_x_character("ACME (UK) LTD/REF-1, 2+3?"),
+            None
+        );
+        assert_eq!(find_non_swift_x_character("info@acme.com"), Some('@'));
+        assert_eq!(find_non_swift_x_character("Müller"), Some('ü'));
+    }
+
+    #[test]
+    fn test_matches_pattern() {
+        assert_eq!(matches_pattern(" DE ", "[A-Z]{2,2}"), Ok(true));
+        assert_eq!(matches_pattern("de", "[A-Z]{2,2}"), Ok(false));
+        assert!(matches_pattern("DE", "[A-Z").is_err());
+    }
+}
@@ -0,0 +1,361 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Dates
+//!
+//! Every date and date time in a document, tagged with its business role.
+//! Dates are found by element name on the visitor walk: elements ending in
+//! `Dt` or `DtTm` that hold an ISO date or date time. Choice elements such as
+//! `BookgDt` and `ValDt` carry the value in a nested `Dt` or `DtTm`, so those
+//! take their role from the enclosing element. Dates with no specific role,
+//! e.g. `BirthDt` or `FrDt`, are reported as [`DateRole::Other`].
+
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+
+use crate::mx_envelope::Document;
+use crate::validation::helpers;
+use crate::visitor;
+
+/// Business meaning of a date in a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DateRole {
+    /// Creation of the message or case assignment (CreDtTm)
+    Creation,
+    /// Booking of a statement or report entry (BookgDt)
+    Booking,
+    /// Value date of an entry or expected value date of a notification (ValDt, XpctdValDt)
+    Value,
+    /// Interbank settlement (IntrBkSttlmDt)
+    Settlement,
+    /// Acceptance of the payment by the debtor agent (AccptncDtTm)
+    Acceptance,
+    /// Signature of a direct debit mandate (DtOfSgntr)
+    MandateSignature,
+    /// Any other date, e.g. a birth date or a reporting period boundary
+    Other,
+}
+
+impl DateRole {
+    /// Role of the date element with the given name
+    pub fn from_element(name: &str) -> Self {
+        match name {
+            "CreDtTm" => DateRole::Creation,
+            "BookgDt" => DateRole::Booking,
+            "ValDt" | "XpctdValDt" => DateRole::Value,
+            "IntrBkSttlmDt" => DateRole::Settlement,
+            "AccptncDtTm" => DateRole::Acceptance,
+            "DtOfSgntr" => DateRole::MandateSignature,
+            _ => DateRole::Other,
+        }
+    }
+}
+
+/// ISO date or date time read from a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParsedDate {
+    /// ISODate, e.g. `2024-01-15`
+    Date(NaiveDate),
+    /// ISODateTime with a UTC offset, e.g. `2024-01-15T10:30:00+01:00`
+    DateTime(DateTime<FixedOffset>),
+    /// ISODateTime without a UTC offset, e.g. `2024-01-15T10:30:00`
+    LocalDateTime(NaiveDateTime),
+}
+
+impl ParsedDate {
+    /// Parse an ISO date or date time, returning None for anything else
+    pub fn parse(value: &str) -> Option<Self> {
+        if let Ok(date_time) = DateTime::parse_from_rfc3339(value) {
+            return Some(ParsedDate::DateTime(date_time));
+        }
+        if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+            return Some(ParsedDate::Date(date));
+        }
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()
+            .map(ParsedDate::LocalDateTime)
+    }
+
+    /// Calendar date, in the local time of the value for date times
+    pub fn date(&self) -> NaiveDate {
+        match self {
+            ParsedDate::Date(date) => *date,
+            ParsedDate::DateTime(date_time) => date_time.date_naive(),
+            ParsedDate::LocalDateTime(date_time) => date_time.date(),
+        }
+    }
+
+    /// Time elapsed from this date to a later one, negative if it lies before
+    /// Two date times with offsets are compared exactly, two date times in
+    /// local time by their wall clock. When either side is a date only, whole
+    /// calendar days are counted.
+    pub fn duration_until(&self, later: &ParsedDate) -> Duration {
+        match (self, later) {
+            (ParsedDate::DateTime(from), ParsedDate::DateTime(to)) => *to - *from,
+            (ParsedDate::Date(_), _) | (_, ParsedDate::Date(_)) => later.date() - self.date(),
+            _ => later.local_date_time() - self.local_date_time(),
+        }
+    }
+
+    /// Wall-clock date time, midnight for a date only
+    fn local_date_time(&self) -> NaiveDateTime {
+        match self {
+            ParsedDate::Date(date) => date.and_time(chrono::NaiveTime::MIN),
+            ParsedDate::DateTime(date_time) => date_time.naive_local(),
+            ParsedDate::LocalDateTime(date_time) => *date_time,
+        }
+    }
+}
+
+/// Implement `processing_latency()` on the related dates of each report message
+macro_rules! impl_processing_latency {
+    ($($ty:path),* $(,)?) => {
+        $(
+            impl $ty {
+                /// Time from acceptance (`AccptncDtTm`) to interbank settlement (`IntrBkSttlmDt`)
+                /// Returns `None` unless both dates are present and ISO dates.
+                /// The settlement date is a date only, so the latency is in whole days.
+                pub fn processing_latency(&self) -> Option<Duration> {
+                    let accepted = ParsedDate::parse(self.accptnc_dt_tm.as_deref()?)?;
+                    let settled = ParsedDate::parse(self.intr_bk_sttlm_dt.as_deref()?)?;
+                    Some(accepted.duration_until(&settled))
+                }
+            }
+        )*
+    };
+}
+
+impl_processing_latency!(
+    crate::document::camt_052_001_08::TransactionDates31,
+    crate::document::camt_053_001_08::TransactionDates31,
+    crate::document::camt_054_001_08::TransactionDates31,
+);
+
+impl crate::document::camt_053_001_08::AccountStatement91 {
+    /// Earliest and latest entry date of the statement, to compare with `FrToDt`
+    /// An entry is dated by its booking date, or its value date if not booked.
+    /// Returns `None` when no entry has an ISO date.
+    pub fn entry_date_span(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.ntry
+            .iter()
+            .flatten()
+            .filter_map(|entry| entry_date(entry))
+            .fold(None, |span, date| match span {
+                None => Some((date, date)),
+                Some((first, last)) => Some((first.min(date), last.max(date))),
+            })
+    }
+}
+
+/// Booking date of a statement entry, falling back to its value date
+pub(crate) fn entry_date(
+    entry: &crate::document::camt_053_001_08::ReportEntry101,
+) -> Option<NaiveDate> {
+    let date_of = |choice: &crate::document::camt_053_001_08::DateAndDateTime2Choice1| {
+        choice
+            .dt
+            .as_deref()
+            .or(choice.dt_tm.as_deref())
+            .and_then(ParsedDate::parse)
+            .map(|parsed| parsed.date())
+    };
+    entry
+        .bookg_dt
+        .as_ref()
+        .and_then(date_of)
+        .or_else(|| date_of(&entry.val_dt))
+}
+
+impl Document {
+    /// Every date in the document with its role and path
+    /// Values that are empty or not ISO dates are skipped. Paths are rooted at
+    /// the message element, e.g. `Stmt.Ntry[0].BookgDt.Dt`
+    pub fn all_dates(&self) -> Vec<(DateRole, String, ParsedDate)> {
+        self.with_json(|value| {
+            let mut dates = Vec::new();
+            visitor::visit(value, "", &mut |path, node| {
+                let leaf = helpers::leaf_name(path);
+                if !(leaf.ends_with("Dt") || leaf.ends_with("DtTm")) {
+                    return;
+                }
+                let Some(parsed) = node.as_str().and_then(ParsedDate::parse) else {
+                    return;
+                };
+                let element = match (leaf, path.rsplit_once('.')) {
+                    ("Dt" | "DtTm", Some((parent, _))) => helpers::leaf_name(parent),
+                    _ => leaf,
+                };
+                dates.push((DateRole::from_element(element), path.to_string(), parsed));
+            });
+            dates
+        })
+        .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_053_001_08::TransactionDates31;
+
+    fn found(document: &Document) -> Vec<(DateRole, String, NaiveDate)> {
+        let mut dates: Vec<_> = document
+            .all_dates()
+            .into_iter()
+            .map(|(role, path, parsed)| (role, path, parsed.date()))
+            .collect();
+        dates.sort_by(|a, b| a.1.cmp(&b.1));
+        dates
+    }
+
+    fn date(value: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_pacs008_dates() {
+        use crate::document::pacs_008_001_08::*;
+
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.cre_dt_tm = "2024-01-15T10:30:00+01:00".to_string();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_dt = "2024-01-16".to_string();
+        doc.cdt_trf_tx_inf.sttlm_tm_indctn = Some(SettlementDateTimeIndication11 {
+            dbt_dt_tm: Some("2024-01-16T09:00:00".to_string()),
+            cdt_dt_tm: None,
+        });
+
+        assert_eq!(
+            found(&Document::Pacs008(Box::new(doc))),
+            vec![
+                (
+                    DateRole::Settlement,
+                    "CdtTrfTxInf.IntrBkSttlmDt".to_string(),
+                    date("2024-01-16")
+                ),
+                (
+                    DateRole::Other,
+                    "CdtTrfTxInf.SttlmTmIndctn.DbtDtTm".to_string(),
+                    date("2024-01-16")
+                ),
+                (
+                    DateRole::Creation,
+                    "GrpHdr.CreDtTm".to_string(),
+                    date("2024-01-15")
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_camt053_entry_dates() {
+        use crate::document::camt_053_001_08::*;
+
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.stmt.ntry = Some(vec![Box::new(ReportEntry101 {
+            bookg_dt: Some(DateAndDateTime2Choice1 {
+                dt: Some("2024-03-01".to_string()),
+                dt_tm: None,
+            }),
+            val_dt: DateAndDateTime2Choice1 {
+                dt: None,
+                dt_tm: Some("2024-03-04T00:00:00Z".to_string()),
+            },
+            ..Default::default()
+        })]);
+
+        let dates = Document::Camt053(Box::new(doc)).all_dates();
+        assert_eq!(dates.len(), 2);
+        assert!(dates.contains(&(
+            DateRole::Booking,
+            "Stmt.Ntry[0].BookgDt.Dt".to_string(),
+            ParsedDate::Date(date("2024-03-01"))
+        )));
+        assert!(dates.contains(&(
+            DateRole::Value,
+            "Stmt.Ntry[0].ValDt.DtTm".to_string(),
+            ParsedDate::parse("2024-03-04T00:00:00+00:00").unwrap()
+        )));
+    }
+
+    fn related_dates(accepted: &str, settled: &str) -> TransactionDates31 {
+        TransactionDates31 {
+            accptnc_dt_tm: Some(accepted.to_string()),
+            intr_bk_sttlm_dt: Some(settled.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_same_day_processing_latency() {
+        let dates = related_dates("2024-01-15T10:30:00+01:00", "2024-01-15");
+        assert_eq!(dates.processing_latency(), Some(Duration::zero()));
+    }
+
+    #[test]
+    fn test_multi_day_processing_latency() {
+        let dates = related_dates("2024-01-12T16:45:00", "2024-01-15");
+        assert_eq!(dates.processing_latency(), Some(Duration::days(3)));
+
+        let exact = ParsedDate::parse("2024-01-15T10:30:00+01:00")
+            .unwrap()
+            .duration_until(&ParsedDate::parse("2024-01-16T09:30:00Z").unwrap());
+        assert_eq!(exact, Duration::hours(24));
+    }
+
+    #[test]
+    fn test_processing_latency_without_acceptance() {
+        let dates = TransactionDates31 {
+            intr_bk_sttlm_dt: Some("2024-01-15".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(dates.processing_latency(), None);
+    }
+
+    #[test]
+    fn test_entry_date_span() {
+        use crate::document::camt_053_001_08::*;
+
+        let entry = |bookg_dt: Option<&str>, val_dt: &str| {
+            Box::new(ReportEntry101 {
+                bookg_dt: bookg_dt.map(|dt| DateAndDateTime2Choice1 {
+                    dt: Some(dt.to_string()),
+                    dt_tm: None,
+                }),
+                val_dt: DateAndDateTime2Choice1 {
+                    dt: None,
+                    dt_tm: Some(val_dt.to_string()),
+                },
+                ..Default::default()
+            })
+        };
+        let mut stmt = AccountStatement91::default();
+        assert_eq!(stmt.entry_date_span(), None);
+
+        stmt.ntry = Some(vec![
+            entry(Some("2024-03-04"), "2024-03-01T10:00:00"),
+            entry(None, "2024-03-02T09:00:00+01:00"),
+            entry(Some("2024-03-03"), "2024-03-06T10:00:00"),
+        ]);
+        assert_eq!(
+            stmt.entry_date_span(),
+            Some((date("2024-03-02"), date("2024-03-04")))
+        );
+    }
+}
@@ -0,0 +1,103 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Nesting Depth Limits
+//!
+//! Deserializing into the generated message types recurses once per nesting
+//! level, so hostile input nested deeply enough can overflow the stack. These
+//! checks scan the raw input without recursion and reject it up front with
+//! `MxError::DepthLimitExceeded`. Malformed input is left for the parser to
+//! report.
+
+use crate::error::MxError;
+use quick_xml::Reader;
+use quick_xml::events::Event;
+
+/// Check that JSON arrays and objects nest no deeper than `max_depth`
+pub fn check_json_depth(json: &str, max_depth: usize) -> Result<(), MxError> {
+    let mut depth = 0usize;
+    let mut in_string = false;
+    let mut escaped = false;
+    for byte in json.bytes() {
+        if in_string {
+            match byte {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        match byte {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                depth += 1;
+                if depth > max_depth {
+                    return Err(MxError::DepthLimitExceeded(max_depth));
+                }
+            }
+            b'}' | b']' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Check that XML elements nest no deeper than `max_depth`
+pub fn check_xml_depth(xml: &str, max_depth: usize) -> Result<(), MxError> {
+    let mut reader = Reader::from_str(xml);
+    let mut depth = 0usize;
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(_)) => {
+                depth += 1;
+                if depth > max_depth {
+                    return Err(MxError::DepthLimitExceeded(max_depth));
+                }
+            }
+            Ok(Event::End(_)) => depth = depth.saturating_sub(1),
+            Ok(Event::Eof) | Err(_) => return Ok(()),
+            Ok(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_depth() {
+        assert!(check_json_depth(r#"{"a":[{"b":"[[[["}]}"#, 3).is_ok());
+        assert!(matches!(
+            check_json_depth(r#"{"a":[{"b":{}}]}"#, 3),
+            Err(MxError::DepthLimitExceeded(3))
+        ));
+    }
+
+    #[test]
+    fn test_xml_depth() {
+        let xml = "<a><b><c/></b><b><c>text</c></b></a>";
+        assert!(check_xml_depth(xml, 3).is_ok());
+        assert!(matches!(
+            check_xml_depth(xml, 2),
+            Err(MxError::DepthLimitExceeded(2))
+        ));
+    }
+}
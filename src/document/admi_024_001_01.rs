@@ -16,6 +16,7 @@
 //
 // You may obtain a copy of this library at
 // https://github.com/GoPlasmatic/MXMessage
+use crate::identifiers::Bic;
 use crate::parse_result::{ErrorCollector, ParserConfig};
 use crate::validation::{Validate, helpers};
 use serde::{Deserialize, Serialize};
@@ -29,6 +30,7 @@ pub struct BranchAndFinancialInstitutionIdentification81 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification81 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
     }
@@ -47,6 +49,7 @@ pub struct CorrespondenceNotification11 {
 
 impl Validate for CorrespondenceNotification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.sndr_ntfctn_id,
             "SndrNtfctnId",
@@ -94,19 +97,14 @@ impl Validate for CorrespondenceNotification11 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification231 {
     #[serde(rename = "BICFI")]
-    pub bicfi: String,
+    pub bicfi: Bic,
 }
 
 impl Validate for FinancialInstitutionIdentification231 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.bicfi,
-            "BICFI",
-            "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-            &helpers::child_path(path, "BICFI"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.bicfi
+            .validate(&helpers::child_path(path, "BICFI"), config, collector);
     }
 }
 
@@ -125,6 +123,7 @@ pub struct GroupHeader1291 {
 
 impl Validate for GroupHeader1291 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -168,6 +167,7 @@ pub struct NotificationOfCorrespondenceV01 {
 
 impl Validate for NotificationOfCorrespondenceV01 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.grp_hdr
             .validate(&helpers::child_path(path, "GrpHdr"), config, collector);
         self.ntfctn_data
@@ -184,6 +184,7 @@ pub struct NotificationType1Choice1 {
 
 impl Validate for NotificationType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -207,6 +208,7 @@ pub struct Party50Choice1 {
 
 impl Validate for Party50Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.agt
             && config.validate_optional_fields
         {
@@ -31,6 +31,7 @@ pub struct MessageHeader91 {
 
 impl Validate for MessageHeader91 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -70,6 +71,7 @@ pub struct OriginalMessageAndIssuer11 {
 
 impl Validate for OriginalMessageAndIssuer11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -118,6 +120,7 @@ pub struct Receipt61 {
 
 impl Validate for Receipt61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.orgnl_msg_id
             .validate(&helpers::child_path(path, "OrgnlMsgId"), config, collector);
         self.req_hdlg
@@ -136,6 +139,7 @@ pub struct ReceiptV08 {
 
 impl Validate for ReceiptV08 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.msg_hdr
             .validate(&helpers::child_path(path, "MsgHdr"), config, collector);
         for item in &self.rct_dtls {
@@ -155,6 +159,7 @@ pub struct RequestHandling31 {
 
 impl Validate for RequestHandling31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.sts
             .validate(&helpers::child_path(path, "Sts"), config, collector);
         if let Some(ref val) = self.sts_rsn
@@ -174,6 +179,7 @@ pub struct RequestStatus1Choice1 {
 
 impl Validate for RequestStatus1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -197,6 +203,7 @@ pub struct StatusReason6Choice1 {
 
 impl Validate for StatusReason6Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -224,6 +231,7 @@ pub struct StatusReasonInformation141 {
 
 impl Validate for StatusReasonInformation141 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.rsn
             && config.validate_optional_fields
         {
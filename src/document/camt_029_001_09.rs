@@ -16,6 +16,7 @@
 //
 // You may obtain a copy of this library at
 // https://github.com/GoPlasmatic/MXMessage
+use crate::identifiers::Bic;
 use crate::parse_result::{ErrorCollector, ParserConfig};
 use crate::validation::{Validate, helpers};
 use serde::{Deserialize, Serialize};
@@ -29,6 +30,7 @@ pub struct BranchAndFinancialInstitutionIdentification61 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
     }
@@ -43,6 +45,7 @@ pub struct BranchAndFinancialInstitutionIdentification62 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification62 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
     }
@@ -111,6 +114,7 @@ pub struct CancellationStatusReason3Choice1 {
 
 impl Validate for CancellationStatusReason3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -132,6 +136,7 @@ pub struct CancellationStatusReason41 {
 
 impl Validate for CancellationStatusReason41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.orgtr
             && config.validate_optional_fields
         {
@@ -181,6 +186,7 @@ pub struct Case51 {
 
 impl Validate for Case51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -218,6 +224,7 @@ pub struct CaseAssignment51 {
 
 impl Validate for CaseAssignment51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -259,6 +266,7 @@ pub struct ClearingSystemIdentification2Choice1 {
 
 impl Validate for ClearingSystemIdentification2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -284,6 +292,7 @@ pub struct ClearingSystemMemberIdentification21 {
 
 impl Validate for ClearingSystemMemberIdentification21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.clr_sys_id
             .validate(&helpers::child_path(path, "ClrSysId"), config, collector);
         helpers::validate_length(
@@ -321,6 +330,7 @@ pub struct DateAndPlaceOfBirth11 {
 
 impl Validate for DateAndPlaceOfBirth11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prvc_of_birth {
             helpers::validate_length(
                 val,
@@ -385,6 +395,7 @@ pub struct DateAndPlaceOfBirth12 {
 
 impl Validate for DateAndPlaceOfBirth12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prvc_of_birth {
             helpers::validate_length(
                 val,
@@ -438,7 +449,7 @@ impl Validate for DateAndPlaceOfBirth12 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification181 {
     #[serde(rename = "BICFI")]
-    pub bicfi: String,
+    pub bicfi: Bic,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -447,14 +458,9 @@ pub struct FinancialInstitutionIdentification181 {
 
 impl Validate for FinancialInstitutionIdentification181 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.bicfi,
-            "BICFI",
-            "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-            &helpers::child_path(path, "BICFI"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.bicfi
+            .validate(&helpers::child_path(path, "BICFI"), config, collector);
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
         {
@@ -477,7 +483,7 @@ impl Validate for FinancialInstitutionIdentification181 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification182 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -490,15 +496,9 @@ pub struct FinancialInstitutionIdentification182 {
 
 impl Validate for FinancialInstitutionIdentification182 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -557,6 +557,7 @@ pub struct GenericOrganisationIdentification11 {
 
 impl Validate for GenericOrganisationIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -616,6 +617,7 @@ pub struct GenericPersonIdentification11 {
 
 impl Validate for GenericPersonIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -671,6 +673,7 @@ pub struct InvestigationStatus5Choice1 {
 
 impl Validate for InvestigationStatus5Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.conf
             && config.validate_optional_fields
         {
@@ -683,7 +686,7 @@ impl Validate for InvestigationStatus5Choice1 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification291 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -692,15 +695,9 @@ pub struct OrganisationIdentification291 {
 
 impl Validate for OrganisationIdentification291 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -733,6 +730,7 @@ pub struct OrganisationIdentificationSchemeName1Choice1 {
 
 impl Validate for OrganisationIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -781,6 +779,7 @@ pub struct OriginalGroupInformation291 {
 
 impl Validate for OriginalGroupInformation291 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.orgnl_msg_id,
             "OrgnlMsgId",
@@ -839,6 +838,7 @@ pub struct Party38Choice1 {
 
 impl Validate for Party38Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -863,6 +863,7 @@ pub struct Party38Choice2 {
 
 impl Validate for Party38Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -885,6 +886,7 @@ pub struct Party40Choice1 {
 
 impl Validate for Party40Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.agt
             && config.validate_optional_fields
         {
@@ -904,6 +906,7 @@ pub struct Party40Choice2 {
 
 impl Validate for Party40Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -932,6 +935,7 @@ pub struct PartyIdentification1351 {
 
 impl Validate for PartyIdentification1351 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -991,6 +995,7 @@ pub struct PartyIdentification1352 {
 
 impl Validate for PartyIdentification1352 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -1060,6 +1065,7 @@ pub struct PaymentTransaction1021 {
 
 impl Validate for PaymentTransaction1021 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cxl_sts_id,
             "CxlStsId",
@@ -1196,6 +1202,7 @@ pub struct PersonIdentification131 {
 
 impl Validate for PersonIdentification131 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -1226,6 +1233,7 @@ pub struct PersonIdentification132 {
 
 impl Validate for PersonIdentification132 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -1256,6 +1264,7 @@ pub struct PersonIdentificationSchemeName1Choice1 {
 
 impl Validate for PersonIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -1328,6 +1337,7 @@ pub struct PostalAddress241 {
 
 impl Validate for PostalAddress241 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dept {
             helpers::validate_length(
                 val,
@@ -1652,6 +1662,7 @@ pub struct ResolutionOfInvestigationV09 {
 
 impl Validate for ResolutionOfInvestigationV09 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.assgnmt
             .validate(&helpers::child_path(path, "Assgnmt"), config, collector);
         self.sts
@@ -1670,6 +1681,7 @@ pub struct UnderlyingTransaction221 {
 
 impl Validate for UnderlyingTransaction221 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tx_inf_and_sts
             .validate(&helpers::child_path(path, "TxInfAndSts"), config, collector);
     }
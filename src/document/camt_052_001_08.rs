@@ -16,6 +16,7 @@
 //
 // You may obtain a copy of this library at
 // https://github.com/GoPlasmatic/MXMessage
+use crate::identifiers::{Bic, CurrencyCode, Iban};
 use crate::parse_result::{ErrorCollector, ParserConfig};
 use crate::validation::{Validate, helpers};
 use serde::{Deserialize, Serialize};
@@ -24,22 +25,16 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AccountIdentification4Choice1 {
     #[serde(rename = "IBAN", skip_serializing_if = "Option::is_none")]
-    pub iban: Option<String>,
+    pub iban: Option<Iban>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
     pub othr: Option<GenericAccountIdentification11>,
 }
 
 impl Validate for AccountIdentification4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.iban {
-            helpers::validate_pattern(
-                val,
-                "IBAN",
-                "[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}",
-                &helpers::child_path(path, "IBAN"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "IBAN"), config, collector);
         }
         if let Some(ref val) = self.othr
             && config.validate_optional_fields
@@ -66,6 +61,7 @@ pub struct AccountInterest41 {
 
 impl Validate for AccountInterest41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -151,6 +147,7 @@ pub struct AccountReport251 {
 
 impl Validate for AccountReport251 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -268,6 +265,7 @@ pub struct AccountSchemeName1Choice1 {
 
 impl Validate for AccountSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -307,13 +305,17 @@ impl Validate for AccountSchemeName1Choice1 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ActiveOrHistoricCurrencyAndAmount {
     #[serde(rename = "@Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "$value")]
     pub value: f64,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAndAmount {
-    fn validate(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector) {}
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
+    }
 }
 
 // ActiveOrHistoricCurrencyAndAmountRange2: Medium of exchange of value, used to qualify an amount.
@@ -324,11 +326,12 @@ pub struct ActiveOrHistoricCurrencyAndAmountRange2 {
     #[serde(rename = "CdtDbtInd", skip_serializing_if = "Option::is_none")]
     pub cdt_dbt_ind: Option<CreditDebitCode>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAndAmountRange2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -336,14 +339,8 @@ impl Validate for ActiveOrHistoricCurrencyAndAmountRange2 {
         {
             val.validate(&helpers::child_path(path, "CdtDbtInd"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
     }
 }
 
@@ -382,6 +379,7 @@ pub struct AddressType3Choice1 {
 
 impl Validate for AddressType3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -412,6 +410,7 @@ pub struct AmountAndCurrencyExchange31 {
 
 impl Validate for AmountAndCurrencyExchange31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.instd_amt
             && config.validate_optional_fields
         {
@@ -463,6 +462,7 @@ pub struct AmountAndCurrencyExchange32 {
 
 impl Validate for AmountAndCurrencyExchange32 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.instd_amt
             && config.validate_optional_fields
         {
@@ -508,6 +508,7 @@ pub struct AmountAndCurrencyExchangeDetails31 {
 
 impl Validate for AmountAndCurrencyExchangeDetails31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.ccy_xchg
@@ -529,6 +530,7 @@ pub struct AmountAndCurrencyExchangeDetails32 {
 
 impl Validate for AmountAndCurrencyExchangeDetails32 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.ccy_xchg
@@ -552,6 +554,7 @@ pub struct AmountAndCurrencyExchangeDetails41 {
 
 impl Validate for AmountAndCurrencyExchangeDetails41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -592,6 +595,7 @@ pub struct AmountAndCurrencyExchangeDetails42 {
 
 impl Validate for AmountAndCurrencyExchangeDetails42 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -630,6 +634,7 @@ pub struct AmountAndDirection35 {
 
 impl Validate for AmountAndDirection35 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cdt_dbt_ind
             .validate(&helpers::child_path(path, "CdtDbtInd"), config, collector);
     }
@@ -729,6 +734,7 @@ pub struct BalanceSubType1Choice1 {
 
 impl Validate for BalanceSubType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -775,6 +781,7 @@ pub struct BalanceType10Choice1 {
 
 impl Validate for BalanceType10Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -821,6 +828,7 @@ pub struct BalanceType131 {
 
 impl Validate for BalanceType131 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.sub_tp
@@ -842,6 +850,7 @@ pub struct BankToCustomerAccountReportV08 {
 
 impl Validate for BankToCustomerAccountReportV08 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.grp_hdr
             .validate(&helpers::child_path(path, "GrpHdr"), config, collector);
         self.rpt
@@ -860,6 +869,7 @@ pub struct BankTransactionCodeStructure41 {
 
 impl Validate for BankTransactionCodeStructure41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.domn
             && config.validate_optional_fields
         {
@@ -884,6 +894,7 @@ pub struct BankTransactionCodeStructure5 {
 
 impl Validate for BankTransactionCodeStructure5 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -909,6 +920,7 @@ pub struct BankTransactionCodeStructure6 {
 
 impl Validate for BankTransactionCodeStructure6 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -947,6 +959,7 @@ pub struct BatchInformation21 {
 
 impl Validate for BatchInformation21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_id {
             helpers::validate_length(
                 val,
@@ -1025,6 +1038,7 @@ pub struct BranchAndFinancialInstitutionIdentification61 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -1048,6 +1062,7 @@ pub struct BranchAndFinancialInstitutionIdentification62 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification62 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -1071,6 +1086,7 @@ pub struct BranchAndFinancialInstitutionIdentification63 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification63 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -1094,6 +1110,7 @@ pub struct BranchAndFinancialInstitutionIdentification64 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification64 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -1119,6 +1136,7 @@ pub struct BranchData31 {
 
 impl Validate for BranchData31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1184,6 +1202,7 @@ pub struct BranchData32 {
 
 impl Validate for BranchData32 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1259,6 +1278,7 @@ pub struct BranchData33 {
 
 impl Validate for BranchData33 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1346,6 +1366,7 @@ pub struct CardAggregated21 {
 
 impl Validate for CardAggregated21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.addtl_svc
             && config.validate_optional_fields
         {
@@ -1439,6 +1460,7 @@ pub struct CardEntry41 {
 
 impl Validate for CardEntry41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.card
             && config.validate_optional_fields
         {
@@ -1495,6 +1517,7 @@ pub struct CardIndividualTransaction21 {
 
 impl Validate for CardIndividualTransaction21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.icc_rltd_data {
             helpers::validate_length(
                 val,
@@ -1690,6 +1713,7 @@ pub struct CardSecurityInformation1 {
 
 impl Validate for CardSecurityInformation1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.csc_mgmt
             .validate(&helpers::child_path(path, "CSCMgmt"), config, collector);
         if let Some(ref val) = self.csc_val {
@@ -1716,6 +1740,7 @@ pub struct CardSequenceNumberRange11 {
 
 impl Validate for CardSequenceNumberRange11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.frst_tx {
             helpers::validate_length(
                 val,
@@ -1776,6 +1801,7 @@ pub struct CardTransaction171 {
 
 impl Validate for CardTransaction171 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.card
             && config.validate_optional_fields
         {
@@ -1810,6 +1836,7 @@ pub struct CardTransaction3Choice1 {
 
 impl Validate for CardTransaction3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.aggtd
             && config.validate_optional_fields
         {
@@ -1834,6 +1861,7 @@ pub struct CardholderAuthentication2 {
 
 impl Validate for CardholderAuthentication2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.authntcn_mtd
             .validate(&helpers::child_path(path, "AuthntcnMtd"), config, collector);
         self.authntcn_ntty.validate(
@@ -1888,7 +1916,7 @@ pub struct CashAccount381 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -1897,6 +1925,7 @@ pub struct CashAccount381 {
 
 impl Validate for CashAccount381 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -1904,14 +1933,8 @@ impl Validate for CashAccount381 {
         {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -1949,7 +1972,7 @@ pub struct CashAccount382 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy", skip_serializing_if = "Option::is_none")]
-    pub ccy: Option<String>,
+    pub ccy: Option<CurrencyCode>,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -1958,6 +1981,7 @@ pub struct CashAccount382 {
 
 impl Validate for CashAccount382 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -1966,14 +1990,7 @@ impl Validate for CashAccount382 {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
         if let Some(ref val) = self.ccy {
-            helpers::validate_pattern(
-                val,
-                "Ccy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "Ccy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "Ccy"), config, collector);
         }
         if let Some(ref val) = self.nm {
             helpers::validate_length(
@@ -2012,7 +2029,7 @@ pub struct CashAccount391 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -2025,6 +2042,7 @@ pub struct CashAccount391 {
 
 impl Validate for CashAccount391 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -2032,14 +2050,8 @@ impl Validate for CashAccount391 {
         {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2090,6 +2102,7 @@ pub struct CashAccountType2Choice1 {
 
 impl Validate for CashAccountType2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2139,6 +2152,7 @@ pub struct CashAvailability1 {
 
 impl Validate for CashAvailability1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.dt
             .validate(&helpers::child_path(path, "Dt"), config, collector);
         self.amt
@@ -2159,6 +2173,7 @@ pub struct CashAvailabilityDate1Choice {
 
 impl Validate for CashAvailabilityDate1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_days {
             helpers::validate_pattern(
                 val,
@@ -2194,6 +2209,7 @@ pub struct CashBalance81 {
 
 impl Validate for CashBalance81 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         if let Some(ref vec) = self.cdt_line
@@ -2232,6 +2248,7 @@ pub struct CashDeposit11 {
 
 impl Validate for CashDeposit11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.note_dnmtn
             .validate(&helpers::child_path(path, "NoteDnmtn"), config, collector);
         helpers::validate_pattern(
@@ -2278,6 +2295,7 @@ pub struct ChargeType3Choice1 {
 
 impl Validate for ChargeType3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2308,6 +2326,7 @@ pub struct Charges61 {
 
 impl Validate for Charges61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_chrgs_and_tax_amt
             && config.validate_optional_fields
         {
@@ -2350,6 +2369,7 @@ pub struct ChargesRecord31 {
 
 impl Validate for ChargesRecord31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -2389,6 +2409,7 @@ pub struct ClearingSystemIdentification2Choice1 {
 
 impl Validate for ClearingSystemIdentification2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2414,6 +2435,7 @@ pub struct ClearingSystemMemberIdentification21 {
 
 impl Validate for ClearingSystemMemberIdentification21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.clr_sys_id
             .validate(&helpers::child_path(path, "ClrSysId"), config, collector);
         helpers::validate_length(
@@ -2447,6 +2469,7 @@ pub struct ClearingSystemMemberIdentification22 {
 
 impl Validate for ClearingSystemMemberIdentification22 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.clr_sys_id
             .validate(&helpers::child_path(path, "ClrSysId"), config, collector);
         helpers::validate_length(
@@ -2470,6 +2493,7 @@ pub struct Contact41 {
 
 impl Validate for Contact41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2493,6 +2517,7 @@ pub struct Contact42 {
 
 impl Validate for Contact42 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2528,6 +2553,7 @@ pub struct Contact43 {
 
 impl Validate for Contact43 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2586,6 +2612,7 @@ pub struct CorporateAction91 {
 
 impl Validate for CorporateAction91 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.evt_tp,
             "EvtTp",
@@ -2654,6 +2681,7 @@ pub struct CreditLine31 {
 
 impl Validate for CreditLine31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -2683,6 +2711,7 @@ pub struct CreditLineType1Choice1 {
 
 impl Validate for CreditLineType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2733,6 +2762,7 @@ pub struct CreditorReferenceInformation21 {
 
 impl Validate for CreditorReferenceInformation21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -2773,6 +2803,7 @@ pub struct CreditorReferenceType1Choice1 {
 
 impl Validate for CreditorReferenceType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -2813,6 +2844,7 @@ pub struct CreditorReferenceType21 {
 
 impl Validate for CreditorReferenceType21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -2843,9 +2875,9 @@ impl Validate for CreditorReferenceType21 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CurrencyExchange51 {
     #[serde(rename = "SrcCcy")]
-    pub src_ccy: String,
+    pub src_ccy: CurrencyCode,
     #[serde(rename = "TrgtCcy", skip_serializing_if = "Option::is_none")]
-    pub trgt_ccy: Option<String>,
+    pub trgt_ccy: Option<CurrencyCode>,
     #[serde(rename = "UnitCcy", skip_serializing_if = "Option::is_none")]
     pub unit_ccy: Option<String>,
     #[serde(rename = "XchgRate")]
@@ -2858,23 +2890,11 @@ pub struct CurrencyExchange51 {
 
 impl Validate for CurrencyExchange51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.src_ccy,
-            "SrcCcy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "SrcCcy"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.src_ccy
+            .validate(&helpers::child_path(path, "SrcCcy"), config, collector);
         if let Some(ref val) = self.trgt_ccy {
-            helpers::validate_pattern(
-                val,
-                "TrgtCcy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "TrgtCcy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "TrgtCcy"), config, collector);
         }
         if let Some(ref val) = self.unit_ccy {
             helpers::validate_pattern(
@@ -2924,9 +2944,9 @@ impl Validate for CurrencyExchange51 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CurrencyExchange52 {
     #[serde(rename = "SrcCcy")]
-    pub src_ccy: String,
+    pub src_ccy: CurrencyCode,
     #[serde(rename = "TrgtCcy", skip_serializing_if = "Option::is_none")]
-    pub trgt_ccy: Option<String>,
+    pub trgt_ccy: Option<CurrencyCode>,
     #[serde(rename = "UnitCcy", skip_serializing_if = "Option::is_none")]
     pub unit_ccy: Option<String>,
     #[serde(rename = "XchgRate")]
@@ -2939,23 +2959,11 @@ pub struct CurrencyExchange52 {
 
 impl Validate for CurrencyExchange52 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.src_ccy,
-            "SrcCcy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "SrcCcy"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.src_ccy
+            .validate(&helpers::child_path(path, "SrcCcy"), config, collector);
         if let Some(ref val) = self.trgt_ccy {
-            helpers::validate_pattern(
-                val,
-                "TrgtCcy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "TrgtCcy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "TrgtCcy"), config, collector);
         }
         if let Some(ref val) = self.unit_ccy {
             helpers::validate_pattern(
@@ -3002,6 +3010,7 @@ pub struct DateAndDateTime2Choice1 {
 
 impl Validate for DateAndDateTime2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_tm {
             helpers::validate_pattern(
                 val,
@@ -3030,6 +3039,7 @@ pub struct DateAndPlaceOfBirth1 {
 
 impl Validate for DateAndPlaceOfBirth1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prvc_of_birth {
             helpers::validate_length(
                 val,
@@ -3076,6 +3086,7 @@ pub struct DateAndPlaceOfBirth11 {
 
 impl Validate for DateAndPlaceOfBirth11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prvc_of_birth {
             helpers::validate_length(
                 val,
@@ -3136,6 +3147,7 @@ pub struct DateOrDateTimePeriod1Choice1 {
 
 impl Validate for DateOrDateTimePeriod1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt
             && config.validate_optional_fields
         {
@@ -3173,6 +3185,7 @@ pub struct DateTimePeriod11 {
 
 impl Validate for DateTimePeriod11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.fr_dt_tm,
             "FrDtTm",
@@ -3203,6 +3216,7 @@ pub struct DiscountAmountAndType1 {
 
 impl Validate for DiscountAmountAndType1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -3224,6 +3238,7 @@ pub struct DiscountAmountAndType11 {
 
 impl Validate for DiscountAmountAndType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -3245,6 +3260,7 @@ pub struct DiscountAmountType1Choice {
 
 impl Validate for DiscountAmountType1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3281,6 +3297,7 @@ pub struct DiscountAmountType1Choice1 {
 
 impl Validate for DiscountAmountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3329,6 +3346,7 @@ pub struct DisplayCapabilities1 {
 
 impl Validate for DisplayCapabilities1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.disp_tp
             .validate(&helpers::child_path(path, "DispTp"), config, collector);
         helpers::validate_pattern(
@@ -3365,6 +3383,7 @@ pub struct DocumentAdjustment11 {
 
 impl Validate for DocumentAdjustment11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -3430,6 +3449,7 @@ pub struct DocumentLineIdentification11 {
 
 impl Validate for DocumentLineIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -3472,6 +3492,7 @@ pub struct DocumentLineInformation11 {
 
 impl Validate for DocumentLineInformation11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         for item in &self.id {
             item.validate(&helpers::child_path(path, "Id"), config, collector);
         }
@@ -3515,6 +3536,7 @@ pub struct DocumentLineType1Choice1 {
 
 impl Validate for DocumentLineType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3561,6 +3583,7 @@ pub struct DocumentLineType11 {
 
 impl Validate for DocumentLineType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -3666,6 +3689,7 @@ pub struct EntryDetails91 {
 
 impl Validate for EntryDetails91 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.btch
             && config.validate_optional_fields
         {
@@ -3685,6 +3709,7 @@ pub struct EntryStatus1Choice1 {
 
 impl Validate for EntryStatus1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3756,6 +3781,7 @@ pub struct EntryTransaction101 {
 
 impl Validate for EntryTransaction101 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.refs
             .validate(&helpers::child_path(path, "Refs"), config, collector);
         self.amt
@@ -3910,6 +3936,7 @@ pub struct FinancialIdentificationSchemeName1Choice1 {
 
 impl Validate for FinancialIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3949,7 +3976,7 @@ impl Validate for FinancialIdentificationSchemeName1Choice1 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification181 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -3964,15 +3991,9 @@ pub struct FinancialInstitutionIdentification181 {
 
 impl Validate for FinancialInstitutionIdentification181 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -4027,7 +4048,7 @@ impl Validate for FinancialInstitutionIdentification181 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification182 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification22>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -4042,15 +4063,9 @@ pub struct FinancialInstitutionIdentification182 {
 
 impl Validate for FinancialInstitutionIdentification182 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -4127,6 +4142,7 @@ pub struct FromToAmountRange1 {
 
 impl Validate for FromToAmountRange1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fr_amt
             .validate(&helpers::child_path(path, "FrAmt"), config, collector);
         self.to_amt
@@ -4157,6 +4173,7 @@ pub struct Garnishment31 {
 
 impl Validate for Garnishment31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         if let Some(ref val) = self.grnshee
@@ -4213,6 +4230,7 @@ pub struct GarnishmentType1Choice1 {
 
 impl Validate for GarnishmentType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -4259,6 +4277,7 @@ pub struct GarnishmentType11 {
 
 impl Validate for GarnishmentType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -4298,6 +4317,7 @@ pub struct GenericAccountIdentification11 {
 
 impl Validate for GenericAccountIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4357,6 +4377,7 @@ pub struct GenericFinancialIdentification11 {
 
 impl Validate for GenericFinancialIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4416,6 +4437,7 @@ pub struct GenericIdentification11 {
 
 impl Validate for GenericIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4491,6 +4513,7 @@ pub struct GenericIdentification301 {
 
 impl Validate for GenericIdentification301 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.id,
             "Id",
@@ -4553,6 +4576,7 @@ pub struct GenericIdentification302 {
 
 impl Validate for GenericIdentification302 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.id,
             "Id",
@@ -4617,6 +4641,7 @@ pub struct GenericIdentification321 {
 
 impl Validate for GenericIdentification321 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4679,6 +4704,7 @@ pub struct GenericIdentification31 {
 
 impl Validate for GenericIdentification31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4733,6 +4759,7 @@ pub struct GenericOrganisationIdentification11 {
 
 impl Validate for GenericOrganisationIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4792,6 +4819,7 @@ pub struct GenericOrganisationIdentification12 {
 
 impl Validate for GenericOrganisationIdentification12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4851,6 +4879,7 @@ pub struct GenericPersonIdentification11 {
 
 impl Validate for GenericPersonIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4910,6 +4939,7 @@ pub struct GenericPersonIdentification12 {
 
 impl Validate for GenericPersonIdentification12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4973,6 +5003,7 @@ pub struct GroupHeader811 {
 
 impl Validate for GroupHeader811 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -5043,6 +5074,7 @@ pub struct IdentificationSource3Choice1 {
 
 impl Validate for IdentificationSource3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -5095,6 +5127,7 @@ pub struct ImpliedCurrencyAmountRange1Choice {
 
 impl Validate for ImpliedCurrencyAmountRange1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.fr_amt
             && config.validate_optional_fields
         {
@@ -5134,6 +5167,7 @@ pub struct InterestRecord21 {
 
 impl Validate for InterestRecord21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         self.cdt_dbt_ind
@@ -5193,6 +5227,7 @@ pub struct InterestType1Choice1 {
 
 impl Validate for InterestType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -5249,6 +5284,7 @@ pub struct LocalInstrument2Choice1 {
 
 impl Validate for LocalInstrument2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -5295,6 +5331,7 @@ pub struct MessageIdentification21 {
 
 impl Validate for MessageIdentification21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_nm_id {
             helpers::validate_length(
                 val,
@@ -5351,6 +5388,7 @@ pub struct NameAndAddress161 {
 
 impl Validate for NameAndAddress161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.nm,
             "Nm",
@@ -5384,6 +5422,7 @@ pub struct NumberAndSumOfTransactions1 {
 
 impl Validate for NumberAndSumOfTransactions1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -5410,6 +5449,7 @@ pub struct NumberAndSumOfTransactions4 {
 
 impl Validate for NumberAndSumOfTransactions4 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -5450,7 +5490,7 @@ impl Validate for OnLineCapability1Code {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification291 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -5459,15 +5499,9 @@ pub struct OrganisationIdentification291 {
 
 impl Validate for OrganisationIdentification291 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -5493,7 +5527,7 @@ impl Validate for OrganisationIdentification291 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification292 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -5502,15 +5536,9 @@ pub struct OrganisationIdentification292 {
 
 impl Validate for OrganisationIdentification292 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -5543,6 +5571,7 @@ pub struct OrganisationIdentificationSchemeName1Choice1 {
 
 impl Validate for OrganisationIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -5589,6 +5618,7 @@ pub struct OrganisationIdentificationSchemeName1Choice2 {
 
 impl Validate for OrganisationIdentificationSchemeName1Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -5650,6 +5680,7 @@ pub struct OriginalBusinessQuery11 {
 
 impl Validate for OriginalBusinessQuery11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -5714,6 +5745,7 @@ pub struct OtherIdentification11 {
 
 impl Validate for OtherIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5794,6 +5826,7 @@ pub struct Pagination1 {
 
 impl Validate for Pagination1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.pg_nb,
             "PgNb",
@@ -5816,6 +5849,7 @@ pub struct Party38Choice1 {
 
 impl Validate for Party38Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -5840,6 +5874,7 @@ pub struct Party38Choice2 {
 
 impl Validate for Party38Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -5864,6 +5899,7 @@ pub struct Party38Choice3 {
 
 impl Validate for Party38Choice3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -5888,6 +5924,7 @@ pub struct Party40Choice1 {
 
 impl Validate for Party40Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -5912,6 +5949,7 @@ pub struct Party40Choice2 {
 
 impl Validate for Party40Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -5936,6 +5974,7 @@ pub struct Party40Choice3 {
 
 impl Validate for Party40Choice3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -5964,6 +6003,7 @@ pub struct PartyIdentification1351 {
 
 impl Validate for PartyIdentification1351 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6018,6 +6058,7 @@ pub struct PartyIdentification1352 {
 
 impl Validate for PartyIdentification1352 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6079,6 +6120,7 @@ pub struct PartyIdentification1353 {
 
 impl Validate for PartyIdentification1353 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6145,6 +6187,7 @@ pub struct PartyIdentification1354 {
 
 impl Validate for PartyIdentification1354 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6211,6 +6254,7 @@ pub struct PartyIdentification1355 {
 
 impl Validate for PartyIdentification1355 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6277,6 +6321,7 @@ pub struct PartyIdentification1356 {
 
 impl Validate for PartyIdentification1356 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6343,6 +6388,7 @@ pub struct PartyIdentification1357 {
 
 impl Validate for PartyIdentification1357 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6457,6 +6503,7 @@ pub struct PaymentCard41 {
 
 impl Validate for PaymentCard41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.plain_card_data
             && config.validate_optional_fields
         {
@@ -6534,6 +6581,7 @@ pub struct PaymentContext3 {
 
 impl Validate for PaymentContext3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.attndnc_cntxt
             && config.validate_optional_fields
         {
@@ -6591,6 +6639,7 @@ pub struct PaymentReturnReason51 {
 
 impl Validate for PaymentReturnReason51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.orgnl_bk_tx_cd
             && config.validate_optional_fields
         {
@@ -6645,6 +6694,7 @@ pub struct PersonIdentification131 {
 
 impl Validate for PersonIdentification131 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -6675,6 +6725,7 @@ pub struct PersonIdentification132 {
 
 impl Validate for PersonIdentification132 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -6705,6 +6756,7 @@ pub struct PersonIdentification133 {
 
 impl Validate for PersonIdentification133 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -6735,6 +6787,7 @@ pub struct PersonIdentificationSchemeName1Choice1 {
 
 impl Validate for PersonIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -6781,6 +6834,7 @@ pub struct PersonIdentificationSchemeName1Choice2 {
 
 impl Validate for PersonIdentificationSchemeName1Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -6837,6 +6891,7 @@ pub struct PlainCardData11 {
 
 impl Validate for PlainCardData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.pan,
             "PAN",
@@ -6897,6 +6952,7 @@ pub struct PointOfInteraction11 {
 
 impl Validate for PointOfInteraction11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.sys_nm {
@@ -6976,6 +7032,7 @@ pub struct PointOfInteractionCapabilities1 {
 
 impl Validate for PointOfInteractionCapabilities1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref vec) = self.card_rdng_cpblties
             && config.validate_optional_fields
         {
@@ -7052,6 +7109,7 @@ pub struct PointOfInteractionComponent11 {
 
 impl Validate for PointOfInteractionComponent11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.poi_cmpnt_tp
             .validate(&helpers::child_path(path, "POICmpntTp"), config, collector);
         if let Some(ref val) = self.manfctr_id {
@@ -7205,6 +7263,7 @@ pub struct PostalAddress241 {
 
 impl Validate for PostalAddress241 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.adr_tp
             && config.validate_optional_fields
         {
@@ -7560,6 +7619,7 @@ pub struct PostalAddress242 {
 
 impl Validate for PostalAddress242 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.adr_tp
             && config.validate_optional_fields
         {
@@ -7909,6 +7969,7 @@ pub struct Price71 {
 
 impl Validate for Price71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         self.val
@@ -7927,6 +7988,7 @@ pub struct PriceRateOrAmount3Choice1 {
 
 impl Validate for PriceRateOrAmount3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.amt
             && config.validate_optional_fields
         {
@@ -7974,6 +8036,7 @@ pub struct Product21 {
 
 impl Validate for Product21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.pdct_cd,
             "PdctCd",
@@ -8052,6 +8115,7 @@ pub struct ProprietaryAgent41 {
 
 impl Validate for ProprietaryAgent41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -8085,6 +8149,7 @@ pub struct ProprietaryBankTransactionCodeStructure11 {
 
 impl Validate for ProprietaryBankTransactionCodeStructure11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -8133,6 +8198,7 @@ pub struct ProprietaryDate31 {
 
 impl Validate for ProprietaryDate31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -8166,6 +8232,7 @@ pub struct ProprietaryParty51 {
 
 impl Validate for ProprietaryParty51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -8199,6 +8266,7 @@ pub struct ProprietaryPrice21 {
 
 impl Validate for ProprietaryPrice21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -8232,6 +8300,7 @@ pub struct ProprietaryQuantity11 {
 
 impl Validate for ProprietaryQuantity11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -8280,6 +8349,7 @@ pub struct ProprietaryReference11 {
 
 impl Validate for ProprietaryReference11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -8328,6 +8398,7 @@ pub struct ProxyAccountIdentification11 {
 
 impl Validate for ProxyAccountIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -8364,6 +8435,7 @@ pub struct ProxyAccountType1Choice1 {
 
 impl Validate for ProxyAccountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -8410,6 +8482,7 @@ pub struct Purpose2Choice1 {
 
 impl Validate for Purpose2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -8456,6 +8529,7 @@ pub struct Rate41 {
 
 impl Validate for Rate41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         if let Some(ref val) = self.vldty_rg
@@ -8477,6 +8551,7 @@ pub struct RateType4Choice1 {
 
 impl Validate for RateType4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.othr {
             helpers::validate_length(
                 val,
@@ -8516,6 +8591,7 @@ pub struct ReferredDocumentInformation71 {
 
 impl Validate for ReferredDocumentInformation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -8563,6 +8639,7 @@ pub struct ReferredDocumentType3Choice {
 
 impl Validate for ReferredDocumentType3Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -8593,6 +8670,7 @@ pub struct ReferredDocumentType41 {
 
 impl Validate for ReferredDocumentType41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -8638,6 +8716,7 @@ pub struct RemittanceAmount21 {
 
 impl Validate for RemittanceAmount21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.due_pybl_amt
             && config.validate_optional_fields
         {
@@ -8704,6 +8783,7 @@ pub struct RemittanceAmount31 {
 
 impl Validate for RemittanceAmount31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.due_pybl_amt
             && config.validate_optional_fields
         {
@@ -8762,6 +8842,7 @@ pub struct RemittanceInformation161 {
 
 impl Validate for RemittanceInformation161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ustrd {
             helpers::validate_length(
                 val,
@@ -8804,6 +8885,7 @@ pub struct RemittanceLocation71 {
 
 impl Validate for RemittanceLocation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.rmt_id {
             helpers::validate_length(
                 val,
@@ -8848,6 +8930,7 @@ pub struct RemittanceLocationData11 {
 
 impl Validate for RemittanceLocationData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.mtd
             .validate(&helpers::child_path(path, "Mtd"), config, collector);
         if let Some(ref val) = self.elctrnc_adr {
@@ -8948,6 +9031,7 @@ pub struct ReportEntry101 {
 
 impl Validate for ReportEntry101 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ntry_ref {
             helpers::validate_length(
                 val,
@@ -9088,6 +9172,7 @@ pub struct ReportingSource1Choice1 {
 
 impl Validate for ReportingSource1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -9134,6 +9219,7 @@ pub struct ReturnReason5Choice1 {
 
 impl Validate for ReturnReason5Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -9182,6 +9268,7 @@ pub struct SecuritiesAccount191 {
 
 impl Validate for SecuritiesAccount191 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -9241,6 +9328,7 @@ pub struct SecurityIdentification191 {
 
 impl Validate for SecurityIdentification191 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.isin {
             helpers::validate_pattern(
                 val,
@@ -9299,6 +9387,7 @@ pub struct SequenceRange1Choice1 {
 
 impl Validate for SequenceRange1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.fr_seq {
             helpers::validate_length(
                 val,
@@ -9412,6 +9501,7 @@ pub struct SequenceRange11 {
 
 impl Validate for SequenceRange11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.fr_seq,
             "FrSeq",
@@ -9472,6 +9562,7 @@ pub struct StructuredRemittanceInformation161 {
 
 impl Validate for StructuredRemittanceInformation161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref vec) = self.rfrd_doc_inf
             && config.validate_optional_fields
         {
@@ -9552,6 +9643,7 @@ pub struct TaxAmount2 {
 
 impl Validate for TaxAmount2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.taxbl_base_amt
             && config.validate_optional_fields
         {
@@ -9587,6 +9679,7 @@ pub struct TaxAmountAndType1 {
 
 impl Validate for TaxAmountAndType1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -9608,6 +9701,7 @@ pub struct TaxAmountAndType11 {
 
 impl Validate for TaxAmountAndType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -9629,6 +9723,7 @@ pub struct TaxAmountType1Choice {
 
 impl Validate for TaxAmountType1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -9665,6 +9760,7 @@ pub struct TaxAmountType1Choice1 {
 
 impl Validate for TaxAmountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -9711,6 +9807,7 @@ pub struct TaxAuthorisation11 {
 
 impl Validate for TaxAuthorisation11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.titl {
             helpers::validate_length(
                 val,
@@ -9767,6 +9864,7 @@ pub struct TaxAuthorisation12 {
 
 impl Validate for TaxAuthorisation12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.titl {
             helpers::validate_length(
                 val,
@@ -9825,6 +9923,7 @@ pub struct TaxCharges21 {
 
 impl Validate for TaxCharges21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -9883,6 +9982,7 @@ pub struct TaxInformation71 {
 
 impl Validate for TaxInformation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cdtr
             && config.validate_optional_fields
         {
@@ -10012,6 +10112,7 @@ pub struct TaxInformation81 {
 
 impl Validate for TaxInformation81 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cdtr
             && config.validate_optional_fields
         {
@@ -10122,6 +10223,7 @@ pub struct TaxParty11 {
 
 impl Validate for TaxParty11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -10201,6 +10303,7 @@ pub struct TaxParty12 {
 
 impl Validate for TaxParty12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -10282,6 +10385,7 @@ pub struct TaxParty21 {
 
 impl Validate for TaxParty21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -10368,6 +10472,7 @@ pub struct TaxParty22 {
 
 impl Validate for TaxParty22 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -10452,6 +10557,7 @@ pub struct TaxPeriod2 {
 
 impl Validate for TaxPeriod2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -10490,6 +10596,7 @@ pub struct TaxRecord21 {
 
 impl Validate for TaxRecord21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp {
             helpers::validate_length(
                 val,
@@ -10675,6 +10782,7 @@ pub struct TaxRecord22 {
 
 impl Validate for TaxRecord22 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp {
             helpers::validate_length(
                 val,
@@ -10846,6 +10954,7 @@ pub struct TaxRecordDetails2 {
 
 impl Validate for TaxRecordDetails2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prd
             && config.validate_optional_fields
         {
@@ -10915,6 +11024,7 @@ pub struct TechnicalInputChannel1Choice1 {
 
 impl Validate for TechnicalInputChannel1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -10965,6 +11075,7 @@ pub struct TotalTransactions61 {
 
 impl Validate for TotalTransactions61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_ntries
             && config.validate_optional_fields
         {
@@ -11027,6 +11138,7 @@ pub struct TotalsPerBankTransactionCode51 {
 
 impl Validate for TotalsPerBankTransactionCode51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -11080,6 +11192,7 @@ pub struct TrackData11 {
 
 impl Validate for TrackData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.trck_nb {
             helpers::validate_pattern(
                 val,
@@ -11141,6 +11254,7 @@ pub struct TransactionAgents51 {
 
 impl Validate for TransactionAgents51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.instg_agt
             && config.validate_optional_fields
         {
@@ -11252,6 +11366,7 @@ pub struct TransactionDates31 {
 
 impl Validate for TransactionDates31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.accptnc_dt_tm {
             helpers::validate_pattern(
                 val,
@@ -11311,6 +11426,7 @@ pub struct TransactionIdentifier11 {
 
 impl Validate for TransactionIdentifier11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.tx_dt_tm,
             "TxDtTm",
@@ -11350,6 +11466,7 @@ pub struct TransactionInterest41 {
 
 impl Validate for TransactionInterest41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_intrst_and_tax_amt
             && config.validate_optional_fields
         {
@@ -11394,6 +11511,7 @@ pub struct TransactionParties61 {
 
 impl Validate for TransactionParties61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.initg_pty
             && config.validate_optional_fields
         {
@@ -11455,6 +11573,7 @@ pub struct TransactionPrice4Choice1 {
 
 impl Validate for TransactionPrice4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.deal_pric
             && config.validate_optional_fields
         {
@@ -11483,6 +11602,7 @@ pub struct TransactionQuantities3Choice1 {
 
 impl Validate for TransactionQuantities3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.qty
             && config.validate_optional_fields
         {
@@ -11542,6 +11662,7 @@ pub struct TransactionReferences61 {
 
 impl Validate for TransactionReferences61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_id {
             helpers::validate_length(
                 val,
@@ -11944,6 +12065,7 @@ pub struct YieldedOrValueType1Choice {
 
 impl Validate for YieldedOrValueType1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.val_tp
             && config.validate_optional_fields
         {
@@ -16,6 +16,7 @@
 //
 // You may obtain a copy of this library at
 // https://github.com/GoPlasmatic/MXMessage
+use crate::identifiers::{Bic, CurrencyCode, Iban};
 use crate::parse_result::{ErrorCollector, ParserConfig};
 use crate::validation::{Validate, helpers};
 use serde::{Deserialize, Serialize};
@@ -24,22 +25,16 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AccountIdentification4Choice1 {
     #[serde(rename = "IBAN", skip_serializing_if = "Option::is_none")]
-    pub iban: Option<String>,
+    pub iban: Option<Iban>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
     pub othr: Option<GenericAccountIdentification11>,
 }
 
 impl Validate for AccountIdentification4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.iban {
-            helpers::validate_pattern(
-                val,
-                "IBAN",
-                "[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}",
-                &helpers::child_path(path, "IBAN"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "IBAN"), config, collector);
         }
         if let Some(ref val) = self.othr
             && config.validate_optional_fields
@@ -66,6 +61,7 @@ pub struct AccountInterest41 {
 
 impl Validate for AccountInterest41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -123,6 +119,7 @@ pub struct AccountSchemeName1Choice1 {
 
 impl Validate for AccountSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -197,6 +194,7 @@ pub struct AccountStatement91 {
 
 impl Validate for AccountStatement91 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -303,13 +301,17 @@ impl Validate for AccountStatement91 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ActiveCurrencyAndAmount {
     #[serde(rename = "@Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "$value")]
     pub value: f64,
 }
 
 impl Validate for ActiveCurrencyAndAmount {
-    fn validate(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector) {}
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        self.ccy
+            .validate_active(&helpers::child_path(path, "Ccy"), config, collector);
+    }
 }
 
 // ActiveOrHistoricCurrencyAnd13DecimalAmount: A number of monetary units specified in an active or a historic currency where the unit of currency is explicit and compliant with ISO 4217. The number of fractional digits (or minor unit of currency) is not checked as per ISO 4217: It must be lesser than or equal to 13.
@@ -317,26 +319,34 @@ impl Validate for ActiveCurrencyAndAmount {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ActiveOrHistoricCurrencyAnd13DecimalAmount {
     #[serde(rename = "@Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "$value")]
     pub value: f64,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAnd13DecimalAmount {
-    fn validate(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector) {}
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
+    }
 }
 
 // ActiveOrHistoricCurrencyAndAmount: A number of monetary units specified in an active or a historic currency where the unit of currency is explicit and compliant with ISO 4217.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ActiveOrHistoricCurrencyAndAmount {
     #[serde(rename = "@Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "$value")]
     pub value: f64,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAndAmount {
-    fn validate(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector) {}
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
+    }
 }
 
 // ActiveOrHistoricCurrencyAndAmountRange2: Medium of exchange of value, used to qualify an amount.
@@ -347,11 +357,12 @@ pub struct ActiveOrHistoricCurrencyAndAmountRange2 {
     #[serde(rename = "CdtDbtInd", skip_serializing_if = "Option::is_none")]
     pub cdt_dbt_ind: Option<CreditDebitCode>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAndAmountRange2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -359,14 +370,8 @@ impl Validate for ActiveOrHistoricCurrencyAndAmountRange2 {
         {
             val.validate(&helpers::child_path(path, "CdtDbtInd"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
     }
 }
 
@@ -405,6 +410,7 @@ pub struct AddressType3Choice1 {
 
 impl Validate for AddressType3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -429,6 +435,7 @@ pub struct AddressType3Choice2 {
 
 impl Validate for AddressType3Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -459,6 +466,7 @@ pub struct AmountAndCurrencyExchange31 {
 
 impl Validate for AmountAndCurrencyExchange31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.instd_amt
             && config.validate_optional_fields
         {
@@ -510,6 +518,7 @@ pub struct AmountAndCurrencyExchange32 {
 
 impl Validate for AmountAndCurrencyExchange32 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.instd_amt
             && config.validate_optional_fields
         {
@@ -555,6 +564,7 @@ pub struct AmountAndCurrencyExchangeDetails31 {
 
 impl Validate for AmountAndCurrencyExchangeDetails31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.ccy_xchg
@@ -576,6 +586,7 @@ pub struct AmountAndCurrencyExchangeDetails32 {
 
 impl Validate for AmountAndCurrencyExchangeDetails32 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.ccy_xchg
@@ -599,6 +610,7 @@ pub struct AmountAndCurrencyExchangeDetails41 {
 
 impl Validate for AmountAndCurrencyExchangeDetails41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -639,6 +651,7 @@ pub struct AmountAndCurrencyExchangeDetails42 {
 
 impl Validate for AmountAndCurrencyExchangeDetails42 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -677,6 +690,7 @@ pub struct AmountAndDirection35 {
 
 impl Validate for AmountAndDirection35 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cdt_dbt_ind
             .validate(&helpers::child_path(path, "CdtDbtInd"), config, collector);
     }
@@ -776,6 +790,7 @@ pub struct BalanceSubType1Choice1 {
 
 impl Validate for BalanceSubType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -822,6 +837,7 @@ pub struct BalanceType10Choice1 {
 
 impl Validate for BalanceType10Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -868,6 +884,7 @@ pub struct BalanceType131 {
 
 impl Validate for BalanceType131 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.sub_tp
@@ -889,6 +906,7 @@ pub struct BankToCustomerStatementV08 {
 
 impl Validate for BankToCustomerStatementV08 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.grp_hdr
             .validate(&helpers::child_path(path, "GrpHdr"), config, collector);
         self.stmt
@@ -907,6 +925,7 @@ pub struct BankTransactionCodeStructure41 {
 
 impl Validate for BankTransactionCodeStructure41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.domn
             && config.validate_optional_fields
         {
@@ -931,6 +950,7 @@ pub struct BankTransactionCodeStructure5 {
 
 impl Validate for BankTransactionCodeStructure5 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -956,6 +976,7 @@ pub struct BankTransactionCodeStructure6 {
 
 impl Validate for BankTransactionCodeStructure6 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -994,6 +1015,7 @@ pub struct BatchInformation21 {
 
 impl Validate for BatchInformation21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_id {
             helpers::validate_length(
                 val,
@@ -1072,6 +1094,7 @@ pub struct BranchAndFinancialInstitutionIdentification61 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -1095,6 +1118,7 @@ pub struct BranchAndFinancialInstitutionIdentification62 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification62 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -1120,6 +1144,7 @@ pub struct BranchData31 {
 
 impl Validate for BranchData31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1195,6 +1220,7 @@ pub struct BranchData32 {
 
 impl Validate for BranchData32 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1292,6 +1318,7 @@ pub struct CardAggregated21 {
 
 impl Validate for CardAggregated21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.addtl_svc
             && config.validate_optional_fields
         {
@@ -1385,6 +1412,7 @@ pub struct CardEntry41 {
 
 impl Validate for CardEntry41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.card
             && config.validate_optional_fields
         {
@@ -1441,6 +1469,7 @@ pub struct CardIndividualTransaction21 {
 
 impl Validate for CardIndividualTransaction21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.icc_rltd_data {
             helpers::validate_length(
                 val,
@@ -1636,6 +1665,7 @@ pub struct CardSecurityInformation1 {
 
 impl Validate for CardSecurityInformation1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.csc_mgmt
             .validate(&helpers::child_path(path, "CSCMgmt"), config, collector);
         if let Some(ref val) = self.csc_val {
@@ -1662,6 +1692,7 @@ pub struct CardSequenceNumberRange11 {
 
 impl Validate for CardSequenceNumberRange11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.frst_tx {
             helpers::validate_length(
                 val,
@@ -1722,6 +1753,7 @@ pub struct CardTransaction171 {
 
 impl Validate for CardTransaction171 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.card
             && config.validate_optional_fields
         {
@@ -1756,6 +1788,7 @@ pub struct CardTransaction3Choice1 {
 
 impl Validate for CardTransaction3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.aggtd
             && config.validate_optional_fields
         {
@@ -1780,6 +1813,7 @@ pub struct CardholderAuthentication2 {
 
 impl Validate for CardholderAuthentication2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.authntcn_mtd
             .validate(&helpers::child_path(path, "AuthntcnMtd"), config, collector);
         self.authntcn_ntty.validate(
@@ -1834,7 +1868,7 @@ pub struct CashAccount381 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -1843,6 +1877,7 @@ pub struct CashAccount381 {
 
 impl Validate for CashAccount381 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -1850,14 +1885,8 @@ impl Validate for CashAccount381 {
         {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -1895,7 +1924,7 @@ pub struct CashAccount382 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy", skip_serializing_if = "Option::is_none")]
-    pub ccy: Option<String>,
+    pub ccy: Option<CurrencyCode>,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -1904,6 +1933,7 @@ pub struct CashAccount382 {
 
 impl Validate for CashAccount382 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -1912,14 +1942,7 @@ impl Validate for CashAccount382 {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
         if let Some(ref val) = self.ccy {
-            helpers::validate_pattern(
-                val,
-                "Ccy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "Ccy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "Ccy"), config, collector);
         }
         if let Some(ref val) = self.nm {
             helpers::validate_length(
@@ -1958,7 +1981,7 @@ pub struct CashAccount383 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy", skip_serializing_if = "Option::is_none")]
-    pub ccy: Option<String>,
+    pub ccy: Option<CurrencyCode>,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -1967,6 +1990,7 @@ pub struct CashAccount383 {
 
 impl Validate for CashAccount383 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -1975,14 +1999,7 @@ impl Validate for CashAccount383 {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
         if let Some(ref val) = self.ccy {
-            helpers::validate_pattern(
-                val,
-                "Ccy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "Ccy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "Ccy"), config, collector);
         }
         if let Some(ref val) = self.nm {
             helpers::validate_length(
@@ -2021,7 +2038,7 @@ pub struct CashAccount391 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -2034,6 +2051,7 @@ pub struct CashAccount391 {
 
 impl Validate for CashAccount391 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -2041,14 +2059,8 @@ impl Validate for CashAccount391 {
         {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2099,6 +2111,7 @@ pub struct CashAccountType2Choice1 {
 
 impl Validate for CashAccountType2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2148,6 +2161,7 @@ pub struct CashAvailability1 {
 
 impl Validate for CashAvailability1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.dt
             .validate(&helpers::child_path(path, "Dt"), config, collector);
         self.amt
@@ -2168,6 +2182,7 @@ pub struct CashAvailabilityDate1Choice {
 
 impl Validate for CashAvailabilityDate1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_days {
             helpers::validate_pattern(
                 val,
@@ -2203,6 +2218,7 @@ pub struct CashBalance81 {
 
 impl Validate for CashBalance81 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         if let Some(ref vec) = self.cdt_line
@@ -2241,6 +2257,7 @@ pub struct CashDeposit1 {
 
 impl Validate for CashDeposit1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.note_dnmtn
             .validate(&helpers::child_path(path, "NoteDnmtn"), config, collector);
         helpers::validate_pattern(
@@ -2287,6 +2304,7 @@ pub struct ChargeType3Choice1 {
 
 impl Validate for ChargeType3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2317,6 +2335,7 @@ pub struct Charges61 {
 
 impl Validate for Charges61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_chrgs_and_tax_amt
             && config.validate_optional_fields
         {
@@ -2359,6 +2378,7 @@ pub struct ChargesRecord31 {
 
 impl Validate for ChargesRecord31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -2398,6 +2418,7 @@ pub struct ClearingSystemIdentification2Choice1 {
 
 impl Validate for ClearingSystemIdentification2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2423,6 +2444,7 @@ pub struct ClearingSystemMemberIdentification21 {
 
 impl Validate for ClearingSystemMemberIdentification21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.clr_sys_id
             .validate(&helpers::child_path(path, "ClrSysId"), config, collector);
         helpers::validate_length(
@@ -2454,6 +2476,7 @@ pub struct Contact41 {
 
 impl Validate for Contact41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2489,6 +2512,7 @@ pub struct Contact42 {
 
 impl Validate for Contact42 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2547,6 +2571,7 @@ pub struct CorporateAction91 {
 
 impl Validate for CorporateAction91 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.evt_tp,
             "EvtTp",
@@ -2615,6 +2640,7 @@ pub struct CreditLine31 {
 
 impl Validate for CreditLine31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -2644,6 +2670,7 @@ pub struct CreditLineType1Choice1 {
 
 impl Validate for CreditLineType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2694,6 +2721,7 @@ pub struct CreditorReferenceInformation21 {
 
 impl Validate for CreditorReferenceInformation21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -2734,6 +2762,7 @@ pub struct CreditorReferenceType1Choice {
 
 impl Validate for CreditorReferenceType1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -2764,6 +2793,7 @@ pub struct CreditorReferenceType21 {
 
 impl Validate for CreditorReferenceType21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -2794,9 +2824,9 @@ impl Validate for CreditorReferenceType21 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CurrencyExchange5 {
     #[serde(rename = "SrcCcy")]
-    pub src_ccy: String,
+    pub src_ccy: CurrencyCode,
     #[serde(rename = "TrgtCcy", skip_serializing_if = "Option::is_none")]
-    pub trgt_ccy: Option<String>,
+    pub trgt_ccy: Option<CurrencyCode>,
     #[serde(rename = "UnitCcy", skip_serializing_if = "Option::is_none")]
     pub unit_ccy: Option<String>,
     #[serde(rename = "XchgRate")]
@@ -2809,23 +2839,11 @@ pub struct CurrencyExchange5 {
 
 impl Validate for CurrencyExchange5 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.src_ccy,
-            "SrcCcy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "SrcCcy"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.src_ccy
+            .validate(&helpers::child_path(path, "SrcCcy"), config, collector);
         if let Some(ref val) = self.trgt_ccy {
-            helpers::validate_pattern(
-                val,
-                "TrgtCcy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "TrgtCcy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "TrgtCcy"), config, collector);
         }
         if let Some(ref val) = self.unit_ccy {
             helpers::validate_pattern(
@@ -2855,9 +2873,9 @@ impl Validate for CurrencyExchange5 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CurrencyExchange51 {
     #[serde(rename = "SrcCcy")]
-    pub src_ccy: String,
+    pub src_ccy: CurrencyCode,
     #[serde(rename = "TrgtCcy", skip_serializing_if = "Option::is_none")]
-    pub trgt_ccy: Option<String>,
+    pub trgt_ccy: Option<CurrencyCode>,
     #[serde(rename = "UnitCcy", skip_serializing_if = "Option::is_none")]
     pub unit_ccy: Option<String>,
     #[serde(rename = "XchgRate")]
@@ -2870,23 +2888,11 @@ pub struct CurrencyExchange51 {
 
 impl Validate for CurrencyExchange51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.src_ccy,
-            "SrcCcy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "SrcCcy"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.src_ccy
+            .validate(&helpers::child_path(path, "SrcCcy"), config, collector);
         if let Some(ref val) = self.trgt_ccy {
-            helpers::validate_pattern(
-                val,
-                "TrgtCcy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "TrgtCcy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "TrgtCcy"), config, collector);
         }
         if let Some(ref val) = self.unit_ccy {
             helpers::validate_pattern(
@@ -2936,9 +2942,9 @@ impl Validate for CurrencyExchange51 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CurrencyExchange52 {
     #[serde(rename = "SrcCcy")]
-    pub src_ccy: String,
+    pub src_ccy: CurrencyCode,
     #[serde(rename = "TrgtCcy", skip_serializing_if = "Option::is_none")]
-    pub trgt_ccy: Option<String>,
+    pub trgt_ccy: Option<CurrencyCode>,
     #[serde(rename = "UnitCcy", skip_serializing_if = "Option::is_none")]
     pub unit_ccy: Option<String>,
     #[serde(rename = "XchgRate")]
@@ -2951,23 +2957,11 @@ pub struct CurrencyExchange52 {
 
 impl Validate for CurrencyExchange52 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.src_ccy,
-            "SrcCcy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "SrcCcy"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.src_ccy
+            .validate(&helpers::child_path(path, "SrcCcy"), config, collector);
         if let Some(ref val) = self.trgt_ccy {
-            helpers::validate_pattern(
-                val,
-                "TrgtCcy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "TrgtCcy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "TrgtCcy"), config, collector);
         }
         if let Some(ref val) = self.unit_ccy {
             helpers::validate_pattern(
@@ -3027,6 +3021,7 @@ pub struct DateAndDateTime2Choice1 {
 
 impl Validate for DateAndDateTime2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_tm {
             helpers::validate_pattern(
                 val,
@@ -3055,6 +3050,7 @@ pub struct DateAndPlaceOfBirth11 {
 
 impl Validate for DateAndPlaceOfBirth11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prvc_of_birth {
             helpers::validate_length(
                 val,
@@ -3115,6 +3111,7 @@ pub struct DateOrDateTimePeriod1Choice1 {
 
 impl Validate for DateOrDateTimePeriod1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt
             && config.validate_optional_fields
         {
@@ -3152,6 +3149,7 @@ pub struct DateTimePeriod11 {
 
 impl Validate for DateTimePeriod11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.fr_dt_tm,
             "FrDtTm",
@@ -3182,6 +3180,7 @@ pub struct DiscountAmountAndType11 {
 
 impl Validate for DiscountAmountAndType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -3203,6 +3202,7 @@ pub struct DiscountAmountType1Choice1 {
 
 impl Validate for DiscountAmountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3251,6 +3251,7 @@ pub struct DisplayCapabilities1 {
 
 impl Validate for DisplayCapabilities1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.disp_tp
             .validate(&helpers::child_path(path, "DispTp"), config, collector);
         helpers::validate_pattern(
@@ -3287,6 +3288,7 @@ pub struct DocumentAdjustment11 {
 
 impl Validate for DocumentAdjustment11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -3352,6 +3354,7 @@ pub struct DocumentLineIdentification11 {
 
 impl Validate for DocumentLineIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -3394,6 +3397,7 @@ pub struct DocumentLineInformation11 {
 
 impl Validate for DocumentLineInformation11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         for item in &self.id {
             item.validate(&helpers::child_path(path, "Id"), config, collector);
         }
@@ -3437,6 +3441,7 @@ pub struct DocumentLineType1Choice1 {
 
 impl Validate for DocumentLineType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3483,6 +3488,7 @@ pub struct DocumentLineType11 {
 
 impl Validate for DocumentLineType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -3588,6 +3594,7 @@ pub struct EntryDetails91 {
 
 impl Validate for EntryDetails91 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.btch
             && config.validate_optional_fields
         {
@@ -3607,6 +3614,7 @@ pub struct EntryStatus1Choice1 {
 
 impl Validate for EntryStatus1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -3672,6 +3680,7 @@ pub struct EntryTransaction101 {
 
 impl Validate for EntryTransaction101 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.refs
             .validate(&helpers::child_path(path, "Refs"), config, collector);
         self.amt
@@ -3840,6 +3849,7 @@ pub struct FinancialIdentificationSchemeName1Choice1 {
 
 impl Validate for FinancialIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3879,7 +3889,7 @@ impl Validate for FinancialIdentificationSchemeName1Choice1 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification181 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -3894,15 +3904,9 @@ pub struct FinancialInstitutionIdentification181 {
 
 impl Validate for FinancialInstitutionIdentification181 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -3979,6 +3983,7 @@ pub struct FromToAmountRange1 {
 
 impl Validate for FromToAmountRange1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fr_amt
             .validate(&helpers::child_path(path, "FrAmt"), config, collector);
         self.to_amt
@@ -4009,6 +4014,7 @@ pub struct Garnishment31 {
 
 impl Validate for Garnishment31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         if let Some(ref val) = self.grnshee
@@ -4065,6 +4071,7 @@ pub struct GarnishmentType1Choice1 {
 
 impl Validate for GarnishmentType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -4111,6 +4118,7 @@ pub struct GarnishmentType11 {
 
 impl Validate for GarnishmentType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -4150,6 +4158,7 @@ pub struct GenericAccountIdentification11 {
 
 impl Validate for GenericAccountIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4209,6 +4218,7 @@ pub struct GenericFinancialIdentification11 {
 
 impl Validate for GenericFinancialIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4268,6 +4278,7 @@ pub struct GenericIdentification11 {
 
 impl Validate for GenericIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4343,6 +4354,7 @@ pub struct GenericIdentification301 {
 
 impl Validate for GenericIdentification301 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.id,
             "Id",
@@ -4405,6 +4417,7 @@ pub struct GenericIdentification302 {
 
 impl Validate for GenericIdentification302 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.id,
             "Id",
@@ -4469,6 +4482,7 @@ pub struct GenericIdentification321 {
 
 impl Validate for GenericIdentification321 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4531,6 +4545,7 @@ pub struct GenericIdentification31 {
 
 impl Validate for GenericIdentification31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4585,6 +4600,7 @@ pub struct GenericOrganisationIdentification11 {
 
 impl Validate for GenericOrganisationIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4644,6 +4660,7 @@ pub struct GenericPersonIdentification11 {
 
 impl Validate for GenericPersonIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4707,6 +4724,7 @@ pub struct GroupHeader811 {
 
 impl Validate for GroupHeader811 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -4777,6 +4795,7 @@ pub struct IdentificationSource3Choice1 {
 
 impl Validate for IdentificationSource3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -4829,6 +4848,7 @@ pub struct ImpliedCurrencyAmountRange1Choice {
 
 impl Validate for ImpliedCurrencyAmountRange1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.fr_amt
             && config.validate_optional_fields
         {
@@ -4868,6 +4888,7 @@ pub struct InterestRecord21 {
 
 impl Validate for InterestRecord21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         self.cdt_dbt_ind
@@ -4927,6 +4948,7 @@ pub struct InterestType1Choice1 {
 
 impl Validate for InterestType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -4983,6 +5005,7 @@ pub struct LocalInstrument2Choice1 {
 
 impl Validate for LocalInstrument2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -5029,6 +5052,7 @@ pub struct MessageIdentification21 {
 
 impl Validate for MessageIdentification21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_nm_id {
             helpers::validate_length(
                 val,
@@ -5085,6 +5109,7 @@ pub struct NameAndAddress161 {
 
 impl Validate for NameAndAddress161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.nm,
             "Nm",
@@ -5118,6 +5143,7 @@ pub struct NumberAndSumOfTransactions1 {
 
 impl Validate for NumberAndSumOfTransactions1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -5144,6 +5170,7 @@ pub struct NumberAndSumOfTransactions4 {
 
 impl Validate for NumberAndSumOfTransactions4 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -5184,7 +5211,7 @@ impl Validate for OnLineCapability1Code {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification291 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -5193,15 +5220,9 @@ pub struct OrganisationIdentification291 {
 
 impl Validate for OrganisationIdentification291 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -5234,6 +5255,7 @@ pub struct OrganisationIdentificationSchemeName1Choice1 {
 
 impl Validate for OrganisationIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -5295,6 +5317,7 @@ pub struct OriginalBusinessQuery11 {
 
 impl Validate for OriginalBusinessQuery11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -5359,6 +5382,7 @@ pub struct OtherIdentification11 {
 
 impl Validate for OtherIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5439,6 +5463,7 @@ pub struct Pagination1 {
 
 impl Validate for Pagination1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.pg_nb,
             "PgNb",
@@ -5461,6 +5486,7 @@ pub struct Party38Choice1 {
 
 impl Validate for Party38Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -5485,6 +5511,7 @@ pub struct Party40Choice1 {
 
 impl Validate for Party40Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -5509,6 +5536,7 @@ pub struct Party40Choice2 {
 
 impl Validate for Party40Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -5533,6 +5561,7 @@ pub struct Party40Choice3 {
 
 impl Validate for Party40Choice3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -5561,6 +5590,7 @@ pub struct PartyIdentification1351 {
 
 impl Validate for PartyIdentification1351 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -5615,6 +5645,7 @@ pub struct PartyIdentification1352 {
 
 impl Validate for PartyIdentification1352 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -5676,6 +5707,7 @@ pub struct PartyIdentification1353 {
 
 impl Validate for PartyIdentification1353 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -5742,6 +5774,7 @@ pub struct PartyIdentification1354 {
 
 impl Validate for PartyIdentification1354 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -5856,6 +5889,7 @@ pub struct PaymentCard41 {
 
 impl Validate for PaymentCard41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.plain_card_data
             && config.validate_optional_fields
         {
@@ -5933,6 +5967,7 @@ pub struct PaymentContext3 {
 
 impl Validate for PaymentContext3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.attndnc_cntxt
             && config.validate_optional_fields
         {
@@ -5990,6 +6025,7 @@ pub struct PaymentReturnReason51 {
 
 impl Validate for PaymentReturnReason51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.orgnl_bk_tx_cd
             && config.validate_optional_fields
         {
@@ -6044,6 +6080,7 @@ pub struct PersonIdentification131 {
 
 impl Validate for PersonIdentification131 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -6074,6 +6111,7 @@ pub struct PersonIdentificationSchemeName1Choice1 {
 
 impl Validate for PersonIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -6130,6 +6168,7 @@ pub struct PlainCardData11 {
 
 impl Validate for PlainCardData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.pan,
             "PAN",
@@ -6190,6 +6229,7 @@ pub struct PointOfInteraction11 {
 
 impl Validate for PointOfInteraction11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.sys_nm {
@@ -6266,6 +6306,7 @@ pub struct PointOfInteraction12 {
 
 impl Validate for PointOfInteraction12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.sys_nm {
@@ -6345,6 +6386,7 @@ pub struct PointOfInteractionCapabilities1 {
 
 impl Validate for PointOfInteractionCapabilities1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref vec) = self.card_rdng_cpblties
             && config.validate_optional_fields
         {
@@ -6421,6 +6463,7 @@ pub struct PointOfInteractionComponent11 {
 
 impl Validate for PointOfInteractionComponent11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.poi_cmpnt_tp
             .validate(&helpers::child_path(path, "POICmpntTp"), config, collector);
         if let Some(ref val) = self.manfctr_id {
@@ -6556,6 +6599,7 @@ pub struct PointOfInteractionComponent12 {
 
 impl Validate for PointOfInteractionComponent12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.poi_cmpnt_tp
             .validate(&helpers::child_path(path, "POICmpntTp"), config, collector);
         if let Some(ref val) = self.manfctr_id {
@@ -6709,6 +6753,7 @@ pub struct PostalAddress241 {
 
 impl Validate for PostalAddress241 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.adr_tp
             && config.validate_optional_fields
         {
@@ -7064,6 +7109,7 @@ pub struct PostalAddress242 {
 
 impl Validate for PostalAddress242 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.adr_tp
             && config.validate_optional_fields
         {
@@ -7413,6 +7459,7 @@ pub struct Price7 {
 
 impl Validate for Price7 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         self.val
@@ -7431,6 +7478,7 @@ pub struct PriceRateOrAmount3Choice {
 
 impl Validate for PriceRateOrAmount3Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.amt
             && config.validate_optional_fields
         {
@@ -7478,6 +7526,7 @@ pub struct Product21 {
 
 impl Validate for Product21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.pdct_cd,
             "PdctCd",
@@ -7556,6 +7605,7 @@ pub struct ProprietaryAgent41 {
 
 impl Validate for ProprietaryAgent41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -7589,6 +7639,7 @@ pub struct ProprietaryBankTransactionCodeStructure11 {
 
 impl Validate for ProprietaryBankTransactionCodeStructure11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -7637,6 +7688,7 @@ pub struct ProprietaryDate31 {
 
 impl Validate for ProprietaryDate31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -7670,6 +7722,7 @@ pub struct ProprietaryParty51 {
 
 impl Validate for ProprietaryParty51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -7703,6 +7756,7 @@ pub struct ProprietaryPrice21 {
 
 impl Validate for ProprietaryPrice21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -7736,6 +7790,7 @@ pub struct ProprietaryQuantity11 {
 
 impl Validate for ProprietaryQuantity11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -7784,6 +7839,7 @@ pub struct ProprietaryReference11 {
 
 impl Validate for ProprietaryReference11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -7832,6 +7888,7 @@ pub struct ProxyAccountIdentification11 {
 
 impl Validate for ProxyAccountIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -7868,6 +7925,7 @@ pub struct ProxyAccountIdentification12 {
 
 impl Validate for ProxyAccountIdentification12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -7904,6 +7962,7 @@ pub struct ProxyAccountType1Choice {
 
 impl Validate for ProxyAccountType1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -7940,6 +7999,7 @@ pub struct ProxyAccountType1Choice1 {
 
 impl Validate for ProxyAccountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -7986,6 +8046,7 @@ pub struct Purpose2Choice1 {
 
 impl Validate for Purpose2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -8032,6 +8093,7 @@ pub struct Rate41 {
 
 impl Validate for Rate41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         if let Some(ref val) = self.vldty_rg
@@ -8053,6 +8115,7 @@ pub struct RateType4Choice1 {
 
 impl Validate for RateType4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.othr {
             helpers::validate_length(
                 val,
@@ -8092,6 +8155,7 @@ pub struct ReferredDocumentInformation71 {
 
 impl Validate for ReferredDocumentInformation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -8139,6 +8203,7 @@ pub struct ReferredDocumentType3Choice1 {
 
 impl Validate for ReferredDocumentType3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -8179,6 +8244,7 @@ pub struct ReferredDocumentType41 {
 
 impl Validate for ReferredDocumentType41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -8224,6 +8290,7 @@ pub struct RemittanceAmount21 {
 
 impl Validate for RemittanceAmount21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.due_pybl_amt
             && config.validate_optional_fields
         {
@@ -8290,6 +8357,7 @@ pub struct RemittanceAmount31 {
 
 impl Validate for RemittanceAmount31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.due_pybl_amt
             && config.validate_optional_fields
         {
@@ -8348,6 +8416,7 @@ pub struct RemittanceInformation161 {
 
 impl Validate for RemittanceInformation161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ustrd {
             helpers::validate_length(
                 val,
@@ -8390,6 +8459,7 @@ pub struct RemittanceLocation71 {
 
 impl Validate for RemittanceLocation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.rmt_id {
             helpers::validate_length(
                 val,
@@ -8434,6 +8504,7 @@ pub struct RemittanceLocationData11 {
 
 impl Validate for RemittanceLocationData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.mtd
             .validate(&helpers::child_path(path, "Mtd"), config, collector);
         if let Some(ref val) = self.elctrnc_adr {
@@ -8534,6 +8605,7 @@ pub struct ReportEntry101 {
 
 impl Validate for ReportEntry101 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ntry_ref {
             helpers::validate_length(
                 val,
@@ -8674,6 +8746,7 @@ pub struct ReportingSource1Choice1 {
 
 impl Validate for ReportingSource1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -8720,6 +8793,7 @@ pub struct ReturnReason5Choice1 {
 
 impl Validate for ReturnReason5Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -8768,6 +8842,7 @@ pub struct SecuritiesAccount191 {
 
 impl Validate for SecuritiesAccount191 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -8827,6 +8902,7 @@ pub struct SecurityIdentification191 {
 
 impl Validate for SecurityIdentification191 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.isin {
             helpers::validate_pattern(
                 val,
@@ -8885,6 +8961,7 @@ pub struct SequenceRange1Choice1 {
 
 impl Validate for SequenceRange1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.fr_seq {
             helpers::validate_length(
                 val,
@@ -8998,6 +9075,7 @@ pub struct SequenceRange11 {
 
 impl Validate for SequenceRange11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.fr_seq,
             "FrSeq",
@@ -9058,6 +9136,7 @@ pub struct StructuredRemittanceInformation161 {
 
 impl Validate for StructuredRemittanceInformation161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref vec) = self.rfrd_doc_inf
             && config.validate_optional_fields
         {
@@ -9138,6 +9217,7 @@ pub struct TaxAmount2 {
 
 impl Validate for TaxAmount2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.taxbl_base_amt
             && config.validate_optional_fields
         {
@@ -9173,6 +9253,7 @@ pub struct TaxAmountAndType11 {
 
 impl Validate for TaxAmountAndType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -9194,6 +9275,7 @@ pub struct TaxAmountType1Choice1 {
 
 impl Validate for TaxAmountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -9240,6 +9322,7 @@ pub struct TaxAuthorisation11 {
 
 impl Validate for TaxAuthorisation11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.titl {
             helpers::validate_length(
                 val,
@@ -9296,6 +9379,7 @@ pub struct TaxAuthorisation12 {
 
 impl Validate for TaxAuthorisation12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.titl {
             helpers::validate_length(
                 val,
@@ -9354,6 +9438,7 @@ pub struct TaxCharges21 {
 
 impl Validate for TaxCharges21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -9412,6 +9497,7 @@ pub struct TaxInformation71 {
 
 impl Validate for TaxInformation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cdtr
             && config.validate_optional_fields
         {
@@ -9541,6 +9627,7 @@ pub struct TaxInformation81 {
 
 impl Validate for TaxInformation81 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cdtr
             && config.validate_optional_fields
         {
@@ -9651,6 +9738,7 @@ pub struct TaxParty11 {
 
 impl Validate for TaxParty11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -9730,6 +9818,7 @@ pub struct TaxParty12 {
 
 impl Validate for TaxParty12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -9811,6 +9900,7 @@ pub struct TaxParty21 {
 
 impl Validate for TaxParty21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -9897,6 +9987,7 @@ pub struct TaxParty22 {
 
 impl Validate for TaxParty22 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -9981,6 +10072,7 @@ pub struct TaxPeriod2 {
 
 impl Validate for TaxPeriod2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -10019,6 +10111,7 @@ pub struct TaxRecord21 {
 
 impl Validate for TaxRecord21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp {
             helpers::validate_length(
                 val,
@@ -10204,6 +10297,7 @@ pub struct TaxRecord22 {
 
 impl Validate for TaxRecord22 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp {
             helpers::validate_length(
                 val,
@@ -10375,6 +10469,7 @@ pub struct TaxRecordDetails2 {
 
 impl Validate for TaxRecordDetails2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prd
             && config.validate_optional_fields
         {
@@ -10444,6 +10539,7 @@ pub struct TechnicalInputChannel1Choice1 {
 
 impl Validate for TechnicalInputChannel1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -10494,6 +10590,7 @@ pub struct TotalTransactions61 {
 
 impl Validate for TotalTransactions61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_ntries
             && config.validate_optional_fields
         {
@@ -10556,6 +10653,7 @@ pub struct TotalsPerBankTransactionCode51 {
 
 impl Validate for TotalsPerBankTransactionCode51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -10609,6 +10707,7 @@ pub struct TrackData11 {
 
 impl Validate for TrackData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.trck_nb {
             helpers::validate_pattern(
                 val,
@@ -10670,6 +10769,7 @@ pub struct TransactionAgents51 {
 
 impl Validate for TransactionAgents51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.instg_agt
             && config.validate_optional_fields
         {
@@ -10781,6 +10881,7 @@ pub struct TransactionDates31 {
 
 impl Validate for TransactionDates31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.accptnc_dt_tm {
             helpers::validate_pattern(
                 val,
@@ -10840,6 +10941,7 @@ pub struct TransactionIdentifier11 {
 
 impl Validate for TransactionIdentifier11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.tx_dt_tm,
             "TxDtTm",
@@ -10879,6 +10981,7 @@ pub struct TransactionInterest41 {
 
 impl Validate for TransactionInterest41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_intrst_and_tax_amt
             && config.validate_optional_fields
         {
@@ -10923,6 +11026,7 @@ pub struct TransactionParties61 {
 
 impl Validate for TransactionParties61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.initg_pty
             && config.validate_optional_fields
         {
@@ -10984,6 +11088,7 @@ pub struct TransactionPrice4Choice1 {
 
 impl Validate for TransactionPrice4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.deal_pric
             && config.validate_optional_fields
         {
@@ -11012,6 +11117,7 @@ pub struct TransactionQuantities3Choice1 {
 
 impl Validate for TransactionQuantities3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.qty
             && config.validate_optional_fields
         {
@@ -11071,6 +11177,7 @@ pub struct TransactionReferences61 {
 
 impl Validate for TransactionReferences61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_id {
             helpers::validate_length(
                 val,
@@ -11473,6 +11580,7 @@ pub struct YieldedOrValueType1Choice {
 
 impl Validate for YieldedOrValueType1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.val_tp
             && config.validate_optional_fields
         {
@@ -16,6 +16,7 @@
 //
 // You may obtain a copy of this library at
 // https://github.com/GoPlasmatic/MXMessage
+use crate::identifiers::{Bic, CurrencyCode, Iban};
 use crate::parse_result::{ErrorCollector, ParserConfig};
 use crate::validation::{Validate, helpers};
 use serde::{Deserialize, Serialize};
@@ -24,22 +25,16 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AccountIdentification4Choice1 {
     #[serde(rename = "IBAN", skip_serializing_if = "Option::is_none")]
-    pub iban: Option<String>,
+    pub iban: Option<Iban>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
     pub othr: Option<GenericAccountIdentification11>,
 }
 
 impl Validate for AccountIdentification4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.iban {
-            helpers::validate_pattern(
-                val,
-                "IBAN",
-                "[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}",
-                &helpers::child_path(path, "IBAN"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "IBAN"), config, collector);
         }
         if let Some(ref val) = self.othr
             && config.validate_optional_fields
@@ -66,6 +61,7 @@ pub struct AccountInterest41 {
 
 impl Validate for AccountInterest41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -149,6 +145,7 @@ pub struct AccountNotification171 {
 
 impl Validate for AccountNotification171 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -258,6 +255,7 @@ pub struct AccountSchemeName1Choice1 {
 
 impl Validate for AccountSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -297,13 +295,17 @@ impl Validate for AccountSchemeName1Choice1 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ActiveCurrencyAndAmount {
     #[serde(rename = "@Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "$value")]
     pub value: f64,
 }
 
 impl Validate for ActiveCurrencyAndAmount {
-    fn validate(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector) {}
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        self.ccy
+            .validate_active(&helpers::child_path(path, "Ccy"), config, collector);
+    }
 }
 
 // ActiveOrHistoricCurrencyAnd13DecimalAmount: A number of monetary units specified in an active or a historic currency where the unit of currency is explicit and compliant with ISO 4217. The number of fractional digits (or minor unit of currency) is not checked as per ISO 4217: It must be lesser than or equal to 13.
@@ -311,26 +313,34 @@ impl Validate for ActiveCurrencyAndAmount {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ActiveOrHistoricCurrencyAnd13DecimalAmount {
     #[serde(rename = "@Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "$value")]
     pub value: f64,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAnd13DecimalAmount {
-    fn validate(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector) {}
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
+    }
 }
 
 // ActiveOrHistoricCurrencyAndAmount: A number of monetary units specified in an active or a historic currency where the unit of currency is explicit and compliant with ISO 4217.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ActiveOrHistoricCurrencyAndAmount {
     #[serde(rename = "@Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "$value")]
     pub value: f64,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAndAmount {
-    fn validate(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector) {}
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
+    }
 }
 
 // ActiveOrHistoricCurrencyAndAmountRange2: Medium of exchange of value, used to qualify an amount.
@@ -341,11 +351,12 @@ pub struct ActiveOrHistoricCurrencyAndAmountRange2 {
     #[serde(rename = "CdtDbtInd", skip_serializing_if = "Option::is_none")]
     pub cdt_dbt_ind: Option<CreditDebitCode>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAndAmountRange2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -353,14 +364,8 @@ impl Validate for ActiveOrHistoricCurrencyAndAmountRange2 {
         {
             val.validate(&helpers::child_path(path, "CdtDbtInd"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
     }
 }
 
@@ -399,6 +404,7 @@ pub struct AddressType3Choice {
 
 impl Validate for AddressType3Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -423,6 +429,7 @@ pub struct AddressType3Choice1 {
 
 impl Validate for AddressType3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -453,6 +460,7 @@ pub struct AmountAndCurrencyExchange31 {
 
 impl Validate for AmountAndCurrencyExchange31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.instd_amt
             && config.validate_optional_fields
         {
@@ -498,6 +506,7 @@ pub struct AmountAndCurrencyExchangeDetails31 {
 
 impl Validate for AmountAndCurrencyExchangeDetails31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.ccy_xchg
@@ -521,6 +530,7 @@ pub struct AmountAndCurrencyExchangeDetails41 {
 
 impl Validate for AmountAndCurrencyExchangeDetails41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -559,6 +569,7 @@ pub struct AmountAndDirection35 {
 
 impl Validate for AmountAndDirection35 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cdt_dbt_ind
             .validate(&helpers::child_path(path, "CdtDbtInd"), config, collector);
     }
@@ -658,6 +669,7 @@ pub struct BankToCustomerDebitCreditNotificationV08 {
 
 impl Validate for BankToCustomerDebitCreditNotificationV08 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.grp_hdr
             .validate(&helpers::child_path(path, "GrpHdr"), config, collector);
         for item in &self.ntfctn {
@@ -677,6 +689,7 @@ pub struct BankTransactionCodeStructure41 {
 
 impl Validate for BankTransactionCodeStructure41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.domn
             && config.validate_optional_fields
         {
@@ -701,6 +714,7 @@ pub struct BankTransactionCodeStructure5 {
 
 impl Validate for BankTransactionCodeStructure5 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -726,6 +740,7 @@ pub struct BankTransactionCodeStructure6 {
 
 impl Validate for BankTransactionCodeStructure6 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -764,6 +779,7 @@ pub struct BatchInformation21 {
 
 impl Validate for BatchInformation21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_id {
             helpers::validate_length(
                 val,
@@ -842,6 +858,7 @@ pub struct BranchAndFinancialInstitutionIdentification61 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -865,6 +882,7 @@ pub struct BranchAndFinancialInstitutionIdentification62 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification62 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -884,6 +902,7 @@ pub struct BranchAndFinancialInstitutionIdentification63 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification63 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
     }
@@ -902,6 +921,7 @@ pub struct BranchAndFinancialInstitutionIdentification64 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification64 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -925,6 +945,7 @@ pub struct BranchAndFinancialInstitutionIdentification65 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification65 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -948,6 +969,7 @@ pub struct BranchAndFinancialInstitutionIdentification66 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification66 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -971,6 +993,7 @@ pub struct BranchAndFinancialInstitutionIdentification67 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification67 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
         if let Some(ref val) = self.brnch_id
@@ -996,6 +1019,7 @@ pub struct BranchData31 {
 
 impl Validate for BranchData31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1071,6 +1095,7 @@ pub struct BranchData32 {
 
 impl Validate for BranchData32 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1146,6 +1171,7 @@ pub struct BranchData33 {
 
 impl Validate for BranchData33 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1201,6 +1227,7 @@ pub struct BranchData34 {
 
 impl Validate for BranchData34 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -1288,6 +1315,7 @@ pub struct CardAggregated21 {
 
 impl Validate for CardAggregated21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.addtl_svc
             && config.validate_optional_fields
         {
@@ -1381,6 +1409,7 @@ pub struct CardEntry41 {
 
 impl Validate for CardEntry41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.card
             && config.validate_optional_fields
         {
@@ -1437,6 +1466,7 @@ pub struct CardIndividualTransaction21 {
 
 impl Validate for CardIndividualTransaction21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.icc_rltd_data {
             helpers::validate_length(
                 val,
@@ -1632,6 +1662,7 @@ pub struct CardSecurityInformation1 {
 
 impl Validate for CardSecurityInformation1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.csc_mgmt
             .validate(&helpers::child_path(path, "CSCMgmt"), config, collector);
         if let Some(ref val) = self.csc_val {
@@ -1658,6 +1689,7 @@ pub struct CardSequenceNumberRange11 {
 
 impl Validate for CardSequenceNumberRange11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.frst_tx {
             helpers::validate_length(
                 val,
@@ -1718,6 +1750,7 @@ pub struct CardTransaction171 {
 
 impl Validate for CardTransaction171 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.card
             && config.validate_optional_fields
         {
@@ -1752,6 +1785,7 @@ pub struct CardTransaction3Choice1 {
 
 impl Validate for CardTransaction3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.aggtd
             && config.validate_optional_fields
         {
@@ -1776,6 +1810,7 @@ pub struct CardholderAuthentication2 {
 
 impl Validate for CardholderAuthentication2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.authntcn_mtd
             .validate(&helpers::child_path(path, "AuthntcnMtd"), config, collector);
         self.authntcn_ntty.validate(
@@ -1830,7 +1865,7 @@ pub struct CashAccount381 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -1839,6 +1874,7 @@ pub struct CashAccount381 {
 
 impl Validate for CashAccount381 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -1846,14 +1882,8 @@ impl Validate for CashAccount381 {
         {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -1891,7 +1921,7 @@ pub struct CashAccount382 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy", skip_serializing_if = "Option::is_none")]
-    pub ccy: Option<String>,
+    pub ccy: Option<CurrencyCode>,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -1900,6 +1930,7 @@ pub struct CashAccount382 {
 
 impl Validate for CashAccount382 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -1908,14 +1939,7 @@ impl Validate for CashAccount382 {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
         if let Some(ref val) = self.ccy {
-            helpers::validate_pattern(
-                val,
-                "Ccy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "Ccy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "Ccy"), config, collector);
         }
         if let Some(ref val) = self.nm {
             helpers::validate_length(
@@ -1954,7 +1978,7 @@ pub struct CashAccount383 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy", skip_serializing_if = "Option::is_none")]
-    pub ccy: Option<String>,
+    pub ccy: Option<CurrencyCode>,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -1963,6 +1987,7 @@ pub struct CashAccount383 {
 
 impl Validate for CashAccount383 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -1971,14 +1996,7 @@ impl Validate for CashAccount383 {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
         if let Some(ref val) = self.ccy {
-            helpers::validate_pattern(
-                val,
-                "Ccy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "Ccy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "Ccy"), config, collector);
         }
         if let Some(ref val) = self.nm {
             helpers::validate_length(
@@ -2017,7 +2035,7 @@ pub struct CashAccount384 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy", skip_serializing_if = "Option::is_none")]
-    pub ccy: Option<String>,
+    pub ccy: Option<CurrencyCode>,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -2026,6 +2044,7 @@ pub struct CashAccount384 {
 
 impl Validate for CashAccount384 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -2034,14 +2053,7 @@ impl Validate for CashAccount384 {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
         if let Some(ref val) = self.ccy {
-            helpers::validate_pattern(
-                val,
-                "Ccy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "Ccy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "Ccy"), config, collector);
         }
         if let Some(ref val) = self.nm {
             helpers::validate_length(
@@ -2080,7 +2092,7 @@ pub struct CashAccount391 {
     #[serde(rename = "Tp", skip_serializing_if = "Option::is_none")]
     pub tp: Option<CashAccountType2Choice1>,
     #[serde(rename = "Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "Nm", skip_serializing_if = "Option::is_none")]
     pub nm: Option<String>,
     #[serde(rename = "Prxy", skip_serializing_if = "Option::is_none")]
@@ -2093,6 +2105,7 @@ pub struct CashAccount391 {
 
 impl Validate for CashAccount391 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.tp
@@ -2100,14 +2113,8 @@ impl Validate for CashAccount391 {
         {
             val.validate(&helpers::child_path(path, "Tp"), config, collector);
         }
-        helpers::validate_pattern(
-            &self.ccy,
-            "Ccy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "Ccy"),
-            config,
-            collector,
-        );
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2158,6 +2165,7 @@ pub struct CashAccountType2Choice1 {
 
 impl Validate for CashAccountType2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2207,6 +2215,7 @@ pub struct CashAvailability1 {
 
 impl Validate for CashAvailability1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.dt
             .validate(&helpers::child_path(path, "Dt"), config, collector);
         self.amt
@@ -2227,6 +2236,7 @@ pub struct CashAvailabilityDate1Choice {
 
 impl Validate for CashAvailabilityDate1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_days {
             helpers::validate_pattern(
                 val,
@@ -2253,6 +2263,7 @@ pub struct CashDeposit1 {
 
 impl Validate for CashDeposit1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.note_dnmtn
             .validate(&helpers::child_path(path, "NoteDnmtn"), config, collector);
         helpers::validate_pattern(
@@ -2299,6 +2310,7 @@ pub struct ChargeType3Choice1 {
 
 impl Validate for ChargeType3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2329,6 +2341,7 @@ pub struct Charges61 {
 
 impl Validate for Charges61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_chrgs_and_tax_amt
             && config.validate_optional_fields
         {
@@ -2359,6 +2372,7 @@ pub struct Charges62 {
 
 impl Validate for Charges62 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_chrgs_and_tax_amt
             && config.validate_optional_fields
         {
@@ -2401,6 +2415,7 @@ pub struct ChargesRecord31 {
 
 impl Validate for ChargesRecord31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -2454,6 +2469,7 @@ pub struct ChargesRecord32 {
 
 impl Validate for ChargesRecord32 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -2495,6 +2511,7 @@ pub struct ClearingSystemIdentification2Choice {
 
 impl Validate for ClearingSystemIdentification2Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2529,6 +2546,7 @@ pub struct ClearingSystemIdentification2Choice1 {
 
 impl Validate for ClearingSystemIdentification2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2554,6 +2572,7 @@ pub struct ClearingSystemIdentification2Choice2 {
 
 impl Validate for ClearingSystemIdentification2Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -2600,6 +2619,7 @@ pub struct ClearingSystemMemberIdentification2 {
 
 impl Validate for ClearingSystemMemberIdentification2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.clr_sys_id
             && config.validate_optional_fields
         {
@@ -2628,6 +2648,7 @@ pub struct ClearingSystemMemberIdentification21 {
 
 impl Validate for ClearingSystemMemberIdentification21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.clr_sys_id
             .validate(&helpers::child_path(path, "ClrSysId"), config, collector);
         helpers::validate_length(
@@ -2661,6 +2682,7 @@ pub struct ClearingSystemMemberIdentification22 {
 
 impl Validate for ClearingSystemMemberIdentification22 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.clr_sys_id
             .validate(&helpers::child_path(path, "ClrSysId"), config, collector);
         helpers::validate_length(
@@ -2692,6 +2714,7 @@ pub struct Contact41 {
 
 impl Validate for Contact41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2727,6 +2750,7 @@ pub struct Contact42 {
 
 impl Validate for Contact42 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2765,6 +2789,7 @@ pub struct Contact43 {
 
 impl Validate for Contact43 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -2808,6 +2833,7 @@ pub struct CorporateAction91 {
 
 impl Validate for CorporateAction91 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.evt_tp,
             "EvtTp",
@@ -2876,6 +2902,7 @@ pub struct CreditorReferenceInformation21 {
 
 impl Validate for CreditorReferenceInformation21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -2916,6 +2943,7 @@ pub struct CreditorReferenceType1Choice1 {
 
 impl Validate for CreditorReferenceType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -2956,6 +2984,7 @@ pub struct CreditorReferenceType21 {
 
 impl Validate for CreditorReferenceType21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -2986,9 +3015,9 @@ impl Validate for CreditorReferenceType21 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CurrencyExchange51 {
     #[serde(rename = "SrcCcy")]
-    pub src_ccy: String,
+    pub src_ccy: CurrencyCode,
     #[serde(rename = "TrgtCcy", skip_serializing_if = "Option::is_none")]
-    pub trgt_ccy: Option<String>,
+    pub trgt_ccy: Option<CurrencyCode>,
     #[serde(rename = "UnitCcy", skip_serializing_if = "Option::is_none")]
     pub unit_ccy: Option<String>,
     #[serde(rename = "XchgRate")]
@@ -3001,23 +3030,11 @@ pub struct CurrencyExchange51 {
 
 impl Validate for CurrencyExchange51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.src_ccy,
-            "SrcCcy",
-            "[A-Z]{3,3}",
-            &helpers::child_path(path, "SrcCcy"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.src_ccy
+            .validate(&helpers::child_path(path, "SrcCcy"), config, collector);
         if let Some(ref val) = self.trgt_ccy {
-            helpers::validate_pattern(
-                val,
-                "TrgtCcy",
-                "[A-Z]{3,3}",
-                &helpers::child_path(path, "TrgtCcy"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "TrgtCcy"), config, collector);
         }
         if let Some(ref val) = self.unit_ccy {
             helpers::validate_pattern(
@@ -3074,6 +3091,7 @@ pub struct DateAndDateTime2Choice1 {
 
 impl Validate for DateAndDateTime2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_tm {
             helpers::validate_pattern(
                 val,
@@ -3096,6 +3114,7 @@ pub struct DateAndDateTime2Choice2 {
 
 impl Validate for DateAndDateTime2Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_tm {
             helpers::validate_pattern(
                 val,
@@ -3124,6 +3143,7 @@ pub struct DateAndPlaceOfBirth11 {
 
 impl Validate for DateAndPlaceOfBirth11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prvc_of_birth {
             helpers::validate_length(
                 val,
@@ -3184,6 +3204,7 @@ pub struct DateOrDateTimePeriod1Choice1 {
 
 impl Validate for DateOrDateTimePeriod1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt
             && config.validate_optional_fields
         {
@@ -3221,6 +3242,7 @@ pub struct DateTimePeriod11 {
 
 impl Validate for DateTimePeriod11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.fr_dt_tm,
             "FrDtTm",
@@ -3251,6 +3273,7 @@ pub struct DiscountAmountAndType11 {
 
 impl Validate for DiscountAmountAndType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -3272,6 +3295,7 @@ pub struct DiscountAmountAndType12 {
 
 impl Validate for DiscountAmountAndType12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -3293,6 +3317,7 @@ pub struct DiscountAmountType1Choice1 {
 
 impl Validate for DiscountAmountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3339,6 +3364,7 @@ pub struct DiscountAmountType1Choice2 {
 
 impl Validate for DiscountAmountType1Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3387,6 +3413,7 @@ pub struct DisplayCapabilities1 {
 
 impl Validate for DisplayCapabilities1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.disp_tp
             .validate(&helpers::child_path(path, "DispTp"), config, collector);
         helpers::validate_pattern(
@@ -3423,6 +3450,7 @@ pub struct DocumentAdjustment11 {
 
 impl Validate for DocumentAdjustment11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         if let Some(ref val) = self.cdt_dbt_ind
@@ -3488,6 +3516,7 @@ pub struct DocumentLineIdentification11 {
 
 impl Validate for DocumentLineIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -3530,6 +3559,7 @@ pub struct DocumentLineInformation11 {
 
 impl Validate for DocumentLineInformation11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         for item in &self.id {
             item.validate(&helpers::child_path(path, "Id"), config, collector);
         }
@@ -3573,6 +3603,7 @@ pub struct DocumentLineType1Choice1 {
 
 impl Validate for DocumentLineType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3619,6 +3650,7 @@ pub struct DocumentLineType11 {
 
 impl Validate for DocumentLineType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -3724,6 +3756,7 @@ pub struct EntryDetails91 {
 
 impl Validate for EntryDetails91 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.btch
             && config.validate_optional_fields
         {
@@ -3748,6 +3781,7 @@ pub struct EntryStatus1Choice1 {
 
 impl Validate for EntryStatus1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -3819,6 +3853,7 @@ pub struct EntryTransaction101 {
 
 impl Validate for EntryTransaction101 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.refs
             .validate(&helpers::child_path(path, "Refs"), config, collector);
         self.amt
@@ -3970,6 +4005,7 @@ pub struct FinancialIdentificationSchemeName1Choice1 {
 
 impl Validate for FinancialIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -4009,7 +4045,7 @@ impl Validate for FinancialIdentificationSchemeName1Choice1 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification181 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -4024,15 +4060,9 @@ pub struct FinancialInstitutionIdentification181 {
 
 impl Validate for FinancialInstitutionIdentification181 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -4087,7 +4117,7 @@ impl Validate for FinancialInstitutionIdentification181 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification182 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification22>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -4102,15 +4132,9 @@ pub struct FinancialInstitutionIdentification182 {
 
 impl Validate for FinancialInstitutionIdentification182 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -4165,7 +4189,7 @@ impl Validate for FinancialInstitutionIdentification182 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification183 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification22>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -4178,15 +4202,9 @@ pub struct FinancialInstitutionIdentification183 {
 
 impl Validate for FinancialInstitutionIdentification183 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -4236,7 +4254,7 @@ impl Validate for FinancialInstitutionIdentification183 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification184 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -4251,15 +4269,9 @@ pub struct FinancialInstitutionIdentification184 {
 
 impl Validate for FinancialInstitutionIdentification184 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -4314,7 +4326,7 @@ impl Validate for FinancialInstitutionIdentification184 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification185 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification2>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -4329,15 +4341,9 @@ pub struct FinancialInstitutionIdentification185 {
 
 impl Validate for FinancialInstitutionIdentification185 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -4392,7 +4398,7 @@ impl Validate for FinancialInstitutionIdentification185 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification186 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -4407,15 +4413,9 @@ pub struct FinancialInstitutionIdentification186 {
 
 impl Validate for FinancialInstitutionIdentification186 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -4492,6 +4492,7 @@ pub struct FromToAmountRange1 {
 
 impl Validate for FromToAmountRange1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fr_amt
             .validate(&helpers::child_path(path, "FrAmt"), config, collector);
         self.to_amt
@@ -4522,6 +4523,7 @@ pub struct Garnishment31 {
 
 impl Validate for Garnishment31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         if let Some(ref val) = self.grnshee
@@ -4578,6 +4580,7 @@ pub struct GarnishmentType1Choice1 {
 
 impl Validate for GarnishmentType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -4624,6 +4627,7 @@ pub struct GarnishmentType11 {
 
 impl Validate for GarnishmentType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -4663,6 +4667,7 @@ pub struct GenericAccountIdentification11 {
 
 impl Validate for GenericAccountIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4722,6 +4727,7 @@ pub struct GenericFinancialIdentification11 {
 
 impl Validate for GenericFinancialIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4781,6 +4787,7 @@ pub struct GenericIdentification11 {
 
 impl Validate for GenericIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -4856,6 +4863,7 @@ pub struct GenericIdentification30 {
 
 impl Validate for GenericIdentification30 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.id,
             "Id",
@@ -4900,6 +4908,7 @@ pub struct GenericIdentification301 {
 
 impl Validate for GenericIdentification301 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.id,
             "Id",
@@ -4962,6 +4971,7 @@ pub struct GenericIdentification302 {
 
 impl Validate for GenericIdentification302 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.id,
             "Id",
@@ -5026,6 +5036,7 @@ pub struct GenericIdentification321 {
 
 impl Validate for GenericIdentification321 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5088,6 +5099,7 @@ pub struct GenericIdentification31 {
 
 impl Validate for GenericIdentification31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5142,6 +5154,7 @@ pub struct GenericOrganisationIdentification11 {
 
 impl Validate for GenericOrganisationIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5201,6 +5214,7 @@ pub struct GenericOrganisationIdentification12 {
 
 impl Validate for GenericOrganisationIdentification12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5257,6 +5271,7 @@ pub struct GenericOrganisationIdentification13 {
 
 impl Validate for GenericOrganisationIdentification13 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5316,6 +5331,7 @@ pub struct GenericPersonIdentification11 {
 
 impl Validate for GenericPersonIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5375,6 +5391,7 @@ pub struct GenericPersonIdentification12 {
 
 impl Validate for GenericPersonIdentification12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5431,6 +5448,7 @@ pub struct GenericPersonIdentification13 {
 
 impl Validate for GenericPersonIdentification13 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -5494,6 +5512,7 @@ pub struct GroupHeader811 {
 
 impl Validate for GroupHeader811 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -5564,6 +5583,7 @@ pub struct IdentificationSource3Choice1 {
 
 impl Validate for IdentificationSource3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -5616,6 +5636,7 @@ pub struct ImpliedCurrencyAmountRange1Choice {
 
 impl Validate for ImpliedCurrencyAmountRange1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.fr_amt
             && config.validate_optional_fields
         {
@@ -5655,6 +5676,7 @@ pub struct InterestRecord21 {
 
 impl Validate for InterestRecord21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.amt
             .validate(&helpers::child_path(path, "Amt"), config, collector);
         self.cdt_dbt_ind
@@ -5714,6 +5736,7 @@ pub struct InterestType1Choice1 {
 
 impl Validate for InterestType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -5770,6 +5793,7 @@ pub struct LocalInstrument2Choice1 {
 
 impl Validate for LocalInstrument2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -5816,6 +5840,7 @@ pub struct MessageIdentification21 {
 
 impl Validate for MessageIdentification21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_nm_id {
             helpers::validate_length(
                 val,
@@ -5872,6 +5897,7 @@ pub struct NameAndAddress161 {
 
 impl Validate for NameAndAddress161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.nm,
             "Nm",
@@ -5905,6 +5931,7 @@ pub struct NumberAndSumOfTransactions1 {
 
 impl Validate for NumberAndSumOfTransactions1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -5931,6 +5958,7 @@ pub struct NumberAndSumOfTransactions4 {
 
 impl Validate for NumberAndSumOfTransactions4 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -5971,7 +5999,7 @@ impl Validate for OnLineCapability1Code {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification291 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -5980,15 +6008,9 @@ pub struct OrganisationIdentification291 {
 
 impl Validate for OrganisationIdentification291 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -6014,7 +6036,7 @@ impl Validate for OrganisationIdentification291 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification292 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -6023,15 +6045,9 @@ pub struct OrganisationIdentification292 {
 
 impl Validate for OrganisationIdentification292 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -6057,7 +6073,7 @@ impl Validate for OrganisationIdentification292 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification293 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -6066,15 +6082,9 @@ pub struct OrganisationIdentification293 {
 
 impl Validate for OrganisationIdentification293 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -6100,7 +6110,7 @@ impl Validate for OrganisationIdentification293 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification294 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -6109,15 +6119,9 @@ pub struct OrganisationIdentification294 {
 
 impl Validate for OrganisationIdentification294 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -6150,6 +6154,7 @@ pub struct OrganisationIdentificationSchemeName1Choice1 {
 
 impl Validate for OrganisationIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -6196,6 +6201,7 @@ pub struct OrganisationIdentificationSchemeName1Choice2 {
 
 impl Validate for OrganisationIdentificationSchemeName1Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -6257,6 +6263,7 @@ pub struct OriginalBusinessQuery11 {
 
 impl Validate for OriginalBusinessQuery11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.msg_id,
             "MsgId",
@@ -6321,6 +6328,7 @@ pub struct OtherIdentification11 {
 
 impl Validate for OtherIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -6401,6 +6409,7 @@ pub struct Pagination1 {
 
 impl Validate for Pagination1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.pg_nb,
             "PgNb",
@@ -6423,6 +6432,7 @@ pub struct Party38Choice1 {
 
 impl Validate for Party38Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -6447,6 +6457,7 @@ pub struct Party38Choice2 {
 
 impl Validate for Party38Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -6471,6 +6482,7 @@ pub struct Party38Choice3 {
 
 impl Validate for Party38Choice3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -6495,6 +6507,7 @@ pub struct Party38Choice4 {
 
 impl Validate for Party38Choice4 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -6519,6 +6532,7 @@ pub struct Party40Choice1 {
 
 impl Validate for Party40Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -6543,6 +6557,7 @@ pub struct Party40Choice2 {
 
 impl Validate for Party40Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -6567,6 +6582,7 @@ pub struct Party40Choice3 {
 
 impl Validate for Party40Choice3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -6591,6 +6607,7 @@ pub struct Party40Choice4 {
 
 impl Validate for Party40Choice4 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -6615,6 +6632,7 @@ pub struct Party40Choice5 {
 
 impl Validate for Party40Choice5 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -6643,6 +6661,7 @@ pub struct PartyIdentification1351 {
 
 impl Validate for PartyIdentification1351 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6699,6 +6718,7 @@ pub struct PartyIdentification13510 {
 
 impl Validate for PartyIdentification13510 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6763,6 +6783,7 @@ pub struct PartyIdentification1352 {
 
 impl Validate for PartyIdentification1352 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6822,6 +6843,7 @@ pub struct PartyIdentification1353 {
 
 impl Validate for PartyIdentification1353 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6883,6 +6905,7 @@ pub struct PartyIdentification1354 {
 
 impl Validate for PartyIdentification1354 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -6949,6 +6972,7 @@ pub struct PartyIdentification1355 {
 
 impl Validate for PartyIdentification1355 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -7015,6 +7039,7 @@ pub struct PartyIdentification1356 {
 
 impl Validate for PartyIdentification1356 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -7081,6 +7106,7 @@ pub struct PartyIdentification1357 {
 
 impl Validate for PartyIdentification1357 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -7147,6 +7173,7 @@ pub struct PartyIdentification1358 {
 
 impl Validate for PartyIdentification1358 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -7213,6 +7240,7 @@ pub struct PartyIdentification1359 {
 
 impl Validate for PartyIdentification1359 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -7327,6 +7355,7 @@ pub struct PaymentCard41 {
 
 impl Validate for PaymentCard41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.plain_card_data
             && config.validate_optional_fields
         {
@@ -7404,6 +7433,7 @@ pub struct PaymentContext3 {
 
 impl Validate for PaymentContext3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.attndnc_cntxt
             && config.validate_optional_fields
         {
@@ -7461,6 +7491,7 @@ pub struct PaymentReturnReason51 {
 
 impl Validate for PaymentReturnReason51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.orgnl_bk_tx_cd
             && config.validate_optional_fields
         {
@@ -7515,6 +7546,7 @@ pub struct PersonIdentification131 {
 
 impl Validate for PersonIdentification131 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -7545,6 +7577,7 @@ pub struct PersonIdentification132 {
 
 impl Validate for PersonIdentification132 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -7575,6 +7608,7 @@ pub struct PersonIdentification133 {
 
 impl Validate for PersonIdentification133 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -7605,6 +7639,7 @@ pub struct PersonIdentification134 {
 
 impl Validate for PersonIdentification134 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -7635,6 +7670,7 @@ pub struct PersonIdentificationSchemeName1Choice1 {
 
 impl Validate for PersonIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -7681,6 +7717,7 @@ pub struct PersonIdentificationSchemeName1Choice2 {
 
 impl Validate for PersonIdentificationSchemeName1Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -7737,6 +7774,7 @@ pub struct PlainCardData11 {
 
 impl Validate for PlainCardData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.pan,
             "PAN",
@@ -7797,6 +7835,7 @@ pub struct PointOfInteraction11 {
 
 impl Validate for PointOfInteraction11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.id
             .validate(&helpers::child_path(path, "Id"), config, collector);
         if let Some(ref val) = self.sys_nm {
@@ -7876,6 +7915,7 @@ pub struct PointOfInteractionCapabilities1 {
 
 impl Validate for PointOfInteractionCapabilities1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref vec) = self.card_rdng_cpblties
             && config.validate_optional_fields
         {
@@ -7952,6 +7992,7 @@ pub struct PointOfInteractionComponent11 {
 
 impl Validate for PointOfInteractionComponent11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.poi_cmpnt_tp
             .validate(&helpers::child_path(path, "POICmpntTp"), config, collector);
         if let Some(ref val) = self.manfctr_id {
@@ -8105,6 +8146,7 @@ pub struct PostalAddress241 {
 
 impl Validate for PostalAddress241 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.adr_tp
             && config.validate_optional_fields
         {
@@ -8460,6 +8502,7 @@ pub struct PostalAddress242 {
 
 impl Validate for PostalAddress242 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.adr_tp
             && config.validate_optional_fields
         {
@@ -8813,6 +8856,7 @@ pub struct PostalAddress243 {
 
 impl Validate for PostalAddress243 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dept {
             helpers::validate_length(
                 val,
@@ -9163,6 +9207,7 @@ pub struct PostalAddress244 {
 
 impl Validate for PostalAddress244 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.adr_tp
             && config.validate_optional_fields
         {
@@ -9382,6 +9427,7 @@ pub struct Price7 {
 
 impl Validate for Price7 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         self.val
@@ -9400,6 +9446,7 @@ pub struct PriceRateOrAmount3Choice {
 
 impl Validate for PriceRateOrAmount3Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.amt
             && config.validate_optional_fields
         {
@@ -9447,6 +9494,7 @@ pub struct Product21 {
 
 impl Validate for Product21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.pdct_cd,
             "PdctCd",
@@ -9525,6 +9573,7 @@ pub struct ProprietaryAgent41 {
 
 impl Validate for ProprietaryAgent41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -9558,6 +9607,7 @@ pub struct ProprietaryBankTransactionCodeStructure11 {
 
 impl Validate for ProprietaryBankTransactionCodeStructure11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.cd,
             "Cd",
@@ -9606,6 +9656,7 @@ pub struct ProprietaryDate31 {
 
 impl Validate for ProprietaryDate31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -9639,6 +9690,7 @@ pub struct ProprietaryParty51 {
 
 impl Validate for ProprietaryParty51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -9672,6 +9724,7 @@ pub struct ProprietaryPrice21 {
 
 impl Validate for ProprietaryPrice21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -9705,6 +9758,7 @@ pub struct ProprietaryQuantity11 {
 
 impl Validate for ProprietaryQuantity11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -9753,6 +9807,7 @@ pub struct ProprietaryReference11 {
 
 impl Validate for ProprietaryReference11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.tp,
             "Tp",
@@ -9801,6 +9856,7 @@ pub struct ProxyAccountIdentification11 {
 
 impl Validate for ProxyAccountIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -9837,6 +9893,7 @@ pub struct ProxyAccountIdentification12 {
 
 impl Validate for ProxyAccountIdentification12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -9865,6 +9922,7 @@ pub struct ProxyAccountIdentification13 {
 
 impl Validate for ProxyAccountIdentification13 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -9901,6 +9959,7 @@ pub struct ProxyAccountType1Choice {
 
 impl Validate for ProxyAccountType1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -9937,6 +9996,7 @@ pub struct ProxyAccountType1Choice1 {
 
 impl Validate for ProxyAccountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -9983,6 +10043,7 @@ pub struct Purpose2Choice1 {
 
 impl Validate for Purpose2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -10029,6 +10090,7 @@ pub struct Rate41 {
 
 impl Validate for Rate41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.tp
             .validate(&helpers::child_path(path, "Tp"), config, collector);
         if let Some(ref val) = self.vldty_rg
@@ -10050,6 +10112,7 @@ pub struct RateType4Choice1 {
 
 impl Validate for RateType4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.othr {
             helpers::validate_length(
                 val,
@@ -10089,6 +10152,7 @@ pub struct ReferredDocumentInformation71 {
 
 impl Validate for ReferredDocumentInformation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -10136,6 +10200,7 @@ pub struct ReferredDocumentType3Choice1 {
 
 impl Validate for ReferredDocumentType3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -10176,6 +10241,7 @@ pub struct ReferredDocumentType41 {
 
 impl Validate for ReferredDocumentType41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.cd_or_prtry
             .validate(&helpers::child_path(path, "CdOrPrtry"), config, collector);
         if let Some(ref val) = self.issr {
@@ -10221,6 +10287,7 @@ pub struct RemittanceAmount21 {
 
 impl Validate for RemittanceAmount21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.due_pybl_amt
             && config.validate_optional_fields
         {
@@ -10287,6 +10354,7 @@ pub struct RemittanceAmount31 {
 
 impl Validate for RemittanceAmount31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.due_pybl_amt
             && config.validate_optional_fields
         {
@@ -10345,6 +10413,7 @@ pub struct RemittanceInformation161 {
 
 impl Validate for RemittanceInformation161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ustrd {
             helpers::validate_length(
                 val,
@@ -10387,6 +10456,7 @@ pub struct RemittanceLocation71 {
 
 impl Validate for RemittanceLocation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.rmt_id {
             helpers::validate_length(
                 val,
@@ -10431,6 +10501,7 @@ pub struct RemittanceLocationData11 {
 
 impl Validate for RemittanceLocationData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.mtd
             .validate(&helpers::child_path(path, "Mtd"), config, collector);
         if let Some(ref val) = self.elctrnc_adr {
@@ -10531,6 +10602,7 @@ pub struct ReportEntry101 {
 
 impl Validate for ReportEntry101 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.ntry_ref,
             "NtryRef",
@@ -10660,6 +10732,7 @@ pub struct ReportingSource1Choice1 {
 
 impl Validate for ReportingSource1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -10706,6 +10779,7 @@ pub struct ReturnReason5Choice1 {
 
 impl Validate for ReturnReason5Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -10754,6 +10828,7 @@ pub struct SecuritiesAccount191 {
 
 impl Validate for SecuritiesAccount191 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -10813,6 +10888,7 @@ pub struct SecurityIdentification191 {
 
 impl Validate for SecurityIdentification191 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.isin {
             helpers::validate_pattern(
                 val,
@@ -10871,6 +10947,7 @@ pub struct SequenceRange1Choice1 {
 
 impl Validate for SequenceRange1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.fr_seq {
             helpers::validate_length(
                 val,
@@ -10984,6 +11061,7 @@ pub struct SequenceRange11 {
 
 impl Validate for SequenceRange11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.fr_seq,
             "FrSeq",
@@ -11044,6 +11122,7 @@ pub struct StructuredRemittanceInformation161 {
 
 impl Validate for StructuredRemittanceInformation161 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref vec) = self.rfrd_doc_inf
             && config.validate_optional_fields
         {
@@ -11124,6 +11203,7 @@ pub struct TaxAmount2 {
 
 impl Validate for TaxAmount2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.taxbl_base_amt
             && config.validate_optional_fields
         {
@@ -11159,6 +11239,7 @@ pub struct TaxAmountAndType11 {
 
 impl Validate for TaxAmountAndType11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -11180,6 +11261,7 @@ pub struct TaxAmountType1Choice1 {
 
 impl Validate for TaxAmountType1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -11226,6 +11308,7 @@ pub struct TaxAuthorisation11 {
 
 impl Validate for TaxAuthorisation11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.titl {
             helpers::validate_length(
                 val,
@@ -11282,6 +11365,7 @@ pub struct TaxAuthorisation12 {
 
 impl Validate for TaxAuthorisation12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.titl {
             helpers::validate_length(
                 val,
@@ -11340,6 +11424,7 @@ pub struct TaxCharges21 {
 
 impl Validate for TaxCharges21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.id {
             helpers::validate_length(
                 val,
@@ -11398,6 +11483,7 @@ pub struct TaxInformation71 {
 
 impl Validate for TaxInformation71 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cdtr
             && config.validate_optional_fields
         {
@@ -11527,6 +11613,7 @@ pub struct TaxInformation81 {
 
 impl Validate for TaxInformation81 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cdtr
             && config.validate_optional_fields
         {
@@ -11637,6 +11724,7 @@ pub struct TaxParty11 {
 
 impl Validate for TaxParty11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -11716,6 +11804,7 @@ pub struct TaxParty12 {
 
 impl Validate for TaxParty12 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -11797,6 +11886,7 @@ pub struct TaxParty21 {
 
 impl Validate for TaxParty21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -11883,6 +11973,7 @@ pub struct TaxParty22 {
 
 impl Validate for TaxParty22 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tax_id {
             helpers::validate_length(
                 val,
@@ -11967,6 +12058,7 @@ pub struct TaxPeriod2 {
 
 impl Validate for TaxPeriod2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp
             && config.validate_optional_fields
         {
@@ -12005,6 +12097,7 @@ pub struct TaxRecord21 {
 
 impl Validate for TaxRecord21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp {
             helpers::validate_length(
                 val,
@@ -12190,6 +12283,7 @@ pub struct TaxRecord22 {
 
 impl Validate for TaxRecord22 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.tp {
             helpers::validate_length(
                 val,
@@ -12361,6 +12455,7 @@ pub struct TaxRecordDetails2 {
 
 impl Validate for TaxRecordDetails2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prd
             && config.validate_optional_fields
         {
@@ -12430,6 +12525,7 @@ pub struct TechnicalInputChannel1Choice1 {
 
 impl Validate for TechnicalInputChannel1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -12480,6 +12576,7 @@ pub struct TotalTransactions61 {
 
 impl Validate for TotalTransactions61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_ntries
             && config.validate_optional_fields
         {
@@ -12542,6 +12639,7 @@ pub struct TotalsPerBankTransactionCode51 {
 
 impl Validate for TotalsPerBankTransactionCode51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nb_of_ntries {
             helpers::validate_pattern(
                 val,
@@ -12595,6 +12693,7 @@ pub struct TrackData11 {
 
 impl Validate for TrackData11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.trck_nb {
             helpers::validate_pattern(
                 val,
@@ -12656,6 +12755,7 @@ pub struct TransactionAgents51 {
 
 impl Validate for TransactionAgents51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.instg_agt
             && config.validate_optional_fields
         {
@@ -12767,6 +12867,7 @@ pub struct TransactionDates31 {
 
 impl Validate for TransactionDates31 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.accptnc_dt_tm {
             helpers::validate_pattern(
                 val,
@@ -12826,6 +12927,7 @@ pub struct TransactionIdentifier11 {
 
 impl Validate for TransactionIdentifier11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_pattern(
             &self.tx_dt_tm,
             "TxDtTm",
@@ -12865,6 +12967,7 @@ pub struct TransactionInterest41 {
 
 impl Validate for TransactionInterest41 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.ttl_intrst_and_tax_amt
             && config.validate_optional_fields
         {
@@ -12909,6 +13012,7 @@ pub struct TransactionParties61 {
 
 impl Validate for TransactionParties61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.initg_pty
             && config.validate_optional_fields
         {
@@ -12970,6 +13074,7 @@ pub struct TransactionPrice4Choice1 {
 
 impl Validate for TransactionPrice4Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.deal_pric
             && config.validate_optional_fields
         {
@@ -12998,6 +13103,7 @@ pub struct TransactionQuantities3Choice1 {
 
 impl Validate for TransactionQuantities3Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.qty
             && config.validate_optional_fields
         {
@@ -13057,6 +13163,7 @@ pub struct TransactionReferences61 {
 
 impl Validate for TransactionReferences61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.msg_id {
             helpers::validate_length(
                 val,
@@ -13455,6 +13562,7 @@ pub struct YieldedOrValueType1Choice {
 
 impl Validate for YieldedOrValueType1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.val_tp
             && config.validate_optional_fields
         {
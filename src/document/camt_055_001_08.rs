@@ -16,6 +16,7 @@
 //
 // You may obtain a copy of this library at
 // https://github.com/GoPlasmatic/MXMessage
+use crate::identifiers::{Bic, CurrencyCode};
 use crate::parse_result::{ErrorCollector, ParserConfig};
 use crate::validation::{Validate, helpers};
 use serde::{Deserialize, Serialize};
@@ -24,13 +25,17 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct ActiveOrHistoricCurrencyAndAmount {
     #[serde(rename = "@Ccy")]
-    pub ccy: String,
+    pub ccy: CurrencyCode,
     #[serde(rename = "$value")]
     pub value: f64,
 }
 
 impl Validate for ActiveOrHistoricCurrencyAndAmount {
-    fn validate(&self, _path: &str, _config: &ParserConfig, _collector: &mut ErrorCollector) {}
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        self.ccy
+            .validate(&helpers::child_path(path, "Ccy"), config, collector);
+    }
 }
 
 // BranchAndFinancialInstitutionIdentification61: Unique and unambiguous identification of a financial institution, as assigned under an internationally recognised or proprietary identification scheme.
@@ -42,6 +47,7 @@ pub struct BranchAndFinancialInstitutionIdentification61 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification61 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
     }
@@ -56,6 +62,7 @@ pub struct BranchAndFinancialInstitutionIdentification62 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification62 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
     }
@@ -70,6 +77,7 @@ pub struct BranchAndFinancialInstitutionIdentification63 {
 
 impl Validate for BranchAndFinancialInstitutionIdentification63 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.fin_instn_id
             .validate(&helpers::child_path(path, "FinInstnId"), config, collector);
     }
@@ -116,6 +124,7 @@ pub struct CancellationReason33Choice1 {
 
 impl Validate for CancellationReason33Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd
             && config.validate_optional_fields
         {
@@ -135,6 +144,7 @@ pub struct Case51 {
 
 impl Validate for Case51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -172,6 +182,7 @@ pub struct CaseAssignment51 {
 
 impl Validate for CaseAssignment51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -205,6 +216,7 @@ pub struct ClearingSystemIdentification2Choice1 {
 
 impl Validate for ClearingSystemIdentification2Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -230,6 +242,7 @@ pub struct ClearingSystemMemberIdentification21 {
 
 impl Validate for ClearingSystemMemberIdentification21 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.clr_sys_id
             .validate(&helpers::child_path(path, "ClrSysId"), config, collector);
         helpers::validate_length(
@@ -263,6 +276,7 @@ pub struct CustomerPaymentCancellationRequestV08 {
 
 impl Validate for CustomerPaymentCancellationRequestV08 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.assgnmt
             .validate(&helpers::child_path(path, "Assgnmt"), config, collector);
         self.undrlyg
@@ -298,6 +312,7 @@ pub struct DateAndPlaceOfBirth1 {
 
 impl Validate for DateAndPlaceOfBirth1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.prvc_of_birth {
             helpers::validate_length(
                 val,
@@ -333,7 +348,7 @@ impl Validate for DateAndPlaceOfBirth1 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification181 {
     #[serde(rename = "BICFI")]
-    pub bicfi: String,
+    pub bicfi: Bic,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -342,14 +357,9 @@ pub struct FinancialInstitutionIdentification181 {
 
 impl Validate for FinancialInstitutionIdentification181 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
-        helpers::validate_pattern(
-            &self.bicfi,
-            "BICFI",
-            "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-            &helpers::child_path(path, "BICFI"),
-            config,
-            collector,
-        );
+        let _span = helpers::validation_span::<Self>(path);
+        self.bicfi
+            .validate(&helpers::child_path(path, "BICFI"), config, collector);
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
         {
@@ -372,7 +382,7 @@ impl Validate for FinancialInstitutionIdentification181 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification182 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -381,15 +391,9 @@ pub struct FinancialInstitutionIdentification182 {
 
 impl Validate for FinancialInstitutionIdentification182 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -413,7 +417,7 @@ impl Validate for FinancialInstitutionIdentification182 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct FinancialInstitutionIdentification183 {
     #[serde(rename = "BICFI", skip_serializing_if = "Option::is_none")]
-    pub bicfi: Option<String>,
+    pub bicfi: Option<Bic>,
     #[serde(rename = "ClrSysMmbId", skip_serializing_if = "Option::is_none")]
     pub clr_sys_mmb_id: Option<ClearingSystemMemberIdentification21>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
@@ -426,15 +430,9 @@ pub struct FinancialInstitutionIdentification183 {
 
 impl Validate for FinancialInstitutionIdentification183 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.bicfi {
-            helpers::validate_pattern(
-                val,
-                "BICFI",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "BICFI"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "BICFI"), config, collector);
         }
         if let Some(ref val) = self.clr_sys_mmb_id
             && config.validate_optional_fields
@@ -493,6 +491,7 @@ pub struct GenericOrganisationIdentification1 {
 
 impl Validate for GenericOrganisationIdentification1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -534,6 +533,7 @@ pub struct GenericOrganisationIdentification11 {
 
 impl Validate for GenericOrganisationIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -575,6 +575,7 @@ pub struct GenericPersonIdentification1 {
 
 impl Validate for GenericPersonIdentification1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -616,6 +617,7 @@ pub struct GenericPersonIdentification11 {
 
 impl Validate for GenericPersonIdentification11 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.id,
             "Id",
@@ -645,7 +647,7 @@ impl Validate for GenericPersonIdentification11 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification291 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -654,15 +656,9 @@ pub struct OrganisationIdentification291 {
 
 impl Validate for OrganisationIdentification291 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -688,7 +684,7 @@ impl Validate for OrganisationIdentification291 {
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OrganisationIdentification292 {
     #[serde(rename = "AnyBIC", skip_serializing_if = "Option::is_none")]
-    pub any_bic: Option<String>,
+    pub any_bic: Option<Bic>,
     #[serde(rename = "LEI", skip_serializing_if = "Option::is_none")]
     pub lei: Option<String>,
     #[serde(rename = "Othr", skip_serializing_if = "Option::is_none")]
@@ -697,15 +693,9 @@ pub struct OrganisationIdentification292 {
 
 impl Validate for OrganisationIdentification292 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.any_bic {
-            helpers::validate_pattern(
-                val,
-                "AnyBIC",
-                "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}",
-                &helpers::child_path(path, "AnyBIC"),
-                config,
-                collector,
-            );
+            val.validate(&helpers::child_path(path, "AnyBIC"), config, collector);
         }
         if let Some(ref val) = self.lei {
             helpers::validate_pattern(
@@ -738,6 +728,7 @@ pub struct OrganisationIdentificationSchemeName1Choice {
 
 impl Validate for OrganisationIdentificationSchemeName1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -772,6 +763,7 @@ pub struct OrganisationIdentificationSchemeName1Choice1 {
 
 impl Validate for OrganisationIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -799,6 +791,7 @@ pub struct OriginalGroupInformation29 {
 
 impl Validate for OriginalGroupInformation29 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.orgnl_msg_id,
             "OrgnlMsgId",
@@ -833,6 +826,7 @@ pub struct OriginalPaymentInstruction341 {
 
 impl Validate for OriginalPaymentInstruction341 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         helpers::validate_length(
             &self.orgnl_pmt_inf_id,
             "OrgnlPmtInfId",
@@ -860,6 +854,7 @@ pub struct Party38Choice1 {
 
 impl Validate for Party38Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -884,6 +879,7 @@ pub struct Party38Choice2 {
 
 impl Validate for Party38Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.org_id
             && config.validate_optional_fields
         {
@@ -906,6 +902,7 @@ pub struct Party40Choice1 {
 
 impl Validate for Party40Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.agt
             && config.validate_optional_fields
         {
@@ -923,6 +920,7 @@ pub struct Party40Choice2 {
 
 impl Validate for Party40Choice2 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.agt
             && config.validate_optional_fields
         {
@@ -942,6 +940,7 @@ pub struct Party40Choice3 {
 
 impl Validate for Party40Choice3 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.pty
             && config.validate_optional_fields
         {
@@ -970,6 +969,7 @@ pub struct PartyIdentification1351 {
 
 impl Validate for PartyIdentification1351 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -1019,6 +1019,7 @@ pub struct PartyIdentification1352 {
 
 impl Validate for PartyIdentification1352 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.nm {
             helpers::validate_length(
                 val,
@@ -1066,6 +1067,7 @@ pub struct PaymentCancellationReason51 {
 
 impl Validate for PaymentCancellationReason51 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.orgtr
             && config.validate_optional_fields
         {
@@ -1126,6 +1128,7 @@ pub struct PaymentTransaction1091 {
 
 impl Validate for PaymentTransaction1091 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cxl_id {
             helpers::validate_length(
                 val,
@@ -1197,6 +1200,7 @@ pub struct PersonIdentification131 {
 
 impl Validate for PersonIdentification131 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -1227,6 +1231,7 @@ pub struct PersonIdentification132 {
 
 impl Validate for PersonIdentification132 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dt_and_plc_of_birth
             && config.validate_optional_fields
         {
@@ -1257,6 +1262,7 @@ pub struct PersonIdentificationSchemeName1Choice {
 
 impl Validate for PersonIdentificationSchemeName1Choice {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -1291,6 +1297,7 @@ pub struct PersonIdentificationSchemeName1Choice1 {
 
 impl Validate for PersonIdentificationSchemeName1Choice1 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.cd {
             helpers::validate_length(
                 val,
@@ -1342,6 +1349,7 @@ pub struct PostalAddress241 {
 
 impl Validate for PostalAddress241 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dept {
             helpers::validate_length(
                 val,
@@ -1548,6 +1556,7 @@ pub struct PostalAddress242 {
 
 impl Validate for PostalAddress242 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         if let Some(ref val) = self.dept {
             helpers::validate_length(
                 val,
@@ -1868,6 +1877,7 @@ pub struct UnderlyingTransaction241 {
 
 impl Validate for UnderlyingTransaction241 {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
         self.orgnl_pmt_inf_and_cxl.validate(
             &helpers::child_path(path, "OrgnlPmtInfAndCxl"),
             config,
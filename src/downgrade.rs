@@ -0,0 +1,409 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Version Downgrade
+//!
+//! Converts messages into an earlier minor version of the same message
+//! definition, for counterparties that have not migrated yet. The earlier
+//! versions have no generated types, so the result is the JSON form of the
+//! target document. Elements without an equivalent in the target version are
+//! dropped and reported; elements that moved or changed shape are remapped.
+//! The conversion fails when an element that carries mandatory content in the
+//! source has nowhere to go in the target.
+
+use crate::document::camt_053_001_08::BankToCustomerStatementV08;
+use crate::error::{MxError, ValidationError};
+use crate::validation::helpers::{child_path, index_path};
+use serde_json::{Map, Value};
+
+/// Result of downgrading a message to an earlier version
+#[derive(Debug, Clone, PartialEq)]
+pub struct Downgraded {
+    /// Message definition identifier of the target version (e.g. "camt.053.001.02")
+    pub version: String,
+    /// JSON form of the target document, keyed by its root element
+    /// Elements are keyed by name, so sibling order follows the JSON map, not the schema.
+    pub document: Value,
+    /// Paths of the source elements that were dropped
+    pub dropped: Vec<String>,
+}
+
+/// How a source element is carried into the target version
+#[derive(Debug, Clone, Copy)]
+enum Rule {
+    /// No equivalent element in the target; dropped
+    Drop,
+    /// Same content under a different element name
+    Rename(&'static str),
+    /// Choice collapsed to the given branch; the other branches have no home
+    Unwrap(&'static str),
+    /// Repeating in the source but single in the target; extra occurrences are dropped
+    First,
+}
+
+/// Differences between camt.053.001.08 and camt.053.001.02, keyed by path suffix
+const CAMT_053_001_02_RULES: &[(&str, Rule)] = &[
+    ("GrpHdr.OrgnlBizQry", Rule::Drop),
+    ("Stmt.RptgSeq", Rule::Drop),
+    ("Stmt.RptgSrc", Rule::Drop),
+    ("Stmt.Intrst.Tax", Rule::Drop),
+    ("Prxy", Rule::Drop),
+    ("Bal.CdtLine", Rule::First),
+    ("Bal.CdtLine.Tp", Rule::Drop),
+    ("TtlNtriesPerBkTxCd.Dt", Rule::Drop),
+    ("Ntry.Sts", Rule::Unwrap("Cd")),
+    ("Ntry.Chrgs", Rule::Drop),
+    ("Ntry.CardTx", Rule::Drop),
+    ("TxDtls.Chrgs", Rule::Drop),
+    ("TxDtls.LclInstrm", Rule::Drop),
+    ("TxDtls.CorpActn", Rule::Drop),
+    ("TxDtls.CshDpst", Rule::Drop),
+    ("TxDtls.CardTx", Rule::Drop),
+    ("Refs.UETR", Rule::Drop),
+    ("Refs.AcctOwnrTxId", Rule::Drop),
+    ("Refs.AcctSvcrTxId", Rule::Drop),
+    ("Refs.MktInfrstrctrTxId", Rule::Drop),
+    ("Refs.PrcgId", Rule::Drop),
+    ("Refs.Prtry", Rule::First),
+    ("RltdPties.InitgPty", Rule::Unwrap("Pty")),
+    ("RltdPties.Dbtr", Rule::Unwrap("Pty")),
+    ("RltdPties.UltmtDbtr", Rule::Unwrap("Pty")),
+    ("RltdPties.Cdtr", Rule::Unwrap("Pty")),
+    ("RltdPties.UltmtCdtr", Rule::Unwrap("Pty")),
+    ("RltdPties.TradgPty", Rule::Unwrap("Pty")),
+    ("RltdAgts.InstgAgt", Rule::Drop),
+    ("RltdAgts.InstdAgt", Rule::Drop),
+    ("RltdRmtInf.RmtLctnDtls", Rule::Drop),
+    ("Strd.TaxRmt", Rule::Drop),
+    ("Strd.GrnshmtRmt", Rule::Drop),
+    ("RfrdDocInf.LineDtls", Rule::Drop),
+    ("FinInstnId.BICFI", Rule::Rename("BIC")),
+    ("FinInstnId.LEI", Rule::Drop),
+    ("OrgId.AnyBIC", Rule::Rename("BICOrBEI")),
+    ("OrgId.LEI", Rule::Drop),
+    ("PstlAdr.AdrTp", Rule::Unwrap("Cd")),
+    ("PstlAdr.BldgNm", Rule::Drop),
+    ("PstlAdr.Flr", Rule::Drop),
+    ("PstlAdr.PstBx", Rule::Drop),
+    ("PstlAdr.Room", Rule::Drop),
+    ("PstlAdr.TwnLctnNm", Rule::Drop),
+    ("PstlAdr.DstrctNm", Rule::Drop),
+    ("CtctDtls.EmailPurp", Rule::Drop),
+    ("CtctDtls.JobTitl", Rule::Drop),
+    ("CtctDtls.Rspnsblty", Rule::Drop),
+    ("CtctDtls.Dept", Rule::Drop),
+    ("CtctDtls.Othr", Rule::Drop),
+    ("CtctDtls.PrefrdMtd", Rule::Drop),
+];
+
+impl BankToCustomerStatementV08 {
+    /// Downgrade the statement to an earlier camt.053 version
+    /// Supported targets: camt.053.001.02 (and camt.053.001.08, which is returned unchanged).
+    pub fn downgrade_to(&self, version: &str) -> Result<Downgraded, MxError> {
+        let mut document = serde_json::to_value(self)?;
+        let mut dropped = Vec::new();
+        match version {
+            "camt.053.001.08" => {}
+            "camt.053.001.02" => downgrade_camt_053_001_02(&mut document, &mut dropped)?,
+            _ => return Err(MxError::UnknownMessageType(version.to_string())),
+        }
+        Ok(Downgraded {
+            version: version.to_string(),
+            document: serde_json::json!({ "BkToCstmrStmt": document }),
+            dropped,
+        })
+    }
+}
+
+fn downgrade_camt_053_001_02(root: &mut Value, dropped: &mut Vec<String>) -> Result<(), MxError> {
+    // Statement pagination became GrpHdr/MsgPgntn, and Stmt/CreDtTm is mandatory in .02
+    let grp_hdr_cre_dt_tm = root.pointer("/GrpHdr/CreDtTm").cloned();
+    let mut pagination = None;
+    if let Some(stmt) = root.get_mut("Stmt").and_then(Value::as_object_mut) {
+        pagination = stmt.remove("StmtPgntn");
+        if let Some(cre_dt_tm) = grp_hdr_cre_dt_tm {
+            stmt.entry("CreDtTm").or_insert(cre_dt_tm);
+        }
+    }
+    if let Some(pagination) = pagination
+        && let Some(grp_hdr) = root.get_mut("GrpHdr").and_then(Value::as_object_mut)
+    {
+        grp_hdr.insert("MsgPgntn".to_string(), pagination);
+    }
+
+    let entries = root.pointer_mut("/Stmt/Ntry").and_then(Value::as_array_mut);
+    for (idx, entry) in entries.into_iter().flatten().enumerate() {
+        let details_path = child_path(&index_path("Stmt.Ntry", idx), "NtryDtls");
+        let entry_ind = entry.get("CdtDbtInd").cloned();
+        let details = entry.get_mut("NtryDtls").and_then(Value::as_array_mut);
+        for (idx, detail) in details.into_iter().flatten().enumerate() {
+            if let Some(tx) = detail.get_mut("TxDtls") {
+                let tx_path = child_path(&index_path(&details_path, idx), "TxDtls");
+                remap_transaction_amount(tx, entry_ind.as_ref(), &tx_path, dropped)?;
+            }
+        }
+    }
+
+    apply_rules(root, "", "", CAMT_053_001_02_RULES, dropped)?;
+    flatten_net_entries(root);
+    Ok(())
+}
+
+/// Move TxDtls/Amt to AmtDtls/TxAmt/Amt; .02 has no transaction-level amount or direction
+fn remap_transaction_amount(
+    tx: &mut Value,
+    entry_ind: Option<&Value>,
+    path: &str,
+    dropped: &mut Vec<String>,
+) -> Result<(), MxError> {
+    let Some(tx) = tx.as_object_mut() else {
+        return Ok(());
+    };
+    if let Some(ind) = tx.remove("CdtDbtInd")
+        && Some(&ind) != entry_ind
+    {
+        return Err(no_target_home(
+            &child_path(path, "CdtDbtInd"),
+            "differs from the entry direction",
+        ));
+    }
+    if let Some(amt) = tx.remove("Amt") {
+        let amt_dtls = tx
+            .entry("AmtDtls")
+            .or_insert_with(|| Value::Object(Map::new()));
+        match amt_dtls.as_object_mut() {
+            Some(amt_dtls) if !amt_dtls.contains_key("TxAmt") => {
+                amt_dtls.insert("TxAmt".to_string(), serde_json::json!({ "Amt": amt }));
+            }
+            _ => dropped.push(child_path(path, "Amt")),
+        }
+    }
+    Ok(())
+}
+
+/// TtlNetNtry { Amt, CdtDbtInd } became TtlNetNtryAmt and CdtDbtInd on the parent
+fn flatten_net_entries(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            if let Some(Value::Object(mut net)) = map.remove("TtlNetNtry") {
+                if let Some(amt) = net.remove("Amt") {
+                    map.insert("TtlNetNtryAmt".to_string(), amt);
+                }
+                if let Some(ind) = net.remove("CdtDbtInd") {
+                    map.insert("CdtDbtInd".to_string(), ind);
+                }
+            }
+            map.values_mut().for_each(flatten_net_entries);
+        }
+        Value::Array(items) => items.iter_mut().for_each(flatten_net_entries),
+        _ => {}
+    }
+}
+
+/// Apply the rule table to every element of a JSON tree
+/// `norm` is the path without repetition indices, used to match the rules.
+fn apply_rules(
+    value: &mut Value,
+    path: &str,
+    norm: &str,
+    rules: &[(&str, Rule)],
+    dropped: &mut Vec<String>,
+) -> Result<(), MxError> {
+    match value {
+        Value::Object(map) => {
+            let keys: Vec<String> = map.keys().cloned().collect();
+            for key in keys {
+                // Attributes and text content are carried over as they are
+                if key.starts_with('@') || key.starts_with('$') {
+                    continue;
+                }
+                let mut child = child_path(path, &key);
+                let child_norm = child_path(norm, &key);
+                let mut key = key;
+                match rule_for(&child_norm, rules) {
+                    Some(Rule::Drop) => {
+                        map.remove(&key);
+                        dropped.push(child);
+                        continue;
+                    }
+                    Some(Rule::Rename(to)) => {
+                        if let Some(moved) = map.remove(&key) {
+                            map.insert(to.to_string(), moved);
+                        }
+                        key = to.to_string();
+                    }
+                    Some(Rule::Unwrap(branch)) => {
+                        let node = &mut map[&key];
+                        if let Some(other) = node
+                            .as_object()
+                            .and_then(|choice| choice.keys().find(|k| k.as_str() != branch))
+                        {
+                            return Err(no_target_home(
+                                &child_path(&child, other),
+                                "has no equivalent in the target version",
+                            ));
+                        }
+                        if let Some(inner) = node.get(branch).cloned() {
+                            *node = inner;
+                            child = child_path(&child, branch);
+                        }
+                    }
+                    Some(Rule::First) => {
+                        if let Some(items) = map[&key].as_array_mut() {
+                            for idx in 1..items.len() {
+                                dropped.push(index_path(&child, idx));
+                            }
+                            items.truncate(1);
+                        }
+                    }
+                    None => {}
+                }
+                if let Some(node) = map.get_mut(&key) {
+                    apply_rules(node, &child, &child_norm, rules, dropped)?;
+                }
+            }
+        }
+        Value::Array(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                apply_rules(item, &index_path(path, idx), norm, rules, dropped)?;
+            }
+        }
+        _ => {}
+    }
+    Ok(())
+}
+
+/// Find the rule whose path matches the end of the given path
+fn rule_for(norm: &str, rules: &[(&str, Rule)]) -> Option<Rule> {
+    rules.iter().find_map(|&(pattern, rule)| {
+        let matches = norm == pattern
+            || norm
+                .strip_suffix(pattern)
+                .is_some_and(|prefix| prefix.ends_with('.'));
+        matches.then_some(rule)
+    })
+}
+
+fn no_target_home(path: &str, reason: &str) -> MxError {
+    ValidationError::new(3001, format!("{path} {reason}"))
+        .with_path(path.to_string())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_053_001_08::*;
+
+    fn statement() -> BankToCustomerStatementV08 {
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.grp_hdr.msg_id = "STMT-001".to_string();
+        doc.grp_hdr.cre_dt_tm = "2024-01-15T10:30:00Z".to_string();
+        doc.stmt.id = "STMT-001-1".to_string();
+        doc.stmt.stmt_pgntn = Pagination1 {
+            pg_nb: "1".to_string(),
+            last_pg_ind: true,
+        };
+        doc.stmt.rptg_src = Some(ReportingSource1Choice1 {
+            cd: Some("ACCT".to_string()),
+            ..Default::default()
+        });
+
+        let mut tx = EntryTransaction101::default();
+        tx.refs.end_to_end_id = Some("E2E-1".to_string());
+        tx.refs.uetr = Some("eb6305c9-1f7f-49de-aed0-16487c27b42d".to_string());
+        tx.amt.ccy = "EUR".into();
+        tx.amt.value = 100.0;
+        doc.stmt.ntry = Some(vec![Box::new(ReportEntry101 {
+            ntry_ref: Some("NTRY-1".to_string()),
+            sts: EntryStatus1Choice1 {
+                cd: Some(ExternalEntryStatus1Codefixed::CodeBOOK),
+            },
+            ntry_dtls: Some(vec![Box::new(EntryDetails91 {
+                btch: None,
+                tx_dtls: Box::new(tx),
+            })]),
+            ..Default::default()
+        })]);
+        doc
+    }
+
+    #[test]
+    fn test_downgrade_reports_dropped_fields() {
+        let downgraded = statement().downgrade_to("camt.053.001.02").unwrap();
+        assert_eq!(downgraded.version, "camt.053.001.02");
+        assert_eq!(
+            downgraded.dropped,
+            vec![
+                "Stmt.Ntry[0].NtryDtls[0].TxDtls.Refs.UETR".to_string(),
+                "Stmt.RptgSrc".to_string(),
+            ]
+        );
+
+        let doc = &downgraded.document["BkToCstmrStmt"];
+        assert_eq!(doc["GrpHdr"]["MsgPgntn"]["PgNb"], "1");
+        assert!(doc["Stmt"].get("StmtPgntn").is_none());
+        assert_eq!(doc["Stmt"]["CreDtTm"], "2024-01-15T10:30:00Z");
+
+        let entry = &doc["Stmt"]["Ntry"][0];
+        assert_eq!(entry["Sts"], "BOOK");
+        let tx = &entry["NtryDtls"][0]["TxDtls"];
+        assert!(tx.get("Amt").is_none());
+        assert!(tx.get("CdtDbtInd").is_none());
+        assert_eq!(tx["AmtDtls"]["TxAmt"]["Amt"]["@Ccy"], "EUR");
+        assert_eq!(tx["Refs"]["EndToEndId"], "E2E-1");
+    }
+
+    #[test]
+    fn test_downgrade_fails_without_target_home() {
+        let mut doc = statement();
+        let tx = &mut doc.stmt.ntry.as_mut().unwrap()[0]
+            .ntry_dtls
+            .as_mut()
+            .unwrap()[0]
+            .tx_dtls;
+        tx.rltd_pties = Some(TransactionParties61 {
+            dbtr: Some(Party40Choice2 {
+                pty: None,
+                agt: Some(BranchAndFinancialInstitutionIdentification61::default()),
+            }),
+            ..Default::default()
+        });
+
+        let Err(MxError::Validation { code, path, .. }) = doc.downgrade_to("camt.053.001.02")
+        else {
+            panic!("expected downgrade to fail");
+        };
+        assert_eq!(code, 3001);
+        assert_eq!(
+            path.as_deref(),
+            Some("Stmt.Ntry[0].NtryDtls[0].TxDtls.RltdPties.Dbtr.Agt")
+        );
+    }
+
+    #[test]
+    fn test_downgrade_unsupported_version() {
+        assert!(matches!(
+            statement().downgrade_to("camt.053.001.01"),
+            Err(MxError::UnknownMessageType(_))
+        ));
+        let same = statement().downgrade_to("camt.053.001.08").unwrap();
+        assert!(same.dropped.is_empty());
+    }
+}
@@ -55,10 +55,26 @@ pub enum MxError {
     #[error("Cannot detect message format")]
     FormatDetection,
 
+    /// Input nests deeper than the configured limit
+    #[error("Nesting depth exceeds the limit of {0}")]
+    DepthLimitExceeded(usize),
+
+    /// Compressed input inflates beyond the configured limit
+    #[error("Decompressed size exceeds the limit of {0} bytes")]
+    DecompressedSizeExceeded(usize),
+
     /// Unknown message type
     #[error("Unknown message type: {0}")]
     UnknownMessageType(String),
 
+    /// Document namespace of another message version than the requested type
+    #[error("Document namespace {found} does not match the expected {expected}")]
+    VersionMismatch { expected: String, found: String },
+
+    /// Message type outside the types the parser is configured to accept
+    #[error("Unsupported message type: {0}")]
+    UnsupportedMessageType(String),
+
     /// IO error
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
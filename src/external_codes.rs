@@ -0,0 +1,101 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! ISO 20022 External Code Sets
+//!
+//! Code lists published by ISO 20022 outside of the message schemas. The XSDs
+//! only constrain these codes by length, so membership is checked here.
+//!
+//! The lists are a snapshot of the ExternalCodeSets release named in
+//! [`EXTERNAL_CODE_SETS_RELEASE`]. ISO publishes new codes every quarter, so a
+//! code missing here may simply be newer than the snapshot.
+
+/// ExternalCodeSets release the code lists in this module were taken from
+pub const EXTERNAL_CODE_SETS_RELEASE: &str = "2Q2024";
+
+/// ExternalPurpose1Code - underlying reason for the payment transaction
+pub const EXTERNAL_PURPOSE_CODES: &[&str] = &[
+    "ACCT", "ADCS", "ADMG", "ADVA", "AEMP", "AGRT", "AIRB", "ALLW", "ALMY", "AMEX", "ANNI", "ANTS",
+    "AREN", "B112", "BBSC", "BCDM", "BCFG", "BECH", "BENE", "BEXP", "BFWD", "BKDF", "BKFE", "BKFM",
+    "BKIP", "BKPP", "BLDM", "BNET", "BOCE", "BOND", "BONU", "BR12", "BUSB", "CABD", "CAFI", "CASH",
+    "CBCR", "CBFF", "CBFR", "CBLK", "CBTV", "CCHD", "CCIR", "CCPC", "CCPM", "CCRD", "CCSM", "CDBL",
+    "CDCB", "CDCD", "CDCS", "CDDP", "CDEP", "CDOC", "CDQC", "CFDI", "CFEE", "CGDD", "CHAR", "CLPR",
+    "CMDT", "COLL", "COMC", "COMM", "COMP", "COMT", "CORT", "COST", "CPKC", "CPYR", "CRDS", "CRPR",
+    "CRSP", "CRTL", "CSDB", "CSLP", "CVCF", "DBCR", "DBTC", "DCRD", "DEPT", "DERI", "DIVD", "DMEQ",
+    "DNTS", "DSMT", "DVPM", "ECPG", "ECPR", "ECPU", "EDUC", "EFTC", "EFTD", "ELEC", "ENRG", "EPAY",
+    "EQPT", "EQTS", "EQUS", "ESTX", "ETUP", "EXPT", "EXTD", "FACT", "FAND", "FCOL", "FCPM", "FEES",
+    "FERB", "FIXI", "FNET", "FREX", "FUTR", "FWBC", "FWCC", "FWLV", "FWSB", "FWSC", "FXNT", "GAFA",
+    "GAHO", "GAMB", "GASB", "GDDS", "GDSV", "GFRP", "GIFT", "GOVI", "GOVT", "GSCB", "GSTX", "GVEA",
+    "GVEB", "GVEC", "GVED", "GWLT", "HEDG", "HLRP", "HLST", "HLTC", "HLTI", "HREC", "HSPC", "HSTX",
+    "ICCP", "ICRF", "IDCP", "IHRP", "INPC", "INPR", "INSC", "INSM", "INSU", "INTC", "INTE", "INTP",
+    "INTX", "INVS", "IPAY", "IPCA", "IPDO", "IPEA", "IPEC", "IPEW", "IPPS", "IPRT", "IPU2", "IPUW",
+    "IVPT", "LBIN", "LBRI", "LCOL", "LFEE", "LICF", "LIFI", "LIMA", "LMEQ", "LMFI", "LMRK", "LOAN",
+    "LOAR", "LOTT", "LREB", "LREV", "LSFL", "LTCF", "MAFC", "MARF", "MARG", "MBSB", "MBSC", "MCDM",
+    "MCFG", "MDCS", "MGCC", "MGSC", "MOMA", "MP2B", "MP2P", "MSVC", "MTUP", "NETT", "NITX", "NOWS",
+    "NWCH", "NWCM", "OCCC", "OCDM", "OCFG", "OFEE", "OPBC", "OPCC", "OPSB", "OPSC", "OPTN", "OTHR",
+    "OTLC", "PADD", "PAYR", "PCOM", "PDEP", "PEFC", "PENO", "PENS", "PHON", "POPE", "PPTI", "PRCP",
+    "PRME", "PTSP", "PTXP", "RAPI", "RCKE", "RCPT", "RDTX", "REBT", "REFU", "RELG", "REME", "RENT",
+    "REOD", "REPO", "RHBS", "RIMB", "RINP", "RLWY", "ROYA", "RPBC", "RPCC", "RPSB", "RPSC", "RRBN",
+    "RRCT", "RRTP", "RVPM", "RVPO", "SALA", "SASW", "SAVG", "SBSC", "SCIE", "SCIR", "SCRP", "SCVE",
+    "SECU", "SEPI", "SHBC", "SHCC", "SHSL", "SLEB", "SLOA", "SLPI", "SPLT", "SPSP", "SSBE", "STDY",
+    "SUBS", "SUPP", "SWBC", "SWCC", "SWFP", "SWPP", "SWPT", "SWRS", "SWSB", "SWSC", "SWUF", "TAXR",
+    "TAXS", "TBAN", "TBAS", "TBBC", "TBCC", "TBIL", "TCSC", "TELI", "TLRF", "TLRR", "TMPG", "TPRI",
+    "TPRP", "TRAD", "TRCP", "TREA", "TRFD", "TRNC", "TRPT", "TRVC", "UBIL", "UNIT", "VATX", "VIEW",
+    "VOST", "WEBI", "WHLD", "WTER",
+];
+
+/// Check whether a code is in the ExternalPurpose1Code list
+pub fn is_external_purpose_code(code: &str) -> bool {
+    EXTERNAL_PURPOSE_CODES.contains(&code)
+}
+
+/// ExternalCategoryPurpose1Code - high-level category of the payment, used
+/// by the banks for routing and processing (e.g. salary, supplier, tax)
+pub const EXTERNAL_CATEGORY_PURPOSE_CODES: &[&str] = &[
+    "BONU", "CASH", "CBLK", "CCRD", "CGWV", "CIPC", "CONC", "CORT", "DCRD", "DIVI", "DVPM", "EPAY",
+    "FCDT", "FCIN", "FCOL", "GOVI", "GOVT", "GP2P", "HEDG", "ICCP", "IDCP", "INTC", "INTE", "LBOX",
+    "LOAN", "MP2B", "MP2P", "OTHR", "PENS", "RPRE", "RRCT", "RVPM", "SALA", "SECU", "SSBE", "SUPP",
+    "SWEP", "TAXS", "TOPG", "TRAD", "TREA", "VATX", "VOST", "WHLD", "ZABA",
+];
+
+/// Check whether a code is in the ExternalCategoryPurpose1Code list
+pub fn is_external_category_purpose_code(code: &str) -> bool {
+    EXTERNAL_CATEGORY_PURPOSE_CODES.contains(&code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_purpose_codes() {
+        assert!(is_external_purpose_code("SALA"));
+        assert!(is_external_purpose_code("SUPP"));
+        assert!(!is_external_purpose_code("sala"));
+        assert!(!is_external_purpose_code("ZZZZ"));
+    }
+
+    #[test]
+    fn test_external_category_purpose_codes() {
+        assert!(is_external_category_purpose_code("SALA"));
+        assert!(is_external_category_purpose_code("TAXS"));
+        assert!(!is_external_category_purpose_code("AEMP"));
+        assert!(!is_external_category_purpose_code("supp"));
+    }
+}
@@ -0,0 +1,124 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Gzip-compressed messages
+//!
+//! Archival channels deliver messages as `.xml.gz` files. These functions
+//! inflate the file and parse the XML in one step. A small file can inflate to
+//! an enormous one, so the decompressed size is capped and input beyond the cap
+//! is rejected with `MxError::DecompressedSizeExceeded` without being read in
+//! full.
+
+use crate::error::MxError;
+use crate::mx_envelope::MxMessage;
+use flate2::read::MultiGzDecoder;
+use std::io::Read;
+
+/// Default cap on the decompressed size of a message file (16 MiB)
+pub const DEFAULT_MAX_DECOMPRESSED_SIZE: usize = 16 * 1024 * 1024;
+
+/// Inflate a gzip-compressed message file and parse it
+/// The decompressed XML may be at most [`DEFAULT_MAX_DECOMPRESSED_SIZE`] bytes.
+pub fn from_mx_xml_gz(bytes: &[u8]) -> Result<MxMessage, MxError> {
+    from_mx_xml_gz_with_limit(bytes, DEFAULT_MAX_DECOMPRESSED_SIZE)
+}
+
+/// Inflate a gzip-compressed message file and parse it, capping the decompressed size
+/// Files made of several gzip members are inflated as one stream.
+pub fn from_mx_xml_gz_with_limit(bytes: &[u8], max_size: usize) -> Result<MxMessage, MxError> {
+    // Read bytes, so a cap falling inside a multi-byte character still reports the size
+    let mut buf = Vec::new();
+    MultiGzDecoder::new(bytes)
+        .take(max_size.saturating_add(1) as u64)
+        .read_to_end(&mut buf)?;
+    if buf.len() > max_size {
+        return Err(MxError::DecompressedSizeExceeded(max_size));
+    }
+    let xml = String::from_utf8(buf)
+        .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))?;
+    MxMessage::from_xml(&xml)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+    use crate::header::AppHdr;
+    use crate::mx_envelope::Document;
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn test_from_mx_xml_gz() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = "ARCHIVED-1".to_string();
+        let app_hdr = AppHdr {
+            biz_msg_idr: "ARCHIVED-1".to_string(),
+            msg_def_idr: "pacs.008.001.08".to_string(),
+            ..Default::default()
+        };
+        let xml = MxMessage::new(app_hdr, Document::Pacs008(Box::new(doc)))
+            .to_xml()
+            .unwrap();
+
+        let message = from_mx_xml_gz(&gzip(xml.as_bytes())).unwrap();
+        assert_eq!(message, MxMessage::from_xml(&xml).unwrap());
+        assert_eq!(message.document.message_id(), "ARCHIVED-1");
+    }
+
+    #[test]
+    fn test_decompression_bomb_rejected() {
+        let bomb = gzip(&vec![b' '; 4 * 1024 * 1024]);
+        assert!(bomb.len() < 16 * 1024);
+        assert!(matches!(
+            from_mx_xml_gz_with_limit(&bomb, 1024 * 1024),
+            Err(MxError::DecompressedSizeExceeded(1048576))
+        ));
+    }
+
+    #[test]
+    fn test_limit_inside_multibyte_character() {
+        let data = "é".repeat(1024);
+        assert!(matches!(
+            from_mx_xml_gz_with_limit(&gzip(data.as_bytes()), 1023),
+            Err(MxError::DecompressedSizeExceeded(1023))
+        ));
+    }
+
+    #[test]
+    fn test_unlimited_size() {
+        let result = from_mx_xml_gz_with_limit(&gzip(b"not a message"), usize::MAX);
+        assert!(!matches!(result, Err(MxError::DecompressedSizeExceeded(_))));
+    }
+
+    #[test]
+    fn test_not_gzip() {
+        assert!(matches!(
+            from_mx_xml_gz(b"<Envelope/>"),
+            Err(MxError::Io(_))
+        ));
+    }
+}
@@ -0,0 +1,865 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Identifier Types
+//!
+//! Validated newtypes for identifiers that appear throughout the message
+//! types. They serialize as plain strings, so the wire format is unchanged.
+//! Deserialization and `From` conversions are unchecked so that malformed
+//! values surface through `Validate` with a path instead of aborting the parse;
+//! use the `new` constructors to build a value that is known to be valid.
+
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::{Validate, helpers};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// ISO 3166-1 alpha-2 country codes, plus XK (Kosovo) as used by SWIFT
+pub const COUNTRY_CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AI", "AL", "AM", "AO", "AQ", "AR", "AS", "AT", "AU", "AW", "AX", "AZ",
+    "BA", "BB", "BD", "BE", "BF", "BG", "BH", "BI", "BJ", "BL", "BM", "BN", "BO", "BQ", "BR", "BS",
+    "BT", "BV", "BW", "BY", "BZ", "CA", "CC", "CD", "CF", "CG", "CH", "CI", "CK", "CL", "CM", "CN",
+    "CO", "CR", "CU", "CV", "CW", "CX", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO", "DZ", "EC", "EE",
+    "EG", "EH", "ER", "ES", "ET", "FI", "FJ", "FK", "FM", "FO", "FR", "GA", "GB", "GD", "GE", "GF",
+    "GG", "GH", "GI", "GL", "GM", "GN", "GP", "GQ", "GR", "GS", "GT", "GU", "GW", "GY", "HK", "HM",
+    "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IM", "IN", "IO", "IQ", "IR", "IS", "IT", "JE", "JM",
+    "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW", "KY", "KZ", "LA", "LB", "LC",
+    "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME", "MF", "MG", "MH", "MK",
+    "ML", "MM", "MN", "MO", "MP", "MQ", "MR", "MS", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA",
+    "NC", "NE", "NF", "NG", "NI", "NL", "NO", "NP", "NR", "NU", "NZ", "OM", "PA", "PE", "PF", "PG",
+    "PH", "PK", "PL", "PM", "PN", "PR", "PS", "PT", "PW", "PY", "QA", "RE", "RO", "RS", "RU", "RW",
+    "SA", "SB", "SC", "SD", "SE", "SG", "SH", "SI", "SJ", "SK", "SL", "SM", "SN", "SO", "SR", "SS",
+    "ST", "SV", "SX", "SY", "SZ", "TC", "TD", "TF", "TG", "TH", "TJ", "TK", "TL", "TM", "TN", "TO",
+    "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "UM", "US", "UY", "UZ", "VA", "VC", "VE", "VG", "VI",
+    "VN", "VU", "WF", "WS", "XK", "YE", "YT", "ZA", "ZM", "ZW",
+];
+
+/// Check whether a code is an ISO 3166-1 alpha-2 country code
+pub fn is_country_code(code: &str) -> bool {
+    COUNTRY_CODES.contains(&code)
+}
+
+/// Schema pattern for BICFI and AnyBIC elements
+pub const BIC_PATTERN: &str = "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}";
+
+/// Business Identifier Code (ISO 9362)
+///
+/// Structure: 4-character institution code, 2-letter country code,
+/// 2-character location code and an optional 3-character branch code.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Bic(String);
+
+impl Bic {
+    /// Create a BIC, validating its structure and country code
+    pub fn new(value: &str) -> Result<Self, ValidationError> {
+        let bic = Bic(value.trim().to_string());
+        if !bic.has_valid_structure() {
+            return Err(ValidationError::new(
+                1005,
+                format!("BIC does not match the required pattern (value: '{value}')"),
+            ));
+        }
+        if !is_country_code(bic.country_code()) {
+            return Err(ValidationError::new(
+                1006,
+                format!("BIC has an unknown country code (value: '{value}')"),
+            ));
+        }
+        Ok(bic)
+    }
+
+    /// Get the BIC as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Check the structure and country code of the BIC
+    pub fn is_valid(&self) -> bool {
+        self.has_valid_structure() && is_country_code(self.country_code())
+    }
+
+    /// Institution (business party prefix) code, characters 1-4
+    pub fn institution_code(&self) -> &str {
+        self.0.get(0..4).unwrap_or("")
+    }
+
+    /// ISO 3166 country code, characters 5-6
+    pub fn country_code(&self) -> &str {
+        self.0.get(4..6).unwrap_or("")
+    }
+
+    /// Location code, characters 7-8
+    pub fn location_code(&self) -> &str {
+        self.0.get(6..8).unwrap_or("")
+    }
+
+    /// Branch code, characters 9-11, if present
+    pub fn branch_code(&self) -> Option<&str> {
+        self.0.get(8..11)
+    }
+
+    /// Uppercase BIC with a BIC8 expanded to its primary-office BIC11 (`XXX` branch)
+    /// A BIC8 and its primary-office BIC11 name the same institution, so BICs
+    /// are compared in this form.
+    pub fn primary_office(&self) -> Bic {
+        let bic = self.0.trim().to_ascii_uppercase();
+        Bic(if bic.len() == 8 { bic + "XXX" } else { bic })
+    }
+
+    /// Whether the BIC belongs to an institution connected to the SWIFT network
+    /// Non-connected BICs, such as most BEIs, carry a "1" as the second character of the location code.
+    pub fn is_connected(&self) -> bool {
+        self.0.get(7..8).is_some_and(|c| c != "1")
+    }
+
+    fn has_valid_structure(&self) -> bool {
+        let bytes = self.0.as_bytes();
+        let alnum = |b: &u8| b.is_ascii_uppercase() || b.is_ascii_digit();
+        (bytes.len() == 8 || bytes.len() == 11)
+            && bytes[0..4].iter().all(alnum)
+            && bytes[4..6].iter().all(u8::is_ascii_uppercase)
+            && bytes[6..].iter().all(alnum)
+    }
+}
+
+impl fmt::Display for Bic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Bic {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Bic {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Bic {
+    type Err = ValidationError;
+
+    /// Parse and validate a BIC, as [`Bic::new`]
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Bic::new(value)
+    }
+}
+
+impl From<String> for Bic {
+    fn from(value: String) -> Self {
+        Bic(value)
+    }
+}
+
+impl From<&str> for Bic {
+    fn from(value: &str) -> Self {
+        Bic(value.to_string())
+    }
+}
+
+impl Validate for Bic {
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        let field_name = helpers::leaf_name(path);
+        if !helpers::validate_pattern(&self.0, field_name, BIC_PATTERN, path, config, collector) {
+            return;
+        }
+        if !is_country_code(self.country_code()) {
+            helpers::report_error(
+                ValidationError::new(
+                    1006,
+                    format!(
+                        "{field_name} has an unknown country code (value: '{}')",
+                        self.0
+                    ),
+                )
+                .with_field(field_name.to_string())
+                .with_path(path.to_string()),
+                config,
+                collector,
+            );
+        }
+    }
+}
+
+/// Schema pattern for IBAN2007Identifier elements
+pub const IBAN_PATTERN: &str = "[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}";
+
+/// International Bank Account Number (ISO 13616)
+///
+/// Structure: 2-letter country code, 2 check digits and a country-specific
+/// Basic Bank Account Number (BBAN) of up to 30 characters.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Iban(String);
+
+impl Iban {
+    /// Create an IBAN, validating its structure, country code and mod-97 checksum
+    pub fn new(value: &str) -> Result<Self, ValidationError> {
+        let iban = Iban(value.trim().to_string());
+        if !iban.has_valid_structure() {
+            return Err(ValidationError::new(
+                1005,
+                format!("IBAN does not match the required pattern (value: '{value}')"),
+            ));
+        }
+        if !is_country_code(iban.country_code()) {
+            return Err(ValidationError::new(
+                1006,
+                format!("IBAN has an unknown country code (value: '{value}')"),
+            ));
+        }
+        if !iban.has_valid_checksum() {
+            return Err(ValidationError::new(
+                1007,
+                format!("IBAN has an invalid checksum (value: '{value}')"),
+            ));
+        }
+        Ok(iban)
+    }
+
+    /// Get the IBAN as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Check the structure, country code and checksum of the IBAN
+    pub fn is_valid(&self) -> bool {
+        self.has_valid_structure()
+            && is_country_code(self.country_code())
+            && self.has_valid_checksum()
+    }
+
+    /// ISO 3166 country code, characters 1-2
+    pub fn country_code(&self) -> &str {
+        self.0.get(0..2).unwrap_or("")
+    }
+
+    /// Check digits, characters 3-4
+    pub fn check_digits(&self) -> &str {
+        self.0.get(2..4).unwrap_or("")
+    }
+
+    /// Basic Bank Account Number, characters 5 onwards
+    pub fn bban(&self) -> &str {
+        self.0.get(4..).unwrap_or("")
+    }
+
+    fn has_valid_structure(&self) -> bool {
+        let bytes = self.0.as_bytes();
+        (5..=34).contains(&bytes.len())
+            && bytes[0..2].iter().all(u8::is_ascii_uppercase)
+            && bytes[2..4].iter().all(u8::is_ascii_digit)
+            && bytes[4..].iter().all(u8::is_ascii_alphanumeric)
+    }
+
+    /// ISO 7064 mod-97: move the first four characters to the end, map letters
+    /// to 10-35 and check that the resulting number leaves a remainder of 1
+    fn has_valid_checksum(&self) -> bool {
+        mod97(self.bban().chars().chain(self.0.chars().take(4))) == Some(1)
+    }
+
+    /// Build an IBAN from its country code and BBAN, computing the check digits
+    pub fn from_bban(country_code: &str, bban: &str) -> Result<Self, ValidationError> {
+        let remainder = mod97(bban.chars().chain(country_code.chars()).chain("00".chars()))
+            .ok_or_else(|| {
+                ValidationError::new(
+                    1005,
+                    format!(
+                        "IBAN does not match the required pattern (value: '{country_code}00{bban}')"
+                    ),
+                )
+            })?;
+        Iban::new(&format!("{country_code}{:02}{bban}", 98 - remainder))
+    }
+}
+
+/// Remainder modulo 97 of the digits, letters counting as 10-35, or `None`
+/// for a character that is neither
+fn mod97(chars: impl Iterator<Item = char>) -> Option<u32> {
+    let mut remainder = 0u32;
+    for c in chars {
+        let digit = c.to_digit(36)?;
+        remainder = if digit < 10 {
+            (remainder * 10 + digit) % 97
+        } else {
+            (remainder * 100 + digit) % 97
+        };
+    }
+    Some(remainder)
+}
+
+impl fmt::Display for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Iban {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for Iban {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Iban {
+    type Err = ValidationError;
+
+    /// Parse and validate an IBAN, as [`Iban::new`]
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Iban::new(value)
+    }
+}
+
+impl From<String> for Iban {
+    fn from(value: String) -> Self {
+        Iban(value)
+    }
+}
+
+impl From<&str> for Iban {
+    fn from(value: &str) -> Self {
+        Iban(value.to_string())
+    }
+}
+
+impl Validate for Iban {
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        let field_name = helpers::leaf_name(path);
+        if !helpers::validate_pattern(&self.0, field_name, IBAN_PATTERN, path, config, collector) {
+            return;
+        }
+        let error = if !is_country_code(self.country_code()) {
+            ValidationError::new(
+                1006,
+                format!(
+                    "{field_name} has an unknown country code (value: '{}')",
+                    self.0
+                ),
+            )
+        } else if !self.has_valid_checksum() {
+            ValidationError::new(
+                1007,
+                format!("{field_name} has an invalid checksum (value: '{}')", self.0),
+            )
+        } else {
+            return;
+        };
+        helpers::report_error(
+            error
+                .with_field(field_name.to_string())
+                .with_path(path.to_string()),
+            config,
+            collector,
+        );
+    }
+}
+
+/// Schema pattern for ActiveOrHistoricCurrencyCode elements
+pub const CURRENCY_PATTERN: &str = "[A-Z]{3,3}";
+
+/// Active ISO 4217 currency codes, including funds, precious metals and the
+/// testing (XTS) and no-currency (XXX) codes
+pub const ACTIVE_CURRENCIES: &[&str] = &[
+    "AED", "AFN", "ALL", "AMD", "AOA", "ARS", "AUD", "AWG", "AZN", "BAM", "BBD", "BDT", "BGN",
+    "BHD", "BIF", "BMD", "BND", "BOB", "BOV", "BRL", "BSD", "BTN", "BWP", "BYN", "BZD", "CAD",
+    "CDF", "CHE", "CHF", "CHW", "CLF", "CLP", "CNY", "COP", "COU", "CRC", "CUC", "CUP", "CVE",
+    "CZK", "DJF", "DKK", "DOP", "DZD", "EGP", "ERN", "ETB", "EUR", "FJD", "FKP", "GBP", "GEL",
+    "GHS", "GIP", "GMD", "GNF", "GTQ", "GYD", "HKD", "HNL", "HTG", "HUF", "IDR", "ILS", "INR",
+    "IQD", "IRR", "ISK", "JMD", "JOD", "JPY", "KES", "KGS", "KHR", "KMF", "KPW", "KRW", "KWD",
+    "KYD", "KZT", "LAK", "LBP", "LKR", "LRD", "LSL", "LYD", "MAD", "MDL", "MGA", "MKD", "MMK",
+    "MNT", "MOP", "MRU", "MUR", "MVR", "MWK", "MXN", "MXV", "MYR", "MZN", "NAD", "NGN", "NIO",
+    "NOK", "NPR", "NZD", "OMR", "PAB", "PEN", "PGK", "PHP", "PKR", "PLN", "PYG", "QAR", "RON",
+    "RSD", "RUB", "RWF", "SAR", "SBD", "SCR", "SDG", "SEK", "SGD", "SHP", "SLE", "SOS", "SRD",
+    "SSP", "STN", "SVC", "SYP", "SZL", "THB", "TJS", "TMT", "TND", "TOP", "TRY", "TTD", "TWD",
+    "TZS", "UAH", "UGX", "USD", "USN", "UYI", "UYU", "UYW", "UZS", "VED", "VES", "VND", "VUV",
+    "WST", "XAF", "XCD", "XCG", "XOF", "XPF", "YER", "ZAR", "ZMW", "ZWG", "XAG", "XAU", "XBA",
+    "XBB", "XBC", "XBD", "XDR", "XPD", "XPT", "XSU", "XTS", "XUA", "XXX",
+];
+
+/// Active currency codes whose minor unit is not 2 digits, grouped by minor unit
+const ZERO_DECIMAL_CURRENCIES: &[&str] = &[
+    "BIF", "CLP", "DJF", "GNF", "ISK", "JPY", "KMF", "KRW", "PYG", "RWF", "UGX", "UYI", "VND",
+    "VUV", "XAF", "XOF", "XPF",
+];
+const THREE_DECIMAL_CURRENCIES: &[&str] = &["BHD", "IQD", "JOD", "KWD", "LYD", "OMR", "TND"];
+const FOUR_DECIMAL_CURRENCIES: &[&str] = &["CLF", "UYW"];
+/// Active codes that are not currencies and have no minor unit
+const NO_MINOR_UNIT_CURRENCIES: &[&str] = &[
+    "XAG", "XAU", "XBA", "XBB", "XBC", "XBD", "XDR", "XPD", "XPT", "XSU", "XTS", "XUA", "XXX",
+];
+
+/// Withdrawn ISO 4217 currency codes, still accepted where the schema allows
+/// historic currencies (ActiveOrHistoricCurrencyCode)
+pub const HISTORIC_CURRENCIES: &[&str] = &[
+    "ADP", "ANG", "ATS", "AZM", "BEF", "BYR", "CSD", "CYP", "DEM", "EEK", "ESP", "FIM", "FRF",
+    "GHC", "GRD", "HRK", "IEP", "ITL", "LTL", "LUF", "LVL", "MGF", "MRO", "MTL", "MZM", "NLG",
+    "PTE", "ROL", "SDD", "SIT", "SKK", "SLL", "SRG", "STD", "TMM", "TRL", "VEB", "VEF", "XEU",
+    "YUM", "ZMK", "ZWD", "ZWL", "ZWN", "ZWR",
+];
+
+/// ISO 4217 currency code
+///
+/// Structure: 3 uppercase letters, the first two usually being the ISO 3166
+/// country code of the issuing country.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CurrencyCode(String);
+
+impl CurrencyCode {
+    /// Create a currency code, validating it against the active ISO 4217 codes
+    pub fn new(value: &str) -> Result<Self, ValidationError> {
+        let code = Self::new_lenient(value)?;
+        if !code.is_active() {
+            return Err(ValidationError::new(
+                1010,
+                format!("Currency code is not an active ISO 4217 code (value: '{value}')"),
+            ));
+        }
+        Ok(code)
+    }
+
+    /// Create a currency code, checking only its structure
+    /// Accepts codes outside ISO 4217, e.g. currencies agreed for testing.
+    pub fn new_lenient(value: &str) -> Result<Self, ValidationError> {
+        let code = CurrencyCode(value.trim().to_string());
+        if !code.has_valid_structure() {
+            return Err(ValidationError::new(
+                1005,
+                format!("Currency code does not match the required pattern (value: '{value}')"),
+            ));
+        }
+        Ok(code)
+    }
+
+    /// Get the currency code as a string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Check that the code is an active or historic ISO 4217 code
+    pub fn is_valid(&self) -> bool {
+        self.is_active() || self.is_historic()
+    }
+
+    /// Whether the code is an active ISO 4217 code
+    pub fn is_active(&self) -> bool {
+        ACTIVE_CURRENCIES.contains(&self.0.as_str())
+    }
+
+    /// Whether the code is a withdrawn ISO 4217 code
+    pub fn is_historic(&self) -> bool {
+        HISTORIC_CURRENCIES.contains(&self.0.as_str())
+    }
+
+    /// Number of digits after the decimal separator, for active codes that have minor units
+    pub fn minor_units(&self) -> Option<u8> {
+        let code = self.0.as_str();
+        if !self.is_active() || NO_MINOR_UNIT_CURRENCIES.contains(&code) {
+            None
+        } else if ZERO_DECIMAL_CURRENCIES.contains(&code) {
+            Some(0)
+        } else if THREE_DECIMAL_CURRENCIES.contains(&code) {
+            Some(3)
+        } else if FOUR_DECIMAL_CURRENCIES.contains(&code) {
+            Some(4)
+        } else {
+            Some(2)
+        }
+    }
+
+    fn has_valid_structure(&self) -> bool {
+        self.0.len() == 3 && self.0.bytes().all(|b| b.is_ascii_uppercase())
+    }
+}
+
+impl fmt::Display for CurrencyCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for CurrencyCode {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Deref for CurrencyCode {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for CurrencyCode {
+    type Err = ValidationError;
+
+    /// Parse and validate an active currency code, as [`CurrencyCode::new`]
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        CurrencyCode::new(value)
+    }
+}
+
+impl From<String> for CurrencyCode {
+    fn from(value: String) -> Self {
+        CurrencyCode(value)
+    }
+}
+
+impl From<&str> for CurrencyCode {
+    fn from(value: &str) -> Self {
+        CurrencyCode(value.to_string())
+    }
+}
+
+impl PartialEq<str> for CurrencyCode {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for CurrencyCode {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl CurrencyCode {
+    /// Validate the code against the active ISO 4217 codes only
+    /// Used for `ActiveCurrencyCode` elements, e.g. the currency of CBPR+ amounts.
+    pub fn validate_active(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        self.validate_against(Self::is_active, "an active", path, config, collector);
+    }
+
+    fn validate_against(
+        &self,
+        accepts: fn(&Self) -> bool,
+        set: &str,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        let _span = helpers::validation_span::<Self>(path);
+        let field_name = helpers::leaf_name(path);
+        if !helpers::validate_pattern(
+            &self.0,
+            field_name,
+            CURRENCY_PATTERN,
+            path,
+            config,
+            collector,
+        ) {
+            return;
+        }
+        if !accepts(self) {
+            helpers::report_error(
+                ValidationError::new(
+                    1010,
+                    format!(
+                        "{field_name} is not {set} ISO 4217 currency code (value: '{}')",
+                        self.0
+                    ),
+                )
+                .with_field(field_name.to_string())
+                .with_path(path.to_string()),
+                config,
+                collector,
+            );
+        }
+    }
+}
+
+impl Validate for CurrencyCode {
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        self.validate_against(Self::is_valid, "an", path, config, collector);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::{
+        AccountIdentification4Choice1, FinancialInstitutionIdentification181,
+    };
+
+    #[test]
+    fn test_valid_bics() {
+        let bic = Bic::new("DEUTDEFF").unwrap();
+        assert_eq!(bic.institution_code(), "DEUT");
+        assert_eq!(bic.country_code(), "DE");
+        assert_eq!(bic.location_code(), "FF");
+        assert_eq!(bic.branch_code(), None);
+
+        let bic = Bic::new("BNPAFRPPXXX").unwrap();
+        assert_eq!(bic.branch_code(), Some("XXX"));
+        assert_eq!(bic.to_string(), "BNPAFRPPXXX");
+    }
+
+    #[test]
+    fn test_invalid_bics() {
+        assert_eq!(Bic::new("DEUTDEF").unwrap_err().code, 1005);
+        assert_eq!(Bic::new("deutdeff").unwrap_err().code, 1005);
+        assert_eq!(Bic::new("DEUT1EFF").unwrap_err().code, 1005);
+        assert_eq!(Bic::new("DEUTQQFF").unwrap_err().code, 1006);
+        assert!(!Bic::from("DEUTQQFF").is_valid());
+    }
+
+    #[test]
+    fn test_bic_validate_reports_path() {
+        let mut collector = ErrorCollector::new();
+        Bic::from("DEUTQQFF").validate(
+            "DbtrAgt.FinInstnId.BICFI",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        let errors = collector.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 1006);
+        assert_eq!(errors[0].field.as_deref(), Some("BICFI"));
+    }
+
+    #[test]
+    fn test_bic_xml_round_trip() {
+        let fin_instn_id = FinancialInstitutionIdentification181 {
+            bicfi: Some(Bic::new("BANKUS33XXX").unwrap()),
+            ..Default::default()
+        };
+
+        let xml = quick_xml::se::to_string(&fin_instn_id).unwrap();
+        assert!(xml.contains("<BICFI>BANKUS33XXX</BICFI>"));
+
+        let parsed: FinancialInstitutionIdentification181 = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, fin_instn_id);
+    }
+
+    #[test]
+    fn test_valid_ibans() {
+        let iban = Iban::new("GB82WEST12345698765432").unwrap();
+        assert_eq!(iban.country_code(), "GB");
+        assert_eq!(iban.check_digits(), "82");
+        assert_eq!(iban.bban(), "WEST12345698765432");
+
+        for value in [
+            "DE89370400440532013000",
+            "FR1420041010050500013M02606",
+            "NL91ABNA0417164300",
+            "CH9300762011623852957",
+            "NO9386011117947",
+        ] {
+            assert!(Iban::new(value).is_ok(), "{value} should be valid");
+        }
+    }
+
+    #[test]
+    fn test_iban_from_bban() {
+        let iban = Iban::from_bban("GB", "WEST12345698765432").unwrap();
+        assert_eq!(iban.as_str(), "GB82WEST12345698765432");
+        assert_eq!(
+            Iban::from_bban("DE", "370400440532013000")
+                .unwrap()
+                .as_str(),
+            "DE89370400440532013000"
+        );
+        assert_eq!(
+            Iban::from_bban("ZZ", "370400440532013000")
+                .unwrap_err()
+                .code,
+            1006
+        );
+        assert_eq!(Iban::from_bban("DE", "3704-0044").unwrap_err().code, 1005);
+    }
+
+    #[test]
+    fn test_invalid_ibans() {
+        assert_eq!(Iban::new("GB82WEST12345698765433").unwrap_err().code, 1007);
+        assert_eq!(Iban::new("DE88370400440532013000").unwrap_err().code, 1007);
+        assert_eq!(Iban::new("gb82WEST12345698765432").unwrap_err().code, 1005);
+        assert_eq!(Iban::new("GB8").unwrap_err().code, 1005);
+        assert_eq!(Iban::new("QQ82WEST12345698765432").unwrap_err().code, 1006);
+        assert!(!Iban::from("GB00WEST12345698765432").is_valid());
+    }
+
+    #[test]
+    fn test_iban_validate_reports_path() {
+        let mut collector = ErrorCollector::new();
+        Iban::from("GB82WEST12345698765433").validate(
+            "DbtrAcct.Id.IBAN",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        let errors = collector.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 1007);
+        assert_eq!(errors[0].path.as_deref(), Some("DbtrAcct.Id.IBAN"));
+    }
+
+    #[test]
+    fn test_valid_currency_codes() {
+        let eur = CurrencyCode::new("EUR").unwrap();
+        assert!(eur.is_active());
+        assert_eq!(eur.minor_units(), Some(2));
+        assert_eq!(CurrencyCode::new("JPY").unwrap().minor_units(), Some(0));
+        assert_eq!(CurrencyCode::new("KWD").unwrap().minor_units(), Some(3));
+        assert_eq!(CurrencyCode::new("XAU").unwrap().minor_units(), None);
+    }
+
+    #[test]
+    fn test_historic_currency_codes() {
+        let dem = CurrencyCode::from("DEM");
+        assert!(dem.is_historic());
+        assert!(!dem.is_active());
+        assert!(dem.is_valid());
+        assert_eq!(dem.minor_units(), None);
+        assert_eq!(CurrencyCode::new("DEM").unwrap_err().code, 1010);
+
+        let mut collector = ErrorCollector::new();
+        dem.validate("Acct.Ccy", &ParserConfig::default(), &mut collector);
+        assert!(!collector.has_errors());
+    }
+
+    #[test]
+    fn test_invalid_currency_codes() {
+        assert_eq!(CurrencyCode::new("eur").unwrap_err().code, 1005);
+        assert_eq!(CurrencyCode::new("EURO").unwrap_err().code, 1005);
+        assert_eq!(CurrencyCode::new("ABC").unwrap_err().code, 1010);
+        assert!(!CurrencyCode::from("ABC").is_valid());
+
+        let test_code = CurrencyCode::new_lenient("ABC").unwrap();
+        assert_eq!(test_code.as_str(), "ABC");
+        assert_eq!(CurrencyCode::new_lenient("E1R").unwrap_err().code, 1005);
+
+        let mut collector = ErrorCollector::new();
+        test_code.validate(
+            "XchgRateInf.SrcCcy",
+            &ParserConfig::default(),
+            &mut collector,
+        );
+        let errors = collector.errors();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 1010);
+        assert_eq!(errors[0].field.as_deref(), Some("SrcCcy"));
+    }
+
+    #[test]
+    fn test_amount_currency_codes() {
+        use crate::document::pacs_008_001_08::{ActiveOrHistoricCurrencyAndAmount, CBPRAmount1};
+
+        let errors = |amount: &dyn Validate| {
+            let mut collector = ErrorCollector::new();
+            amount.validate("IntrBkSttlmAmt", &ParserConfig::default(), &mut collector);
+            collector.errors()
+        };
+        let cbpr = |ccy: &str| CBPRAmount1 {
+            ccy: ccy.into(),
+            value: 100.0,
+        };
+        let historic = |ccy: &str| ActiveOrHistoricCurrencyAndAmount {
+            ccy: ccy.into(),
+            value: 100.0,
+        };
+
+        assert!(errors(&cbpr("EUR")).is_empty());
+        assert!(errors(&historic("DEM")).is_empty());
+        for amount in [
+            &cbpr("XYZ") as &dyn Validate,
+            &cbpr("DEM"),
+            &historic("XYZ"),
+        ] {
+            let errors = errors(amount);
+            assert_eq!(errors.len(), 1);
+            assert_eq!(errors[0].code, 1010);
+            assert_eq!(errors[0].path.as_deref(), Some("IntrBkSttlmAmt.Ccy"));
+        }
+    }
+
+    #[test]
+    fn test_primary_office_bic() {
+        assert_eq!(
+            Bic::from(" deutdeff ").primary_office().as_str(),
+            "DEUTDEFFXXX"
+        );
+        assert_eq!(
+            Bic::from("DEUTDEFF500").primary_office().as_str(),
+            "DEUTDEFF500"
+        );
+    }
+
+    #[test]
+    fn test_iban_xml_round_trip() {
+        let id = AccountIdentification4Choice1 {
+            iban: Some(Iban::new("DE89370400440532013000").unwrap()),
+            ..Default::default()
+        };
+
+        let xml = quick_xml::se::to_string(&id).unwrap();
+        assert!(xml.contains("<IBAN>DE89370400440532013000</IBAN>"));
+
+        let parsed: AccountIdentification4Choice1 = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, id);
+    }
+
+    #[test]
+    fn test_identifiers_as_str() {
+        fn lower(value: &str) -> String {
+            value.to_lowercase()
+        }
+        fn longest<S: AsRef<str>>(values: &[S]) -> usize {
+            values.iter().map(|v| v.as_ref().len()).max().unwrap_or(0)
+        }
+
+        let bic: Bic = "DEUTDEFFXXX".parse().unwrap();
+        let iban: Iban = "DE89370400440532013000".parse().unwrap();
+        let ccy: CurrencyCode = "EUR".parse().unwrap();
+
+        assert_eq!(lower(&bic), "deutdeffxxx");
+        assert!(iban.starts_with("DE89"));
+        assert_eq!(ccy.len(), 3);
+        assert_eq!(longest(&[Bic::from("BNPAFRPP"), bic.clone()]), 11);
+        assert_eq!(&*ccy, "EUR");
+
+        assert!("DEUT".parse::<Bic>().is_err());
+        assert!("DE00370400440532013000".parse::<Iban>().is_err());
+        assert!("EURO".parse::<CurrencyCode>().is_err());
+    }
+}
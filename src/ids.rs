@@ -0,0 +1,153 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Identifier generation
+//!
+//! Helpers producing message and end-to-end identifiers that satisfy the
+//! Max35Text constraints: at most 35 characters from the restricted SWIFT
+//! character set. Ids are built as `<prefix>-<yyyymmddHHMMSS><sequence>`, with
+//! the prefix stripped of characters outside the set (and of `/`, which SWIFT
+//! restricts at the start and end of references) and shortened to fit. The same
+//! inputs always give the same id; the generators feed in the current UTC time
+//! and a process-wide sequence number.
+
+use chrono::{DateTime, Utc};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Maximum length of Max35Text identifiers (MsgId, EndToEndId, InstrId, TxId)
+pub const MAX_ID_LENGTH: usize = 35;
+
+/// Prefix used for generated end-to-end identifiers
+pub const END_TO_END_ID_PREFIX: &str = "E2E";
+
+/// Digits of the sequence number, which wraps at 10^SEQUENCE_DIGITS
+const SEQUENCE_DIGITS: usize = 6;
+
+/// Length of the timestamp and sequence part of an id
+const SUFFIX_LENGTH: usize = 14 + SEQUENCE_DIGITS;
+
+static SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// Generate a message identifier (GrpHdr/MsgId) starting with `prefix`
+pub fn generate_msg_id(prefix: &str) -> String {
+    build_id(prefix, Utc::now(), next_sequence())
+}
+
+/// Generate an end-to-end identifier (PmtId/EndToEndId)
+pub fn generate_end_to_end_id() -> String {
+    build_id(END_TO_END_ID_PREFIX, Utc::now(), next_sequence())
+}
+
+/// Build an identifier from a prefix, timestamp and sequence number
+/// Only the last six digits of the sequence number are used.
+pub fn build_id(prefix: &str, timestamp: DateTime<Utc>, sequence: u64) -> String {
+    let prefix: String = prefix
+        .chars()
+        .filter(|&c| c.is_ascii_alphanumeric() || "-?:().,'+".contains(c))
+        .take(MAX_ID_LENGTH - SUFFIX_LENGTH - 1)
+        .collect();
+    let suffix = format!(
+        "{}{:0width$}",
+        timestamp.format("%Y%m%d%H%M%S"),
+        sequence % 10u64.pow(SEQUENCE_DIGITS as u32),
+        width = SEQUENCE_DIGITS
+    );
+    if prefix.is_empty() {
+        suffix
+    } else {
+        format!("{prefix}-{suffix}")
+    }
+}
+
+fn next_sequence() -> u64 {
+    SEQUENCE.fetch_add(1, Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_result::{ErrorCollector, ParserConfig};
+    use crate::validation::helpers::{validate_length, validate_pattern};
+    use chrono::TimeZone;
+
+    const MAX35_TEXT_PATTERN: &str = "[0-9a-zA-Z/\\-\\?:\\(\\)\\.,'\\+ ]+";
+
+    fn assert_valid_id(id: &str) {
+        let config = ParserConfig::default();
+        let mut collector = ErrorCollector::new();
+        validate_length(
+            id,
+            "MsgId",
+            Some(1),
+            Some(35),
+            "MsgId",
+            &config,
+            &mut collector,
+        );
+        validate_pattern(
+            id,
+            "MsgId",
+            MAX35_TEXT_PATTERN,
+            "MsgId",
+            &config,
+            &mut collector,
+        );
+        assert!(!collector.has_errors(), "{id} is not a valid Max35Text");
+        assert!(
+            id.chars()
+                .all(|c| c.is_ascii_alphanumeric() || "-?:().,'+".contains(c))
+        );
+    }
+
+    #[test]
+    fn test_build_id_is_deterministic() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 3, 14, 9, 26, 53).unwrap();
+        assert_eq!(build_id("BANK", timestamp, 42), "BANK-20250314092653000042");
+        assert_eq!(
+            build_id("BANK", timestamp, 1_000_042),
+            "BANK-20250314092653000042"
+        );
+        assert_eq!(build_id("", timestamp, 7), "20250314092653000007");
+    }
+
+    #[test]
+    fn test_build_id_sanitizes_prefix() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 3, 14, 9, 26, 53).unwrap();
+        let id = build_id("ACME Bank/Treasury_Ops*Department", timestamp, 1);
+        assert_eq!(id, "ACMEBankTreasu-20250314092653000001");
+        assert_eq!(id.len(), MAX_ID_LENGTH);
+        assert_valid_id(&id);
+    }
+
+    #[test]
+    fn test_generated_ids_validate() {
+        let first = generate_msg_id("MSG");
+        let second = generate_msg_id("MSG");
+        assert_ne!(first, second);
+        assert!(first.starts_with("MSG-"));
+        assert_valid_id(&first);
+        assert_valid_id(&generate_msg_id(
+            "a very long prefix with spaces and ümlauts",
+        ));
+
+        let end_to_end_id = generate_end_to_end_id();
+        assert!(end_to_end_id.starts_with("E2E-"));
+        assert_valid_id(&end_to_end_id);
+    }
+}
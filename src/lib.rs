@@ -17,18 +17,73 @@
 // You may obtain a copy of this library at
 // https://github.com/GoPlasmatic/MXMessage
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+// Text checks that build without std (length, control characters, patterns)
+pub mod core_validation;
+pub mod pattern;
+
+#[cfg(feature = "std")]
+pub mod accessors;
+#[cfg(feature = "std")]
+pub mod batch;
+#[cfg(feature = "std")]
+pub mod business_rules;
+#[cfg(feature = "std")]
+pub mod cancellation;
+#[cfg(feature = "std")]
+pub mod content_hash;
+#[cfg(feature = "std")]
+pub mod dates;
+#[cfg(feature = "std")]
+pub mod depth;
+#[cfg(feature = "std")]
 pub mod document;
+#[cfg(feature = "std")]
+pub mod downgrade;
+#[cfg(feature = "std")]
 pub mod error;
+#[cfg(feature = "std")]
+pub mod external_codes;
+#[cfg(feature = "gzip")]
+pub mod gzip;
+#[cfg(feature = "std")]
 pub mod header;
+#[cfg(feature = "std")]
+pub mod identifiers;
+#[cfg(feature = "std")]
+pub mod ids;
+#[cfg(feature = "std")]
 pub mod message_registry;
+#[cfg(feature = "std")]
+pub mod message_type;
+#[cfg(feature = "std")]
+pub mod multi_currency;
+#[cfg(feature = "std")]
 pub mod parse_result;
+#[cfg(feature = "std")]
+pub mod samples;
+#[cfg(feature = "std")]
+pub mod status_report;
+#[cfg(feature = "std")]
 pub mod validation;
+#[cfg(feature = "std")]
+pub mod validation_cache;
+#[cfg(feature = "std")]
+pub mod visitor;
+#[cfg(feature = "std")]
 pub mod xml;
 
 // Plugin module for dataflow-rs integration
+#[cfg(feature = "std")]
 pub mod plugin;
+#[cfg(feature = "std")]
 pub use plugin::*;
 
 // Re-export MX envelope and XML utilities
+#[cfg(feature = "std")]
 pub mod mx_envelope;
+#[cfg(feature = "std")]
 pub use mx_envelope::MxMessage;
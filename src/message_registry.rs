@@ -29,6 +29,7 @@
 
 /// Message type registry entry
 /// Format: (short_form, full_form, rust_type_name, xml_element_name)
+#[derive(Debug, PartialEq, Eq)]
 pub struct MessageTypeInfo {
     pub short_form: &'static str,
     pub full_form: &'static str,
@@ -249,6 +250,32 @@ pub fn get_full_form(message_type: &str) -> Option<&'static str> {
         .map(|info| info.full_form)
 }
 
+/// Whether the value has the `area.nnn.nnn.nn` form of a message definition identifier
+pub fn is_message_definition_identifier(identifier: &str) -> bool {
+    let parts: Vec<&str> = identifier.split('.').collect();
+    let [area, number, variant, version] = parts[..] else {
+        return false;
+    };
+    area.len() == 4
+        && area.bytes().all(|b| b.is_ascii_lowercase())
+        && [(number, 3), (variant, 3), (version, 2)]
+            .iter()
+            .all(|(part, len)| part.len() == *len && part.bytes().all(|b| b.is_ascii_digit()))
+}
+
+/// Registry entry for a message definition identifier (e.g. "pacs.008.001.08")
+/// The identifier must have the full `area.nnn.nnn.nn` form; any version of a
+/// registered message type matches, since an original message may predate the
+/// supported version.
+pub fn find_message_definition(identifier: &str) -> Option<&'static MessageTypeInfo> {
+    if !is_message_definition_identifier(identifier) {
+        return None;
+    }
+    MESSAGE_REGISTRY
+        .iter()
+        .find(|info| info.short_form == &identifier[..8])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -305,4 +332,17 @@ mod tests {
         assert_eq!(get_full_form("pacs.008.001.08"), Some("pacs.008.001.08"));
         assert_eq!(get_full_form("unknown"), None);
     }
+
+    #[test]
+    fn test_find_message_definition() {
+        let info = find_message_definition("pacs.008.001.08").unwrap();
+        assert_eq!(info.short_form, "pacs.008");
+        assert_eq!(
+            find_message_definition("pain.001.001.08").map(|info| info.full_form),
+            Some("pain.001.001.09")
+        );
+        assert_eq!(find_message_definition("pacs.008"), None);
+        assert_eq!(find_message_definition("pacs.008.001.8"), None);
+        assert_eq!(find_message_definition("pacs.099.001.01"), None);
+    }
 }
@@ -0,0 +1,572 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Message type registry
+//!
+//! Every supported document type implements [`MxDocumentDefinition`], which
+//! carries its namespace and message definition identifier as constants, and
+//! the object-safe [`MxDocumentType`], which exposes the same information
+//! together with the message id, validation and a one-line summary. Adding a
+//! message is a single `mx_document_type!` line here next to its `Document`
+//! variant. [`MessageCategory`] groups the messages into requests, responses
+//! and notifications for workflow routing, and [`RTransactionKind`] picks out
+//! the R-transactions (rejects, returns, refunds, recalls) for exception handling.
+
+use crate::document::*;
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::status_report::STATUS_REJECTED;
+use crate::validation::Validate;
+use serde::{Deserialize, Serialize};
+
+/// Workflow family of a message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MessageCategory {
+    /// Instructs or asks the receiver to act: payments, cancellations, reporting requests
+    Request,
+    /// Answers an earlier message: status reports, resolutions, returns, receipts
+    Response,
+    /// Informs without expecting an answer: statements, advices, notifications
+    Notification,
+}
+
+/// Kind of R-transaction: a message that undoes or stops an earlier payment
+/// Reversals (pacs.007) are not among the supported message types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RTransactionKind {
+    /// Payment rejected before settlement (pacs.002 or pain.002 with status RJCT)
+    Reject,
+    /// Settled payment sent back to the original sender (pacs.004)
+    Return,
+    /// Settled direct debit paid back at the debtor's request (pacs.004 with reason MD06)
+    Refund,
+    /// Request to cancel a sent payment (camt.055, camt.056)
+    Recall,
+}
+
+/// Return reason code of a refund requested by the end customer
+const REFUND_REASON_CODE: &str = "MD06";
+
+/// Static description of a message definition
+pub trait MxDocumentDefinition {
+    /// XML namespace of the Document element (e.g. "urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08")
+    const NAMESPACE: &'static str;
+    /// Full message definition identifier (e.g. "pacs.008.001.08")
+    const MESSAGE_DEFINITION_IDENTIFIER: &'static str;
+    /// Short message type (e.g. "pacs.008")
+    const MESSAGE_TYPE: &'static str;
+}
+
+/// Uniform access to a message document, usable as `dyn MxDocumentType`
+pub trait MxDocumentType {
+    /// XML namespace of the Document element
+    fn namespace(&self) -> &'static str;
+
+    /// Full message definition identifier (e.g. "pacs.008.001.08")
+    fn message_definition_identifier(&self) -> &'static str;
+
+    /// Short message type (e.g. "pacs.008")
+    fn message_type(&self) -> &'static str;
+
+    /// Primary identifier of the document
+    fn message_id(&self) -> &str;
+
+    /// Run the schema rules and every business rule of the document
+    /// This includes the document-level rules (scheme requirements, AnyBIC, agent
+    /// chain, original message, countries, assignment); only the envelope rules tying
+    /// the document to its header are left to `MxMessage::validate_with`.
+    fn validate(&self, config: &ParserConfig) -> ErrorCollector;
+
+    /// Message type and primary id, e.g. "pacs.008 MSG-001"
+    fn summary(&self) -> String {
+        format!("{} {}", self.message_type(), self.message_id())
+    }
+}
+
+macro_rules! mx_document_type {
+    ($ty:ty, $variant:ident, $msg_type:literal, $version:literal, $($id:ident).+) => {
+        impl MxDocumentDefinition for $ty {
+            const NAMESPACE: &'static str =
+                concat!("urn:iso:std:iso:20022:tech:xsd:", $msg_type, ".", $version);
+            const MESSAGE_DEFINITION_IDENTIFIER: &'static str = concat!($msg_type, ".", $version);
+            const MESSAGE_TYPE: &'static str = $msg_type;
+        }
+
+        impl MxDocumentType for $ty {
+            fn namespace(&self) -> &'static str {
+                <Self as MxDocumentDefinition>::NAMESPACE
+            }
+
+            fn message_definition_identifier(&self) -> &'static str {
+                <Self as MxDocumentDefinition>::MESSAGE_DEFINITION_IDENTIFIER
+            }
+
+            fn message_type(&self) -> &'static str {
+                <Self as MxDocumentDefinition>::MESSAGE_TYPE
+            }
+
+            fn message_id(&self) -> &str {
+                &self.$($id).+
+            }
+
+            fn validate(&self, config: &ParserConfig) -> ErrorCollector {
+                // The document-level rules (scheme requirements, AnyBIC, agent chain, ...)
+                // work on the surrounding Document
+                let mut collector = ErrorCollector::new();
+                Validate::validate(
+                    &Document::$variant(Box::new(self.clone())),
+                    "",
+                    config,
+                    &mut collector,
+                );
+                collector
+            }
+        }
+    };
+}
+
+// PACS - Payment Clearing and Settlement
+mx_document_type!(
+    pacs_008_001_08::FIToFICustomerCreditTransferV08,
+    Pacs008,
+    "pacs.008",
+    "001.08",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    pacs_002_001_10::FIToFIPaymentStatusReportV10,
+    Pacs002,
+    "pacs.002",
+    "001.10",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    pacs_003_001_08::FIToFICustomerDirectDebitV08,
+    Pacs003,
+    "pacs.003",
+    "001.08",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    pacs_004_001_09::PaymentReturnV09,
+    Pacs004,
+    "pacs.004",
+    "001.09",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    pacs_009_001_08::FinancialInstitutionCreditTransferV08,
+    Pacs009,
+    "pacs.009",
+    "001.08",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    pacs_010_001_03::FinancialInstitutionDirectDebitV03,
+    Pacs010,
+    "pacs.010",
+    "001.03",
+    grp_hdr.msg_id
+);
+
+// PAIN - Payment Initiation
+mx_document_type!(
+    pain_001_001_09::CustomerCreditTransferInitiationV09,
+    Pain001,
+    "pain.001",
+    "001.09",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    pain_002_001_10::CustomerPaymentStatusReportV10,
+    Pain002,
+    "pain.002",
+    "001.10",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    pain_008_001_08::CustomerDirectDebitInitiationV08,
+    Pain008,
+    "pain.008",
+    "001.08",
+    grp_hdr.msg_id
+);
+
+// CAMT - Cash Management
+mx_document_type!(
+    camt_025_001_08::ReceiptV08,
+    Camt025,
+    "camt.025",
+    "001.08",
+    msg_hdr.msg_id
+);
+mx_document_type!(
+    camt_029_001_09::ResolutionOfInvestigationV09,
+    Camt029,
+    "camt.029",
+    "001.09",
+    assgnmt.id
+);
+mx_document_type!(
+    camt_052_001_08::BankToCustomerAccountReportV08,
+    Camt052,
+    "camt.052",
+    "001.08",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_053_001_08::BankToCustomerStatementV08,
+    Camt053,
+    "camt.053",
+    "001.08",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_054_001_08::BankToCustomerDebitCreditNotificationV08,
+    Camt054,
+    "camt.054",
+    "001.08",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_055_001_08::CustomerPaymentCancellationRequestV08,
+    Camt055,
+    "camt.055",
+    "001.08",
+    assgnmt.id
+);
+mx_document_type!(
+    camt_056_001_08::FIToFIPaymentCancellationRequestV08,
+    Camt056,
+    "camt.056",
+    "001.08",
+    assgnmt.id
+);
+mx_document_type!(
+    camt_057_001_06::NotificationToReceiveV06,
+    Camt057,
+    "camt.057",
+    "001.06",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_058_001_08::NotificationToReceiveCancellationAdviceV08,
+    Camt058,
+    "camt.058",
+    "001.08",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_060_001_05::AccountReportingRequestV05,
+    Camt060,
+    "camt.060",
+    "001.05",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_105_001_02::ChargesPaymentNotificationV02,
+    Camt105,
+    "camt.105",
+    "001.02",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_106_001_02::ChargesPaymentRequestV02,
+    Camt106,
+    "camt.106",
+    "001.02",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_107_001_01::ChequePresentmentNotificationV01,
+    Camt107,
+    "camt.107",
+    "001.01",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_108_001_01::ChequeCancellationOrStopRequestV01,
+    Camt108,
+    "camt.108",
+    "001.01",
+    grp_hdr.msg_id
+);
+mx_document_type!(
+    camt_109_001_01::ChequeCancellationOrStopReportV01,
+    Camt109,
+    "camt.109",
+    "001.01",
+    grp_hdr.msg_id
+);
+
+// ADMI - Administration
+mx_document_type!(
+    admi_024_001_01::NotificationOfCorrespondenceV01,
+    Admi024,
+    "admi.024",
+    "001.01",
+    grp_hdr.msg_id
+);
+
+impl Document {
+    /// Borrow the contained message through the uniform [`MxDocumentType`] interface
+    pub fn as_document_type(&self) -> &dyn MxDocumentType {
+        match self {
+            Document::Pacs008(doc) => doc.as_ref(),
+            Document::Pacs002(doc) => doc.as_ref(),
+            Document::Pacs003(doc) => doc.as_ref(),
+            Document::Pacs004(doc) => doc.as_ref(),
+            Document::Pacs009(doc) => doc.as_ref(),
+            Document::Pacs010(doc) => doc.as_ref(),
+            Document::Pain001(doc) => doc.as_ref(),
+            Document::Pain002(doc) => doc.as_ref(),
+            Document::Pain008(doc) => doc.as_ref(),
+            Document::Camt025(doc) => doc.as_ref(),
+            Document::Camt029(doc) => doc.as_ref(),
+            Document::Camt052(doc) => doc.as_ref(),
+            Document::Camt053(doc) => doc.as_ref(),
+            Document::Camt054(doc) => doc.as_ref(),
+            Document::Camt055(doc) => doc.as_ref(),
+            Document::Camt056(doc) => doc.as_ref(),
+            Document::Camt057(doc) => doc.as_ref(),
+            Document::Camt058(doc) => doc.as_ref(),
+            Document::Camt060(doc) => doc.as_ref(),
+            Document::Camt105(doc) => doc.as_ref(),
+            Document::Camt106(doc) => doc.as_ref(),
+            Document::Camt107(doc) => doc.as_ref(),
+            Document::Camt108(doc) => doc.as_ref(),
+            Document::Camt109(doc) => doc.as_ref(),
+            Document::Admi024(doc) => doc.as_ref(),
+        }
+    }
+
+    /// Workflow family of the contained message
+    /// A payment return (pacs.004) answers the original payment and counts as a response.
+    pub fn message_category(&self) -> MessageCategory {
+        match self {
+            Document::Pacs008(_)
+            | Document::Pacs003(_)
+            | Document::Pacs009(_)
+            | Document::Pacs010(_)
+            | Document::Pain001(_)
+            | Document::Pain008(_)
+            | Document::Camt055(_)
+            | Document::Camt056(_)
+            | Document::Camt060(_)
+            | Document::Camt106(_)
+            | Document::Camt108(_) => MessageCategory::Request,
+            Document::Pacs002(_)
+            | Document::Pacs004(_)
+            | Document::Pain002(_)
+            | Document::Camt025(_)
+            | Document::Camt029(_)
+            | Document::Camt109(_) => MessageCategory::Response,
+            Document::Camt052(_)
+            | Document::Camt053(_)
+            | Document::Camt054(_)
+            | Document::Camt057(_)
+            | Document::Camt058(_)
+            | Document::Camt105(_)
+            | Document::Camt107(_)
+            | Document::Admi024(_) => MessageCategory::Notification,
+        }
+    }
+
+    /// Kind of R-transaction carried by the document, if it is one
+    pub fn r_transaction_kind(&self) -> Option<RTransactionKind> {
+        match self {
+            Document::Pacs002(doc) if doc.tx_inf_and_sts.tx_sts == STATUS_REJECTED => {
+                Some(RTransactionKind::Reject)
+            }
+            Document::Pain002(doc)
+                if doc.orgnl_pmt_inf_and_sts.tx_inf_and_sts.tx_sts == STATUS_REJECTED =>
+            {
+                Some(RTransactionKind::Reject)
+            }
+            Document::Pacs004(doc) => {
+                if doc.tx_inf.rtr_rsn_inf.rsn.cd.as_deref() == Some(REFUND_REASON_CODE) {
+                    Some(RTransactionKind::Refund)
+                } else {
+                    Some(RTransactionKind::Return)
+                }
+            }
+            Document::Camt055(_) | Document::Camt056(_) => Some(RTransactionKind::Recall),
+            _ => None,
+        }
+    }
+
+    /// Whether the document is an R-transaction (reject, return, refund or recall)
+    pub fn is_r_transaction(&self) -> bool {
+        self.r_transaction_kind().is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::message_registry::{self, MESSAGE_REGISTRY};
+
+    fn all_types() -> Vec<Box<dyn MxDocumentType>> {
+        vec![
+            Box::new(pacs_008_001_08::FIToFICustomerCreditTransferV08::default()),
+            Box::new(pacs_002_001_10::FIToFIPaymentStatusReportV10::default()),
+            Box::new(pacs_003_001_08::FIToFICustomerDirectDebitV08::default()),
+            Box::new(pacs_004_001_09::PaymentReturnV09::default()),
+            Box::new(pacs_009_001_08::FinancialInstitutionCreditTransferV08::default()),
+            Box::new(pacs_010_001_03::FinancialInstitutionDirectDebitV03::default()),
+            Box::new(pain_001_001_09::CustomerCreditTransferInitiationV09::default()),
+            Box::new(pain_002_001_10::CustomerPaymentStatusReportV10::default()),
+            Box::new(pain_008_001_08::CustomerDirectDebitInitiationV08::default()),
+            Box::new(camt_025_001_08::ReceiptV08::default()),
+            Box::new(camt_029_001_09::ResolutionOfInvestigationV09::default()),
+            Box::new(camt_052_001_08::BankToCustomerAccountReportV08::default()),
+            Box::new(camt_053_001_08::BankToCustomerStatementV08::default()),
+            Box::new(camt_054_001_08::BankToCustomerDebitCreditNotificationV08::default()),
+            Box::new(camt_055_001_08::CustomerPaymentCancellationRequestV08::default()),
+            Box::new(camt_056_001_08::FIToFIPaymentCancellationRequestV08::default()),
+            Box::new(camt_057_001_06::NotificationToReceiveV06::default()),
+            Box::new(camt_058_001_08::NotificationToReceiveCancellationAdviceV08::default()),
+            Box::new(camt_060_001_05::AccountReportingRequestV05::default()),
+            Box::new(camt_105_001_02::ChargesPaymentNotificationV02::default()),
+            Box::new(camt_106_001_02::ChargesPaymentRequestV02::default()),
+            Box::new(camt_107_001_01::ChequePresentmentNotificationV01::default()),
+            Box::new(camt_108_001_01::ChequeCancellationOrStopRequestV01::default()),
+            Box::new(camt_109_001_01::ChequeCancellationOrStopReportV01::default()),
+            Box::new(admi_024_001_01::NotificationOfCorrespondenceV01::default()),
+        ]
+    }
+
+    #[test]
+    fn test_every_registered_type_is_covered() {
+        let types = all_types();
+        assert_eq!(types.len(), MESSAGE_REGISTRY.len());
+
+        for doc in &types {
+            let full_form = message_registry::get_full_form(doc.message_type());
+            assert_eq!(full_form, Some(doc.message_definition_identifier()));
+            assert_eq!(
+                doc.namespace(),
+                message_registry::get_namespace(doc.message_type())
+            );
+            assert_eq!(doc.summary(), format!("{} ", doc.message_type()));
+            // Empty defaults never pass the schema rules
+            assert!(doc.validate(&ParserConfig::default()).has_errors());
+        }
+    }
+
+    #[test]
+    fn test_validate_runs_document_level_rules() {
+        let doc = camt_056_001_08::FIToFIPaymentCancellationRequestV08::default();
+        let collector = MxDocumentType::validate(&doc, &ParserConfig::default());
+        let errors = collector.errors();
+        assert!(errors.iter().any(|e| e.code == 2042));
+
+        let mut expected = ErrorCollector::new();
+        Document::Camt056(Box::new(doc)).validate("", &ParserConfig::default(), &mut expected);
+        assert_eq!(errors, expected.errors());
+    }
+
+    #[test]
+    fn test_document_exposes_its_type() {
+        let mut inner = camt_025_001_08::ReceiptV08::default();
+        inner.msg_hdr.msg_id = "RCPT-1".to_string();
+        let document = Document::Camt025(Box::new(inner));
+
+        let doc_type = document.as_document_type();
+        assert_eq!(doc_type.summary(), document.to_string());
+        assert_eq!(doc_type.namespace(), document.namespace());
+        assert_eq!(
+            <camt_025_001_08::ReceiptV08 as MxDocumentDefinition>::NAMESPACE,
+            "urn:iso:std:iso:20022:tech:xsd:camt.025.001.08"
+        );
+    }
+
+    #[test]
+    fn test_message_category() {
+        let category = |document: Document| document.message_category();
+        assert_eq!(
+            category(Document::Camt060(Box::default())),
+            MessageCategory::Request
+        );
+        assert_eq!(
+            category(Document::Pacs008(Box::default())),
+            MessageCategory::Request
+        );
+        assert_eq!(
+            category(Document::Camt056(Box::default())),
+            MessageCategory::Request
+        );
+        assert_eq!(
+            category(Document::Pacs002(Box::default())),
+            MessageCategory::Response
+        );
+        assert_eq!(
+            category(Document::Camt029(Box::default())),
+            MessageCategory::Response
+        );
+        assert_eq!(
+            category(Document::Camt054(Box::default())),
+            MessageCategory::Notification
+        );
+        assert_eq!(
+            category(Document::Camt053(Box::default())),
+            MessageCategory::Notification
+        );
+    }
+
+    #[test]
+    fn test_r_transaction_kind() {
+        let mut ret = pacs_004_001_09::PaymentReturnV09::default();
+        ret.tx_inf.rtr_rsn_inf.rsn.cd = Some("AC04".to_string());
+        let document = Document::Pacs004(Box::new(ret.clone()));
+        assert!(document.is_r_transaction());
+        assert_eq!(
+            document.r_transaction_kind(),
+            Some(RTransactionKind::Return)
+        );
+
+        ret.tx_inf.rtr_rsn_inf.rsn.cd = Some("MD06".to_string());
+        assert_eq!(
+            Document::Pacs004(Box::new(ret)).r_transaction_kind(),
+            Some(RTransactionKind::Refund)
+        );
+
+        assert_eq!(
+            Document::Camt056(Box::default()).r_transaction_kind(),
+            Some(RTransactionKind::Recall)
+        );
+
+        let mut status = pacs_002_001_10::FIToFIPaymentStatusReportV10::default();
+        status.tx_inf_and_sts.tx_sts = "ACCP".to_string();
+        assert!(!Document::Pacs002(Box::new(status.clone())).is_r_transaction());
+        status.tx_inf_and_sts.tx_sts = "RJCT".to_string();
+        assert_eq!(
+            Document::Pacs002(Box::new(status)).r_transaction_kind(),
+            Some(RTransactionKind::Reject)
+        );
+    }
+
+    #[test]
+    fn test_payment_is_not_r_transaction() {
+        assert!(!Document::Pacs008(Box::default()).is_r_transaction());
+        assert_eq!(Document::Camt053(Box::default()).r_transaction_kind(), None);
+    }
+}
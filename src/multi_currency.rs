@@ -0,0 +1,482 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Multi-Currency Variants
+//!
+//! `camt.105`, `camt.106` and `pacs.010` each come in a base form and an `_mc`
+//! variant. The base charges messages carry one charges record; the `_mc`
+//! charges messages batch several records under a single total. Splitting
+//! groups the records by currency and turns each into a base message;
+//! combining batches base messages back into one `_mc` message, provided they
+//! share a currency and a group header. `pacs.010` carries one direct debit in
+//! both forms, so the conversion is one to one.
+
+use crate::document::{
+    camt_105_001_02, camt_105_001_02_mc, camt_106_001_02, camt_106_001_02_mc, pacs_010_001_03,
+    pacs_010_001_03_mc,
+};
+use crate::error::{MxError, ValidationError};
+use crate::validation::helpers::{child_path, index_path};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value, json};
+use std::collections::BTreeMap;
+
+/// Conversion between an `_mc` variant and its base message type
+pub trait MultiCurrency: Sized {
+    /// Base message type the variant splits into
+    type Base;
+
+    /// Split into base messages, keyed by currency
+    /// Each base message keeps the source group header and message identifier.
+    fn split_by_currency(&self) -> Result<BTreeMap<String, Vec<Self::Base>>, MxError>;
+
+    /// Combine base messages into one message
+    /// The group header and message identifier are taken from the first message.
+    fn combine(messages: &[Self::Base]) -> Result<Self, MxError>;
+}
+
+/// Group header elements that move into each record in the `_mc` charges variants
+const RECORD_LEVEL_HEADER: &[&str] = &["ChrgsRqstr"];
+
+impl MultiCurrency for camt_105_001_02_mc::ChargesPaymentNotificationV02 {
+    type Base = camt_105_001_02::ChargesPaymentNotificationV02;
+
+    fn split_by_currency(&self) -> Result<BTreeMap<String, Vec<Self::Base>>, MxError> {
+        split_charges(self)
+    }
+
+    fn combine(messages: &[Self::Base]) -> Result<Self, MxError> {
+        combine_charges(messages)
+    }
+}
+
+impl MultiCurrency for camt_106_001_02_mc::ChargesPaymentRequestV02 {
+    type Base = camt_106_001_02::ChargesPaymentRequestV02;
+
+    fn split_by_currency(&self) -> Result<BTreeMap<String, Vec<Self::Base>>, MxError> {
+        split_charges(self)
+    }
+
+    fn combine(messages: &[Self::Base]) -> Result<Self, MxError> {
+        combine_charges(messages)
+    }
+}
+
+impl MultiCurrency for pacs_010_001_03_mc::FinancialInstitutionDirectDebitV03 {
+    type Base = pacs_010_001_03::FinancialInstitutionDirectDebitV03;
+
+    fn split_by_currency(&self) -> Result<BTreeMap<String, Vec<Self::Base>>, MxError> {
+        let instr = &self.cdt_instr;
+        // Elements of the credit instruction that only the `_mc` variant has
+        let mc_only = [
+            ("PmtTpInf", instr.pmt_tp_inf.is_some()),
+            (
+                "IntrmyAgt1",
+                instr.intrmy_agt1.is_some() || instr.intrmy_agt1_acct.is_some(),
+            ),
+            (
+                "IntrmyAgt2",
+                instr.intrmy_agt2.is_some() || instr.intrmy_agt2_acct.is_some(),
+            ),
+            (
+                "IntrmyAgt3",
+                instr.intrmy_agt3.is_some() || instr.intrmy_agt3_acct.is_some(),
+            ),
+        ];
+        if let Some((name, _)) = mc_only.iter().find(|(_, present)| *present) {
+            return Err(conversion_error(
+                3001,
+                &child_path("CdtInstr", name),
+                "has no equivalent in the base message",
+            ));
+        }
+
+        let ccy = instr.drct_dbt_tx_inf.intr_bk_sttlm_amt.ccy.to_string();
+        Ok(BTreeMap::from([(ccy, vec![convert(self)?])]))
+    }
+
+    fn combine(messages: &[Self::Base]) -> Result<Self, MxError> {
+        match messages {
+            [message] => convert(message),
+            _ => Err(conversion_error(
+                3003,
+                "CdtInstr",
+                "must come from exactly one message; pacs.010 carries a single direct debit",
+            )),
+        }
+    }
+}
+
+/// Split an `_mc` charges message into one base message per record
+fn split_charges<M, B>(message: &M) -> Result<BTreeMap<String, Vec<B>>, MxError>
+where
+    M: Serialize,
+    B: DeserializeOwned,
+{
+    let mut value = to_object(message)?;
+    let mut header = take_object(&mut value, "GrpHdr");
+    header.remove("TtlChrgs");
+    let mut per_tx = take_object(&mut take_object(&mut value, "Chrgs"), "PerTx");
+    let chrgs_id = per_tx.remove("ChrgsId").unwrap_or(Value::Null);
+    let records = match per_tx.remove("Rcrd") {
+        Some(Value::Array(records)) => records,
+        _ => Vec::new(),
+    };
+
+    let mut split: BTreeMap<String, Vec<B>> = BTreeMap::new();
+    for (i, record) in records.into_iter().enumerate() {
+        let Value::Object(mut record) = record else {
+            continue;
+        };
+        let Some(ccy) = record_currency(&record) else {
+            return Err(conversion_error(
+                3002,
+                &format!(
+                    "{}.TtlChrgsPerRcrd.TtlChrgsAmt",
+                    index_path("Chrgs.PerTx.Rcrd", i)
+                ),
+                "has no currency",
+            ));
+        };
+        let mut grp_hdr = header.clone();
+        for name in RECORD_LEVEL_HEADER {
+            if let Some(element) = record.remove(*name) {
+                grp_hdr.insert(name.to_string(), element);
+            }
+        }
+        let id = record.remove("RcrdId").unwrap_or_else(|| chrgs_id.clone());
+        let base = json!({
+            "GrpHdr": grp_hdr,
+            "Chrgs": { "PerTx": { "ChrgsId": id, "Rcrd": record } },
+        });
+        split
+            .entry(ccy)
+            .or_default()
+            .push(serde_json::from_value(base)?);
+    }
+    Ok(split)
+}
+
+/// Combine base charges messages into an `_mc` message under one total
+fn combine_charges<B, M>(messages: &[B]) -> Result<M, MxError>
+where
+    B: Serialize,
+    M: DeserializeOwned,
+{
+    let mut header: Option<Map<String, Value>> = None;
+    let mut shared: Option<Map<String, Value>> = None;
+    let mut chrgs_id = Value::Null;
+    let mut ccy: Option<String> = None;
+    let mut total = 0.0;
+    let mut records = Vec::new();
+
+    for (i, message) in messages.iter().enumerate() {
+        let path = index_path("", i);
+        let mut value = to_object(message)?;
+        let mut grp_hdr = take_object(&mut value, "GrpHdr");
+        let mut per_tx = take_object(&mut take_object(&mut value, "Chrgs"), "PerTx");
+        let mut record = match per_tx.remove("Rcrd") {
+            Some(Value::Object(record)) => record,
+            _ => Map::new(),
+        };
+
+        let amount_path = format!("{path}.Chrgs.PerTx.Rcrd.TtlChrgsPerRcrd.TtlChrgsAmt");
+        let Some(record_ccy) = record_currency(&record) else {
+            return Err(conversion_error(3002, &amount_path, "has no currency"));
+        };
+        match &ccy {
+            Some(expected) if *expected != record_ccy => {
+                return Err(conversion_error(
+                    3002,
+                    &amount_path,
+                    &format!("is in {record_ccy}, but the combined message is in {expected}"),
+                ));
+            }
+            Some(_) => {}
+            None => ccy = Some(record_ccy),
+        }
+        // Credits add to the total and debits subtract from it
+        let totals = &record["TtlChrgsPerRcrd"];
+        let amount = totals["TtlChrgsAmt"]["$value"].as_f64().unwrap_or_default();
+        match totals["CdtDbtInd"].as_str() {
+            Some("CRDT") => total += amount,
+            _ => total -= amount,
+        }
+
+        for name in RECORD_LEVEL_HEADER {
+            if let Some(element) = grp_hdr.remove(*name) {
+                record.insert(name.to_string(), element);
+            }
+        }
+        let id = per_tx.remove("ChrgsId").unwrap_or(Value::Null);
+        record.insert("RcrdId".to_string(), id.clone());
+        records.push(Value::Object(record));
+
+        let mut rest = grp_hdr.clone();
+        rest.remove("MsgId");
+        rest.remove("CreDtTm");
+        match &shared {
+            Some(expected) if *expected != rest => {
+                return Err(conversion_error(
+                    3003,
+                    &format!("{path}.GrpHdr"),
+                    "differs from the group header of the first message",
+                ));
+            }
+            Some(_) => {}
+            None => {
+                shared = Some(rest);
+                header = Some(grp_hdr);
+                chrgs_id = id;
+            }
+        }
+    }
+
+    let Some(mut header) = header else {
+        return Err(conversion_error(3003, "", "requires at least one message"));
+    };
+    header.insert(
+        "TtlChrgs".to_string(),
+        json!({
+            "NbOfChrgsRcrds": records.len().to_string(),
+            "TtlChrgsAmt": { "@Ccy": ccy, "$value": total.abs() },
+            // A zero total is considered to be a credit
+            "CdtDbtInd": if total < 0.0 { "DBIT" } else { "CRDT" },
+        }),
+    );
+    let combined = json!({
+        "GrpHdr": header,
+        "Chrgs": { "PerTx": { "ChrgsId": chrgs_id, "Rcrd": records } },
+    });
+    Ok(serde_json::from_value(combined)?)
+}
+
+/// Currency of a charges record's total
+fn record_currency(record: &Map<String, Value>) -> Option<String> {
+    record
+        .get("TtlChrgsPerRcrd")?
+        .get("TtlChrgsAmt")?
+        .get("@Ccy")?
+        .as_str()
+        .map(str::to_string)
+}
+
+/// JSON object form of a message
+fn to_object<T: Serialize>(message: &T) -> Result<Map<String, Value>, MxError> {
+    match serde_json::to_value(message)? {
+        Value::Object(map) => Ok(map),
+        _ => Ok(Map::new()),
+    }
+}
+
+/// Remove the named child object, or an empty one if it is absent
+fn take_object(map: &mut Map<String, Value>, name: &str) -> Map<String, Value> {
+    match map.remove(name) {
+        Some(Value::Object(child)) => child,
+        _ => Map::new(),
+    }
+}
+
+/// Convert between two types with the same JSON form
+fn convert<T: Serialize, U: DeserializeOwned>(value: &T) -> Result<U, MxError> {
+    Ok(serde_json::from_value(serde_json::to_value(value)?)?)
+}
+
+fn conversion_error(code: u32, path: &str, reason: &str) -> MxError {
+    ValidationError::new(code, format!("{path} {reason}"))
+        .with_path(path.to_string())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::camt_105_001_02_mc::*;
+
+    fn record(id: &str, ccy: &str, value: f64) -> ChargesPerTransactionRecord41 {
+        let mut record = ChargesPerTransactionRecord41 {
+            rcrd_id: Some(id.to_string()),
+            ..Default::default()
+        };
+        record.chrgs_rqstr.fin_instn_id.bicfi = Some("BANKGB22XXX".into());
+        record.undrlyg_tx.end_to_end_id = Some(format!("E2E-{id}"));
+        record.ttl_chrgs_per_rcrd.nb_of_chrgs_brkdwn_itms = "1".to_string();
+        record.ttl_chrgs_per_rcrd.ttl_chrgs_amt = CBPRAmount {
+            ccy: ccy.into(),
+            value,
+        };
+        record
+    }
+
+    fn notification(records: Vec<ChargesPerTransactionRecord41>) -> ChargesPaymentNotificationV02 {
+        let mut message = ChargesPaymentNotificationV02::default();
+        message.grp_hdr.msg_id = "CHRGS-001".to_string();
+        message.grp_hdr.cre_dt_tm = "2024-01-15T10:30:00+00:00".to_string();
+        message.grp_hdr.ttl_chrgs.nb_of_chrgs_rcrds = records.len().to_string();
+        message.chrgs.per_tx = Some(ChargesPerTransaction41 {
+            chrgs_id: "CHRGS-ID".to_string(),
+            rcrd: records,
+        });
+        message
+    }
+
+    #[test]
+    fn test_split_charges_by_currency() {
+        let message = notification(vec![
+            record("R1", "USD", 10.0),
+            record("R2", "EUR", 7.25),
+            record("R3", "USD", 5.5),
+        ]);
+        let split = message.split_by_currency().unwrap();
+        assert_eq!(split.keys().collect::<Vec<_>>(), vec!["EUR", "USD"]);
+        assert_eq!(split["EUR"].len(), 1);
+
+        let usd = &split["USD"];
+        assert_eq!(usd.len(), 2);
+        assert_eq!(usd[0].grp_hdr.msg_id, "CHRGS-001");
+        assert_eq!(
+            usd[0].grp_hdr.chrgs_rqstr.fin_instn_id.bicfi,
+            Some("BANKGB22XXX".into())
+        );
+        let per_tx = usd[1].chrgs.per_tx.as_ref().unwrap();
+        assert_eq!(per_tx.chrgs_id, "R3");
+        assert_eq!(
+            per_tx.rcrd.undrlyg_tx.end_to_end_id.as_deref(),
+            Some("E2E-R3")
+        );
+        assert_eq!(per_tx.rcrd.ttl_chrgs_per_rcrd.ttl_chrgs_amt.value, 5.5);
+    }
+
+    #[test]
+    fn test_combine_charges() {
+        let message = notification(vec![record("R1", "USD", 10.0), record("R2", "USD", 5.5)]);
+        let split = message.split_by_currency().unwrap();
+        let combined = ChargesPaymentNotificationV02::combine(&split["USD"]).unwrap();
+
+        assert_eq!(combined.grp_hdr.msg_id, "CHRGS-001");
+        assert_eq!(combined.grp_hdr.ttl_chrgs.nb_of_chrgs_rcrds, "2");
+        assert_eq!(combined.grp_hdr.ttl_chrgs.ttl_chrgs_amt.ccy, "USD");
+        assert_eq!(combined.grp_hdr.ttl_chrgs.ttl_chrgs_amt.value, 15.5);
+        let per_tx = combined.chrgs.per_tx.unwrap();
+        assert_eq!(per_tx.chrgs_id, "R1");
+        assert_eq!(per_tx.rcrd, message.chrgs.per_tx.unwrap().rcrd);
+    }
+
+    #[test]
+    fn test_combine_nets_credits_and_debits() {
+        // The CBPR+ charges types only allow DBIT, so the records are built as JSON
+        let base = |id: &str, value: f64, ind: &str| {
+            json!({
+                "GrpHdr": { "MsgId": format!("MSG-{id}"), "CreDtTm": "2024-01-15T10:30:00+00:00" },
+                "Chrgs": { "PerTx": { "ChrgsId": id, "Rcrd": {
+                    "TtlChrgsPerRcrd": {
+                        "TtlChrgsAmt": { "@Ccy": "USD", "$value": value },
+                        "CdtDbtInd": ind,
+                    },
+                } } },
+            })
+        };
+        let total = |bases: &[Value]| {
+            let combined: Value = combine_charges(bases).unwrap();
+            combined["GrpHdr"]["TtlChrgs"].clone()
+        };
+
+        let net = total(&[base("R1", 10.0, "CRDT"), base("R2", 4.0, "DBIT")]);
+        assert_eq!(net["TtlChrgsAmt"]["$value"], 6.0);
+        assert_eq!(net["CdtDbtInd"], "CRDT");
+
+        let net = total(&[base("R1", 4.0, "CRDT"), base("R2", 10.0, "DBIT")]);
+        assert_eq!(net["TtlChrgsAmt"]["$value"], 6.0);
+        assert_eq!(net["CdtDbtInd"], "DBIT");
+    }
+
+    #[test]
+    fn test_split_rejects_record_without_currency() {
+        let message = notification(vec![record("R1", "USD", 10.0), record("R2", "", 5.5)]);
+        let mut value = serde_json::to_value(&message).unwrap();
+        value["Chrgs"]["PerTx"]["Rcrd"][1]["TtlChrgsPerRcrd"]["TtlChrgsAmt"]
+            .as_object_mut()
+            .unwrap()
+            .remove("@Ccy");
+        let Err(MxError::Validation { code, path, .. }) = split_charges::<Value, Value>(&value)
+        else {
+            panic!("expected split to fail");
+        };
+        assert_eq!(code, 3002);
+        assert_eq!(
+            path.as_deref(),
+            Some("Chrgs.PerTx.Rcrd[1].TtlChrgsPerRcrd.TtlChrgsAmt")
+        );
+    }
+
+    #[test]
+    fn test_combine_rejects_mixed_currencies() {
+        let message = notification(vec![record("R1", "USD", 10.0), record("R2", "EUR", 5.5)]);
+        let split = message.split_by_currency().unwrap();
+        let bases = vec![split["USD"][0].clone(), split["EUR"][0].clone()];
+
+        let Err(MxError::Validation { code, path, .. }) =
+            ChargesPaymentNotificationV02::combine(&bases)
+        else {
+            panic!("expected combine to fail");
+        };
+        assert_eq!(code, 3002);
+        assert_eq!(
+            path.as_deref(),
+            Some("[1].Chrgs.PerTx.Rcrd.TtlChrgsPerRcrd.TtlChrgsAmt")
+        );
+    }
+
+    #[test]
+    fn test_combine_rejects_different_charges_accounts() {
+        let message = notification(vec![record("R1", "USD", 10.0), record("R2", "USD", 5.5)]);
+        let mut bases = message.split_by_currency().unwrap().remove("USD").unwrap();
+        bases[1].grp_hdr.chrgs_acct.nm = Some("Other".to_string());
+
+        let Err(MxError::Validation { code, path, .. }) =
+            ChargesPaymentNotificationV02::combine(&bases)
+        else {
+            panic!("expected combine to fail");
+        };
+        assert_eq!(code, 3003);
+        assert_eq!(path.as_deref(), Some("[1].GrpHdr"));
+    }
+
+    #[test]
+    fn test_direct_debit_conversion() {
+        let mut message = pacs_010_001_03_mc::FinancialInstitutionDirectDebitV03::default();
+        message.grp_hdr.msg_id = "DD-001".to_string();
+        message.cdt_instr.drct_dbt_tx_inf.intr_bk_sttlm_amt.ccy = "EUR".into();
+        message.cdt_instr.drct_dbt_tx_inf.intr_bk_sttlm_amt.value = 250.0;
+
+        let split = message.split_by_currency().unwrap();
+        assert_eq!(split["EUR"].len(), 1);
+        let combined =
+            pacs_010_001_03_mc::FinancialInstitutionDirectDebitV03::combine(&split["EUR"]).unwrap();
+        assert_eq!(combined, message);
+
+        message.cdt_instr.intrmy_agt2 = Some(Default::default());
+        let Err(MxError::Validation { code, path, .. }) = message.split_by_currency() else {
+            panic!("expected split to fail");
+        };
+        assert_eq!(code, 3001);
+        assert_eq!(path.as_deref(), Some("CdtInstr.IntrmyAgt2"));
+    }
+}
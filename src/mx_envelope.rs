@@ -1,11 +1,25 @@
 // MX Message Envelope Structure for ISO 20022 compliant XML generation
 
+use chrono::{DateTime, FixedOffset};
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::Read;
 
 // Re-export AppHdr for convenience
-use crate::error::MxError;
+use crate::business_rules::{
+    agent_chain, any_bic, assignment, countries, creation_time, original_message, remittance,
+    scheme_requirements, serialization, settlement_total,
+};
+use crate::depth;
+use crate::error::{MxError, ValidationError};
 pub use crate::header::AppHdr;
+use crate::identifiers::{Bic, CurrencyCode};
 use crate::message_registry;
+use crate::message_type::{MessageCategory, RTransactionKind};
+use crate::parse_result::{ErrorCollector, ParserConfig, ValidationProfile, WhitespaceMode};
+use crate::validation::{BusinessRules, Validate, helpers};
+use crate::visitor;
+use crate::xml::{self, EnvelopeForm, XmlConfig};
 
 /// Document enum - represents the Document element in MX messages
 /// Each variant uses serde rename to match the XML element name
@@ -92,10 +106,29 @@ pub enum Document {
     Admi024(Box<crate::document::admi_024_001_01::NotificationOfCorrespondenceV01>),
 }
 
+/// A monetary amount of a document, see [`Document::all_amounts`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentAmount {
+    /// Path rooted at the message element, e.g. `CdtTrfTxInf.IntrBkSttlmAmt`
+    pub path: String,
+    /// Amount as a float, for display and approximate comparisons
+    pub value: f64,
+    /// Amount in plain decimal notation, as written to XML
+    /// Sum these with a decimal type to reconcile amounts without float drift.
+    pub text: String,
+    /// Currency of the `Ccy` attribute, `None` for implied-currency amounts
+    pub ccy: Option<CurrencyCode>,
+}
+
 impl Document {
     /// Get the namespace for this document based on its type
     pub fn namespace(&self) -> String {
-        let msg_type = match self {
+        message_registry::get_namespace(self.message_type())
+    }
+
+    /// Short message type of the contained document (e.g. "pacs.008")
+    pub fn message_type(&self) -> &'static str {
+        match self {
             Document::Pacs008(_) => "pacs.008",
             Document::Pacs009(_) => "pacs.009",
             Document::Pacs003(_) => "pacs.003",
@@ -121,8 +154,402 @@ impl Document {
             Document::Camt108(_) => "camt.108",
             Document::Camt109(_) => "camt.109",
             Document::Admi024(_) => "admi.024",
+        }
+    }
+
+    /// Primary identifier of the document
+    /// GrpHdr/MsgId for most messages, MsgHdr/MsgId for camt.025 and
+    /// Assgnmt/Id for the case management messages.
+    pub fn message_id(&self) -> &str {
+        match self {
+            Document::Pacs008(doc) => &doc.grp_hdr.msg_id,
+            Document::Pacs009(doc) => &doc.grp_hdr.msg_id,
+            Document::Pacs003(doc) => &doc.grp_hdr.msg_id,
+            Document::Pacs004(doc) => &doc.grp_hdr.msg_id,
+            Document::Pacs002(doc) => &doc.grp_hdr.msg_id,
+            Document::Pacs010(doc) => &doc.grp_hdr.msg_id,
+            Document::Pain001(doc) => &doc.grp_hdr.msg_id,
+            Document::Pain002(doc) => &doc.grp_hdr.msg_id,
+            Document::Pain008(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt025(doc) => &doc.msg_hdr.msg_id,
+            Document::Camt029(doc) => &doc.assgnmt.id,
+            Document::Camt052(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt053(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt054(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt055(doc) => &doc.assgnmt.id,
+            Document::Camt056(doc) => &doc.assgnmt.id,
+            Document::Camt057(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt058(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt060(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt105(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt106(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt107(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt108(doc) => &doc.grp_hdr.msg_id,
+            Document::Camt109(doc) => &doc.grp_hdr.msg_id,
+            Document::Admi024(doc) => &doc.grp_hdr.msg_id,
+        }
+    }
+
+    /// Creation date and time of the document, with its UTC offset
+    /// GrpHdr/CreDtTm for most messages, MsgHdr/CreDtTm for camt.025 and
+    /// Assgnmt/CreDtTm for the case management messages.
+    pub fn creation_datetime(&self) -> Result<DateTime<FixedOffset>, MxError> {
+        let (path, value) = match self {
+            Document::Pacs008(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Pacs009(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Pacs003(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Pacs004(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Pacs002(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Pacs010(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Pain001(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Pain002(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Pain008(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt025(doc) => ("MsgHdr.CreDtTm", &doc.msg_hdr.cre_dt_tm),
+            Document::Camt029(doc) => ("Assgnmt.CreDtTm", &doc.assgnmt.cre_dt_tm),
+            Document::Camt052(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt053(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt054(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt055(doc) => ("Assgnmt.CreDtTm", &doc.assgnmt.cre_dt_tm),
+            Document::Camt056(doc) => ("Assgnmt.CreDtTm", &doc.assgnmt.cre_dt_tm),
+            Document::Camt057(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt058(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt060(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt105(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt106(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt107(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt108(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Camt109(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+            Document::Admi024(doc) => ("GrpHdr.CreDtTm", &doc.grp_hdr.cre_dt_tm),
+        };
+        DateTime::parse_from_rfc3339(value).map_err(|e| {
+            ValidationError::new(
+                1009,
+                format!("CreDtTm '{value}' is not a valid ISO date time: {e}"),
+            )
+            .with_field("CreDtTm".to_string())
+            .with_path(path.to_string())
+            .into()
+        })
+    }
+
+    /// Replace an agent BIC wherever it appears in the document
+    /// Every financial institution identification (`FinInstnId/BICFI` of the
+    /// instructing/instructed agents, intermediaries, reimbursement agents,
+    /// debtor/creditor agents) is rewritten; a customer's `OrgId/AnyBIC` is not an
+    /// agent and is left alone. BICs are matched as in [`Self::contains_bic`]: a
+    /// BIC8 and its primary-office BIC11 (`XXX` branch) are the same institution,
+    /// and case is ignored.
+    /// Returns the number of substitutions made. Unlike a plain count, the result
+    /// is a `Result` because `to_bic` is checked first: writing an invalid BIC into
+    /// every agent slot would leave a message that no longer validates.
+    pub fn replace_agent(&mut self, from_bic: &str, to_bic: &str) -> Result<usize, MxError> {
+        let to_bic = Bic::new(to_bic)?;
+        let wanted = Bic::from(from_bic).primary_office();
+        self.with_json_mut(|value| {
+            let mut count = 0;
+            visitor::visit_mut(value, "", &mut |path, node| {
+                if path.ends_with("FinInstnId.BICFI")
+                    && node
+                        .as_str()
+                        .is_some_and(|bic| Bic::from(bic).primary_office() == wanted)
+                {
+                    *node = serde_json::Value::String(to_bic.to_string());
+                    count += 1;
+                }
+            });
+            count
+        })
+    }
+
+    /// Whether the document references the given BIC in any agent or party field
+    /// BIC8 and its primary-office BIC11 (`XXX` branch) are treated as the same
+    /// institution; the comparison ignores case.
+    pub fn contains_bic(&self, bic: &str) -> bool {
+        let wanted = Bic::from(bic).primary_office();
+        self.with_json(|value| {
+            let mut found = false;
+            visitor::visit(value, "", &mut |path, node| {
+                if matches!(helpers::leaf_name(path), "BICFI" | "AnyBIC")
+                    && node
+                        .as_str()
+                        .is_some_and(|bic| Bic::from(bic).primary_office() == wanted)
+                {
+                    found = true;
+                }
+            });
+            found
+        })
+        .unwrap_or(false)
+    }
+
+    /// Whether the document references the given account in any account field
+    /// Both IBANs and other account identifications are searched; spaces and
+    /// case are ignored, so a printed IBAN matches its electronic form.
+    pub fn contains_account(&self, id: &str) -> bool {
+        let wanted = normalize_account_id(id);
+        self.with_json(|value| {
+            let mut found = false;
+            visitor::visit(value, "", &mut |path, node| {
+                if is_account_id_path(path)
+                    && node.as_str().map(normalize_account_id).as_ref() == Some(&wanted)
+                {
+                    found = true;
+                }
+            });
+            found
+        })
+        .unwrap_or(false)
+    }
+
+    /// Every monetary amount in the document with its path and currency
+    /// Amounts carrying a `Ccy` attribute are reported with that currency;
+    /// implied-currency amounts (plain `...Amt` values) are reported without one.
+    /// Each amount also carries its exact decimal text, see [`DocumentAmount::text`].
+    pub fn all_amounts(&self) -> Vec<DocumentAmount> {
+        let amount = |path: &str, value: f64, ccy: Option<CurrencyCode>| DocumentAmount {
+            path: path.to_string(),
+            value,
+            // Display gives the shortest decimal that reads back as the same
+            // float, never in exponent notation
+            text: value.to_string(),
+            ccy,
+        };
+        self.with_json(|value| {
+            let mut amounts = Vec::new();
+            visitor::visit(value, "", &mut |path, node| match node {
+                serde_json::Value::Object(map) => {
+                    if let Some(value) = map.get("$value").and_then(|v| v.as_f64()) {
+                        let ccy = map
+                            .get("@Ccy")
+                            .and_then(|v| v.as_str())
+                            .map(CurrencyCode::from);
+                        amounts.push(amount(path, value, ccy));
+                    }
+                }
+                serde_json::Value::Number(number) if helpers::leaf_name(path).ends_with("Amt") => {
+                    if let Some(value) = number.as_f64() {
+                        amounts.push(amount(path, value, None));
+                    }
+                }
+                _ => {}
+            });
+            amounts
+        })
+        .unwrap_or_default()
+    }
+
+    /// Every distinct issuer (`Issr`) of a generic identification, sorted
+    /// Covers organisation, person and account identifications as well as
+    /// document and reference types, wherever an `Issr` is populated.
+    pub fn all_issuers(&self) -> Vec<String> {
+        self.with_json(|value| {
+            let mut issuers = Vec::new();
+            visitor::visit(value, "", &mut |path, node| {
+                if helpers::leaf_name(path) == "Issr"
+                    && let Some(issuer) = node.as_str().map(str::trim)
+                    && !issuer.is_empty()
+                {
+                    issuers.push(issuer.to_string());
+                }
+            });
+            issuers.sort();
+            issuers.dedup();
+            issuers
+        })
+        .unwrap_or_default()
+    }
+
+    /// Every distinct country code in the document, sorted, for geo-screening
+    /// Collects postal address countries (`Ctry`, including regulatory reporting
+    /// countries), countries of residence (`CtryOfRes`) and of birth (`CtryOfBirth`).
+    pub fn all_countries(&self) -> Vec<String> {
+        self.with_json(|value| {
+            let mut countries = Vec::new();
+            visitor::visit(value, "", &mut |path, node| {
+                if countries::is_country_element(helpers::leaf_name(path))
+                    && let Some(code) = node.as_str()
+                {
+                    countries.push(code.to_string());
+                }
+            });
+            countries.sort();
+            countries.dedup();
+            countries
+        })
+        .unwrap_or_default()
+    }
+
+    /// Copy of the document reduced to its mandatory elements
+    /// Optional elements and surplus repetitions are dropped one at a time, keeping
+    /// a drop only if the message still deserializes and validates (schema and base
+    /// business rules) without errors. A choice therefore keeps one of its
+    /// alternatives, and a repeating group keeps its first occurrence. A document
+    /// that does not validate to begin with is returned unchanged.
+    ///
+    /// Every candidate drop re-validates the whole document, so the cost grows
+    /// quadratically with the number of elements; this is meant for building
+    /// fixtures and test cases, not for use on the processing path.
+    pub fn minimize(&self) -> Self {
+        let Ok(mut value) = serde_json::to_value(self) else {
+            return self.clone();
+        };
+        let config = ParserConfig::default();
+        let mut keeps_valid = |value: &serde_json::Value| {
+            serde_json::from_value::<Document>(value.clone()).is_ok_and(|document| {
+                let mut collector = ErrorCollector::new();
+                document.validate("", &config, &mut collector);
+                !collector.has_errors()
+            })
+        };
+        if !keeps_valid(&value) {
+            return self.clone();
+        }
+        prune_optional(&mut value, "", &mut keeps_valid);
+        serde_json::from_value(value).unwrap_or_else(|_| self.clone())
+    }
+
+    /// Run a read-only function over the JSON form of the message
+    pub(crate) fn with_json<R>(
+        &self,
+        f: impl FnOnce(&serde_json::Value) -> R,
+    ) -> Result<R, MxError> {
+        let value = serde_json::to_value(self)?;
+        let inner = value
+            .as_object()
+            .and_then(|map| map.values().next())
+            .ok_or_else(|| {
+                MxError::XmlSerialization("Unexpected document JSON shape".to_string())
+            })?;
+        Ok(f(inner))
+    }
+
+    /// Apply a mutation to the JSON form of the message and deserialize it back
+    /// The document is left untouched if the mutated value no longer deserializes
+    pub(crate) fn with_json_mut<R>(
+        &mut self,
+        f: impl FnOnce(&mut serde_json::Value) -> R,
+    ) -> Result<R, MxError> {
+        let mut value = serde_json::to_value(&*self)?;
+        let inner = value
+            .as_object_mut()
+            .and_then(|map| map.values_mut().next())
+            .ok_or_else(|| {
+                MxError::XmlSerialization("Unexpected document JSON shape".to_string())
+            })?;
+        let result = f(inner);
+        *self = serde_json::from_value(value)?;
+        Ok(result)
+    }
+}
+
+/// Drop every element below the JSON pointer that `keeps_valid` accepts losing
+/// Object members are tried first; members that must stay are descended into.
+/// Array occurrences are tried from the last one, the first is always kept.
+fn prune_optional(
+    root: &mut serde_json::Value,
+    pointer: &str,
+    keeps_valid: &mut impl FnMut(&serde_json::Value) -> bool,
+) {
+    let children: Vec<String> = match root.pointer(pointer) {
+        Some(serde_json::Value::Object(map)) => map
+            .keys()
+            .map(|key| key.replace('~', "~0").replace('/', "~1"))
+            .collect(),
+        Some(serde_json::Value::Array(items)) => (0..items.len()).map(|i| i.to_string()).collect(),
+        _ => return,
+    };
+    for child in children.iter().rev() {
+        let child_pointer = format!("{pointer}/{child}");
+        let mut candidate = root.clone();
+        let removed = match candidate.pointer_mut(pointer) {
+            Some(serde_json::Value::Object(map)) => {
+                let key = child.replace("~1", "/").replace("~0", "~");
+                map.remove(&key).is_some()
+            }
+            Some(serde_json::Value::Array(items)) => {
+                let idx: usize = child.parse().unwrap_or_default();
+                idx > 0 && idx == items.len() - 1 && {
+                    items.pop();
+                    true
+                }
+            }
+            _ => false,
         };
-        message_registry::get_namespace(msg_type)
+        if removed && keeps_valid(&candidate) {
+            *root = candidate;
+        } else {
+            prune_optional(root, &child_pointer, keeps_valid);
+        }
+    }
+}
+
+impl fmt::Display for Document {
+    /// Render the message type and primary id, e.g. "pacs.008 MSG-001"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.message_type(), self.message_id())
+    }
+}
+
+impl Validate for Document {
+    /// Validate the schema rules of the contained message, then its business rules
+    fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector) {
+        let _span = helpers::validation_span::<Self>(path);
+        match self {
+            Document::Pacs008(doc) => doc.validate(path, config, collector),
+            Document::Pacs002(doc) => doc.validate(path, config, collector),
+            Document::Pacs003(doc) => doc.validate(path, config, collector),
+            Document::Pacs004(doc) => doc.validate(path, config, collector),
+            Document::Pacs009(doc) => doc.validate(path, config, collector),
+            Document::Pacs010(doc) => doc.validate(path, config, collector),
+            Document::Pain001(doc) => doc.validate(path, config, collector),
+            Document::Pain002(doc) => doc.validate(path, config, collector),
+            Document::Pain008(doc) => doc.validate(path, config, collector),
+            Document::Camt025(doc) => doc.validate(path, config, collector),
+            Document::Camt029(doc) => doc.validate(path, config, collector),
+            Document::Camt052(doc) => doc.validate(path, config, collector),
+            Document::Camt053(doc) => doc.validate(path, config, collector),
+            Document::Camt054(doc) => doc.validate(path, config, collector),
+            Document::Camt055(doc) => doc.validate(path, config, collector),
+            Document::Camt056(doc) => doc.validate(path, config, collector),
+            Document::Camt058(doc) => doc.validate(path, config, collector),
+            Document::Camt057(doc) => doc.validate(path, config, collector),
+            Document::Camt060(doc) => doc.validate(path, config, collector),
+            Document::Camt105(doc) => doc.validate(path, config, collector),
+            Document::Camt106(doc) => doc.validate(path, config, collector),
+            Document::Camt107(doc) => doc.validate(path, config, collector),
+            Document::Camt108(doc) => doc.validate(path, config, collector),
+            Document::Camt109(doc) => doc.validate(path, config, collector),
+            Document::Admi024(doc) => doc.validate(path, config, collector),
+        }
+        self.validate_business_rules(path, config, collector);
+    }
+}
+
+impl BusinessRules for Document {
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        scheme_requirements::validate_required_for_scheme(self, path, config, collector);
+        any_bic::validate_any_bic(self, path, config, collector);
+        agent_chain::validate_agent_chain(self, path, config, collector);
+        original_message::validate_original_message_name_ids(self, path, config, collector);
+        countries::validate_country_codes(self, path, config, collector);
+        assignment::validate_assignment_parties(self, path, config, collector);
+        match self {
+            Document::Pacs008(doc) => doc.validate_business_rules(path, config, collector),
+            Document::Pacs009(doc) => doc.validate_business_rules(path, config, collector),
+            Document::Pacs003(doc) => doc.validate_business_rules(path, config, collector),
+            Document::Pacs004(doc) => doc.validate_business_rules(path, config, collector),
+            Document::Pain001(doc) => doc.validate_business_rules(path, config, collector),
+            Document::Pain008(doc) => doc.validate_business_rules(path, config, collector),
+            Document::Camt052(doc) => doc.validate_business_rules(path, config, collector),
+            Document::Camt053(doc) => doc.validate_business_rules(path, config, collector),
+            Document::Camt054(doc) => doc.validate_business_rules(path, config, collector),
+            _ => {}
+        }
     }
 }
 
@@ -146,18 +573,82 @@ pub struct MxMessage {
     /// Document containing the actual message
     #[serde(rename = "Document")]
     pub document: Document,
+
+    /// Message-level supplementary data (`SplmtryData`) kept as raw XML
+    /// The generated document types do not model it; see [`MxMessage::set_supplementary_data`].
+    #[serde(
+        rename = "SplmtryData",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub supplementary_data: Option<String>,
+}
+
+/// Free-text elements whose line breaks and spacing are part of the content
+/// Their XSD types keep the default `preserve` facet, so
+/// [`WhitespaceMode::Collapse`] leaves them untouched.
+const PRESERVED_WHITESPACE_ELEMENTS: &[&str] = &[
+    "Ustrd",
+    "AddtlRmtInf",
+    "AddtlInf",
+    "AddtlNtryInf",
+    "AddtlTxInf",
+    "InstrInf",
+];
+
+/// Collapse whitespace in every text node of `value` outside the preserved elements
+fn collapse_text_nodes<T>(value: &T) -> Result<T, MxError>
+where
+    T: Serialize + serde::de::DeserializeOwned,
+{
+    let mut json = serde_json::to_value(value)?;
+    visitor::visit_mut(&mut json, "", &mut |path, node| {
+        if let Some(text) = node.as_str()
+            && !PRESERVED_WHITESPACE_ELEMENTS.contains(&helpers::leaf_name(path))
+        {
+            *node = serde_json::Value::String(xml::collapse_whitespace(text));
+        }
+    });
+    Ok(serde_json::from_value(json)?)
 }
 
 impl MxMessage {
     /// Create a new MX message with default namespaces
     pub fn new(app_hdr: crate::header::AppHdr, document: Document) -> Self {
         Self {
-            xmlns: Some("urn:iso:std:iso:20022:tech:xsd:head.001.001.02".to_string()),
+            xmlns: Some(xml::HEADER_NAMESPACE.to_string()),
             xmlns_xsi: Some("http://www.w3.org/2001/XMLSchema-instance".to_string()),
             app_hdr,
             document,
+            supplementary_data: None,
         }
     }
+
+    /// Attach one or more `SplmtryData` blocks, e.g. copied from a template
+    ///
+    /// The blocks are written as the last children of the message element when
+    /// the message is serialized to XML, which is where the schemas place
+    /// message-level supplementary data. Any previously attached blocks are
+    /// replaced. Fails when `raw_xml` is not well-formed or contains anything
+    /// other than `SplmtryData` elements.
+    pub fn set_supplementary_data(&mut self, raw_xml: &str) -> Result<(), MxError> {
+        let raw_xml = raw_xml.trim();
+        xml::check_supplementary_data(raw_xml)?;
+        self.supplementary_data = Some(raw_xml.to_string());
+        Ok(())
+    }
+}
+
+impl fmt::Display for MxMessage {
+    /// Render the message definition and primary id, e.g. "pacs.008.001.08 MSG-001"
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.app_hdr.msg_def_idr,
+            self.document.message_id()
+        )
+    }
 }
 
 /// Get the appropriate namespace for a message type
@@ -200,6 +691,97 @@ impl MxMessage {
         Ok(get_namespace_for_message_type(self.message_type()?))
     }
 
+    /// Workflow family of the message: request, response or notification
+    pub fn message_category(&self) -> MessageCategory {
+        self.document.message_category()
+    }
+
+    /// Kind of R-transaction carried by the message, if it is one
+    pub fn r_transaction_kind(&self) -> Option<RTransactionKind> {
+        self.document.r_transaction_kind()
+    }
+
+    /// Whether the message is an R-transaction (reject, return, refund or recall)
+    pub fn is_r_transaction(&self) -> bool {
+        self.document.is_r_transaction()
+    }
+
+    /// Whether the document references the given BIC in any agent or party field
+    pub fn contains_bic(&self, bic: &str) -> bool {
+        self.document.contains_bic(bic)
+    }
+
+    /// Whether the document references the given account in any account field
+    pub fn contains_account(&self, id: &str) -> bool {
+        self.document.contains_account(id)
+    }
+
+    /// Validate the document's schema and business rules under a scheme profile
+    /// Returns the collector holding both errors and warnings
+    pub fn validate_with(&self, profile: ValidationProfile) -> ErrorCollector {
+        self.validate_with_config(&ParserConfig::default().with_profile(profile))
+    }
+
+    /// Validate the document, then the envelope-level rules tying it to the header
+    /// Returns the collector holding both errors and warnings
+    pub fn validate_with_config(&self, config: &ParserConfig) -> ErrorCollector {
+        let mut collector = ErrorCollector::new();
+        self.document.validate("", config, &mut collector);
+        if let Ok(cre_dt_tm) = self.document.creation_datetime() {
+            creation_time::validate_creation_time_consistency(
+                &self.app_hdr.cre_dt,
+                cre_dt_tm,
+                config,
+                &mut collector,
+            );
+        }
+        collector
+    }
+
+    /// Check that the message would serialize to an accepted document, without writing it
+    /// Runs [`Self::validate_with_config`] with the default configuration, then the checks
+    /// only serialization needs: every amount and rate must be finite to have a plain
+    /// decimal form. Warnings are not reported.
+    pub fn validate_serializable(&self) -> Result<(), Vec<ValidationError>> {
+        let config = ParserConfig::default();
+        let mut collector = self.validate_with_config(&config);
+        serialization::validate_finite_numbers(&self.document, "", &config, &mut collector);
+        if collector.has_errors() {
+            Err(collector.errors())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Replace an agent BIC throughout the message, returning the number of substitutions
+    /// Rewrites the document as [`Document::replace_agent`] does, and the header
+    /// sender (`Fr`) and receiver (`To`) when they identify the same institution.
+    pub fn replace_agent(&mut self, from_bic: &str, to_bic: &str) -> Result<usize, MxError> {
+        let mut count = self.document.replace_agent(from_bic, to_bic)?;
+        let to_bic = Bic::new(to_bic)?;
+        let wanted = Bic::from(from_bic).primary_office();
+        for party in [&mut self.app_hdr.fr, &mut self.app_hdr.to] {
+            if let Some(fi_id) = party.fi_id.as_mut()
+                && fi_id.fin_instn_id.bicfi.primary_office() == wanted
+            {
+                fi_id.fin_instn_id.bicfi = to_bic.clone();
+                count += 1;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Copy of the message with the document reduced to its mandatory elements
+    /// The header is kept as is; message-level supplementary data is dropped.
+    /// See [`Document::minimize`].
+    pub fn minimize(&self) -> Self {
+        Self {
+            document: self.document.minimize(),
+            supplementary_data: None,
+            ..self.clone()
+        }
+    }
+
     /// Helper function to serialize a document with struct name replacement
     fn serialize_with_rename<T: Serialize>(
         value: &T,
@@ -217,6 +799,23 @@ impl MxMessage {
 
     /// Serialize to XML string
     pub fn to_xml(&self) -> Result<String, MxError> {
+        self.to_xml_with_config(&XmlConfig::default())
+    }
+
+    /// Serialize to XML string with the given serialization options
+    pub fn to_xml_with_config(&self, config: &XmlConfig) -> Result<String, MxError> {
+        let Some(key) = config.sort_repeating_by else {
+            return self.write_xml(config);
+        };
+        let mut message = self.clone();
+        message
+            .document
+            .with_json_mut(|value| xml::sort_repeating_groups(value, key))?;
+        message.write_xml(config)
+    }
+
+    /// Write the envelope, header and document as XML
+    fn write_xml(&self, config: &XmlConfig) -> Result<String, MxError> {
         // Custom serialization to handle enum variants
         // Serialize AppHdr
         let app_hdr_xml = quick_xml::se::to_string(&self.app_hdr)
@@ -226,7 +825,18 @@ impl MxMessage {
         let app_hdr_inner = app_hdr_xml;
 
         // Serialize Document based on its variant
-        let doc_xml = self.serialize_document()?;
+        let mut doc_xml = self.serialize_document()?;
+
+        if !config.nil_elements.is_empty() {
+            doc_xml = xml::write_nil_elements(&doc_xml, &config.nil_elements)?;
+        }
+
+        // Supplementary data goes last inside the message element
+        if let Some(blocks) = &self.supplementary_data
+            && let Some(close_idx) = doc_xml.rfind("</")
+        {
+            doc_xml.insert_str(close_idx, blocks);
+        }
 
         // Build complete envelope
         // Note: quick-xml adds struct name as wrapper, so app_hdr_inner already contains <BusinessApplicationHeaderV02>
@@ -235,15 +845,44 @@ impl MxMessage {
             .replace("<BusinessApplicationHeaderV02>", "<AppHdr>")
             .replace("</BusinessApplicationHeaderV02>", "</AppHdr>");
 
-        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>");
-        xml.push_str("<Envelope>");
-        xml.push_str(&app_hdr_wrapped);
-        xml.push_str("<Document>");
-        xml.push_str(&doc_xml);
-        xml.push_str("</Document>");
-        xml.push_str("</Envelope>");
+        let mut xml = String::from(config.xml_declaration().unwrap_or_default());
+        match config.envelope {
+            EnvelopeForm::Envelope => {
+                xml.push_str("<Envelope>");
+                xml.push_str(&app_hdr_wrapped);
+                xml.push_str("<Document>");
+                xml.push_str(&doc_xml);
+                xml.push_str("</Document>");
+                xml.push_str("</Envelope>");
+            }
+            EnvelopeForm::BusinessMessage => {
+                xml.push_str(&format!(
+                    r#"<BizMsgEnvlp xmlns="{}">"#,
+                    xml::BUSINESS_MESSAGE_NAMESPACE
+                ));
+                // Unqualified children would otherwise fall into the envelope namespace
+                let header_namespace = self.xmlns.as_deref().unwrap_or(xml::HEADER_NAMESPACE);
+                xml.push_str("<Hdr>");
+                xml.push_str(&app_hdr_wrapped.replacen(
+                    "<AppHdr>",
+                    &format!(r#"<AppHdr xmlns="{header_namespace}">"#),
+                    1,
+                ));
+                xml.push_str("</Hdr>");
+                xml.push_str(&format!(
+                    r#"<Doc><Document xmlns="{}">"#,
+                    self.document.namespace()
+                ));
+                xml.push_str(&doc_xml);
+                xml.push_str("</Document></Doc>");
+                xml.push_str("</BizMsgEnvlp>");
+            }
+        }
 
-        Ok(xml)
+        match config.indent {
+            Some(width) => xml::indent_xml(&xml, width, config.line_ending),
+            None => Ok(xml),
+        }
     }
 
     /// Serialize document based on its variant
@@ -384,12 +1023,22 @@ impl MxMessage {
     }
 
     /// Serialize to JSON string
+    /// Amounts are written in plain decimal notation, never as e.g. `1e16`.
     pub fn to_json(&self) -> Result<String, MxError> {
-        serde_json::to_string_pretty(self).map_err(|e| MxError::XmlSerialization(e.to_string()))
+        let mut out = Vec::new();
+        let mut serializer =
+            serde_json::Serializer::with_formatter(&mut out, PlainDecimalFormatter::default());
+        self.serialize(&mut serializer)
+            .map_err(|e| MxError::XmlSerialization(e.to_string()))?;
+        String::from_utf8(out).map_err(|e| MxError::XmlSerialization(e.to_string()))
     }
 
     /// Deserialize from XML string using quick-xml with custom enum handling
+    /// Accepts both the `Envelope` and the `BizMsgEnvlp` form, see [`EnvelopeForm`].
     pub fn from_xml(xml: &str) -> Result<Self, MxError> {
+        // The generated types hold a single Ustrd line; report extra lines with their path
+        remittance::check_unstructured_lines(xml)?;
+
         // Check if XML contains full envelope or just Document
         let has_envelope = xml.contains("<AppHdr") || xml.contains("<Envelope");
 
@@ -400,6 +1049,76 @@ impl MxMessage {
         }
     }
 
+    /// Deserialize from XML string, applying the parse options from the given configuration
+    pub fn from_xml_with_config(xml: &str, config: &ParserConfig) -> Result<Self, MxError> {
+        depth::check_xml_depth(xml, config.max_depth)?;
+        if let Ok(message_type) = peek_message_type_from_xml(xml) {
+            check_message_type_allowed(&message_type, config)?;
+        }
+        let message = Self::from_xml(xml)?;
+        check_message_type_allowed(message.document.message_type(), config)?;
+        let message = match config.whitespace {
+            WhitespaceMode::Preserve => message,
+            WhitespaceMode::Collapse => message.collapse_whitespace()?,
+        };
+        if config.empty_as_absent {
+            message.drop_empty_optional_elements()
+        } else {
+            Ok(message)
+        }
+    }
+
+    /// Deserialize as [`Self::from_xml_with_config`], then validate the message
+    /// Returns the collector of [`Self::validate_with_config`] together with the
+    /// rules only the raw XML can answer: a declared group header settlement total
+    /// (pacs.008, pacs.009) must match the transactions, but the typed model drops it.
+    pub fn from_xml_validated(
+        xml: &str,
+        config: &ParserConfig,
+    ) -> Result<(Self, ErrorCollector), MxError> {
+        let message = Self::from_xml_with_config(xml, config)?;
+        let mut collector = message.validate_with_config(config);
+        settlement_total::validate_settlement_total(xml, config, &mut collector);
+        Ok((message, collector))
+    }
+
+    /// Collapse whitespace in the text nodes of the header and document
+    /// Namespace declarations and the raw `SplmtryData` block are left as parsed,
+    /// and so is free-form narrative (see [`PRESERVED_WHITESPACE_ELEMENTS`]).
+    fn collapse_whitespace(&self) -> Result<Self, MxError> {
+        Ok(Self {
+            app_hdr: collapse_text_nodes(&self.app_hdr)?,
+            document: collapse_text_nodes(&self.document)?,
+            xmlns: self.xmlns.clone(),
+            xmlns_xsi: self.xmlns_xsi.clone(),
+            supplementary_data: self.supplementary_data.clone(),
+        })
+    }
+
+    /// Remove empty-valued elements wherever the field is optional
+    /// An element is only dropped if the message still deserializes without it,
+    /// so empty required elements are kept and left to validation.
+    fn drop_empty_optional_elements(&self) -> Result<Self, MxError> {
+        let mut value = serde_json::to_value(self)?;
+        let mut empty = Vec::new();
+        collect_empty_elements(&value, String::new(), &mut empty);
+        for (parent, key) in empty {
+            let Some(removed) = value
+                .pointer_mut(&parent)
+                .and_then(|node| node.as_object_mut())
+                .and_then(|map| map.remove(&key))
+            else {
+                continue;
+            };
+            if serde_json::from_value::<Self>(value.clone()).is_err()
+                && let Some(map) = value.pointer_mut(&parent).and_then(|n| n.as_object_mut())
+            {
+                map.insert(key, removed);
+            }
+        }
+        Ok(serde_json::from_value(value)?)
+    }
+
     /// Deserialize XML with full envelope (AppHdr + Document)
     fn from_xml_with_envelope(xml: &str) -> Result<Self, MxError> {
         // Extract AppHdr section
@@ -421,16 +1140,28 @@ impl MxMessage {
 
         // Deserialize the document based on its type
         let document = Self::deserialize_document(&doc_xml, &doc_type)?;
-
-        // Extract namespace attributes if present
-        let xmlns = Self::extract_attribute(xml, "xmlns");
-        let xmlns_xsi = Self::extract_attribute(xml, "xmlns:xsi");
+        let supplementary_data = xml::extract_supplementary_data(&doc_xml)?;
+
+        // Extract namespace attributes if present; those of a business message
+        // envelope belong to the BizMsgEnvlp element, so the header namespace is
+        // read from AppHdr, and declarations inside the document (e.g. on nil
+        // elements) are not looked at
+        let mut head = &xml[..xml.find("<Document").unwrap_or(xml.len())];
+        if xml.contains("<BizMsgEnvlp") {
+            head = head.find("<AppHdr").map_or("", |start| {
+                let tag = &head[start..];
+                &tag[..tag.find('>').unwrap_or(tag.len())]
+            });
+        }
+        let xmlns = Self::extract_attribute(head, "xmlns");
+        let xmlns_xsi = Self::extract_attribute(head, "xmlns:xsi");
 
         Ok(MxMessage {
             xmlns,
             xmlns_xsi,
             app_hdr,
             document,
+            supplementary_data,
         })
     }
 
@@ -662,6 +1393,59 @@ impl MxMessage {
 
         Ok(message)
     }
+
+    /// Deserialize from a JSON reader, rejecting input nested deeper than
+    /// `config.max_depth` before it reaches the recursive deserializer
+    pub fn from_json_reader<R: Read>(
+        mut reader: R,
+        config: &ParserConfig,
+    ) -> Result<Self, MxError> {
+        let mut json = String::new();
+        reader.read_to_string(&mut json).map_err(|e| {
+            MxError::XmlDeserialization(format!("JSON deserialization failed: {}", e))
+        })?;
+        depth::check_json_depth(&json, config.max_depth)?;
+        if let Ok(message_type) = peek_message_type_from_json(&json) {
+            check_message_type_allowed(&message_type, config)?;
+        }
+        let message = Self::from_json(&json)?;
+        check_message_type_allowed(message.document.message_type(), config)?;
+        Ok(message)
+    }
+}
+
+/// Reject a message type the configuration does not accept
+fn check_message_type_allowed(message_type: &str, config: &ParserConfig) -> Result<(), MxError> {
+    if config.allows_message_type(message_type) {
+        Ok(())
+    } else {
+        Err(MxError::UnsupportedMessageType(message_type.to_string()))
+    }
+}
+
+/// Collect (parent JSON pointer, key) of every object member holding an empty string
+fn collect_empty_elements(
+    value: &serde_json::Value,
+    pointer: String,
+    out: &mut Vec<(String, String)>,
+) {
+    match value {
+        serde_json::Value::Object(map) => {
+            for (key, child) in map {
+                if child.as_str() == Some("") {
+                    out.push((pointer.clone(), key.clone()));
+                } else {
+                    collect_empty_elements(child, format!("{pointer}/{key}"), out);
+                }
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for (idx, child) in items.iter().enumerate() {
+                collect_empty_elements(child, format!("{pointer}/{idx}"), out);
+            }
+        }
+        _ => {}
+    }
 }
 
 /// Extract message type from XML without full deserialization
@@ -702,3 +1486,1063 @@ pub fn peek_message_type_from_json(json: &str) -> Result<String, MxError> {
         "Could not find MsgDefIdr in JSON".to_string(),
     ))
 }
+
+fn normalize_account_id(id: &str) -> String {
+    id.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Whether a path points at an account identification: `<...Acct>.Id.IBAN`
+/// or `<...Acct>.Id.Othr.Id`
+fn is_account_id_path(path: &str) -> bool {
+    let segments: Vec<&str> = path.split('.').map(helpers::leaf_name).collect();
+    match segments.as_slice() {
+        [.., acct, "Id", "IBAN"] | [.., acct, "Id", "Othr", "Id"] => acct.ends_with("Acct"),
+        _ => false,
+    }
+}
+
+/// Pretty JSON formatter that writes floats without exponent notation
+///
+/// serde_json switches to exponent notation for large and tiny floats
+/// (`1e16`, `1e-7`), which ISO 20022 decimal amounts do not allow. Such values
+/// are written with `Display` instead, which always gives plain decimals.
+#[derive(Default)]
+struct PlainDecimalFormatter(serde_json::ser::PrettyFormatter<'static>);
+
+impl serde_json::ser::Formatter for PlainDecimalFormatter {
+    fn write_f64<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        value: f64,
+    ) -> std::io::Result<()> {
+        let mut buf = Vec::new();
+        serde_json::ser::CompactFormatter.write_f64(&mut buf, value)?;
+        if buf.contains(&b'e') {
+            write!(writer, "{value}")
+        } else {
+            writer.write_all(&buf)
+        }
+    }
+
+    fn begin_array<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.0.begin_array(writer)
+    }
+
+    fn end_array<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.0.end_array(writer)
+    }
+
+    fn begin_array_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        self.0.begin_array_value(writer, first)
+    }
+
+    fn end_array_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        self.0.end_array_value(writer)
+    }
+
+    fn begin_object<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.0.begin_object(writer)
+    }
+
+    fn end_object<W: ?Sized + std::io::Write>(&mut self, writer: &mut W) -> std::io::Result<()> {
+        self.0.end_object(writer)
+    }
+
+    fn begin_object_key<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+        first: bool,
+    ) -> std::io::Result<()> {
+        self.0.begin_object_key(writer, first)
+    }
+
+    fn begin_object_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        self.0.begin_object_value(writer)
+    }
+
+    fn end_object_value<W: ?Sized + std::io::Write>(
+        &mut self,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        self.0.end_object_value(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::*;
+    use crate::identifiers::Bic;
+
+    fn agent(bic: &str) -> BranchAndFinancialInstitutionIdentification61 {
+        BranchAndFinancialInstitutionIdentification61 {
+            fin_instn_id: FinancialInstitutionIdentification181 {
+                bicfi: Some(bic.into()),
+                ..Default::default()
+            },
+        }
+    }
+
+    #[test]
+    fn test_replace_agent() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.instg_agt.fin_instn_id.bicfi = "OLDBGB22XXX".into();
+        doc.cdt_trf_tx_inf.instd_agt.fin_instn_id.bicfi = "BANKUS33XXX".into();
+        doc.cdt_trf_tx_inf.intrmy_agt1 = Some(agent("OLDBGB22XXX"));
+        doc.cdt_trf_tx_inf.dbtr_agt = agent("OLDBGB22XXX");
+        doc.cdt_trf_tx_inf.cdtr_agt.fin_instn_id.bicfi = Some("BANKUS33XXX".into());
+        doc.cdt_trf_tx_inf.dbtr.id = Some(Party38Choice2 {
+            org_id: Some(OrganisationIdentification292 {
+                any_bic: Some("oldbgb22".into()),
+                ..Default::default()
+            }),
+            prvt_id: None,
+        });
+
+        let mut document = Document::Pacs008(Box::new(doc));
+        assert!(matches!(
+            document.replace_agent("OLDBGB22XXX", "NOT A BIC"),
+            Err(MxError::Validation { .. })
+        ));
+        assert_eq!(
+            document.replace_agent("OLDBGB22", "NEWBDEFFXXX").unwrap(),
+            3
+        );
+
+        let Document::Pacs008(doc) = &document else {
+            panic!("expected pacs.008");
+        };
+        let tx = &doc.cdt_trf_tx_inf;
+        assert_eq!(tx.instg_agt.fin_instn_id.bicfi.as_str(), "NEWBDEFFXXX");
+        assert_eq!(
+            tx.intrmy_agt1
+                .as_ref()
+                .unwrap()
+                .fin_instn_id
+                .bicfi
+                .as_ref()
+                .map(Bic::as_str),
+            Some("NEWBDEFFXXX")
+        );
+        assert_eq!(
+            tx.dbtr_agt.fin_instn_id.bicfi.as_ref().map(Bic::as_str),
+            Some("NEWBDEFFXXX")
+        );
+        assert_eq!(tx.instd_agt.fin_instn_id.bicfi.as_str(), "BANKUS33XXX");
+        assert_eq!(
+            tx.cdtr_agt.fin_instn_id.bicfi.as_ref().map(Bic::as_str),
+            Some("BANKUS33XXX")
+        );
+        let any_bic = tx
+            .dbtr
+            .id
+            .as_ref()
+            .and_then(|id| id.org_id.as_ref()?.any_bic.as_ref());
+        assert_eq!(any_bic.map(Bic::as_str), Some("oldbgb22"));
+
+        assert_eq!(
+            document
+                .replace_agent("OLDBGB22XXX", "NEWBDEFFXXX")
+                .unwrap(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_replace_agent_in_header() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.instg_agt.fin_instn_id.bicfi = "OLDBGB22XXX".into();
+        let mut app_hdr = AppHdr::default();
+        app_hdr
+            .fr
+            .fi_id
+            .get_or_insert_with(Default::default)
+            .fin_instn_id
+            .bicfi = "OLDBGB22".into();
+        app_hdr
+            .to
+            .fi_id
+            .get_or_insert_with(Default::default)
+            .fin_instn_id
+            .bicfi = "BANKUS33XXX".into();
+        let mut message = MxMessage::new(app_hdr, Document::Pacs008(Box::new(doc)));
+
+        assert_eq!(
+            message.replace_agent("OLDBGB22XXX", "NEWBDEFFXXX").unwrap(),
+            2
+        );
+        let bic = |party: &crate::header::bah_pacs_008_001_08::Party44Choice1| {
+            party.fi_id.as_ref().unwrap().fin_instn_id.bicfi.to_string()
+        };
+        assert_eq!(bic(&message.app_hdr.fr), "NEWBDEFFXXX");
+        assert_eq!(bic(&message.app_hdr.to), "BANKUS33XXX");
+    }
+
+    #[test]
+    fn test_contains_bic_in_intermediary() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.instg_agt.fin_instn_id.bicfi = "BANKGB22XXX".into();
+        doc.cdt_trf_tx_inf.intrmy_agt2 = Some(agent("INTMDEFFXXX"));
+        let document = Document::Pacs008(Box::new(doc));
+
+        assert!(document.contains_bic("INTMDEFFXXX"));
+        assert!(document.contains_bic("intmdeff"));
+        assert!(!document.contains_bic("INTMDEFF100"));
+        assert!(!document.contains_bic("OTHRUS33XXX"));
+    }
+
+    #[test]
+    fn test_contains_account_in_creditor_account() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.cdtr_acct = Some(CashAccount381 {
+            id: AccountIdentification4Choice1 {
+                iban: Some("DE89370400440532013000".into()),
+                othr: None,
+            },
+            ..Default::default()
+        });
+        doc.cdt_trf_tx_inf.dbtr_acct = Some(CashAccount381 {
+            id: AccountIdentification4Choice1 {
+                iban: None,
+                othr: Some(GenericAccountIdentification11 {
+                    id: "123456789".to_string(),
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        });
+        let document = Document::Pacs008(Box::new(doc));
+
+        assert!(document.contains_account("DE89 3704 0044 0532 0130 00"));
+        assert!(document.contains_account("123456789"));
+        assert!(!document.contains_account("GB29NWBK60161331926819"));
+    }
+
+    #[test]
+    fn test_minimize() {
+        let mut message = crate::samples::sample_message("pacs.008", 7).unwrap();
+        let Document::Pacs008(doc) = &mut message.document else {
+            panic!("expected pacs.008");
+        };
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.intrmy_agt1 = Some(agent("INTMDEFFXXX"));
+        tx.dbtr_acct = Some(CashAccount381 {
+            id: AccountIdentification4Choice1 {
+                iban: Some("DE89370400440532013000".into()),
+                othr: None,
+            },
+            ..Default::default()
+        });
+        tx.rmt_inf = Some(RemittanceInformation161 {
+            ustrd: Some("Invoice 4711".to_string()),
+            strd: None,
+        });
+        message.supplementary_data = Some("<SplmtryData/>".to_string());
+        assert!(
+            !message
+                .validate_with_config(&ParserConfig::default())
+                .has_errors()
+        );
+
+        let minimal = message.minimize();
+        assert!(
+            !minimal
+                .validate_with_config(&ParserConfig::default())
+                .has_errors()
+        );
+        assert_eq!(minimal.app_hdr, message.app_hdr);
+        assert!(minimal.supplementary_data.is_none());
+
+        let Document::Pacs008(doc) = &minimal.document else {
+            panic!("expected pacs.008");
+        };
+        let tx = &doc.cdt_trf_tx_inf;
+        assert!(tx.pmt_id.tx_id.is_none());
+        assert!(tx.intrmy_agt1.is_none());
+        assert!(tx.dbtr_acct.is_none());
+        assert!(tx.rmt_inf.is_none());
+        assert!(tx.dbtr.nm.is_none());
+        assert!(!tx.pmt_id.uetr.is_empty());
+        assert_eq!(minimal.document.minimize(), minimal.document);
+    }
+
+    #[test]
+    fn test_minimize_keeps_invalid_document() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.intrmy_agt1 = Some(agent("INTMDEFFXXX"));
+        let document = Document::Pacs008(Box::new(doc));
+        let mut collector = ErrorCollector::new();
+        document.validate("", &ParserConfig::default(), &mut collector);
+        assert!(collector.has_errors());
+
+        assert_eq!(document.minimize(), document);
+    }
+
+    #[test]
+    fn test_all_issuers() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.dbtr.id = Some(Party38Choice2 {
+            org_id: Some(OrganisationIdentification292 {
+                othr: Some(vec![
+                    GenericOrganisationIdentification12 {
+                        id: "12345678".to_string(),
+                        issr: Some("KVK".to_string()),
+                        ..Default::default()
+                    },
+                    GenericOrganisationIdentification12 {
+                        id: "DE123456789".to_string(),
+                        issr: Some("BZST".to_string()),
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            }),
+            prvt_id: None,
+        });
+        tx.cdtr.id = Some(Party38Choice1 {
+            org_id: None,
+            prvt_id: Some(PersonIdentification131 {
+                othr: Some(vec![GenericPersonIdentification11 {
+                    id: "P-998877".to_string(),
+                    issr: Some("KVK".to_string()),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            }),
+        });
+        tx.cdtr_acct = Some(CashAccount381 {
+            id: AccountIdentification4Choice1 {
+                iban: None,
+                othr: Some(GenericAccountIdentification11 {
+                    id: "0001234567".to_string(),
+                    issr: Some("BANKREG".to_string()),
+                    ..Default::default()
+                }),
+            },
+            ..Default::default()
+        });
+
+        assert_eq!(
+            Document::Pacs008(Box::new(doc)).all_issuers(),
+            ["BANKREG", "BZST", "KVK"]
+        );
+        assert!(Document::Pacs008(Box::default()).all_issuers().is_empty());
+    }
+
+    #[test]
+    fn test_all_countries() {
+        let address = |ctry: &str| PostalAddress241 {
+            ctry: Some(ctry.to_string()),
+            ..Default::default()
+        };
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.dbtr.pstl_adr = Some(address("DE"));
+        tx.dbtr.ctry_of_res = Some("FR".to_string());
+        tx.cdtr.pstl_adr = Some(address("US"));
+        tx.ultmt_dbtr = Some(PartyIdentification1351 {
+            ctry_of_res: Some("DE".to_string()),
+            ..Default::default()
+        });
+        tx.dbtr_agt = agent("DEUTDEFFXXX");
+        tx.dbtr_agt.fin_instn_id.pstl_adr = Some(address("CH"));
+
+        let document = Document::Pacs008(Box::new(doc));
+        assert_eq!(document.all_countries(), vec!["CH", "DE", "FR", "US"]);
+        assert!(Document::Pacs008(Box::default()).all_countries().is_empty());
+    }
+
+    #[test]
+    fn test_all_amounts() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.intr_bk_sttlm_amt = CBPRAmount1 {
+            ccy: "EUR".into(),
+            value: 1000.0,
+        };
+        tx.instd_amt = Some(CBPRAmount1 {
+            ccy: "USD".into(),
+            value: 1085.5,
+        });
+        tx.chrgs_inf = Some(vec![Charges71 {
+            amt: CBPRAmount1 {
+                ccy: "EUR".into(),
+                value: 2.5,
+            },
+            agt: agent("BANKGB22XXX"),
+        }]);
+
+        let amounts = Document::Pacs008(Box::new(doc)).all_amounts();
+        let mut found: Vec<(&str, f64, &str, Option<&str>)> = amounts
+            .iter()
+            .map(|amount| {
+                (
+                    amount.path.as_str(),
+                    amount.value,
+                    amount.text.as_str(),
+                    amount.ccy.as_ref().map(|c| c.as_str()),
+                )
+            })
+            .collect();
+        found.sort_by(|a, b| a.0.cmp(b.0));
+        assert_eq!(
+            found,
+            vec![
+                ("CdtTrfTxInf.ChrgsInf[0].Amt", 2.5, "2.5", Some("EUR")),
+                ("CdtTrfTxInf.InstdAmt", 1085.5, "1085.5", Some("USD")),
+                ("CdtTrfTxInf.IntrBkSttlmAmt", 1000.0, "1000", Some("EUR")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_all_amounts_text_is_exact() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt = CBPRAmount1 {
+            ccy: "EUR".into(),
+            value: 0.1,
+        };
+        doc.cdt_trf_tx_inf.chrgs_inf = Some(vec![Charges71 {
+            amt: CBPRAmount1 {
+                ccy: "EUR".into(),
+                value: 0.2,
+            },
+            agt: agent("BANKGB22XXX"),
+        }]);
+        let amounts = Document::Pacs008(Box::new(doc)).all_amounts();
+
+        // The float sum drifts, the decimal texts do not
+        let float_sum: f64 = amounts.iter().map(|amount| amount.value).sum();
+        assert_ne!(float_sum, 0.3);
+        let mut texts: Vec<&str> = amounts.iter().map(|amount| amount.text.as_str()).collect();
+        texts.sort();
+        assert_eq!(texts, vec!["0.1", "0.2"]);
+
+        let big = Document::Pacs008(Box::new(FIToFICustomerCreditTransferV08 {
+            cdt_trf_tx_inf: CreditTransferTransaction391 {
+                intr_bk_sttlm_amt: CBPRAmount1 {
+                    ccy: "EUR".into(),
+                    value: 12345678901234.56,
+                },
+                ..Default::default()
+            },
+            ..Default::default()
+        }));
+        assert!(
+            big.all_amounts()
+                .iter()
+                .any(|amount| amount.text == "12345678901234.56")
+        );
+    }
+
+    fn indented_message_xml() -> String {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = "MSGID".to_string();
+        doc.cdt_trf_tx_inf.dbtr.nm = Some("DEBTOR".to_string());
+        let app_hdr = AppHdr {
+            biz_msg_idr: "MSGID".to_string(),
+            msg_def_idr: "pacs.008.001.08".to_string(),
+            ..Default::default()
+        };
+        let message = MxMessage::new(app_hdr, Document::Pacs008(Box::new(doc)));
+        message.to_xml().unwrap().replace(
+            "<Nm>DEBTOR</Nm>",
+            "<Nm>\n          ACME\n          Trading   Ltd\n        </Nm>",
+        )
+    }
+
+    #[test]
+    fn test_from_xml_preserves_whitespace_by_default() {
+        let xml = indented_message_xml();
+        let message = MxMessage::from_xml_with_config(&xml, &ParserConfig::default()).unwrap();
+        assert_eq!(message, MxMessage::from_xml(&xml).unwrap());
+
+        let Document::Pacs008(doc) = &message.document else {
+            panic!("expected pacs.008");
+        };
+        let name = doc.cdt_trf_tx_inf.dbtr.nm.as_deref().unwrap();
+        assert!(name.contains('\n'), "{name:?}");
+    }
+
+    #[test]
+    fn test_from_xml_collapses_whitespace() {
+        let xml = indented_message_xml();
+        let config = ParserConfig::default().with_whitespace(WhitespaceMode::Collapse);
+        let message = MxMessage::from_xml_with_config(&xml, &config).unwrap();
+
+        let Document::Pacs008(doc) = &message.document else {
+            panic!("expected pacs.008");
+        };
+        assert_eq!(
+            doc.cdt_trf_tx_inf.dbtr.nm.as_deref(),
+            Some("ACME Trading Ltd")
+        );
+        assert_eq!(doc.grp_hdr.msg_id, "MSGID");
+        assert_eq!(message.app_hdr.msg_def_idr, "pacs.008.001.08");
+    }
+
+    #[test]
+    fn test_collapse_whitespace_keeps_supplementary_data_and_narrative() {
+        let block = "<SplmtryData>\n  <PlcAndNm>Ext</PlcAndNm>\n  <Envlp>\n    <Ref>T   1</Ref>\n  </Envlp>\n</SplmtryData>";
+        let mut message = MxMessage::from_xml(&indented_message_xml()).unwrap();
+        let Document::Pacs008(doc) = &mut message.document else {
+            panic!("expected pacs.008");
+        };
+        doc.cdt_trf_tx_inf.rmt_inf = Some(RemittanceInformation161 {
+            ustrd: Some("INVOICE 1\n  LINE   2".to_string()),
+            ..Default::default()
+        });
+        message.set_supplementary_data(block).unwrap();
+        let xml = message.to_xml().unwrap();
+
+        let config = ParserConfig::default().with_whitespace(WhitespaceMode::Collapse);
+        let collapsed = MxMessage::from_xml_with_config(&xml, &config).unwrap();
+        assert_eq!(collapsed.supplementary_data.as_deref(), Some(block));
+        assert_eq!(collapsed.xmlns, message.xmlns);
+        let Document::Pacs008(doc) = &collapsed.document else {
+            panic!("expected pacs.008");
+        };
+        assert_eq!(
+            doc.cdt_trf_tx_inf.dbtr.nm.as_deref(),
+            Some("ACME Trading Ltd")
+        );
+        assert_eq!(
+            doc.cdt_trf_tx_inf
+                .rmt_inf
+                .as_ref()
+                .unwrap()
+                .ustrd
+                .as_deref(),
+            Some("INVOICE 1\n  LINE   2")
+        );
+    }
+
+    fn empty_name_message_xml() -> String {
+        indented_message_xml().replace(
+            "<Nm>\n          ACME\n          Trading   Ltd\n        </Nm>",
+            "<Nm></Nm>",
+        )
+    }
+
+    fn name_errors(message: &MxMessage) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        message
+            .document
+            .validate("", &ParserConfig::default(), &mut collector);
+        collector
+            .errors()
+            .into_iter()
+            .filter(|e| e.path.as_deref() == Some("CdtTrfTxInf.Dbtr.Nm"))
+            .collect()
+    }
+
+    #[test]
+    fn test_from_xml_empty_optional_element_strict() {
+        let message =
+            MxMessage::from_xml_with_config(&empty_name_message_xml(), &ParserConfig::default())
+                .unwrap();
+        let Document::Pacs008(doc) = &message.document else {
+            panic!("expected pacs.008");
+        };
+        assert_eq!(doc.cdt_trf_tx_inf.dbtr.nm.as_deref(), Some(""));
+        assert_eq!(name_errors(&message)[0].code, 1001);
+    }
+
+    #[test]
+    fn test_from_xml_empty_optional_element_as_absent() {
+        let config = ParserConfig::default().with_empty_as_absent(true);
+        let message = MxMessage::from_xml_with_config(&empty_name_message_xml(), &config).unwrap();
+        let Document::Pacs008(doc) = &message.document else {
+            panic!("expected pacs.008");
+        };
+        assert_eq!(doc.cdt_trf_tx_inf.dbtr.nm, None);
+        assert!(name_errors(&message).is_empty());
+        assert_eq!(doc.grp_hdr.msg_id, "MSGID");
+    }
+
+    #[test]
+    fn test_validate_with_profile() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.pmt_id.end_to_end_id = "NOTPROVIDED".to_string();
+        let message = MxMessage::new(AppHdr::default(), Document::Pacs008(Box::new(doc)));
+
+        let has_placeholder_error = |profile| {
+            message
+                .validate_with(profile)
+                .errors()
+                .iter()
+                .any(|e| e.code == 2002)
+        };
+        assert!(!has_placeholder_error(ValidationProfile::Base));
+        assert!(has_placeholder_error(ValidationProfile::Target2));
+    }
+
+    #[test]
+    fn test_validate_with_creation_time_discrepancy() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.cre_dt_tm = "2024-01-15T09:00:00+00:00".to_string();
+        let app_hdr = AppHdr {
+            cre_dt: "2024-01-15T09:00:30+00:00".to_string(),
+            ..Default::default()
+        };
+        let mut message = MxMessage::new(app_hdr, Document::Pacs008(Box::new(doc)));
+        let creation_warnings = |message: &MxMessage| {
+            message
+                .validate_with(ValidationProfile::Base)
+                .warnings()
+                .iter()
+                .filter(|w| w.code == 2021)
+                .count()
+        };
+        assert_eq!(creation_warnings(&message), 0);
+
+        message.app_hdr.cre_dt = "2024-02-01T09:00:00+00:00".to_string();
+        assert_eq!(creation_warnings(&message), 1);
+    }
+
+    fn statement_with_entries(refs: &[&str]) -> MxMessage {
+        use crate::document::camt_053_001_08::{BankToCustomerStatementV08, ReportEntry101};
+
+        let mut doc = BankToCustomerStatementV08::default();
+        doc.stmt.ntry = Some(
+            refs.iter()
+                .map(|r| {
+                    Box::new(ReportEntry101 {
+                        ntry_ref: Some(r.to_string()),
+                        ..Default::default()
+                    })
+                })
+                .collect(),
+        );
+        let app_hdr = AppHdr {
+            msg_def_idr: "camt.053.001.08".to_string(),
+            ..Default::default()
+        };
+        MxMessage::new(app_hdr, Document::Camt053(Box::new(doc)))
+    }
+
+    #[test]
+    fn test_to_xml_sorts_repeating_groups() {
+        fn by_entry_ref(path: &str, item: &serde_json::Value) -> Option<String> {
+            if helpers::leaf_name(path) != "Ntry" {
+                return None;
+            }
+            item.get("NtryRef")?.as_str().map(str::to_string)
+        }
+        let config = XmlConfig {
+            sort_repeating_by: Some(by_entry_ref),
+            ..Default::default()
+        };
+
+        let first = statement_with_entries(&["C", "A", "B"]);
+        let second = statement_with_entries(&["B", "C", "A"]);
+        let xml = first.to_xml_with_config(&config).unwrap();
+        assert_eq!(xml, second.to_xml_with_config(&config).unwrap());
+
+        let positions: Vec<usize> = ["A", "B", "C"]
+            .iter()
+            .map(|r| xml.find(&format!("<NtryRef>{r}</NtryRef>")).unwrap())
+            .collect();
+        assert!(positions.is_sorted());
+
+        // Without a sort key the wire order is left untouched
+        assert_eq!(
+            first.to_xml_with_config(&XmlConfig::default()).unwrap(),
+            first.to_xml().unwrap()
+        );
+        assert_ne!(first.to_xml().unwrap(), second.to_xml().unwrap());
+    }
+
+    #[test]
+    fn test_display() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = "MSG-001".to_string();
+        let document = Document::Pacs008(Box::new(doc));
+        assert_eq!(document.to_string(), "pacs.008 MSG-001");
+
+        let app_hdr = AppHdr {
+            msg_def_idr: "pacs.008.001.08".to_string(),
+            ..Default::default()
+        };
+        let message = MxMessage::new(app_hdr, document);
+        assert_eq!(message.to_string(), "pacs.008.001.08 MSG-001");
+
+        let mut cancellation =
+            crate::document::camt_056_001_08::FIToFIPaymentCancellationRequestV08::default();
+        cancellation.assgnmt.id = "CASE-42".to_string();
+        assert_eq!(
+            Document::Camt056(Box::new(cancellation)).to_string(),
+            "camt.056 CASE-42"
+        );
+    }
+
+    #[test]
+    fn test_large_amount_plain_notation() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt.value = 1e16;
+        doc.cdt_trf_tx_inf.chrgs_inf = Some(vec![Charges71 {
+            amt: CBPRAmount1 {
+                ccy: "EUR".into(),
+                value: 1e-7,
+            },
+            ..Default::default()
+        }]);
+        let app_hdr = AppHdr {
+            msg_def_idr: "pacs.008.001.08".to_string(),
+            ..Default::default()
+        };
+        let message = MxMessage::new(app_hdr, Document::Pacs008(Box::new(doc)));
+
+        let json = message.to_json().unwrap();
+        let amounts: Vec<&str> = json
+            .lines()
+            .filter(|line| line.contains("$value"))
+            .collect();
+        assert_eq!(amounts.len(), 2);
+        for line in amounts {
+            let number = line.rsplit(':').next().unwrap();
+            assert!(!number.contains(['e', 'E']), "{line}");
+        }
+        assert!(json.contains("10000000000000000"));
+        assert!(json.contains("0.0000001"));
+        assert_eq!(MxMessage::from_json(&json).unwrap(), message);
+
+        let xml = message.to_xml().unwrap();
+        assert!(xml.contains(">10000000000000000</IntrBkSttlmAmt>"));
+        assert!(xml.contains(">0.0000001</Amt>"));
+    }
+
+    #[test]
+    fn test_from_json_reader_round_trip() {
+        let message = MxMessage::from_xml(&indented_message_xml()).unwrap();
+        let json = message.to_json().unwrap();
+        let parsed =
+            MxMessage::from_json_reader(json.as_bytes(), &ParserConfig::default()).unwrap();
+        assert_eq!(parsed, message);
+    }
+
+    #[test]
+    fn test_from_json_reader_rejects_deep_nesting() {
+        let depth = 100_000;
+        let json = format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+        let result = MxMessage::from_json_reader(json.as_bytes(), &ParserConfig::default());
+        assert!(matches!(result, Err(MxError::DepthLimitExceeded(64))));
+
+        let config = ParserConfig::default().with_max_depth(8);
+        let json = format!(r#"{{"a":{}1{}}}"#, "[".repeat(8), "]".repeat(8));
+        let result = MxMessage::from_json_reader(json.as_bytes(), &config);
+        assert!(matches!(result, Err(MxError::DepthLimitExceeded(8))));
+    }
+
+    #[test]
+    fn test_from_xml_with_config_rejects_deep_nesting() {
+        let depth = 10_000;
+        let xml = format!("{}{}", "<a>".repeat(depth), "</a>".repeat(depth));
+        let result = MxMessage::from_xml_with_config(&xml, &ParserConfig::default());
+        assert!(matches!(result, Err(MxError::DepthLimitExceeded(64))));
+    }
+
+    #[test]
+    fn test_from_xml_validated_checks_settlement_total() {
+        let message = crate::samples::sample_message("pacs.008", 3).unwrap();
+        let Document::Pacs008(doc) = &message.document else {
+            panic!("expected pacs.008");
+        };
+        let amount = &doc.cdt_trf_tx_inf.intr_bk_sttlm_amt;
+        let with_total = |value: f64| {
+            message.to_xml().unwrap().replacen(
+                "</GrpHdr>",
+                &format!(
+                    r#"<TtlIntrBkSttlmAmt Ccy="{}">{value}</TtlIntrBkSttlmAmt></GrpHdr>"#,
+                    amount.ccy
+                ),
+                1,
+            )
+        };
+        let config = ParserConfig::default();
+        let settlement_errors = |xml: &str| {
+            let (_, collector) = MxMessage::from_xml_validated(xml, &config).unwrap();
+            collector
+                .errors()
+                .into_iter()
+                .filter(|e| e.code == 2029)
+                .count()
+        };
+
+        assert_eq!(settlement_errors(&with_total(amount.value)), 0);
+        let mismatched = with_total(amount.value + 1.0);
+        assert_eq!(settlement_errors(&mismatched), 1);
+        // A business rule, not a parse failure
+        assert!(MxMessage::from_xml_with_config(&mismatched, &config).is_ok());
+
+        // The allowed message types are checked first
+        let statements_only = ParserConfig::default().with_allowed_message_types(["camt.053"]);
+        let result = MxMessage::from_xml_validated(&mismatched, &statements_only);
+        assert!(matches!(result, Err(MxError::UnsupportedMessageType(_))));
+    }
+
+    #[test]
+    fn test_allowed_message_types() {
+        let message = crate::samples::sample_message("pacs.008", 3).unwrap();
+        let xml = message.to_xml().unwrap();
+        let json = message.to_json().unwrap();
+
+        let gateway =
+            ParserConfig::default().with_allowed_message_types(["pacs.008.001.08", "pacs.009"]);
+        assert!(MxMessage::from_xml_with_config(&xml, &gateway).is_ok());
+        assert!(MxMessage::from_json_reader(json.as_bytes(), &gateway).is_ok());
+
+        let statements_only = ParserConfig::default().with_allowed_message_types(["camt.053"]);
+        let result = MxMessage::from_xml_with_config(&xml, &statements_only);
+        assert!(matches!(result, Err(MxError::UnsupportedMessageType(ref t)) if t == "pacs.008"));
+        let result = MxMessage::from_json_reader(json.as_bytes(), &statements_only);
+        assert!(matches!(result, Err(MxError::UnsupportedMessageType(_))));
+    }
+
+    #[test]
+    fn test_validate_serializable() {
+        let mut message = crate::samples::sample_message("pacs.008", 3).unwrap();
+        assert!(message.validate_serializable().is_ok());
+
+        let Document::Pacs008(doc) = &mut message.document else {
+            panic!("expected pacs.008");
+        };
+        doc.cdt_trf_tx_inf.intr_bk_sttlm_amt.value = f64::NAN;
+        doc.cdt_trf_tx_inf.pmt_id.end_to_end_id = String::new();
+        let errors = message.validate_serializable().unwrap_err();
+        assert!(errors.iter().any(|e| e.code == 2044));
+        assert!(errors.iter().any(|e| e.code != 2044));
+    }
+
+    #[test]
+    fn test_strip_supplementary_data() {
+        let original = indented_message_xml();
+        let extended = original
+            .replace(
+                "</CdtTrfTxInf>",
+                "<SplmtryData><PlcAndNm>Ext</PlcAndNm><Envlp><Any/></Envlp></SplmtryData></CdtTrfTxInf>",
+            )
+            .replace(
+                "</FIToFICstmrCdtTrf>",
+                "<SplmtryData><Envlp/></SplmtryData></FIToFICstmrCdtTrf>",
+            );
+        assert_ne!(extended, original);
+
+        let stripped = xml::strip_supplementary_data(&extended).unwrap();
+        assert!(!stripped.contains("SplmtryData"));
+        assert_eq!(stripped, original);
+
+        let validation_errors = |xml: &str| {
+            let message = MxMessage::from_xml(xml).unwrap();
+            let mut collector = ErrorCollector::new();
+            message
+                .document
+                .validate("", &ParserConfig::default(), &mut collector);
+            collector.errors()
+        };
+        assert_eq!(validation_errors(&stripped), validation_errors(&original));
+    }
+
+    #[test]
+    fn test_set_supplementary_data_round_trip() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = "MSGID".to_string();
+        let app_hdr = AppHdr {
+            biz_msg_idr: "MSGID".to_string(),
+            msg_def_idr: "pacs.008.001.08".to_string(),
+            ..Default::default()
+        };
+        let mut message = MxMessage::new(app_hdr, Document::Pacs008(Box::new(doc)));
+        let block =
+            "<SplmtryData><PlcAndNm>Ext</PlcAndNm><Envlp><Ref>T-1</Ref></Envlp></SplmtryData>";
+        message.set_supplementary_data(block).unwrap();
+
+        let xml = message.to_xml().unwrap();
+        assert!(xml.contains(&format!("{block}</FIToFICstmrCdtTrf>")));
+        let parsed = MxMessage::from_xml(&xml).unwrap();
+        assert_eq!(parsed.document, message.document);
+        assert_eq!(parsed.supplementary_data, message.supplementary_data);
+        assert_eq!(parsed.to_xml().unwrap(), xml);
+
+        assert!(
+            message
+                .set_supplementary_data("<SplmtryData><Envlp>")
+                .is_err()
+        );
+        assert!(message.set_supplementary_data("<Envlp/>").is_err());
+        assert_eq!(message.supplementary_data.as_deref(), Some(block));
+    }
+
+    #[test]
+    fn test_creation_datetime() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.cre_dt_tm = "2024-01-15T10:30:00.123+02:00".to_string();
+        let created = Document::Pacs008(Box::new(doc))
+            .creation_datetime()
+            .unwrap();
+        assert_eq!(
+            created.to_rfc3339(),
+            "2024-01-15T10:30:00.123+02:00".to_string()
+        );
+        assert_eq!(created.offset().local_minus_utc(), 7200);
+
+        let mut cancellation =
+            crate::document::camt_056_001_08::FIToFIPaymentCancellationRequestV08::default();
+        cancellation.assgnmt.cre_dt_tm = "2024-03-01T08:00:00Z".to_string();
+        let document = Document::Camt056(Box::new(cancellation));
+        assert_eq!(
+            document.creation_datetime().unwrap().timestamp(),
+            1_709_280_000
+        );
+
+        let Err(MxError::Validation { code, path, .. }) =
+            Document::Pacs008(Box::default()).creation_datetime()
+        else {
+            panic!("expected an invalid creation date time");
+        };
+        assert_eq!(code, 1009);
+        assert_eq!(path.as_deref(), Some("GrpHdr.CreDtTm"));
+    }
+
+    #[test]
+    fn test_to_xml_declaration_options() {
+        let message = MxMessage::from_xml(&indented_message_xml()).unwrap();
+
+        let xml = message.to_xml().unwrap();
+        assert!(xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8"?><Envelope>"#));
+
+        let config = XmlConfig {
+            standalone: true,
+            ..Default::default()
+        };
+        let xml = message.to_xml_with_config(&config).unwrap();
+        assert!(
+            xml.starts_with(r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><Envelope>"#)
+        );
+        assert_eq!(MxMessage::from_xml(&xml).unwrap(), message);
+
+        let config = XmlConfig {
+            include_xml_declaration: false,
+            standalone: true,
+            ..Default::default()
+        };
+        let xml = message.to_xml_with_config(&config).unwrap();
+        assert!(xml.starts_with("<Envelope>"));
+        assert!(!xml.contains("<?xml"));
+        assert_eq!(MxMessage::from_xml(&xml).unwrap(), message);
+    }
+
+    #[test]
+    fn test_nil_element_round_trip() {
+        let mut message = MxMessage::from_xml(&indented_message_xml()).unwrap();
+        let Document::Pacs008(doc) = &mut message.document else {
+            unreachable!()
+        };
+        doc.cdt_trf_tx_inf.purp = Some(Purpose2Choice1::default());
+        let config = XmlConfig {
+            nil_elements: vec!["CdtTrfTxInf.Purp".to_string()],
+            ..Default::default()
+        };
+
+        let xml = message.to_xml_with_config(&config).unwrap();
+        assert!(xml.contains(
+            r#"<Purp xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:nil="true"/>"#
+        ));
+
+        let parsed = MxMessage::from_xml(&xml).unwrap();
+        assert_eq!(parsed, message);
+        let Document::Pacs008(doc) = &parsed.document else {
+            unreachable!()
+        };
+        assert_eq!(doc.cdt_trf_tx_inf.purp, Some(Purpose2Choice1::default()));
+        assert_eq!(parsed.to_xml_with_config(&config).unwrap(), xml);
+
+        // Without the designation the empty element is written as is
+        assert!(!message.to_xml().unwrap().contains("xsi:nil"));
+    }
+
+    #[test]
+    fn test_to_xml_indentation() {
+        let message = MxMessage::from_xml(&indented_message_xml()).unwrap();
+        let config = XmlConfig {
+            indent: Some(2),
+            line_ending: xml::LineEnding::Lf,
+            ..Default::default()
+        };
+
+        let xml = message.to_xml_with_config(&config).unwrap();
+        assert!(!xml.contains('\r'));
+        assert!(xml.starts_with(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<Envelope>\n  <AppHdr>\n    <Fr/>\n"
+        ));
+        assert!(xml.contains("\n      <GrpHdr>\n        <MsgId>MSGID</MsgId>\n"));
+        assert!(xml.ends_with("\n  </Document>\n</Envelope>"));
+        for line in xml.lines() {
+            let indent = line.len() - line.trim_start().len();
+            assert_eq!(indent % 2, 0, "{line}");
+        }
+        assert_eq!(MxMessage::from_xml(&xml).unwrap(), message);
+
+        let crlf = XmlConfig {
+            line_ending: xml::LineEnding::CrLf,
+            ..config
+        };
+        let xml_crlf = message.to_xml_with_config(&crlf).unwrap();
+        assert_eq!(xml_crlf.replace("\r\n", "\n"), xml);
+
+        // Compact by default
+        assert!(
+            message
+                .to_xml()
+                .unwrap()
+                .starts_with(r#"<?xml version="1.0" encoding="UTF-8"?><Envelope><AppHdr><Fr/>"#)
+        );
+    }
+
+    #[test]
+    fn test_business_message_envelope_round_trip() {
+        let message = MxMessage::from_xml(&indented_message_xml()).unwrap();
+        let config = XmlConfig {
+            envelope: EnvelopeForm::BusinessMessage,
+            ..Default::default()
+        };
+
+        let xml = message.to_xml_with_config(&config).unwrap();
+        assert!(xml.contains(concat!(
+            r#"<BizMsgEnvlp xmlns="urn:iso:std:iso:20022:tech:xsd:nvlp.001.001.01"><Hdr>"#,
+            r#"<AppHdr xmlns="urn:iso:std:iso:20022:tech:xsd:head.001.001.02">"#
+        )));
+        assert!(xml.contains(concat!(
+            r#"</AppHdr></Hdr><Doc><Document xmlns="urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08">"#,
+            "<FIToFICstmrCdtTrf>"
+        )));
+        assert!(xml.ends_with("</Document></Doc></BizMsgEnvlp>"));
+        assert!(!xml.contains("<Envelope>"));
+
+        let parsed = MxMessage::from_xml(&xml).unwrap();
+        assert_eq!(parsed.xmlns.as_deref(), Some(xml::HEADER_NAMESPACE));
+        assert_eq!(
+            parsed,
+            MxMessage {
+                xmlns: parsed.xmlns.clone(),
+                ..message.clone()
+            }
+        );
+        assert_eq!(parsed.to_xml_with_config(&config).unwrap(), xml);
+        assert_eq!(parsed.to_xml().unwrap(), message.to_xml().unwrap());
+    }
+}
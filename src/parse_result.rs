@@ -18,7 +18,9 @@
 // https://github.com/GoPlasmatic/MXMessage
 
 use crate::error::ValidationError;
+use crate::message_registry::normalize_message_type;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Result of parsing with error collection support
 #[derive(Debug, Clone, PartialEq)]
@@ -71,7 +73,11 @@ impl<T> ParseResult<T> {
 }
 
 /// Configuration for parsing behavior
+/// Build it from [`ParserConfig::default`], [`ParserConfig::fail_fast`] or
+/// [`ParserConfig::lenient`] and the `with_*` setters; new options are added
+/// without breaking existing configurations.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[non_exhaustive]
 pub struct ParserConfig {
     /// If true, stop at first error. If false, collect all errors.
     pub fail_fast: bool,
@@ -79,6 +85,94 @@ pub struct ParserConfig {
     pub validate_optional_fields: bool,
     /// If true, attempt to collect all possible errors even when structure is invalid.
     pub collect_all_errors: bool,
+    /// Scheme profile used to enable business rules from a specific usage guideline.
+    #[serde(default)]
+    pub profile: ValidationProfile,
+    /// Whitespace handling applied to text nodes when parsing XML.
+    #[serde(default)]
+    pub whitespace: WhitespaceMode,
+    /// Maximum nesting depth accepted when parsing XML or JSON input.
+    #[serde(default = "default_max_depth")]
+    pub max_depth: usize,
+    /// If true, optional elements with an empty value (e.g. `<Nm></Nm>`) are read as absent.
+    #[serde(default)]
+    pub empty_as_absent: bool,
+    /// If true, structured remittance document amounts must add up to the transaction amount.
+    #[serde(default)]
+    pub reconcile_remittance: bool,
+    /// If true, a statement must carry an opening (OPBD or PRCD) and a closing (CLBD) balance.
+    #[serde(default)]
+    pub require_statement_balances: bool,
+    /// Plausible range for percentage rates (charges, tax and interest rates).
+    #[serde(default)]
+    pub rate_range: RateRange,
+    /// Largest accepted gap, in seconds, between AppHdr/CreDt and the document CreDtTm.
+    #[serde(default = "default_creation_time_tolerance")]
+    pub creation_time_tolerance_secs: u64,
+    /// Largest cash deposit total (NbOfNotes times NoteDnmtn) per currency before a deposit
+    /// is flagged for cash reporting. Deposits in other currencies are not checked.
+    #[serde(default = "default_cash_deposit_limits")]
+    pub cash_deposit_limits: BTreeMap<String, f64>,
+    /// Message types (short form, e.g. "pacs.008") accepted by the parse entry points.
+    /// Any supported type is accepted if None.
+    #[serde(default)]
+    pub allowed_message_types: Option<Vec<String>>,
+    /// Check contact phone numbers (E.164) and email addresses in CtctDtls.
+    #[serde(default)]
+    pub validate_contact_details: bool,
+}
+
+/// Inclusive range of percentage rates accepted as plausible
+/// Negative rates are allowed by default since interest rates can be negative.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct RateRange {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl Default for RateRange {
+    fn default() -> Self {
+        RateRange {
+            min: -100.0,
+            max: 100.0,
+        }
+    }
+}
+
+impl RateRange {
+    /// Whether the rate lies within the range
+    pub fn contains(&self, rate: f64) -> bool {
+        (self.min..=self.max).contains(&rate)
+    }
+}
+
+/// Default nesting limit; real ISO 20022 messages stay well below it
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+fn default_max_depth() -> usize {
+    DEFAULT_MAX_DEPTH
+}
+
+/// Default gap allowed between the header and document creation times
+/// Both are normally stamped when the message is built, so five minutes is generous.
+pub const DEFAULT_CREATION_TIME_TOLERANCE_SECS: u64 = 300;
+
+fn default_creation_time_tolerance() -> u64 {
+    DEFAULT_CREATION_TIME_TOLERANCE_SECS
+}
+
+/// Default cash deposit limit
+/// Matches the common 10,000 threshold for cash transaction reporting.
+pub const DEFAULT_CASH_DEPOSIT_LIMIT: f64 = 10_000.0;
+
+/// Currencies whose cash reporting threshold is [`DEFAULT_CASH_DEPOSIT_LIMIT`] by default
+pub const DEFAULT_CASH_DEPOSIT_CURRENCIES: [&str; 2] = ["EUR", "USD"];
+
+fn default_cash_deposit_limits() -> BTreeMap<String, f64> {
+    DEFAULT_CASH_DEPOSIT_CURRENCIES
+        .iter()
+        .map(|ccy| (ccy.to_string(), DEFAULT_CASH_DEPOSIT_LIMIT))
+        .collect()
 }
 
 impl Default for ParserConfig {
@@ -87,6 +181,17 @@ impl Default for ParserConfig {
             fail_fast: false,
             validate_optional_fields: true,
             collect_all_errors: true,
+            profile: ValidationProfile::Base,
+            whitespace: WhitespaceMode::Preserve,
+            max_depth: DEFAULT_MAX_DEPTH,
+            empty_as_absent: false,
+            reconcile_remittance: false,
+            require_statement_balances: false,
+            rate_range: RateRange::default(),
+            creation_time_tolerance_secs: DEFAULT_CREATION_TIME_TOLERANCE_SECS,
+            cash_deposit_limits: default_cash_deposit_limits(),
+            allowed_message_types: None,
+            validate_contact_details: false,
         }
     }
 }
@@ -96,25 +201,246 @@ impl ParserConfig {
     pub fn fail_fast() -> Self {
         ParserConfig {
             fail_fast: true,
-            validate_optional_fields: true,
             collect_all_errors: false,
+            ..Self::default()
         }
     }
 
     /// Create a new parser configuration for lenient parsing
     pub fn lenient() -> Self {
         ParserConfig {
-            fail_fast: false,
             validate_optional_fields: false,
             collect_all_errors: false,
+            ..Self::default()
         }
     }
 }
 
+impl ParserConfig {
+    /// Stop at the first error instead of collecting all errors
+    pub fn with_fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// Validate optional fields that are present
+    pub fn with_optional_field_validation(mut self, validate_optional_fields: bool) -> Self {
+        self.validate_optional_fields = validate_optional_fields;
+        self
+    }
+
+    /// Attempt to collect all possible errors even when the structure is invalid
+    pub fn with_collect_all_errors(mut self, collect_all_errors: bool) -> Self {
+        self.collect_all_errors = collect_all_errors;
+        self
+    }
+
+    /// Use the given scheme profile for business rule validation
+    pub fn with_profile(mut self, profile: ValidationProfile) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Use the given nesting limit for XML and JSON input
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Use the given whitespace handling for text nodes when parsing XML
+    pub fn with_whitespace(mut self, whitespace: WhitespaceMode) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Read empty optional elements as absent instead of as empty strings
+    pub fn with_empty_as_absent(mut self, empty_as_absent: bool) -> Self {
+        self.empty_as_absent = empty_as_absent;
+        self
+    }
+
+    /// Check that structured remittance amounts reconcile to the transaction amount
+    pub fn with_remittance_reconciliation(mut self, reconcile_remittance: bool) -> Self {
+        self.reconcile_remittance = reconcile_remittance;
+        self
+    }
+
+    /// Check that statements carry the mandatory opening and closing booked balances
+    pub fn with_required_statement_balances(mut self, require_statement_balances: bool) -> Self {
+        self.require_statement_balances = require_statement_balances;
+        self
+    }
+
+    /// Use the given range of plausible percentage rates
+    pub fn with_rate_range(mut self, min: f64, max: f64) -> Self {
+        self.rate_range = RateRange { min, max };
+        self
+    }
+
+    /// Use the given gap, in seconds, allowed between header and document creation times
+    pub fn with_creation_time_tolerance(mut self, secs: u64) -> Self {
+        self.creation_time_tolerance_secs = secs;
+        self
+    }
+
+    /// Use the given limit for the total of a cash deposit in the given currency
+    pub fn with_cash_deposit_limit(mut self, currency: &str, limit: f64) -> Self {
+        self.cash_deposit_limits.insert(currency.to_string(), limit);
+        self
+    }
+
+    /// Check phone numbers and email addresses of contact details
+    pub fn with_contact_validation(mut self, enabled: bool) -> Self {
+        self.validate_contact_details = enabled;
+        self
+    }
+
+    /// Accept only the given message types; short and full forms are both understood
+    pub fn with_allowed_message_types<I, S>(mut self, message_types: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.allowed_message_types = Some(
+            message_types
+                .into_iter()
+                .map(|message_type| normalize_message_type(message_type.as_ref()))
+                .collect(),
+        );
+        self
+    }
+
+    /// Whether the parse entry points accept the given message type
+    pub fn allows_message_type(&self, message_type: &str) -> bool {
+        self.allowed_message_types.as_ref().is_none_or(|allowed| {
+            let message_type = normalize_message_type(message_type);
+            allowed.contains(&message_type)
+        })
+    }
+}
+
+/// Whitespace handling for XML text nodes on read
+/// Most ISO 20022 text types are restrictions of xs:normalizedString or xs:token,
+/// so indentation or line breaks a producer put inside a value are not significant.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WhitespaceMode {
+    /// Keep text exactly as it appears in the XML
+    #[default]
+    Preserve,
+    /// Apply the XSD collapse facet: runs of whitespace become a single space
+    /// and leading/trailing whitespace is removed in header and document text;
+    /// free-form narrative such as `Ustrd` and the raw `SplmtryData` block are kept
+    Collapse,
+}
+
+/// Scheme profile selecting which usage-guideline business rules apply
+/// Base applies the ISO 20022 rules common to every scheme (schema, external code sets);
+/// the other profiles add their usage-guideline rules on top.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ValidationProfile {
+    /// ISO 20022 rules common to every scheme
+    #[default]
+    Base,
+    /// SWIFT CBPR+ usage guidelines
+    #[serde(alias = "cbpr")]
+    CbprPlus,
+    /// SWIFT CBPR+ usage guidelines with text restricted to the SWIFT x character set,
+    /// as enforced on messages carried over FINplus
+    FinPlus,
+    /// TARGET2 (T2 RTGS) usage guidelines
+    Target2,
+    /// EPC SEPA rulebooks
+    Sepa,
+    /// Instant payment schemes (SCT Inst, RTP): identifiers restricted to the
+    /// EPC character set and checked for placeholder values
+    Instant,
+}
+
+impl ValidationProfile {
+    /// Profile whose usage-guideline rules apply; FinPlus applies the CBPR+ guidelines
+    pub fn usage_guideline(&self) -> ValidationProfile {
+        match self {
+            ValidationProfile::FinPlus => ValidationProfile::CbprPlus,
+            other => *other,
+        }
+    }
+
+    /// Whether text fields are restricted to the SWIFT x character set
+    pub fn restricts_to_x_character_set(&self) -> bool {
+        matches!(self, ValidationProfile::FinPlus)
+    }
+
+    /// Whether MsgId and EndToEndId are restricted to the instant-scheme identifier set
+    pub fn restricts_identifiers(&self) -> bool {
+        matches!(self, ValidationProfile::Instant)
+    }
+
+    /// Whether the scheme requires a real EndToEndId rather than the NOTPROVIDED placeholder
+    pub fn requires_end_to_end_id(&self) -> bool {
+        matches!(
+            self.usage_guideline(),
+            ValidationProfile::Target2 | ValidationProfile::Sepa | ValidationProfile::Instant
+        )
+    }
+
+    /// Whether the scheme flags an ultimate party that merely repeats its corresponding party
+    pub fn flags_redundant_ultimate_parties(&self) -> bool {
+        matches!(
+            self.usage_guideline(),
+            ValidationProfile::CbprPlus | ValidationProfile::Sepa
+        )
+    }
+
+    /// Whether the scheme checks rates for plausibility and exchange rates for positivity
+    pub fn checks_rate_plausibility(&self) -> bool {
+        !matches!(self, ValidationProfile::Base)
+    }
+
+    /// Whether direct debits must identify the creditor by a SEPA creditor identifier
+    pub fn requires_sepa_creditor_identifier(&self) -> bool {
+        matches!(self.usage_guideline(), ValidationProfile::Sepa)
+    }
+
+    /// Whether the scheme warns about cash deposits above `ParserConfig::cash_deposit_limits`
+    pub fn checks_cash_deposit_limit(&self) -> bool {
+        !matches!(self, ValidationProfile::Base)
+    }
+
+    /// Whether the scheme flags a requested execution date before the creation date
+    pub fn checks_requested_execution_date(&self) -> bool {
+        !matches!(self, ValidationProfile::Base)
+    }
+
+    /// Whether a code missing from the external code lists is an error rather than a warning
+    /// Base only warns, since the bundled lists may lag behind the latest code set release.
+    pub fn rejects_unknown_external_codes(&self) -> bool {
+        !matches!(self, ValidationProfile::Base)
+    }
+
+    /// Whether a pain.001 forwarding agent must be the debtor agent, as the customer instructs its own bank
+    pub fn requires_forwarding_agent_as_debtor_agent(&self) -> bool {
+        matches!(
+            self.usage_guideline(),
+            ValidationProfile::Sepa | ValidationProfile::Instant
+        )
+    }
+
+    /// Whether the scheme routes case management over SWIFT and needs connected BICs for case parties
+    pub fn requires_connected_bic(&self) -> bool {
+        matches!(
+            self.usage_guideline(),
+            ValidationProfile::CbprPlus | ValidationProfile::Target2
+        )
+    }
+}
+
 /// Helper struct for collecting validation errors
 #[derive(Debug, Default)]
 pub struct ErrorCollector {
     errors: Vec<ValidationError>,
+    warnings: Vec<ValidationError>,
     has_critical_errors: bool,
 }
 
@@ -122,6 +448,7 @@ impl ErrorCollector {
     pub fn new() -> Self {
         ErrorCollector {
             errors: Vec::new(),
+            warnings: Vec::new(),
             has_critical_errors: false,
         }
     }
@@ -137,6 +464,11 @@ impl ErrorCollector {
         self.errors.push(error);
     }
 
+    /// Add a warning; warnings do not make the message invalid
+    pub fn add_warning(&mut self, warning: ValidationError) {
+        self.warnings.push(warning);
+    }
+
     /// Check if there are any errors
     pub fn has_errors(&self) -> bool {
         !self.errors.is_empty()
@@ -152,6 +484,11 @@ impl ErrorCollector {
         self.errors
     }
 
+    /// Get all collected warnings
+    pub fn warnings(&self) -> &[ValidationError] {
+        &self.warnings
+    }
+
     /// Get the count of errors
     pub fn error_count(&self) -> usize {
         self.errors.len()
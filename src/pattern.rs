@@ -0,0 +1,412 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Pattern Engine
+//!
+//! A small backtracking matcher for the XSD pattern facets used by the generated
+//! validators. It needs only `alloc`, so core validation can run where `regex`
+//! is too heavy (embedded or WASM gateways). It covers the syntax the ISO 20022
+//! schemas use: literals, `.`, character classes with ranges, the `\d`, `\w`,
+//! `\s` shorthands, groups, alternation and the `?`, `*`, `+` and `{n,m}`
+//! quantifiers. Like `Regex::is_match`, a pattern matches if it matches anywhere
+//! in the value unless it is anchored with `^` and `$`.
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Error raised for pattern syntax the engine does not understand
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternError {
+    /// Character offset in the pattern where parsing failed
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+/// Alternatives, each a sequence of nodes
+type Alternatives = Vec<Vec<Node>>;
+
+#[derive(Debug, Clone)]
+enum Node {
+    Char(char),
+    /// `.`: any character except line feed
+    Any,
+    Class {
+        ranges: Vec<(char, char)>,
+        negated: bool,
+    },
+    Group(Alternatives),
+    Repeat {
+        node: Box<Node>,
+        min: usize,
+        max: Option<usize>,
+    },
+    Start,
+    End,
+}
+
+/// A compiled pattern
+#[derive(Debug, Clone)]
+pub struct Pattern {
+    alternatives: Alternatives,
+}
+
+impl Pattern {
+    /// Compile a pattern
+    pub fn new(pattern: &str) -> Result<Self, PatternError> {
+        let mut parser = Parser {
+            chars: pattern.chars().collect(),
+            pos: 0,
+        };
+        let alternatives = parser.alternatives()?;
+        if parser.pos < parser.chars.len() {
+            return Err(parser.error("unbalanced ')'"));
+        }
+        Ok(Pattern { alternatives })
+    }
+
+    /// Whether the pattern matches anywhere in the value
+    pub fn is_match(&self, value: &str) -> bool {
+        let input: Vec<char> = value.chars().collect();
+        let matcher = Matcher { input: &input };
+        (0..=input.len())
+            .any(|start| matcher.alternatives(&self.alternatives, start, &mut |_| true))
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl Parser {
+    fn error(&self, message: &str) -> PatternError {
+        PatternError {
+            position: self.pos,
+            message: message.to_string(),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        Some(c)
+    }
+
+    fn alternatives(&mut self) -> Result<Alternatives, PatternError> {
+        let mut alternatives = vec![self.sequence()?];
+        while self.peek() == Some('|') {
+            self.pos += 1;
+            alternatives.push(self.sequence()?);
+        }
+        Ok(alternatives)
+    }
+
+    fn sequence(&mut self) -> Result<Vec<Node>, PatternError> {
+        let mut nodes = Vec::new();
+        while let Some(c) = self.peek() {
+            if c == '|' || c == ')' {
+                break;
+            }
+            let atom = self.atom()?;
+            nodes.push(self.quantified(atom)?);
+        }
+        Ok(nodes)
+    }
+
+    fn atom(&mut self) -> Result<Node, PatternError> {
+        match self.next() {
+            Some('.') => Ok(Node::Any),
+            Some('^') => Ok(Node::Start),
+            Some('$') => Ok(Node::End),
+            Some('[') => self.class(),
+            Some('(') => {
+                if self.chars[self.pos..].starts_with(&['?', ':']) {
+                    self.pos += 2;
+                }
+                let alternatives = self.alternatives()?;
+                if self.next() != Some(')') {
+                    return Err(self.error("unclosed group"));
+                }
+                Ok(Node::Group(alternatives))
+            }
+            Some('\\') => self.escape(),
+            Some(c @ ('*' | '+' | '?' | '{')) => {
+                self.pos -= 1;
+                Err(self.error(if c == '{' {
+                    "repetition without an operand"
+                } else {
+                    "quantifier without an operand"
+                }))
+            }
+            Some(c) => Ok(Node::Char(c)),
+            None => Err(self.error("unexpected end of pattern")),
+        }
+    }
+
+    fn escape(&mut self) -> Result<Node, PatternError> {
+        let c = self.next().ok_or_else(|| self.error("incomplete escape"))?;
+        Ok(match shorthand_class(c) {
+            Some((ranges, negated)) => Node::Class { ranges, negated },
+            None => Node::Char(escaped_char(c)),
+        })
+    }
+
+    fn class(&mut self) -> Result<Node, PatternError> {
+        let negated = self.peek() == Some('^');
+        if negated {
+            self.pos += 1;
+        }
+        let mut ranges = Vec::new();
+        let mut first = true;
+        loop {
+            let c = self.next().ok_or_else(|| self.error("unclosed class"))?;
+            let start = match c {
+                ']' if !first => break,
+                '\\' => {
+                    let e = self.next().ok_or_else(|| self.error("incomplete escape"))?;
+                    if let Some((shorthand, false)) = shorthand_class(e) {
+                        ranges.extend(shorthand);
+                        first = false;
+                        continue;
+                    }
+                    escaped_char(e)
+                }
+                c => c,
+            };
+            first = false;
+            if self.peek() == Some('-') && self.chars.get(self.pos + 1).is_some_and(|&n| n != ']') {
+                self.pos += 1;
+                let end = match self.next() {
+                    Some('\\') => {
+                        escaped_char(self.next().ok_or_else(|| self.error("incomplete escape"))?)
+                    }
+                    Some(end) => end,
+                    None => return Err(self.error("unclosed class")),
+                };
+                if end < start {
+                    return Err(self.error("invalid class range"));
+                }
+                ranges.push((start, end));
+            } else {
+                ranges.push((start, start));
+            }
+        }
+        Ok(Node::Class { ranges, negated })
+    }
+
+    fn quantified(&mut self, atom: Node) -> Result<Node, PatternError> {
+        let (min, max) = match self.peek() {
+            Some('?') => (0, Some(1)),
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('{') => {
+                self.pos += 1;
+                let min = self.number()?;
+                let max = if self.peek() == Some(',') {
+                    self.pos += 1;
+                    if self.peek() == Some('}') {
+                        None
+                    } else {
+                        Some(self.number()?)
+                    }
+                } else {
+                    Some(min)
+                };
+                if self.peek() != Some('}') {
+                    return Err(self.error("unclosed repetition"));
+                }
+                if max.is_some_and(|max| max < min) {
+                    return Err(self.error("invalid repetition range"));
+                }
+                (min, max)
+            }
+            _ => return Ok(atom),
+        };
+        self.pos += 1;
+        Ok(Node::Repeat {
+            node: Box::new(atom),
+            min,
+            max,
+        })
+    }
+
+    fn number(&mut self) -> Result<usize, PatternError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        digits
+            .parse()
+            .map_err(|_| self.error("invalid repetition count"))
+    }
+}
+
+/// Ranges of a `\d`, `\w` or `\s` shorthand (uppercase forms are negated)
+fn shorthand_class(c: char) -> Option<(Vec<(char, char)>, bool)> {
+    let ranges = match c.to_ascii_lowercase() {
+        'd' => vec![('0', '9')],
+        'w' => vec![('0', '9'), ('A', 'Z'), ('_', '_'), ('a', 'z')],
+        's' => vec![(' ', ' '), ('\t', '\t'), ('\n', '\n'), ('\r', '\r')],
+        _ => return None,
+    };
+    Some((ranges, c.is_ascii_uppercase()))
+}
+
+fn escaped_char(c: char) -> char {
+    match c {
+        'n' => '\n',
+        'r' => '\r',
+        't' => '\t',
+        c => c,
+    }
+}
+
+struct Matcher<'a> {
+    input: &'a [char],
+}
+
+impl Matcher<'_> {
+    fn alternatives(
+        &self,
+        alternatives: &[Vec<Node>],
+        pos: usize,
+        k: &mut dyn FnMut(usize) -> bool,
+    ) -> bool {
+        alternatives
+            .iter()
+            .any(|sequence| self.sequence(sequence, pos, k))
+    }
+
+    fn sequence(&self, nodes: &[Node], pos: usize, k: &mut dyn FnMut(usize) -> bool) -> bool {
+        match nodes.split_first() {
+            None => k(pos),
+            Some((node, rest)) => self.node(node, pos, &mut |next| self.sequence(rest, next, k)),
+        }
+    }
+
+    fn node(&self, node: &Node, pos: usize, k: &mut dyn FnMut(usize) -> bool) -> bool {
+        match node {
+            Node::Char(c) => self.input.get(pos) == Some(c) && k(pos + 1),
+            Node::Any => self.input.get(pos).is_some_and(|&c| c != '\n') && k(pos + 1),
+            Node::Class { ranges, negated } => {
+                self.input
+                    .get(pos)
+                    .is_some_and(|&c| ranges.iter().any(|&(lo, hi)| lo <= c && c <= hi) != *negated)
+                    && k(pos + 1)
+            }
+            Node::Group(alternatives) => self.alternatives(alternatives, pos, k),
+            Node::Repeat { node, min, max } => self.repeat(node, *min, *max, 0, pos, k),
+            Node::Start => pos == 0 && k(pos),
+            Node::End => pos == self.input.len() && k(pos),
+        }
+    }
+
+    /// Greedy repetition; an iteration that consumes nothing ends the loop
+    fn repeat(
+        &self,
+        node: &Node,
+        min: usize,
+        max: Option<usize>,
+        count: usize,
+        pos: usize,
+        k: &mut dyn FnMut(usize) -> bool,
+    ) -> bool {
+        if max.is_none_or(|max| count < max)
+            && self.node(node, pos, &mut |next| {
+                (next != pos || count < min) && self.repeat(node, min, max, count + 1, next, k)
+            })
+        {
+            return true;
+        }
+        count >= min && k(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, value: &str) -> bool {
+        Pattern::new(pattern).unwrap().is_match(value)
+    }
+
+    #[test]
+    fn test_schema_patterns() {
+        let bic = "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}";
+        assert!(matches(bic, "DEUTDEFFXXX"));
+        assert!(matches(bic, "DEUTDEFF"));
+        assert!(!matches(bic, "deutdeff"));
+
+        let iban = "[A-Z]{2,2}[0-9]{2,2}[a-zA-Z0-9]{1,30}";
+        assert!(matches(iban, "DE89370400440532013000"));
+        assert!(!matches(iban, "89DE"));
+
+        let text = "[0-9a-zA-Z/\\-\\?:\\(\\)\\.,'\\+ ]+";
+        assert!(matches(text, "ACME Trading (UK) Ltd."));
+        assert!(!matches(text, "@@@"));
+
+        let phone = "\\+[0-9]{1,3}-[0-9()+\\-]{1,30}";
+        assert!(matches(phone, "+44-(0)20 7946"));
+        assert!(!matches(phone, "0044 20"));
+
+        let uuid = "[a-f0-9]{8}-[a-f0-9]{4}-4[a-f0-9]{3}-[89ab][a-f0-9]{3}-[a-f0-9]{12}";
+        assert!(matches(uuid, "eb6305c9-1f7f-49de-aed0-16487c27b42d"));
+        assert!(!matches(uuid, "eb6305c9-1f7f-39de-aed0-16487c27b42d"));
+
+        let offset = ".*(\\+|-)((0[0-9])|(1[0-4])):[0-5][0-9]";
+        assert!(matches(offset, "2025-01-15T10:30:00+01:00"));
+        assert!(!matches(offset, "2025-01-15T10:30:00Z"));
+
+        let version = "[a-z0-9]{1,10}\\.([a-z0-9]{1,10}\\.)+\\d\\d";
+        assert!(matches(version, "pacs.008.001.08"));
+        assert!(!matches(version, "pacs008"));
+    }
+
+    #[test]
+    fn test_anchors_and_alternation() {
+        assert!(matches("^(CRDT|DBIT)$", "DBIT"));
+        assert!(!matches("^(CRDT|DBIT)$", "DBITX"));
+        assert!(matches("(?:ab)*c", "ababc"));
+        assert!(matches("[^0-9]", "12a"));
+        assert!(!matches("^[^0-9]+$", "12a"));
+    }
+
+    #[test]
+    fn test_invalid_patterns() {
+        assert!(Pattern::new("[A-Z").is_err());
+        assert!(Pattern::new("(ab").is_err());
+        assert!(Pattern::new("ab)").is_err());
+        assert!(Pattern::new("*a").is_err());
+        assert!(Pattern::new("a{3,1}").is_err());
+    }
+}
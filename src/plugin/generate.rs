@@ -11,6 +11,8 @@ use serde_json::Value;
 use std::sync::Arc;
 use tracing::{debug, error, instrument};
 
+use crate::identifiers::Iban;
+
 pub struct Generate;
 
 #[async_trait]
@@ -43,7 +45,7 @@ impl AsyncFunctionHandler for Generate {
         let scenario = (*message.payload).clone();
 
         // Generate data using datafake
-        let generated_data = match DataGenerator::from_value(scenario) {
+        let mut generated_data = match DataGenerator::from_value(scenario) {
             Ok(generator) => generator.generate().map_err(|e| {
                 error!(error = ?e, "Datafake generation failed");
                 DataflowError::Validation(format!("Datafake generation failed: {}", e))
@@ -57,6 +59,9 @@ impl AsyncFunctionHandler for Generate {
             }
         };
 
+        // datafake draws IBAN check digits at random, so recompute them
+        fix_iban_check_digits(&mut generated_data);
+
         // Store the generated data in the target field
         let old_value = message
             .data()
@@ -83,3 +88,48 @@ impl AsyncFunctionHandler for Generate {
         ))
     }
 }
+
+/// Replace the check digits of every generated `IBAN` value with the ones its
+/// country code and BBAN call for, leaving values that cannot form an IBAN
+fn fix_iban_check_digits(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                match child {
+                    Value::String(iban) if key == "IBAN" => {
+                        if let (Some(country_code), Some(bban)) = (iban.get(0..2), iban.get(4..))
+                            && let Ok(fixed) = Iban::from_bban(country_code, bban)
+                        {
+                            *iban = fixed.to_string();
+                        }
+                    }
+                    _ => fix_iban_check_digits(child),
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(fix_iban_check_digits),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_fix_iban_check_digits() {
+        let mut data = json!({
+            "DbtrAcct": {"Id": {"IBAN": "DE00370400440532013000"}},
+            "Ntry": [{"Acct": {"Id": {"IBAN": "GB11WEST12345698765432"}}}],
+            "CdtrAcct": {"Id": {"IBAN": "not an iban"}}
+        });
+        fix_iban_check_digits(&mut data);
+        assert_eq!(data["DbtrAcct"]["Id"]["IBAN"], "DE89370400440532013000");
+        assert_eq!(
+            data["Ntry"][0]["Acct"]["Id"]["IBAN"],
+            "GB82WEST12345698765432"
+        );
+        assert_eq!(data["CdtrAcct"]["Id"]["IBAN"], "not an iban");
+    }
+}
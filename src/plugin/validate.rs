@@ -11,9 +11,15 @@ use std::sync::Arc;
 use tracing::{debug, instrument};
 
 use super::common::extract_mx_content;
+use crate::error::ValidationError;
+use crate::parse_result::{ErrorCollector, ParserConfig, ValidationProfile};
 
 pub struct Validate;
 
+/// Version of the result format written when a `profile` is given, whose
+/// errors and warnings are rendered as "<code> <path>: <message>"
+pub const PROFILE_RESULT_FORMAT_VERSION: u32 = 2;
+
 #[async_trait]
 impl AsyncFunctionHandler for Validate {
     #[instrument(skip(self, message, config, _datalogic))]
@@ -43,17 +49,28 @@ impl AsyncFunctionHandler for Validate {
             DataflowError::Validation("'target' parameter is required".to_string())
         })?;
 
+        // Optional scheme profile for business rules; without one only the
+        // structure is checked by parsing the message
+        let profile = input
+            .get("profile")
+            .map(|value| {
+                serde_json::from_value::<ValidationProfile>(value.clone())
+                    .map_err(|e| DataflowError::Validation(format!("Invalid 'profile': {}", e)))
+            })
+            .transpose()?;
+
         // Get the MX XML message to validate
         let xml_content = extract_mx_content(message.data(), source_field, &message.payload)?;
 
         debug!(
             source_field = %source_field,
             target_field = %target_field,
+            profile = ?profile,
             "Validating MX XML message"
         );
 
         // Perform XML validation
-        let validation_result = self.validate_xml(&xml_content)?;
+        let validation_result = self.validate_xml(&xml_content, profile)?;
 
         // Store validation result
         message
@@ -86,61 +103,142 @@ impl AsyncFunctionHandler for Validate {
 
 impl Validate {
     /// Validate XML MX message by attempting to parse into typed structs
-    /// This validates both structure and content according to ISO20022 schemas
-    fn validate_xml(&self, xml_content: &str) -> Result<Value> {
+    /// This validates both structure and content according to ISO20022 schemas.
+    /// With a scheme profile, the schema and business rules of that profile are
+    /// run as well and reported in the versioned output format
+    fn validate_xml(&self, xml_content: &str, profile: Option<ValidationProfile>) -> Result<Value> {
         use crate::mx_envelope::MxMessage;
 
         debug!("Validating XML MX message");
 
         let mut errors: Vec<String> = Vec::new();
-        let warnings: Vec<String> = Vec::new();
+        let mut warnings: Vec<String> = Vec::new();
+
+        // Parse only, or parse and collect the findings of the selected profile
+        let parse = |xml: &str| match profile {
+            None => MxMessage::from_xml(xml).map(|_| ErrorCollector::new()),
+            Some(profile) => {
+                let config = ParserConfig::default().with_profile(profile);
+                MxMessage::from_xml_validated(xml, &config).map(|(_, collector)| collector)
+            }
+        };
 
         // Check if XML has full envelope or just Document
         let has_envelope = xml_content.contains("<AppHdr") || xml_content.contains("<Envelope");
 
-        if has_envelope {
+        let parsed = if has_envelope {
             debug!("Validating XML with full envelope using MxMessage");
 
             // Validate by attempting to deserialize with MxMessage
-            match MxMessage::from_xml(xml_content) {
-                Ok(_) => {
-                    debug!("XML message with envelope validated successfully");
+            match parse(xml_content) {
+                Ok(collector) => {
+                    debug!("XML message with envelope parsed successfully");
+                    Some(collector)
                 }
                 Err(e) => {
                     errors.push(format!("XML validation failed: {}", e));
+                    None
                 }
             }
         } else {
-            debug!("Validating Document-only XML using from_mx_xml_envelope_str");
+            debug!("Validating Document-only XML");
 
             // For Document-only XML, we need to determine the message type first
             use super::common::extract_message_type_from_xml;
 
             match extract_message_type_from_xml(xml_content) {
-                Ok(message_type) => {
-                    // Validate using the envelope validator which handles both cases
-                    match crate::xml::from_mx_xml_envelope_str(xml_content, &message_type) {
-                        Ok(_) => {
-                            debug!("Document-only XML validated successfully");
-                        }
-                        Err(e) => {
-                            errors.push(format!("XML validation failed: {}", e));
-                        }
+                Ok(_) => match parse(xml_content) {
+                    Ok(collector) => {
+                        debug!("Document-only XML parsed successfully");
+                        Some(collector)
                     }
-                }
+                    Err(e) => {
+                        errors.push(format!("XML validation failed: {}", e));
+                        None
+                    }
+                },
                 Err(e) => {
                     errors.push(format!("Could not determine message type: {}", e));
+                    None
                 }
             }
+        };
+
+        let Some(profile) = profile else {
+            return Ok(json!({
+                "valid": errors.is_empty(),
+                "errors": errors,
+                "warnings": warnings,
+                "timestamp": chrono::Utc::now().to_rfc3339(),
+            }));
+        };
+
+        // Schema and business rules of the selected profile
+        if let Some(collector) = parsed {
+            warnings.extend(collector.warnings().iter().map(format_validation_error));
+            errors.extend(collector.errors().iter().map(format_validation_error));
         }
 
         let is_valid = errors.is_empty();
 
         Ok(json!({
+            "format_version": PROFILE_RESULT_FORMAT_VERSION,
             "valid": is_valid,
+            "profile": profile,
             "errors": errors,
             "warnings": warnings,
             "timestamp": chrono::Utc::now().to_rfc3339(),
         }))
     }
 }
+
+/// Render a validation error as "<code> <path>: <message>"
+fn format_validation_error(error: &ValidationError) -> String {
+    match &error.path {
+        Some(path) => format!("{} {}: {}", error.code, path, error.message),
+        None => format!("{} {}", error.code, error.message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+    use crate::mx_envelope::{AppHdr, Document, MxMessage};
+
+    #[test]
+    fn test_validate_xml_profiles() {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.pmt_id.end_to_end_id = "NOTPROVIDED".to_string();
+        let app_hdr = AppHdr {
+            msg_def_idr: "pacs.008.001.08".to_string(),
+            ..Default::default()
+        };
+        let xml = MxMessage::new(app_hdr, Document::Pacs008(Box::new(doc)))
+            .to_xml()
+            .unwrap();
+
+        let placeholder_errors = |profile| {
+            let result = Validate.validate_xml(&xml, Some(profile)).unwrap();
+            assert_eq!(result["profile"], json!(profile));
+            assert_eq!(
+                result["format_version"],
+                json!(PROFILE_RESULT_FORMAT_VERSION)
+            );
+            result["errors"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .filter(|e| e.as_str().unwrap().starts_with("2002 "))
+                .count()
+        };
+        assert_eq!(placeholder_errors(ValidationProfile::Base), 0);
+        assert_eq!(placeholder_errors(ValidationProfile::Target2), 1);
+
+        // Without a profile only the structure is checked
+        let result = Validate.validate_xml(&xml, None).unwrap();
+        assert_eq!(result["valid"], json!(true));
+        assert!(result.get("profile").is_none());
+        assert!(result.get("format_version").is_none());
+    }
+}
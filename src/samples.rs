@@ -0,0 +1,662 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Sample Messages
+//!
+//! Builds a valid sample envelope (AppHdr and Document) for every message type
+//! in the registry, as a ready-made fixture corpus for integration tests. The
+//! samples carry the mandatory elements plus the amounts, agents and parties a
+//! reader expects to see. They depend only on the seed, which picks the
+//! creation time, the identifiers, the UETR and the amount: the same seed
+//! always gives the same XML.
+
+use crate::cancellation::camt056_from_pacs008;
+use crate::document::camt_056_001_08::CBPRCancellationReasonCode;
+use crate::document::*;
+use crate::header::AppHdr;
+use crate::ids::build_id;
+use crate::message_registry::{MESSAGE_REGISTRY, MessageTypeInfo};
+use crate::mx_envelope::{Document, MxMessage};
+use crate::status_report::pacs002_from_validation;
+use chrono::{DateTime, Duration, SecondsFormat, TimeZone, Utc};
+
+/// Business service of the sample headers
+const BUSINESS_SERVICE: &str = "swift.cbprplus.02";
+
+/// Sending agent (instructing agent, debtor agent)
+const SENDER_BIC: &str = "DEUTDEFFXXX";
+
+/// Receiving agent (instructed agent, creditor agent)
+const RECEIVER_BIC: &str = "BNPAFRPPXXX";
+
+const DEBTOR_IBAN: &str = "DE89370400440532013000";
+const CREDITOR_IBAN: &str = "FR1420041010050500013M02606";
+const DEBTOR_NAME: &str = "Sample Debtor GmbH";
+const CREDITOR_NAME: &str = "Sample Creditor SA";
+const CURRENCY: &str = "EUR";
+
+/// Maximum length of Max16Text references (InstrId, Case/Id, cheque message ids)
+const SHORT_ID_LENGTH: usize = 16;
+
+/// Generate a sample message for every supported message type
+/// The samples are returned in registry order, each as envelope XML.
+pub fn generate_all_samples(seed: u64) -> Vec<(&'static MessageTypeInfo, String)> {
+    MESSAGE_REGISTRY
+        .iter()
+        .filter_map(|info| {
+            let message = sample_message(info.short_form, seed)?;
+            let xml = message.to_xml().ok()?;
+            Some((info, xml))
+        })
+        .collect()
+}
+
+/// Build the sample message of one type (e.g. "pacs.008" or "pacs.008.001.08")
+/// Returns None when the message type is not supported.
+pub fn sample_message(message_type: &str, seed: u64) -> Option<MxMessage> {
+    let values = SampleValues::new(seed);
+    let message_type = message_type.get(..8)?;
+    let document = match message_type {
+        "pacs.008" => Document::Pacs008(Box::new(values.pacs008())),
+        "pacs.002" => Document::Pacs002(Box::new(values.pacs002())),
+        "pacs.003" => Document::Pacs003(Box::new(values.pacs003())),
+        "pacs.004" => Document::Pacs004(Box::new(values.pacs004())),
+        "pacs.009" => Document::Pacs009(Box::new(values.pacs009())),
+        "pacs.010" => Document::Pacs010(Box::new(values.pacs010())),
+        "pain.001" => Document::Pain001(Box::new(values.pain001())),
+        "pain.002" => Document::Pain002(Box::new(values.pain002())),
+        "pain.008" => Document::Pain008(Box::new(values.pain008())),
+        "camt.025" => Document::Camt025(Box::new(values.camt025())),
+        "camt.029" => Document::Camt029(Box::new(values.camt029())),
+        "camt.052" => Document::Camt052(Box::new(values.camt052())),
+        "camt.053" => Document::Camt053(Box::new(values.camt053())),
+        "camt.054" => Document::Camt054(Box::new(values.camt054())),
+        "camt.055" => Document::Camt055(Box::new(values.camt055())),
+        "camt.056" => Document::Camt056(Box::new(values.camt056())),
+        "camt.057" => Document::Camt057(Box::new(values.camt057())),
+        "camt.058" => Document::Camt058(Box::new(values.camt058())),
+        "camt.060" => Document::Camt060(Box::new(values.camt060())),
+        "camt.105" => Document::Camt105(Box::new(values.camt105())),
+        "camt.106" => Document::Camt106(Box::new(values.camt106())),
+        "camt.107" => Document::Camt107(Box::new(values.camt107())),
+        "camt.108" => Document::Camt108(Box::new(values.camt108())),
+        "camt.109" => Document::Camt109(Box::new(values.camt109())),
+        "admi.024" => Document::Admi024(Box::new(values.admi024())),
+        _ => return None,
+    };
+    let app_hdr = values.app_hdr(&document);
+    Some(MxMessage::new(app_hdr, document))
+}
+
+/// Values shared by the samples of one seed
+struct SampleValues {
+    seed: u64,
+    created: DateTime<Utc>,
+}
+
+impl SampleValues {
+    fn new(seed: u64) -> Self {
+        let base = Utc.with_ymd_and_hms(2024, 1, 15, 9, 0, 0).unwrap();
+        let offset = Duration::days((seed % 365) as i64) + Duration::minutes((seed % 480) as i64);
+        Self {
+            seed,
+            created: base + offset,
+        }
+    }
+
+    /// Identifier with the given prefix, e.g. "MSG-20240115090000000042"
+    fn id(&self, prefix: &str) -> String {
+        build_id(prefix, self.created, self.seed)
+    }
+
+    /// Max16Text reference: the timestamp and sequence tail of an id
+    fn short_id(&self) -> String {
+        let id = self.id("");
+        id[id.len() - SHORT_ID_LENGTH..].to_string()
+    }
+
+    fn cre_dt_tm(&self) -> String {
+        self.created.to_rfc3339_opts(SecondsFormat::Secs, false)
+    }
+
+    fn date(&self) -> String {
+        self.created.format("%Y-%m-%d").to_string()
+    }
+
+    /// Amount with two decimals between 100.00 and 10,099.99
+    fn amount(&self) -> f64 {
+        (10_000 + self.seed % 1_000_000) as f64 / 100.0
+    }
+
+    /// Version 4 UUID derived from the seed
+    fn uetr(&self) -> String {
+        let high = splitmix64(self.seed);
+        let low = splitmix64(high);
+        format!(
+            "{:08x}-{:04x}-4{:03x}-{:x}{:03x}-{:012x}",
+            high >> 32,
+            (high >> 16) & 0xffff,
+            high & 0xfff,
+            8 + (low >> 62),
+            (low >> 48) & 0xfff,
+            low & 0xffff_ffff_ffff
+        )
+    }
+
+    fn app_hdr(&self, document: &Document) -> AppHdr {
+        let mut app_hdr = AppHdr {
+            biz_msg_idr: document.message_id().to_string(),
+            msg_def_idr: document
+                .as_document_type()
+                .message_definition_identifier()
+                .to_string(),
+            biz_svc: BUSINESS_SERVICE.to_string(),
+            cre_dt: self.cre_dt_tm(),
+            ..Default::default()
+        };
+        let fr = app_hdr.fr.fi_id.get_or_insert_with(Default::default);
+        fr.fin_instn_id.bicfi = SENDER_BIC.into();
+        let to = app_hdr.to.fi_id.get_or_insert_with(Default::default);
+        to.fin_instn_id.bicfi = RECEIVER_BIC.into();
+        app_hdr
+    }
+
+    fn pacs008(&self) -> pacs_008_001_08::FIToFICustomerCreditTransferV08 {
+        let mut doc = pacs_008_001_08::FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = self.id("MSG");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.pmt_id.instr_id = self.short_id();
+        tx.pmt_id.end_to_end_id = self.id("E2E");
+        tx.pmt_id.tx_id = Some(self.id("TX"));
+        tx.pmt_id.uetr = self.uetr();
+        tx.intr_bk_sttlm_amt.ccy = CURRENCY.into();
+        tx.intr_bk_sttlm_amt.value = self.amount();
+        tx.intr_bk_sttlm_dt = self.date();
+        tx.instg_agt.fin_instn_id.bicfi = SENDER_BIC.into();
+        tx.instd_agt.fin_instn_id.bicfi = RECEIVER_BIC.into();
+        tx.dbtr.nm = Some(DEBTOR_NAME.to_string());
+        tx.dbtr_agt.fin_instn_id.bicfi = Some(SENDER_BIC.into());
+        tx.cdtr_agt.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        tx.cdtr.nm = Some(CREDITOR_NAME.to_string());
+        doc
+    }
+
+    fn pacs002(&self) -> pacs_002_001_10::FIToFIPaymentStatusReportV10 {
+        let mut doc = pacs002_from_validation(&self.pacs008(), &[]);
+        doc.grp_hdr.msg_id = self.id("STS");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc
+    }
+
+    fn pacs003(&self) -> pacs_003_001_08::FIToFICustomerDirectDebitV08 {
+        let mut doc = pacs_003_001_08::FIToFICustomerDirectDebitV08::default();
+        doc.grp_hdr.msg_id = self.id("MSG");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        let tx = &mut doc.drct_dbt_tx_inf;
+        tx.pmt_id.instr_id = self.short_id();
+        tx.pmt_id.end_to_end_id = self.id("E2E");
+        tx.pmt_id.uetr = Some(self.uetr());
+        tx.intr_bk_sttlm_amt.ccy = CURRENCY.into();
+        tx.intr_bk_sttlm_amt.value = self.amount();
+        tx.intr_bk_sttlm_dt = self.date();
+        tx.reqd_colltn_dt = self.date();
+        tx.cdtr.nm = Some(CREDITOR_NAME.to_string());
+        tx.cdtr_agt.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        tx.instg_agt.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        tx.instd_agt.fin_instn_id.bicfi = Some(SENDER_BIC.into());
+        tx.dbtr.nm = Some(DEBTOR_NAME.to_string());
+        tx.dbtr_acct.id.iban = Some(DEBTOR_IBAN.into());
+        tx.dbtr_agt.fin_instn_id.bicfi = Some(SENDER_BIC.into());
+        doc
+    }
+
+    fn pacs004(&self) -> pacs_004_001_09::PaymentReturnV09 {
+        let mut doc = pacs_004_001_09::PaymentReturnV09::default();
+        doc.grp_hdr.msg_id = self.id("RTR");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        let tx = &mut doc.tx_inf;
+        tx.rtr_id = Some(self.id("RTR"));
+        tx.orgnl_end_to_end_id = self.id("E2E");
+        tx.orgnl_uetr = self.uetr();
+        tx.rtrd_intr_bk_sttlm_amt.ccy = CURRENCY.into();
+        tx.rtrd_intr_bk_sttlm_amt.value = self.amount();
+        tx.intr_bk_sttlm_dt = self.date();
+        tx.instg_agt.fin_instn_id.bicfi = RECEIVER_BIC.into();
+        tx.instd_agt.fin_instn_id.bicfi = SENDER_BIC.into();
+        tx.rtr_rsn_inf.rsn.cd = Some("AC04".to_string());
+        doc
+    }
+
+    fn pacs009(&self) -> pacs_009_001_08::FinancialInstitutionCreditTransferV08 {
+        let mut doc = pacs_009_001_08::FinancialInstitutionCreditTransferV08::default();
+        doc.grp_hdr.msg_id = self.id("MSG");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.pmt_id.instr_id = self.short_id();
+        tx.pmt_id.end_to_end_id = self.id("E2E");
+        tx.pmt_id.uetr = self.uetr();
+        tx.intr_bk_sttlm_amt.ccy = CURRENCY.into();
+        tx.intr_bk_sttlm_amt.value = self.amount();
+        tx.intr_bk_sttlm_dt = self.date();
+        tx.instg_agt.fin_instn_id.bicfi = SENDER_BIC.into();
+        tx.instd_agt.fin_instn_id.bicfi = RECEIVER_BIC.into();
+        tx.dbtr.fin_instn_id.bicfi = Some(SENDER_BIC.into());
+        tx.cdtr.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        doc
+    }
+
+    fn pacs010(&self) -> pacs_010_001_03::FinancialInstitutionDirectDebitV03 {
+        let mut doc = pacs_010_001_03::FinancialInstitutionDirectDebitV03::default();
+        doc.grp_hdr.msg_id = self.id("MSG");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        let instr = &mut doc.cdt_instr;
+        instr.cdt_id = self.id("CDT");
+        instr.instg_agt.fin_instn_id.bicfi = SENDER_BIC.into();
+        instr.instd_agt.fin_instn_id.bicfi = RECEIVER_BIC.into();
+        instr.cdtr.fin_instn_id.bicfi = Some(SENDER_BIC.into());
+        let tx = &mut instr.drct_dbt_tx_inf;
+        tx.pmt_id.instr_id = self.short_id();
+        tx.pmt_id.end_to_end_id = self.id("E2E");
+        tx.pmt_id.uetr = self.uetr();
+        tx.intr_bk_sttlm_amt.ccy = CURRENCY.into();
+        tx.intr_bk_sttlm_amt.value = self.amount();
+        tx.intr_bk_sttlm_dt = self.date();
+        tx.dbtr.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        doc
+    }
+
+    fn pain001(&self) -> pain_001_001_09::CustomerCreditTransferInitiationV09 {
+        let mut doc = pain_001_001_09::CustomerCreditTransferInitiationV09::default();
+        doc.grp_hdr.msg_id = self.id("MSG");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.grp_hdr.initg_pty.nm = Some(DEBTOR_NAME.to_string());
+        let pmt_inf = &mut doc.pmt_inf;
+        pmt_inf.pmt_inf_id = self.id("PMT");
+        pmt_inf.reqd_exctn_dt.dt = Some(self.date());
+        pmt_inf.dbtr.nm = Some(DEBTOR_NAME.to_string());
+        pmt_inf.dbtr_acct.id.iban = Some(DEBTOR_IBAN.into());
+        pmt_inf.dbtr_agt.fin_instn_id.bicfi = Some(SENDER_BIC.into());
+        let tx = &mut pmt_inf.cdt_trf_tx_inf;
+        tx.pmt_id.instr_id = Some(self.id("INSTR"));
+        tx.pmt_id.end_to_end_id = self.id("E2E");
+        tx.pmt_id.uetr = self.uetr();
+        tx.amt.instd_amt = Some(pain_001_001_09::ActiveOrHistoricCurrencyAndAmount {
+            ccy: CURRENCY.into(),
+            value: self.amount(),
+        });
+        let cdtr_agt = tx.cdtr_agt.get_or_insert_with(Default::default);
+        cdtr_agt.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        tx.cdtr.nm = Some(CREDITOR_NAME.to_string());
+        doc
+    }
+
+    fn pain002(&self) -> pain_002_001_10::CustomerPaymentStatusReportV10 {
+        let mut doc = pain_002_001_10::CustomerPaymentStatusReportV10::default();
+        doc.grp_hdr.msg_id = self.id("STS");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.orgnl_grp_inf_and_sts.orgnl_msg_id = self.id("MSG");
+        doc.orgnl_grp_inf_and_sts.orgnl_msg_nm_id = "pain.001.001.09".to_string();
+        let pmt_inf = &mut doc.orgnl_pmt_inf_and_sts;
+        pmt_inf.orgnl_pmt_inf_id = self.id("PMT");
+        pmt_inf.tx_inf_and_sts.orgnl_end_to_end_id = self.id("E2E");
+        pmt_inf.tx_inf_and_sts.orgnl_uetr = self.uetr();
+        pmt_inf.tx_inf_and_sts.tx_sts = "ACSC".to_string();
+        doc
+    }
+
+    fn pain008(&self) -> pain_008_001_08::CustomerDirectDebitInitiationV08 {
+        let mut doc = pain_008_001_08::CustomerDirectDebitInitiationV08::default();
+        doc.grp_hdr.msg_id = self.id("MSG");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.grp_hdr.nb_of_txs = "1".to_string();
+        doc.grp_hdr.initg_pty.nm = Some(CREDITOR_NAME.to_string());
+        doc.grp_hdr.fwdg_agt.fin_instn_id.bicfi = RECEIVER_BIC.into();
+        let pmt_inf = &mut doc.pmt_inf;
+        pmt_inf.pmt_inf_id = self.id("PMT");
+        pmt_inf.reqd_colltn_dt = self.date();
+        pmt_inf.cdtr.nm = Some(CREDITOR_NAME.to_string());
+        pmt_inf.cdtr_acct.id.iban = Some(CREDITOR_IBAN.into());
+        pmt_inf.cdtr_agt.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        let mut tx = pain_008_001_08::DirectDebitTransactionInformation231::default();
+        tx.pmt_id.end_to_end_id = self.id("E2E");
+        tx.instd_amt.ccy = CURRENCY.into();
+        tx.instd_amt.value = self.amount();
+        tx.dbtr_agt.fin_instn_id.bicfi = Some(SENDER_BIC.into());
+        tx.dbtr.nm = DEBTOR_NAME.to_string();
+        tx.dbtr_acct.id.iban = Some(DEBTOR_IBAN.into());
+        pmt_inf.drct_dbt_tx_inf = vec![tx];
+        doc
+    }
+
+    fn camt025(&self) -> camt_025_001_08::ReceiptV08 {
+        let mut doc = camt_025_001_08::ReceiptV08::default();
+        doc.msg_hdr.msg_id = self.id("RCPT");
+        doc.msg_hdr.cre_dt_tm = self.cre_dt_tm();
+        let mut receipt = camt_025_001_08::Receipt61::default();
+        receipt.orgnl_msg_id.msg_id = self.id("MSG");
+        receipt.orgnl_msg_id.msg_nm_id = "camt.060.001.05".to_string();
+        receipt.req_hdlg.sts.cd = Some("ACTC".to_string());
+        doc.rct_dtls = vec![receipt];
+        doc
+    }
+
+    fn camt029(&self) -> camt_029_001_09::ResolutionOfInvestigationV09 {
+        let mut doc = camt_029_001_09::ResolutionOfInvestigationV09::default();
+        doc.assgnmt.id = self.id("RSLTN");
+        doc.assgnmt.cre_dt_tm = self.cre_dt_tm();
+        doc.assgnmt.assgnr.agt = Some(
+            camt_029_001_09::BranchAndFinancialInstitutionIdentification61 {
+                fin_instn_id: camt_029_001_09::FinancialInstitutionIdentification181 {
+                    bicfi: RECEIVER_BIC.into(),
+                    ..Default::default()
+                },
+            },
+        );
+        doc.assgnmt.assgne.agt = Some(
+            camt_029_001_09::BranchAndFinancialInstitutionIdentification61 {
+                fin_instn_id: camt_029_001_09::FinancialInstitutionIdentification181 {
+                    bicfi: SENDER_BIC.into(),
+                    ..Default::default()
+                },
+            },
+        );
+        let tx = &mut doc.cxl_dtls.tx_inf_and_sts;
+        tx.cxl_sts_id = self.short_id();
+        tx.rslvd_case.id = self.short_id();
+        tx.orgnl_grp_inf.orgnl_msg_id = self.id("MSG");
+        tx.orgnl_grp_inf.orgnl_msg_nm_id = "pacs.008.001.08".to_string();
+        tx.orgnl_end_to_end_id = Some(self.id("E2E"));
+        tx.orgnl_uetr = self.uetr();
+        doc
+    }
+
+    fn camt052(&self) -> camt_052_001_08::BankToCustomerAccountReportV08 {
+        let mut doc = camt_052_001_08::BankToCustomerAccountReportV08::default();
+        doc.grp_hdr.msg_id = self.id("RPT");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.rpt.id = self.id("RPT");
+        doc.rpt.rpt_pgntn.pg_nb = "1".to_string();
+        doc.rpt.rpt_pgntn.last_pg_ind = true;
+        doc.rpt.acct.id.iban = Some(DEBTOR_IBAN.into());
+        doc.rpt.acct.ccy = CURRENCY.into();
+        doc
+    }
+
+    fn camt053(&self) -> camt_053_001_08::BankToCustomerStatementV08 {
+        use camt_053_001_08::{CashBalance81, CreditDebitCode};
+
+        let balance = |code: &str| {
+            let mut balance = CashBalance81::default();
+            balance.tp.cd_or_prtry.cd = Some(code.to_string());
+            balance.amt.ccy = CURRENCY.into();
+            balance.amt.value = self.amount();
+            balance.cdt_dbt_ind = CreditDebitCode::CodeCRDT;
+            balance.dt.dt = Some(self.date());
+            balance
+        };
+        let mut doc = camt_053_001_08::BankToCustomerStatementV08::default();
+        doc.grp_hdr.msg_id = self.id("STMT");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.stmt.id = self.id("STMT");
+        doc.stmt.stmt_pgntn.pg_nb = "1".to_string();
+        doc.stmt.stmt_pgntn.last_pg_ind = true;
+        doc.stmt.acct.id.iban = Some(DEBTOR_IBAN.into());
+        doc.stmt.acct.ccy = CURRENCY.into();
+        doc.stmt.bal = vec![balance("OPBD"), balance("CLBD")];
+        doc
+    }
+
+    fn camt054(&self) -> camt_054_001_08::BankToCustomerDebitCreditNotificationV08 {
+        let mut doc = camt_054_001_08::BankToCustomerDebitCreditNotificationV08::default();
+        doc.grp_hdr.msg_id = self.id("NTFCTN");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        let mut ntfctn = camt_054_001_08::AccountNotification171 {
+            id: self.id("NTFCTN"),
+            ..Default::default()
+        };
+        ntfctn.acct.id.iban = Some(CREDITOR_IBAN.into());
+        ntfctn.acct.ccy = CURRENCY.into();
+        let mut entry = camt_054_001_08::ReportEntry101 {
+            ntry_ref: self.short_id(),
+            ..Default::default()
+        };
+        entry.amt.ccy = CURRENCY.into();
+        entry.amt.value = self.amount();
+        entry.sts.cd = Some("BOOK".to_string());
+        ntfctn.ntry = vec![Box::new(entry)];
+        doc.ntfctn = vec![ntfctn];
+        doc
+    }
+
+    fn camt055(&self) -> camt_055_001_08::CustomerPaymentCancellationRequestV08 {
+        let mut doc = camt_055_001_08::CustomerPaymentCancellationRequestV08::default();
+        doc.assgnmt.id = self.id("CXL");
+        doc.assgnmt.cre_dt_tm = self.cre_dt_tm();
+        doc.assgnmt.assgnr.agt = Some(
+            camt_055_001_08::BranchAndFinancialInstitutionIdentification61 {
+                fin_instn_id: camt_055_001_08::FinancialInstitutionIdentification181 {
+                    bicfi: SENDER_BIC.into(),
+                    ..Default::default()
+                },
+            },
+        );
+        doc.assgnmt.assgne.agt = Some(
+            camt_055_001_08::BranchAndFinancialInstitutionIdentification62 {
+                fin_instn_id: camt_055_001_08::FinancialInstitutionIdentification182 {
+                    bicfi: Some(RECEIVER_BIC.into()),
+                    ..Default::default()
+                },
+            },
+        );
+        let pmt_inf = &mut doc.undrlyg.orgnl_pmt_inf_and_cxl;
+        pmt_inf.orgnl_pmt_inf_id = self.id("PMT");
+        pmt_inf.orgnl_grp_inf.orgnl_msg_id = self.id("MSG");
+        pmt_inf.orgnl_grp_inf.orgnl_msg_nm_id = "pain.001.001.09".to_string();
+        let tx = &mut pmt_inf.tx_inf;
+        tx.case.id = self.short_id();
+        tx.orgnl_end_to_end_id = self.id("E2E");
+        tx.orgnl_uetr = self.uetr();
+        tx.orgnl_instd_amt.ccy = CURRENCY.into();
+        tx.orgnl_instd_amt.value = self.amount();
+        doc
+    }
+
+    fn camt056(&self) -> camt_056_001_08::FIToFIPaymentCancellationRequestV08 {
+        let mut doc = camt056_from_pacs008(&self.pacs008(), CBPRCancellationReasonCode::CodeDUPL);
+        doc.assgnmt.id = self.id("CXL");
+        doc.assgnmt.cre_dt_tm = self.cre_dt_tm();
+        doc.undrlyg.tx_inf.case.id = self.short_id();
+        doc
+    }
+
+    fn camt057(&self) -> camt_057_001_06::NotificationToReceiveV06 {
+        let mut doc = camt_057_001_06::NotificationToReceiveV06::default();
+        doc.grp_hdr.msg_id = self.id("NTR");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.ntfctn.id = self.id("NTR");
+        doc.ntfctn.xpctd_val_dt = Some(self.date());
+        let mut item = camt_057_001_06::NotificationItem71 {
+            id: self.short_id(),
+            uetr: Some(self.uetr()),
+            ..Default::default()
+        };
+        item.amt.ccy = CURRENCY.into();
+        item.amt.value = self.amount();
+        doc.ntfctn.itm = vec![item];
+        doc
+    }
+
+    fn camt058(&self) -> camt_058_001_08::NotificationToReceiveCancellationAdviceV08 {
+        let mut doc = camt_058_001_08::NotificationToReceiveCancellationAdviceV08::default();
+        doc.grp_hdr.msg_id = self.short_id();
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.orgnl_ntfctn.orgnl_msg_id = self.id("NTR");
+        doc.orgnl_ntfctn.orgnl_ntfctn_id = self.id("NTR");
+        let mut item = camt_058_001_08::OriginalItem71 {
+            orgnl_itm_id: self.short_id(),
+            uetr: Some(self.uetr()),
+            ..Default::default()
+        };
+        item.amt.ccy = CURRENCY.into();
+        item.amt.value = self.amount();
+        doc.orgnl_ntfctn.orgnl_ntfctn_ref.orgnl_itm = vec![item];
+        doc
+    }
+
+    fn camt060(&self) -> camt_060_001_05::AccountReportingRequestV05 {
+        let mut doc = camt_060_001_05::AccountReportingRequestV05::default();
+        doc.grp_hdr.msg_id = self.id("REQ");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.rptg_req = vec![camt_060_001_05::ReportingRequest51 {
+            reqd_msg_nm_id: "camt.053.001.08".to_string(),
+            ..Default::default()
+        }];
+        doc
+    }
+
+    fn camt105(&self) -> camt_105_001_02::ChargesPaymentNotificationV02 {
+        let mut doc = camt_105_001_02::ChargesPaymentNotificationV02::default();
+        doc.grp_hdr.msg_id = self.id("CHRGS");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.grp_hdr.chrgs_rqstr.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        doc.grp_hdr.chrgs_acct.id.iban = Some(DEBTOR_IBAN.into());
+        doc
+    }
+
+    fn camt106(&self) -> camt_106_001_02::ChargesPaymentRequestV02 {
+        let mut doc = camt_106_001_02::ChargesPaymentRequestV02::default();
+        doc.grp_hdr.msg_id = self.id("CHRGS");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.grp_hdr.chrgs_rqstr.fin_instn_id.bicfi = Some(RECEIVER_BIC.into());
+        doc
+    }
+
+    fn camt107(&self) -> camt_107_001_01::ChequePresentmentNotificationV01 {
+        let mut doc = camt_107_001_01::ChequePresentmentNotificationV01::default();
+        doc.grp_hdr.msg_id = self.short_id();
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        let chq = &mut doc.chq;
+        chq.instr_id = self.id("INSTR");
+        chq.chq_nb = self.short_id();
+        chq.isse_dt = self.date();
+        chq.amt.ccy = CURRENCY.into();
+        chq.amt.value = self.amount();
+        chq.pyer.nm = DEBTOR_NAME.to_string();
+        chq.pyee.nm = CREDITOR_NAME.to_string();
+        doc
+    }
+
+    fn camt108(&self) -> camt_108_001_01::ChequeCancellationOrStopRequestV01 {
+        let mut doc = camt_108_001_01::ChequeCancellationOrStopRequestV01::default();
+        doc.grp_hdr.msg_id = self.short_id();
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        let chq = &mut doc.chq;
+        chq.orgnl_instr_id = self.id("INSTR");
+        chq.chq_nb = self.short_id();
+        chq.isse_dt = self.date();
+        chq.amt.ccy = CURRENCY.into();
+        chq.amt.value = self.amount();
+        doc
+    }
+
+    fn camt109(&self) -> camt_109_001_01::ChequeCancellationOrStopReportV01 {
+        let mut doc = camt_109_001_01::ChequeCancellationOrStopReportV01::default();
+        doc.grp_hdr.msg_id = self.short_id();
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.grp_hdr.nb_of_chqs = "1".to_string();
+        let chq = &mut doc.chq;
+        chq.orgnl_instr_id = self.id("INSTR");
+        chq.chq_nb = self.short_id();
+        chq.isse_dt = self.date();
+        chq.amt.ccy = CURRENCY.into();
+        chq.amt.value = self.amount();
+        doc
+    }
+
+    fn admi024(&self) -> admi_024_001_01::NotificationOfCorrespondenceV01 {
+        let mut doc = admi_024_001_01::NotificationOfCorrespondenceV01::default();
+        doc.grp_hdr.msg_id = self.id("CRSPDC");
+        doc.grp_hdr.cre_dt_tm = self.cre_dt_tm();
+        doc.ntfctn_data.sndr_ntfctn_id = self.id("CRSPDC");
+        doc.ntfctn_data.ntfctn_nrrtv = vec!["Sample correspondence".to_string()];
+        doc
+    }
+}
+
+/// SplitMix64 step, spreading the seed over all 64 bits
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_result::{ErrorCollector, ParserConfig, ValidationProfile};
+    use crate::validation::Validate;
+
+    #[test]
+    fn test_every_sample_parses_and_validates() {
+        let samples = generate_all_samples(42);
+        assert_eq!(samples.len(), MESSAGE_REGISTRY.len());
+
+        for (info, xml) in &samples {
+            let message = MxMessage::from_xml(xml)
+                .unwrap_or_else(|e| panic!("{} does not parse: {e}", info.full_form));
+            assert_eq!(message.app_hdr.msg_def_idr, info.full_form);
+            assert_eq!(message.document.message_type(), info.short_form);
+
+            let mut collector = ErrorCollector::new();
+            message
+                .app_hdr
+                .validate("AppHdr", &ParserConfig::default(), &mut collector);
+            let errors = collector.errors();
+            assert!(errors.is_empty(), "{}: {errors:?}", info.full_form);
+
+            for profile in [ValidationProfile::Base, ValidationProfile::CbprPlus] {
+                let errors = message.validate_with(profile).errors();
+                assert!(
+                    errors.is_empty(),
+                    "{} {profile:?}: {errors:?}",
+                    info.full_form
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_samples_are_deterministic() {
+        let xml = |seed| -> Vec<String> {
+            generate_all_samples(seed)
+                .into_iter()
+                .map(|(_, xml)| xml)
+                .collect()
+        };
+        assert_eq!(xml(7), xml(7));
+        assert_ne!(xml(7), xml(8));
+
+        let first = sample_message("pacs.008.001.08", 7).unwrap();
+        let second = sample_message("pacs.008", 8).unwrap();
+        assert_ne!(first.document.message_id(), second.document.message_id());
+        assert!(sample_message("pacs.999", 7).is_none());
+    }
+}
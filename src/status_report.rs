@@ -0,0 +1,240 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Payment Status Report
+//!
+//! Builds the pacs.002 status report that answers an inbound pacs.008 once it
+//! has been validated. A clean validation accepts the transaction (ACCP); any
+//! error rejects it (RJCT) with an ISO status reason code derived from the
+//! first error and the failing element paths as additional information. The
+//! report travels back from the original instructed agent to the original
+//! instructing agent. The CBPR+ pacs.002 carries no group status, so the
+//! status is reported on the transaction only.
+
+use crate::document::pacs_002_001_10::{
+    BranchAndFinancialInstitutionIdentification61, ClearingSystemIdentification2Choice1,
+    ClearingSystemMemberIdentification21, FIToFIPaymentStatusReportV10,
+    FinancialInstitutionIdentification181, GroupHeader911, OriginalGroupInformation291,
+    PaymentTransaction1101, StatusReason6Choice1, StatusReasonInformation121,
+};
+use crate::document::pacs_008_001_08::{self, FIToFICustomerCreditTransferV08};
+use crate::error::ValidationError;
+use crate::ids::generate_msg_id;
+use chrono::{SecondsFormat, Utc};
+
+/// Message definition identifier of the original message (OrgnlMsgNmId)
+const PACS_008_MSG_NM_ID: &str = "pacs.008.001.08";
+
+/// Prefix of the generated status report message identifier
+const MSG_ID_PREFIX: &str = "STS";
+
+/// Transaction status of an accepted transaction (AcceptedCustomerProfile)
+pub const STATUS_ACCEPTED: &str = "ACCP";
+
+/// Transaction status of a rejected transaction
+pub const STATUS_REJECTED: &str = "RJCT";
+
+/// Maximum number of AddtlInf lines (CBPR+ allows two)
+const MAX_ADDTL_INF: usize = 2;
+
+/// Maximum length of an AddtlInf line (Max105Text)
+const ADDTL_INF_LENGTH: usize = 105;
+
+/// Build a pacs.002 status report for a validated pacs.008
+/// `errors` are the validation errors of the original; warnings do not
+/// reject a message and are not reported.
+pub fn pacs002_from_validation(
+    original: &FIToFICustomerCreditTransferV08,
+    errors: &[ValidationError],
+) -> FIToFIPaymentStatusReportV10 {
+    let grp_hdr = &original.grp_hdr;
+    let tx = &original.cdt_trf_tx_inf;
+    let (tx_sts, sts_rsn_inf) = match errors.first() {
+        None => (STATUS_ACCEPTED, None),
+        Some(first) => (
+            STATUS_REJECTED,
+            Some(StatusReasonInformation121 {
+                orgtr: None,
+                rsn: Some(StatusReason6Choice1 {
+                    cd: Some(status_reason_code(first).to_string()),
+                    prtry: None,
+                }),
+                addtl_inf: Some(
+                    errors
+                        .iter()
+                        .take(MAX_ADDTL_INF)
+                        .map(additional_information)
+                        .collect(),
+                ),
+            }),
+        ),
+    };
+
+    FIToFIPaymentStatusReportV10 {
+        grp_hdr: GroupHeader911 {
+            msg_id: generate_msg_id(MSG_ID_PREFIX),
+            cre_dt_tm: Utc::now().to_rfc3339_opts(SecondsFormat::Secs, false),
+        },
+        tx_inf_and_sts: PaymentTransaction1101 {
+            orgnl_grp_inf: OriginalGroupInformation291 {
+                orgnl_msg_id: grp_hdr.msg_id.clone(),
+                orgnl_msg_nm_id: PACS_008_MSG_NM_ID.to_string(),
+                orgnl_cre_dt_tm: Some(grp_hdr.cre_dt_tm.clone()),
+            },
+            orgnl_instr_id: Some(tx.pmt_id.instr_id.clone()),
+            orgnl_end_to_end_id: tx.pmt_id.end_to_end_id.clone(),
+            orgnl_tx_id: tx.pmt_id.tx_id.clone(),
+            orgnl_uetr: tx.pmt_id.uetr.clone(),
+            tx_sts: tx_sts.to_string(),
+            sts_rsn_inf,
+            fctv_intr_bk_sttlm_dt: None,
+            clr_sys_ref: tx.pmt_id.clr_sys_ref.clone(),
+            instg_agt: agent(&tx.instd_agt),
+            instd_agt: agent(&tx.instg_agt),
+        },
+    }
+}
+
+/// ISO status reason code (ExternalStatusReason1Code) for a validation error
+/// Errors on agent BICs map to RC01, on accounts to AC01, on currencies to
+/// AM03 and on dates to DT01; anything else is reported as FF01.
+pub fn status_reason_code(error: &ValidationError) -> &'static str {
+    match (error.code, error.field.as_deref()) {
+        (1010, _) | (_, Some("Ccy")) => "AM03",
+        (1009, _) | (_, Some("CreDtTm" | "IntrBkSttlmDt")) => "DT01",
+        (_, Some("BICFI" | "AnyBIC")) => "RC01",
+        (_, Some("IBAN")) => "AC01",
+        _ => "FF01",
+    }
+}
+
+/// Render an error as AddtlInf: its code and path, within the Max105Text limits
+fn additional_information(error: &ValidationError) -> String {
+    let text = match &error.path {
+        Some(path) => format!("{} {}", error.code, path),
+        None => format!("{} {}", error.code, error.message),
+    };
+    text.chars()
+        .map(|c| match c {
+            '[' => '(',
+            ']' => ')',
+            c if c.is_ascii_alphanumeric() || "/-?:().,'+ ".contains(c) => c,
+            _ => ' ',
+        })
+        .take(ADDTL_INF_LENGTH)
+        .collect()
+}
+
+fn agent(
+    agent: &pacs_008_001_08::BranchAndFinancialInstitutionIdentification62,
+) -> BranchAndFinancialInstitutionIdentification61 {
+    let fin_instn_id = &agent.fin_instn_id;
+    BranchAndFinancialInstitutionIdentification61 {
+        fin_instn_id: FinancialInstitutionIdentification181 {
+            bicfi: fin_instn_id.bicfi.clone(),
+            clr_sys_mmb_id: fin_instn_id.clr_sys_mmb_id.as_ref().map(|member| {
+                ClearingSystemMemberIdentification21 {
+                    clr_sys_id: ClearingSystemIdentification2Choice1 {
+                        cd: member.clr_sys_id.cd.clone(),
+                    },
+                    mmb_id: member.mmb_id.clone(),
+                }
+            }),
+            lei: fin_instn_id.lei.clone(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_result::{ErrorCollector, ParserConfig};
+    use crate::validation::Validate;
+
+    fn original() -> FIToFICustomerCreditTransferV08 {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = "MSG-20240115-001".to_string();
+        doc.grp_hdr.cre_dt_tm = "2024-01-15T10:30:00+00:00".to_string();
+        let tx = &mut doc.cdt_trf_tx_inf;
+        tx.pmt_id.instr_id = "INSTR-001".to_string();
+        tx.pmt_id.end_to_end_id = "E2E-001".to_string();
+        tx.pmt_id.tx_id = Some("TX-001".to_string());
+        tx.pmt_id.uetr = "8a562c67-ca16-48ba-b074-65581be6f011".to_string();
+        tx.instg_agt.fin_instn_id.bicfi = "DEUTDEFFXXX".into();
+        tx.instd_agt.fin_instn_id.bicfi = "BNPAFRPPXXX".into();
+        doc
+    }
+
+    fn validated(report: &FIToFIPaymentStatusReportV10) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        report.validate("", &ParserConfig::default(), &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_accepted_original() {
+        let report = pacs002_from_validation(&original(), &[]);
+        let tx = &report.tx_inf_and_sts;
+
+        assert_eq!(tx.tx_sts, "ACCP");
+        assert!(tx.sts_rsn_inf.is_none());
+        assert_eq!(tx.orgnl_grp_inf.orgnl_msg_id, "MSG-20240115-001");
+        assert_eq!(tx.orgnl_grp_inf.orgnl_msg_nm_id, "pacs.008.001.08");
+        assert_eq!(tx.orgnl_instr_id.as_deref(), Some("INSTR-001"));
+        assert_eq!(tx.orgnl_end_to_end_id, "E2E-001");
+        assert_eq!(tx.orgnl_tx_id.as_deref(), Some("TX-001"));
+        assert_eq!(tx.orgnl_uetr, "8a562c67-ca16-48ba-b074-65581be6f011");
+        assert_eq!(tx.instg_agt.fin_instn_id.bicfi.as_str(), "BNPAFRPPXXX");
+        assert_eq!(tx.instd_agt.fin_instn_id.bicfi.as_str(), "DEUTDEFFXXX");
+        assert_eq!(validated(&report), vec![]);
+    }
+
+    #[test]
+    fn test_rejected_original() {
+        let mut doc = original();
+        doc.cdt_trf_tx_inf.instd_agt.fin_instn_id.bicfi = "BNPA12PPXXX".into();
+        let mut collector = ErrorCollector::new();
+        doc.validate("", &ParserConfig::default(), &mut collector);
+        let errors = collector.errors();
+        assert!(!errors.is_empty());
+
+        let report = pacs002_from_validation(&doc, &errors);
+        let tx = &report.tx_inf_and_sts;
+        assert_eq!(tx.tx_sts, "RJCT");
+        assert_eq!(tx.orgnl_end_to_end_id, "E2E-001");
+
+        let sts_rsn_inf = tx.sts_rsn_inf.as_ref().unwrap();
+        let rsn = sts_rsn_inf.rsn.as_ref().unwrap();
+        assert_eq!(rsn.cd.as_deref(), Some(status_reason_code(&errors[0])));
+        let addtl_inf = sts_rsn_inf.addtl_inf.as_ref().unwrap();
+        assert!(!addtl_inf.is_empty() && addtl_inf.len() <= 2);
+        assert!(addtl_inf.iter().all(|line| line.len() <= 105));
+    }
+
+    #[test]
+    fn test_status_reason_code() {
+        let error = |code, field: &str| {
+            ValidationError::new(code, String::new()).with_field(field.to_string())
+        };
+        assert_eq!(status_reason_code(&error(1005, "BICFI")), "RC01");
+        assert_eq!(status_reason_code(&error(1007, "IBAN")), "AC01");
+        assert_eq!(status_reason_code(&error(1010, "Ccy")), "AM03");
+        assert_eq!(status_reason_code(&error(1003, "EndToEndId")), "FF01");
+    }
+}
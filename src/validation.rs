@@ -25,13 +25,30 @@ pub trait Validate {
     fn validate(&self, path: &str, config: &ParserConfig, collector: &mut ErrorCollector);
 }
 
+/// Trait for message-level business rules that go beyond the schema
+/// Rules may be gated on the scheme profile selected in `ParserConfig`.
+pub trait BusinessRules {
+    /// Check the business rules and collect errors with path information
+    fn validate_business_rules(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    );
+}
+
 /// Helper functions for validation
 pub mod helpers {
+    pub use crate::core_validation::is_disallowed_control;
+    use crate::core_validation::{
+        LengthViolation, check_length, find_disallowed_control, find_non_swift_x_character,
+        matches_pattern,
+    };
     use crate::error::ValidationError;
     use crate::parse_result::{ErrorCollector, ParserConfig};
-    use regex::Regex;
 
     /// Validate string length
+    /// Every text type carries a length facet, so this also rejects control characters
     pub fn validate_length(
         value: &str,
         field_name: &str,
@@ -43,34 +60,27 @@ pub mod helpers {
     ) -> bool {
         let mut valid = true;
 
-        if let Some(min_len) = min
-            && value.chars().count() < min_len
-        {
-            let error = ValidationError::new(
-                1001,
-                format!("{field_name} is shorter than the minimum length of {min_len}"),
-            )
-            .with_field(field_name.to_string())
-            .with_path(path.to_string());
-
+        if !validate_characters(value, field_name, path, config, collector) {
             if config.fail_fast {
-                collector.add_critical_error(error);
                 return false;
-            } else {
-                collector.add_error(error);
-                valid = false;
             }
+            valid = false;
         }
 
-        if let Some(max_len) = max
-            && value.chars().count() > max_len
-        {
-            let error = ValidationError::new(
-                1002,
-                format!("{field_name} exceeds the maximum length of {max_len}"),
-            )
-            .with_field(field_name.to_string())
-            .with_path(path.to_string());
+        if let Some(violation) = check_length(value, min, max) {
+            let (code, message) = match violation {
+                LengthViolation::TooShort(min_len) => (
+                    1001,
+                    format!("{field_name} is shorter than the minimum length of {min_len}"),
+                ),
+                LengthViolation::TooLong(max_len) => (
+                    1002,
+                    format!("{field_name} exceeds the maximum length of {max_len}"),
+                ),
+            };
+            let error = ValidationError::new(code, message)
+                .with_field(field_name.to_string())
+                .with_path(path.to_string());
 
             if config.fail_fast {
                 collector.add_critical_error(error);
@@ -84,6 +94,43 @@ pub mod helpers {
         valid
     }
 
+    /// Validate that a text value contains no disallowed control characters
+    /// Under profiles restricted to the SWIFT x character set, any other character is rejected too.
+    pub fn validate_characters(
+        value: &str,
+        field_name: &str,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) -> bool {
+        let error = if let Some(c) = find_disallowed_control(value) {
+            ValidationError::new(
+                1008,
+                format!(
+                    "{field_name} contains a disallowed control character (U+{:04X})",
+                    c as u32
+                ),
+            )
+        } else if let Some(c) = find_non_swift_x_character(value)
+            .filter(|_| config.profile.restricts_to_x_character_set())
+        {
+            ValidationError::new(
+                1011,
+                format!("{field_name} contains '{c}', which is outside the SWIFT x character set"),
+            )
+        } else {
+            return true;
+        };
+        report_error(
+            error
+                .with_field(field_name.to_string())
+                .with_path(path.to_string()),
+            config,
+            collector,
+        );
+        false
+    }
+
     /// Validate string pattern
     pub fn validate_pattern(
         value: &str,
@@ -93,11 +140,8 @@ pub mod helpers {
         config: &ParserConfig,
         collector: &mut ErrorCollector,
     ) -> bool {
-        // Trim whitespace before validation
-        let trimmed_value = value.trim();
-
-        let regex = match Regex::new(pattern) {
-            Ok(r) => r,
+        let matched = match matches_pattern(value, pattern) {
+            Ok(matched) => matched,
             Err(_) => {
                 collector.add_critical_error(
                     ValidationError::new(
@@ -111,7 +155,7 @@ pub mod helpers {
             }
         };
 
-        if !regex.is_match(trimmed_value) {
+        if !matched {
             let error = ValidationError::new(
                 1005,
                 format!("{field_name} does not match the required pattern (value: '{value}')"),
@@ -150,6 +194,19 @@ pub mod helpers {
         true
     }
 
+    /// Report a business rule violation, honouring fail_fast
+    pub fn report_error(
+        error: ValidationError,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        if config.fail_fast {
+            collector.add_critical_error(error);
+        } else {
+            collector.add_error(error);
+        }
+    }
+
     /// Create a child path for nested validation
     pub fn child_path(parent: &str, field: &str) -> String {
         if parent.is_empty() {
@@ -158,4 +215,185 @@ pub mod helpers {
             format!("{parent}.{field}")
         }
     }
+
+    /// Get the element name at the end of a path (e.g. "BICFI" for "DbtrAgt.FinInstnId.BICFI")
+    pub fn leaf_name(path: &str) -> &str {
+        let last = path.rsplit('.').next().unwrap_or(path);
+        match last.find('[') {
+            Some(idx) => &last[..idx],
+            None => last,
+        }
+    }
+
+    /// Create a child path for an element of a repeating group
+    pub fn index_path(parent: &str, idx: usize) -> String {
+        format!("{parent}[{idx}]")
+    }
+
+    /// Guard of the span covering one `validate` call; the span closes when it is dropped
+    #[must_use]
+    pub struct ValidationSpan {
+        #[cfg(feature = "trace-validation")]
+        _entered: tracing::span::EnteredSpan,
+    }
+
+    /// Enter a `validate` trace span for `T` at `path`
+    /// Spans are only emitted with the `trace-validation` feature; otherwise this does nothing.
+    #[inline(always)]
+    pub fn validation_span<T: ?Sized>(path: &str) -> ValidationSpan {
+        #[cfg(feature = "trace-validation")]
+        {
+            let span =
+                tracing::trace_span!("validate", r#type = std::any::type_name::<T>(), path = path);
+            ValidationSpan {
+                _entered: span.entered(),
+            }
+        }
+        #[cfg(not(feature = "trace-validation"))]
+        {
+            let _ = path;
+            ValidationSpan {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+    use crate::parse_result::ValidationProfile;
+
+    fn errors_for_debtor_name(name: &str) -> Vec<crate::error::ValidationError> {
+        errors_for_debtor_name_under(name, ValidationProfile::Base)
+    }
+
+    fn errors_for_debtor_name_under(
+        name: &str,
+        profile: ValidationProfile,
+    ) -> Vec<crate::error::ValidationError> {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.dbtr.nm = Some(name.to_string());
+        let mut collector = ErrorCollector::new();
+        doc.cdt_trf_tx_inf.dbtr.validate(
+            "CdtTrfTxInf.Dbtr",
+            &ParserConfig::default().with_profile(profile),
+            &mut collector,
+        );
+        collector.errors()
+    }
+
+    #[test]
+    fn test_rejects_nul_character() {
+        let errors = errors_for_debtor_name("ACME\0Corp");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 1008);
+        assert_eq!(errors[0].path.as_deref(), Some("CdtTrfTxInf.Dbtr.Nm"));
+        assert!(errors[0].message.contains("U+0000"));
+    }
+
+    #[test]
+    fn test_allows_tab_character() {
+        assert!(errors_for_debtor_name("ACME\tCorp").is_empty());
+        assert!(helpers::is_disallowed_control('\u{1B}'));
+        assert!(!helpers::is_disallowed_control('\t'));
+    }
+
+    #[test]
+    fn test_fin_plus_rejects_characters_outside_x_set() {
+        let name = "ACME Corp @ London";
+        assert!(errors_for_debtor_name_under(name, ValidationProfile::Base).is_empty());
+        assert!(errors_for_debtor_name_under(name, ValidationProfile::CbprPlus).is_empty());
+
+        let errors = errors_for_debtor_name_under(name, ValidationProfile::FinPlus);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code, 1011);
+        assert_eq!(errors[0].path.as_deref(), Some("CdtTrfTxInf.Dbtr.Nm"));
+        assert!(errors[0].message.contains("'@'"));
+
+        assert!(
+            errors_for_debtor_name_under("ACME (UK) LTD", ValidationProfile::FinPlus).is_empty()
+        );
+    }
+
+    #[cfg(feature = "trace-validation")]
+    mod spans {
+        use std::sync::atomic::{AtomicU64, Ordering};
+        use std::sync::{Arc, Mutex};
+        use tracing::field::{Field, Visit};
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        /// Records the type and path of every span opened
+        #[derive(Default)]
+        pub struct SpanRecorder {
+            pub spans: Arc<Mutex<Vec<(String, String)>>>,
+            next_id: AtomicU64,
+        }
+
+        #[derive(Default)]
+        struct Fields {
+            ty: String,
+            path: String,
+        }
+
+        impl Visit for Fields {
+            fn record_str(&mut self, field: &Field, value: &str) {
+                match field.name() {
+                    "type" => self.ty = value.to_string(),
+                    "path" => self.path = value.to_string(),
+                    _ => {}
+                }
+            }
+
+            fn record_debug(&mut self, _field: &Field, _value: &dyn std::fmt::Debug) {}
+        }
+
+        impl Subscriber for SpanRecorder {
+            fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+                true
+            }
+
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut fields = Fields::default();
+                span.record(&mut fields);
+                self.spans.lock().unwrap().push((fields.ty, fields.path));
+                Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+            }
+
+            fn record(&self, _span: &Id, _values: &Record<'_>) {}
+            fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+            fn event(&self, _event: &Event<'_>) {}
+            fn enter(&self, _span: &Id) {}
+            fn exit(&self, _span: &Id) {}
+        }
+    }
+
+    #[cfg(feature = "trace-validation")]
+    #[test]
+    fn test_validation_spans() {
+        let recorder = spans::SpanRecorder::default();
+        let spans = recorder.spans.clone();
+
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.cdt_trf_tx_inf.instg_agt.fin_instn_id.bicfi = "NOT-A-BIC".into();
+        let mut collector = ErrorCollector::new();
+        tracing::subscriber::with_default(recorder, || {
+            doc.validate("", &ParserConfig::default(), &mut collector);
+        });
+        let failing = "CdtTrfTxInf.InstgAgt.FinInstnId.BICFI";
+        assert!(
+            collector
+                .errors()
+                .iter()
+                .any(|err| err.path.as_deref() == Some(failing))
+        );
+
+        let spans = spans.lock().unwrap();
+        let span_at = |path: &str| spans.iter().find(|(_, p)| p == path).map(|(ty, _)| ty);
+        assert!(
+            span_at("CdtTrfTxInf").is_some_and(|ty| ty.ends_with("CreditTransferTransaction391"))
+        );
+        assert!(span_at("CdtTrfTxInf.InstgAgt.FinInstnId").is_some());
+        assert!(span_at(failing).is_some_and(|ty| ty.ends_with("identifiers::Bic")));
+    }
 }
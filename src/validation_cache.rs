@@ -0,0 +1,305 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Validation Cache
+//!
+//! Opt-in memoization of the schema rules for generators that validate the
+//! same template over and over with only a few fields changed. Results are
+//! keyed by a SHA-256 over the sub-tree's type, path, parser configuration
+//! and serialized content, so an unchanged sub-tree replays its earlier
+//! errors and warnings instead of being walked again.
+//! `Document::validate_cached` caches each top-level element of the document
+//! (e.g. `GrpHdr` and `CdtTrfTxInf` of a pacs.008) separately; business rules
+//! compare values across sub-trees and always run.
+
+use crate::error::ValidationError;
+use crate::mx_envelope::Document;
+use crate::parse_result::{ErrorCollector, ParserConfig};
+use crate::validation::helpers::child_path;
+use crate::validation::{BusinessRules, Validate};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Outcome of validating one sub-tree
+#[derive(Debug, Clone)]
+struct CachedResult {
+    errors: Vec<ValidationError>,
+    warnings: Vec<ValidationError>,
+    critical: bool,
+}
+
+/// Validation results of previously seen sub-trees
+#[derive(Debug, Default)]
+pub struct ValidationCache {
+    entries: HashMap<[u8; 32], CachedResult>,
+    hits: usize,
+    misses: usize,
+}
+
+impl ValidationCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Validate a value, replaying the cached result if the same content was validated before
+    pub fn validate<T: Validate + Serialize>(
+        &mut self,
+        value: &T,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+    ) {
+        let Some(key) = cache_key(value, path, config) else {
+            value.validate(path, config, collector);
+            return;
+        };
+        let result = match self.entries.get(&key) {
+            Some(result) => {
+                self.hits += 1;
+                result
+            }
+            None => {
+                self.misses += 1;
+                let mut fresh = ErrorCollector::new();
+                value.validate(path, config, &mut fresh);
+                let warnings = fresh.warnings().to_vec();
+                let critical = fresh.has_critical_errors();
+                self.entries.entry(key).or_insert(CachedResult {
+                    errors: fresh.errors(),
+                    warnings,
+                    critical,
+                })
+            }
+        };
+        for (idx, error) in result.errors.iter().enumerate() {
+            if result.critical && idx == 0 {
+                collector.add_critical_error(error.clone());
+            } else {
+                collector.add_error(error.clone());
+            }
+        }
+        for warning in &result.warnings {
+            collector.add_warning(warning.clone());
+        }
+    }
+
+    /// Number of sub-trees whose result was replayed from the cache
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Number of sub-trees that had to be validated
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+
+    /// Number of cached results
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop all cached results and reset the counters
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+fn cache_key<T: Serialize>(value: &T, path: &str, config: &ParserConfig) -> Option<[u8; 32]> {
+    let mut hasher = Sha256::new();
+    hasher.update(std::any::type_name::<T>().as_bytes());
+    hasher.update([0]);
+    hasher.update(path.as_bytes());
+    hasher.update([0]);
+    hasher.update(serde_json::to_vec(config).ok()?);
+    hasher.update([0]);
+    hasher.update(serde_json::to_vec(value).ok()?);
+    Some(hasher.finalize().into())
+}
+
+/// A top-level element of a document: a single sub-tree or a repeating one
+trait SubTree {
+    fn validate_cached(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+        cache: &mut ValidationCache,
+    );
+}
+
+impl<T: Validate + Serialize> SubTree for T {
+    fn validate_cached(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+        cache: &mut ValidationCache,
+    ) {
+        cache.validate(self, path, config, collector);
+    }
+}
+
+impl<T: Validate + Serialize> SubTree for Vec<T> {
+    fn validate_cached(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+        cache: &mut ValidationCache,
+    ) {
+        // Repeating elements share the element path, as in the generated `Validate`
+        for item in self {
+            cache.validate(item, path, config, collector);
+        }
+    }
+}
+
+/// List the top-level elements of each message type
+macro_rules! sub_trees {
+    ($($variant:ident { $($field:ident => $name:literal),+ $(,)? }),* $(,)?) => {
+        /// Run the schema rules of every top-level element through the cache
+        fn validate_sub_trees(
+            document: &Document,
+            path: &str,
+            config: &ParserConfig,
+            collector: &mut ErrorCollector,
+            cache: &mut ValidationCache,
+        ) {
+            match document {
+                $(
+                    Document::$variant(doc) => {
+                        $(
+                            doc.$field
+                                .validate_cached(&child_path(path, $name), config, collector, cache);
+                        )+
+                    }
+                )*
+            }
+        }
+    };
+}
+
+sub_trees!(
+    Pacs008 { grp_hdr => "GrpHdr", cdt_trf_tx_inf => "CdtTrfTxInf" },
+    Pacs002 { grp_hdr => "GrpHdr", tx_inf_and_sts => "TxInfAndSts" },
+    Pacs003 { grp_hdr => "GrpHdr", drct_dbt_tx_inf => "DrctDbtTxInf" },
+    Pacs004 { grp_hdr => "GrpHdr", tx_inf => "TxInf" },
+    Pacs009 { grp_hdr => "GrpHdr", cdt_trf_tx_inf => "CdtTrfTxInf" },
+    Pacs010 { grp_hdr => "GrpHdr", cdt_instr => "CdtInstr" },
+    Pain001 { grp_hdr => "GrpHdr", pmt_inf => "PmtInf" },
+    Pain002 {
+        grp_hdr => "GrpHdr",
+        orgnl_grp_inf_and_sts => "OrgnlGrpInfAndSts",
+        orgnl_pmt_inf_and_sts => "OrgnlPmtInfAndSts",
+    },
+    Pain008 { grp_hdr => "GrpHdr", pmt_inf => "PmtInf" },
+    Camt025 { msg_hdr => "MsgHdr", rct_dtls => "RctDtls" },
+    Camt029 { assgnmt => "Assgnmt", sts => "Sts", cxl_dtls => "CxlDtls" },
+    Camt052 { grp_hdr => "GrpHdr", rpt => "Rpt" },
+    Camt053 { grp_hdr => "GrpHdr", stmt => "Stmt" },
+    Camt054 { grp_hdr => "GrpHdr", ntfctn => "Ntfctn" },
+    Camt055 { assgnmt => "Assgnmt", undrlyg => "Undrlyg" },
+    Camt056 { assgnmt => "Assgnmt", undrlyg => "Undrlyg" },
+    Camt057 { grp_hdr => "GrpHdr", ntfctn => "Ntfctn" },
+    Camt058 { grp_hdr => "GrpHdr", orgnl_ntfctn => "OrgnlNtfctn", cxl_rsn => "CxlRsn" },
+    Camt060 { grp_hdr => "GrpHdr", rptg_req => "RptgReq" },
+    Camt105 { grp_hdr => "GrpHdr", chrgs => "Chrgs" },
+    Camt106 { grp_hdr => "GrpHdr", chrgs => "Chrgs" },
+    Camt107 { grp_hdr => "GrpHdr", chq => "Chq" },
+    Camt108 { grp_hdr => "GrpHdr", chq => "Chq" },
+    Camt109 { grp_hdr => "GrpHdr", chq => "Chq" },
+    Admi024 { grp_hdr => "GrpHdr", ntfctn_data => "NtfctnData" },
+);
+
+impl Document {
+    /// Validate like `Validate::validate`, reusing cached results of unchanged top-level elements
+    pub fn validate_cached(
+        &self,
+        path: &str,
+        config: &ParserConfig,
+        collector: &mut ErrorCollector,
+        cache: &mut ValidationCache,
+    ) {
+        validate_sub_trees(self, path, config, collector, cache);
+        self.validate_business_rules(path, config, collector);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::document::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+
+    fn template(msg_id: &str) -> Document {
+        let mut doc = FIToFICustomerCreditTransferV08::default();
+        doc.grp_hdr.msg_id = msg_id.to_string();
+        doc.cdt_trf_tx_inf.pmt_id.end_to_end_id = "E2E-001".to_string();
+        doc.cdt_trf_tx_inf.dbtr.nm = Some("ACME Corp".to_string());
+        Document::Pacs008(Box::new(doc))
+    }
+
+    fn uncached(document: &Document, config: &ParserConfig) -> Vec<ValidationError> {
+        let mut collector = ErrorCollector::new();
+        document.validate("", config, &mut collector);
+        collector.errors()
+    }
+
+    #[test]
+    fn test_unchanged_sub_trees_are_not_revalidated() {
+        let config = ParserConfig::default();
+        let mut cache = ValidationCache::new();
+
+        for (iteration, msg_id) in ["MSG-001", "MSG-002", "MSG-003"].iter().enumerate() {
+            let document = template(msg_id);
+            let mut collector = ErrorCollector::new();
+            document.validate_cached("", &config, &mut collector, &mut cache);
+            assert_eq!(collector.errors(), uncached(&document, &config));
+            // Only GrpHdr changes between iterations; CdtTrfTxInf is validated once
+            assert_eq!(cache.misses(), 2 + iteration);
+            assert_eq!(cache.hits(), iteration);
+        }
+        assert_eq!(cache.len(), 4);
+
+        cache.clear();
+        assert!(cache.is_empty());
+        assert_eq!(cache.hits(), 0);
+    }
+
+    #[test]
+    fn test_configuration_is_part_of_the_key() {
+        let document = template("MSG-001");
+        let mut cache = ValidationCache::new();
+        let lenient = ParserConfig::lenient();
+        let strict = ParserConfig::default();
+
+        let mut collector = ErrorCollector::new();
+        document.validate_cached("", &lenient, &mut collector, &mut cache);
+        assert_eq!(collector.errors(), uncached(&document, &lenient));
+
+        let mut collector = ErrorCollector::new();
+        document.validate_cached("", &strict, &mut collector, &mut cache);
+        assert_eq!(collector.errors(), uncached(&document, &strict));
+        assert_eq!(cache.hits(), 0);
+    }
+}
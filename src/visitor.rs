@@ -0,0 +1,94 @@
+// Plasmatic MX Message Parsing Library
+// https://github.com/GoPlasmatic/MXMessage
+//
+// Copyright (c) 2025 Plasmatic
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// You may obtain a copy of this library at
+// https://github.com/GoPlasmatic/MXMessage
+
+//! Document Visitor
+//!
+//! Generic traversal over the serialized form of MX documents.
+//! The generated message types share no common field-level trait, so helpers
+//! that need to look at "every agent" or "every amount" walk the serde JSON
+//! representation instead. Paths use the XML element names joined with `.`
+//! and `[i]` for repeating elements, e.g. `CdtTrfTxInf.IntrmyAgt1.FinInstnId.BICFI`.
+
+use crate::validation::helpers::{child_path, index_path};
+use serde_json::Value;
+
+/// Visit every node of a JSON tree in pre-order, passing its path and value
+pub fn visit<F>(value: &Value, path: &str, f: &mut F)
+where
+    F: FnMut(&str, &Value),
+{
+    f(path, value);
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                visit(child, &child_path(path, key), f);
+            }
+        }
+        Value::Array(items) => {
+            for (idx, child) in items.iter().enumerate() {
+                visit(child, &index_path(path, idx), f);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Visit every node of a JSON tree in pre-order with mutable access
+pub fn visit_mut<F>(value: &mut Value, path: &str, f: &mut F)
+where
+    F: FnMut(&str, &mut Value),
+{
+    f(path, value);
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map.iter_mut() {
+                visit_mut(child, &child_path(path, key), f);
+            }
+        }
+        Value::Array(items) => {
+            for (idx, child) in items.iter_mut().enumerate() {
+                visit_mut(child, &index_path(path, idx), f);
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::helpers::leaf_name;
+    use serde_json::json;
+
+    #[test]
+    fn test_visit_paths() {
+        let value = json!({
+            "GrpHdr": {"MsgId": "MSG1"},
+            "Ustrd": ["a", "b"]
+        });
+
+        let mut paths = Vec::new();
+        visit(&value, "", &mut |path, _| paths.push(path.to_string()));
+
+        assert!(paths.contains(&"GrpHdr.MsgId".to_string()));
+        assert!(paths.contains(&"Ustrd[1]".to_string()));
+        assert_eq!(leaf_name("Ustrd[1]"), "Ustrd");
+        assert_eq!(leaf_name("DbtrAgt.FinInstnId.BICFI"), "BICFI");
+    }
+}
@@ -4,7 +4,12 @@
 
 use crate::error::MxError;
 use crate::message_registry;
+use crate::message_type::MxDocumentDefinition;
+use crate::visitor;
 use quick_xml::de::from_str as xml_from_str;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::name::ResolveResult;
+use quick_xml::{NsReader, Reader, Writer};
 
 // Re-export MxError as XmlError for backward compatibility
 pub type XmlError = MxError;
@@ -38,6 +43,78 @@ pub fn from_mx_xml_envelope_str(xml: &str, message_type: &str) -> Result<(), Xml
     Ok(())
 }
 
+/// Deserialize the message of an MX XML envelope or bare Document into a typed document
+///
+/// The namespace the `Document` element is bound to, through a default `xmlns`
+/// or a prefix, must equal `T::NAMESPACE`, so that a payload of another version
+/// (e.g. camt.053.001.09 read as camt.053.001.08) is rejected instead of
+/// partially parsed. A `Document` bound to no namespace is not checked.
+pub fn from_mx_xml<T>(xml: &str) -> Result<T, XmlError>
+where
+    T: MxDocumentDefinition + serde::de::DeserializeOwned,
+{
+    let parse_error =
+        |e: quick_xml::Error| MxError::XmlDeserialization(format!("XML parsing error: {}", e));
+    let mut reader = NsReader::from_str(xml);
+    let (namespace, content_start) = loop {
+        match reader.read_resolved_event().map_err(parse_error)? {
+            (resolved, Event::Start(element)) if element.local_name().as_ref() == b"Document" => {
+                let namespace = match resolved {
+                    ResolveResult::Bound(namespace) => {
+                        Some(String::from_utf8_lossy(namespace.as_ref()).into_owned())
+                    }
+                    ResolveResult::Unbound => None,
+                    ResolveResult::Unknown(prefix) => {
+                        return Err(MxError::XmlDeserialization(format!(
+                            "Document uses the undeclared namespace prefix '{}'",
+                            String::from_utf8_lossy(&prefix)
+                        )));
+                    }
+                };
+                break (namespace, reader.buffer_position() as usize);
+            }
+            (_, Event::Eof) => {
+                return Err(MxError::XmlDeserialization(
+                    "Document not found in XML".to_string(),
+                ));
+            }
+            _ => {}
+        }
+    };
+    if let Some(found) = namespace
+        && found != T::NAMESPACE
+    {
+        return Err(MxError::VersionMismatch {
+            expected: T::NAMESPACE.to_string(),
+            found,
+        });
+    }
+
+    // The content ends where the matching end tag of the Document starts
+    let mut depth = 0usize;
+    let content_end = loop {
+        let before = reader.buffer_position() as usize;
+        match reader.read_event().map_err(parse_error)? {
+            Event::Start(_) => depth += 1,
+            Event::End(_) if depth == 0 => break before,
+            Event::End(_) => depth -= 1,
+            Event::Eof => {
+                return Err(MxError::XmlDeserialization(
+                    "Document is not closed".to_string(),
+                ));
+            }
+            _ => {}
+        }
+    };
+    xml_from_str::<T>(&xml[content_start..content_end]).map_err(|e| {
+        MxError::XmlDeserialization(format!(
+            "Failed to parse {}: {}",
+            T::MESSAGE_DEFINITION_IDENTIFIER,
+            e
+        ))
+    })
+}
+
 /// Helper function to get the Document element name for a message type
 /// Used internally by xml_to_json_via_document
 fn get_document_element_name(message_type: &str) -> &'static str {
@@ -199,6 +276,127 @@ pub fn xml_to_json_via_document(
     }))
 }
 
+/// Key used to order the elements of a repeating group
+/// Receives the path of the group (e.g. `Stmt.Ntry`) and one of its elements.
+/// A group keeps its original order if the key returns `None` for any element.
+pub type SortKeyFn = fn(&str, &serde_json::Value) -> Option<String>;
+
+/// Namespace of the ISO 20022 business message envelope (`BizMsgEnvlp`)
+pub const BUSINESS_MESSAGE_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:nvlp.001.001.01";
+
+/// Namespace of the business application header (`AppHdr`)
+pub const HEADER_NAMESPACE: &str = "urn:iso:std:iso:20022:tech:xsd:head.001.001.02";
+
+/// Outer element wrapping the header and document
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EnvelopeForm {
+    /// `<Envelope><AppHdr/><Document/></Envelope>`
+    #[default]
+    Envelope,
+    /// ISO 20022 business message envelope (nvlp.001), used by some CSDs and
+    /// market infrastructures:
+    /// `<BizMsgEnvlp><Hdr><AppHdr/></Hdr><Doc><Document/></Doc></BizMsgEnvlp>`
+    /// The envelope declares its own default namespace, so `AppHdr` and
+    /// `Document` redeclare the header and document namespaces.
+    BusinessMessage,
+}
+
+/// Line break used between elements of indented XML
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\n`
+    #[default]
+    Lf,
+    /// `\r\n`
+    CrLf,
+}
+
+impl LineEnding {
+    /// The line break characters
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+        }
+    }
+}
+
+/// Options for XML serialization
+#[derive(Debug, Clone)]
+pub struct XmlConfig {
+    /// Order the elements of every repeating group by a caller-provided key
+    /// before serialization, so that equivalent messages produce identical XML.
+    ///
+    /// This changes the wire order of the message. Only use it where the order
+    /// of repeating elements is not significant, such as diffing statements;
+    /// never where the receiver relies on the sequence of transactions.
+    pub sort_repeating_by: Option<SortKeyFn>,
+    /// Start the output with the `<?xml version="1.0" encoding="UTF-8"?>` declaration
+    pub include_xml_declaration: bool,
+    /// Add `standalone="yes"` to the XML declaration
+    /// Has no effect when the declaration is omitted.
+    pub standalone: bool,
+    /// Outer element wrapping the header and document
+    pub envelope: EnvelopeForm,
+    /// Elements to write as `xsi:nil="true"` when present without content
+    ///
+    /// Paths are rooted at the message element without indices, e.g.
+    /// `CdtTrfTxInf.Purp`. The model has no separate nil state: set the field
+    /// to an empty value (e.g. `Some(Default::default())`) to mark it nil.
+    /// Parsing a nil element gives that empty value back.
+    pub nil_elements: Vec<String>,
+    /// Indent nested elements by this many spaces, one element per line
+    /// `None` writes compact XML on a single line, as sent on the wire.
+    pub indent: Option<usize>,
+    /// Line break written between elements when indenting
+    /// Has no effect on compact output.
+    pub line_ending: LineEnding,
+}
+
+impl Default for XmlConfig {
+    fn default() -> Self {
+        XmlConfig {
+            sort_repeating_by: None,
+            include_xml_declaration: true,
+            standalone: false,
+            envelope: EnvelopeForm::Envelope,
+            nil_elements: Vec::new(),
+            indent: None,
+            line_ending: LineEnding::Lf,
+        }
+    }
+}
+
+impl XmlConfig {
+    /// XML declaration to start the output with, if any
+    pub fn xml_declaration(&self) -> Option<&'static str> {
+        match (self.include_xml_declaration, self.standalone) {
+            (false, _) => None,
+            (true, false) => Some(r#"<?xml version="1.0" encoding="UTF-8"?>"#),
+            (true, true) => Some(r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?>"#),
+        }
+    }
+}
+
+/// Stable-sort every repeating group of a JSON tree by the given key
+pub fn sort_repeating_groups(value: &mut serde_json::Value, key: SortKeyFn) {
+    visitor::visit_mut(value, "", &mut |path, node| {
+        let serde_json::Value::Array(items) = node else {
+            return;
+        };
+        let Some(keys) = items
+            .iter()
+            .map(|item| key(path, item))
+            .collect::<Option<Vec<_>>>()
+        else {
+            return;
+        };
+        let mut keyed: Vec<_> = keys.into_iter().zip(items.drain(..)).collect();
+        keyed.sort_by(|a, b| a.0.cmp(&b.0));
+        items.extend(keyed.into_iter().map(|(_, item)| item));
+    });
+}
+
 /// Helper function to parse XML to JSON Value (for dataflow plugins)
 /// This function parses XML and converts it to a JSON structure
 ///
@@ -215,6 +413,274 @@ pub fn from_mx_xml_to_json(xml: &str) -> Result<serde_json::Value, XmlError> {
     Ok(value)
 }
 
+/// Apply the XSD `whiteSpace="collapse"` facet to a text value
+/// Runs of spaces, tabs, carriage returns and line feeds become a single space
+/// and leading/trailing whitespace is removed.
+pub fn collapse_whitespace(value: &str) -> String {
+    value
+        .split([' ', '\t', '\r', '\n'])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Remove every supplementary data block (`SplmtryData`) from an MX XML message
+///
+/// The generated message types do not model `SplmtryData`; a parsed
+/// `MxMessage` only keeps the message-level blocks as raw XML. This works on
+/// the raw XML instead, for forwarding a received message to a receiver that
+/// rejects unknown extensions; everything outside the removed blocks is
+/// written back unchanged.
+pub fn strip_supplementary_data(xml: &str) -> Result<String, XmlError> {
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::new());
+    let mut skipping = 0usize;
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| MxError::XmlDeserialization(format!("XML parsing error: {}", e)))?;
+        match &event {
+            Event::Eof => break,
+            Event::Start(e) if skipping > 0 || is_supplementary_data(e) => {
+                skipping += 1;
+                continue;
+            }
+            Event::End(_) if skipping > 0 => {
+                skipping -= 1;
+                continue;
+            }
+            Event::Empty(e) if is_supplementary_data(e) => continue,
+            _ if skipping > 0 => continue,
+            _ => {}
+        }
+        writer
+            .write_event(event)
+            .map_err(|e| MxError::XmlSerialization(e.to_string()))?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|e| MxError::XmlSerialization(e.to_string()))
+}
+
+/// Namespace of the `xsi:nil` attribute
+pub const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Rewrite the empty elements at the given paths as `xsi:nil="true"`
+///
+/// `document_xml` is the content of the `Document` element and `paths` are
+/// rooted at the message element, see [`XmlConfig::nil_elements`]. Each nil
+/// element declares the `xsi` namespace itself so the fragment stays valid
+/// wherever it is embedded. Elements with content are left unchanged.
+pub fn write_nil_elements(document_xml: &str, paths: &[String]) -> Result<String, XmlError> {
+    let mut reader = Reader::from_str(document_xml);
+    let mut writer = Writer::new(Vec::new());
+    // Local names of the open elements below the message element
+    let mut open: Vec<String> = Vec::new();
+    let mut depth = 0usize;
+    // A designated element whose start tag is held back until its content is known
+    let mut pending: Option<BytesStart<'static>> = None;
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| MxError::XmlDeserialization(format!("XML parsing error: {}", e)))?;
+        if let Some(start) = pending.take() {
+            if matches!(event, Event::End(_)) {
+                depth -= 1;
+                open.pop();
+                write_event(&mut writer, Event::Empty(nil_element(start)))?;
+                continue;
+            }
+            write_event(&mut writer, Event::Start(start))?;
+        }
+        match event {
+            Event::Eof => break,
+            Event::Start(element) => {
+                depth += 1;
+                if depth > 1 {
+                    open.push(String::from_utf8_lossy(element.local_name().as_ref()).into_owned());
+                }
+                if depth > 1 && paths.contains(&open.join(".")) {
+                    pending = Some(element.into_owned());
+                    continue;
+                }
+                write_event(&mut writer, Event::Start(element))?;
+            }
+            Event::End(element) => {
+                depth -= 1;
+                open.truncate(depth.saturating_sub(1));
+                write_event(&mut writer, Event::End(element))?;
+            }
+            Event::Empty(element) => {
+                open.push(String::from_utf8_lossy(element.local_name().as_ref()).into_owned());
+                let designated = depth > 0 && paths.contains(&open.join("."));
+                open.pop();
+                if designated {
+                    write_event(&mut writer, Event::Empty(nil_element(element)))?;
+                } else {
+                    write_event(&mut writer, Event::Empty(element))?;
+                }
+            }
+            other => write_event(&mut writer, other)?,
+        }
+    }
+    String::from_utf8(writer.into_inner()).map_err(|e| MxError::XmlSerialization(e.to_string()))
+}
+
+/// Re-write compact XML with one element per line, indented by `width` spaces per level
+/// Elements holding text stay on one line with their text, so values are not
+/// altered; only the breaks between elements are added.
+pub fn indent_xml(xml: &str, width: usize, line_ending: LineEnding) -> Result<String, XmlError> {
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::new());
+    let mut depth = 0usize;
+    // Whether the previous event opened an element or wrote text, so the
+    // closing tag belongs on the same line
+    let mut inline = false;
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| MxError::XmlDeserialization(format!("XML parsing error: {}", e)))?;
+        match event {
+            Event::Eof => break,
+            Event::Start(_) => {
+                break_line(&mut writer, depth, width, line_ending);
+                write_event(&mut writer, event)?;
+                depth += 1;
+                inline = true;
+            }
+            Event::End(_) => {
+                depth = depth.saturating_sub(1);
+                if !inline {
+                    break_line(&mut writer, depth, width, line_ending);
+                }
+                write_event(&mut writer, event)?;
+                inline = false;
+            }
+            Event::Text(_) | Event::CData(_) => {
+                write_event(&mut writer, event)?;
+                inline = true;
+            }
+            _ => {
+                break_line(&mut writer, depth, width, line_ending);
+                write_event(&mut writer, event)?;
+                inline = false;
+            }
+        }
+    }
+    String::from_utf8(writer.into_inner()).map_err(|e| MxError::XmlSerialization(e.to_string()))
+}
+
+/// Start a new line at the given depth, unless nothing has been written yet
+fn break_line(writer: &mut Writer<Vec<u8>>, depth: usize, width: usize, line_ending: LineEnding) {
+    let out = writer.get_mut();
+    if out.is_empty() {
+        return;
+    }
+    out.extend_from_slice(line_ending.as_str().as_bytes());
+    out.resize(out.len() + depth * width, b' ');
+}
+
+fn nil_element(element: BytesStart) -> BytesStart<'static> {
+    let mut nil = element.into_owned();
+    nil.push_attribute(("xmlns:xsi", XSI_NAMESPACE));
+    nil.push_attribute(("xsi:nil", "true"));
+    nil
+}
+
+fn write_event(writer: &mut Writer<Vec<u8>>, event: Event) -> Result<(), XmlError> {
+    writer
+        .write_event(event)
+        .map_err(|e| MxError::XmlSerialization(e.to_string()))
+}
+
+/// Message-level supplementary data blocks of a Document, as raw XML
+///
+/// `document_xml` is the content of the `Document` element. Only blocks that
+/// are direct children of the message element (e.g. `FIToFICstmrCdtTrf`) are
+/// returned, concatenated in document order; blocks nested in transactions
+/// are left out. Returns `None` when the message carries no block.
+pub fn extract_supplementary_data(document_xml: &str) -> Result<Option<String>, XmlError> {
+    let mut reader = Reader::from_str(document_xml);
+    let mut writer = Writer::new(Vec::new());
+    let mut depth = 0usize;
+    // Depth within the block being copied; 0 when outside a block
+    let mut inside = 0usize;
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| MxError::XmlDeserialization(format!("XML parsing error: {}", e)))?;
+        let copy = match &event {
+            Event::Eof => break,
+            Event::Start(e) => {
+                depth += 1;
+                if inside > 0 || (depth == 2 && is_supplementary_data(e)) {
+                    inside += 1;
+                }
+                inside > 0
+            }
+            Event::End(_) => {
+                depth = depth.saturating_sub(1);
+                let copy = inside > 0;
+                inside = inside.saturating_sub(1);
+                copy
+            }
+            Event::Empty(e) => inside > 0 || (depth == 1 && is_supplementary_data(e)),
+            _ => inside > 0,
+        };
+        if copy {
+            writer
+                .write_event(event)
+                .map_err(|e| MxError::XmlSerialization(e.to_string()))?;
+        }
+    }
+    let blocks = String::from_utf8(writer.into_inner())
+        .map_err(|e| MxError::XmlSerialization(e.to_string()))?;
+    Ok((!blocks.is_empty()).then_some(blocks))
+}
+
+/// Check that raw XML is a well-formed sequence of `SplmtryData` elements
+pub fn check_supplementary_data(raw_xml: &str) -> Result<(), XmlError> {
+    let mut reader = Reader::from_str(raw_xml);
+    let mut depth = 0usize;
+    let mut blocks = 0usize;
+    loop {
+        let event = reader.read_event().map_err(|e| {
+            MxError::XmlDeserialization(format!("Supplementary data is not well-formed: {}", e))
+        })?;
+        match &event {
+            Event::Eof => break,
+            Event::Start(e) | Event::Empty(e) if depth == 0 => {
+                if !is_supplementary_data(e) {
+                    return Err(MxError::XmlDeserialization(format!(
+                        "Supplementary data must consist of SplmtryData elements, found {}",
+                        String::from_utf8_lossy(e.local_name().as_ref())
+                    )));
+                }
+                blocks += 1;
+                if matches!(event, Event::Start(_)) {
+                    depth += 1;
+                }
+            }
+            Event::Start(_) => depth += 1,
+            Event::End(_) => depth -= 1,
+            Event::Text(text) if depth == 0 && !text.iter().all(u8::is_ascii_whitespace) => {
+                return Err(MxError::XmlDeserialization(
+                    "Supplementary data has text outside the SplmtryData elements".to_string(),
+                ));
+            }
+            _ => {}
+        }
+    }
+    if depth != 0 || blocks == 0 {
+        return Err(MxError::XmlDeserialization(
+            "Supplementary data must contain at least one complete SplmtryData element".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+fn is_supplementary_data(element: &BytesStart) -> bool {
+    element.local_name().as_ref() == b"SplmtryData"
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,4 +691,82 @@ mod tests {
         assert_eq!(get_document_element_name("camt.053"), "BkToCstmrStmt");
         assert_eq!(get_document_element_name("pain.001"), "CstmrCdtTrfInitn");
     }
+
+    #[test]
+    fn test_from_mx_xml_checks_namespace() {
+        use crate::document::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+
+        let message = crate::samples::sample_message("pacs.008", 5).unwrap();
+        let with_namespace = |namespace: &str| {
+            message
+                .to_xml()
+                .unwrap()
+                .replace("<Document>", &format!(r#"<Document xmlns="{namespace}">"#))
+        };
+
+        let xml = with_namespace("urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08");
+        let doc = from_mx_xml::<FIToFICustomerCreditTransferV08>(&xml).unwrap();
+        assert_eq!(
+            crate::mx_envelope::Document::Pacs008(Box::new(doc)),
+            message.document
+        );
+
+        let xml = with_namespace("urn:iso:std:iso:20022:tech:xsd:pacs.008.001.09");
+        let err = from_mx_xml::<FIToFICustomerCreditTransferV08>(&xml).unwrap_err();
+        assert!(matches!(err, MxError::VersionMismatch { .. }));
+        assert!(err.to_string().contains("pacs.008.001.09"));
+        assert!(err.to_string().contains("pacs.008.001.08"));
+    }
+
+    #[test]
+    fn test_from_mx_xml_checks_prefixed_namespace() {
+        use crate::document::pacs_008_001_08::FIToFICustomerCreditTransferV08;
+
+        let message = crate::samples::sample_message("pacs.008", 5).unwrap();
+        let with_prefix = |namespace: &str| {
+            message
+                .to_xml()
+                .unwrap()
+                .replace(
+                    "<Document>",
+                    &format!(r#"<ns:Document xmlns:ns="{namespace}">"#),
+                )
+                .replace("</Document>", "</ns:Document>")
+        };
+
+        let xml = with_prefix("urn:iso:std:iso:20022:tech:xsd:pacs.008.001.08");
+        let doc = from_mx_xml::<FIToFICustomerCreditTransferV08>(&xml).unwrap();
+        assert_eq!(
+            crate::mx_envelope::Document::Pacs008(Box::new(doc)),
+            message.document
+        );
+
+        let xml = with_prefix("urn:iso:std:iso:20022:tech:xsd:pacs.008.001.09");
+        let err = from_mx_xml::<FIToFICustomerCreditTransferV08>(&xml).unwrap_err();
+        assert!(
+            matches!(err, MxError::VersionMismatch { ref found, .. } if found.ends_with("pacs.008.001.09"))
+        );
+    }
+
+    #[test]
+    fn test_check_supplementary_data() {
+        assert!(
+            check_supplementary_data("<SplmtryData><Envlp><Ext>1</Ext></Envlp></SplmtryData>")
+                .is_ok()
+        );
+        assert!(check_supplementary_data("<SplmtryData><Envlp></SplmtryData>").is_err());
+        assert!(check_supplementary_data("<Envlp/>").is_err());
+        assert!(check_supplementary_data("text").is_err());
+        assert!(check_supplementary_data("").is_err());
+    }
+
+    #[test]
+    fn test_collapse_whitespace() {
+        assert_eq!(collapse_whitespace("ACME"), "ACME");
+        assert_eq!(
+            collapse_whitespace("\n    ACME\r\n\t  Corp  \n"),
+            "ACME Corp"
+        );
+        assert_eq!(collapse_whitespace(" \n\t "), "");
+    }
 }
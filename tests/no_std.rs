@@ -0,0 +1,31 @@
+//! Build test for the `no_std` feature
+//!
+//! Run with `cargo test --no-default-features --features no_std --test no_std`.
+//! The library is then compiled as `#![no_std]` with only the core validation
+//! modules; this test checks they build and behave like the std validators.
+
+use mx_message::core_validation::{
+    LengthViolation, check_length, find_disallowed_control, matches_pattern,
+};
+use mx_message::pattern::Pattern;
+
+const BIC_PATTERN: &str = "[A-Z0-9]{4,4}[A-Z]{2,2}[A-Z0-9]{2,2}([A-Z0-9]{3,3}){0,1}";
+const TEXT_PATTERN: &str = "[0-9a-zA-Z/\\-\\?:\\(\\)\\.,'\\+ ]+";
+
+#[test]
+fn test_core_validation_without_std() {
+    assert_eq!(check_length("MSG-001", Some(1), Some(35)), None);
+    assert_eq!(
+        check_length("", Some(1), Some(35)),
+        Some(LengthViolation::TooShort(1))
+    );
+    assert_eq!(find_disallowed_control("ACME\u{0}"), Some('\u{0}'));
+
+    assert_eq!(matches_pattern("DEUTDEFFXXX", BIC_PATTERN), Ok(true));
+    assert_eq!(matches_pattern("deutdeff", BIC_PATTERN), Ok(false));
+    assert!(
+        Pattern::new(TEXT_PATTERN)
+            .unwrap()
+            .is_match("ACME Trading Ltd.")
+    );
+}